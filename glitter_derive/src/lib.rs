@@ -0,0 +1,297 @@
+//! Custom derives for [glitter](https://github.com/kylewlacy/glitter).
+//! This crate shouldn't be used directly; instead, enable the `derive`
+//! feature of glitter, which re-exports these derives.
+
+extern crate proc_macro;
+extern crate syn;
+#[macro_use] extern crate quote;
+
+use proc_macro::TokenStream;
+
+/// Implement the `VertexData` trait for a `#[repr(C)]` struct (and,
+/// by extension, the `VertexBytes` trait). See the `VertexData` docs in
+/// glitter for more details.
+#[proc_macro_derive(VertexData, attributes(glitter))]
+pub fn derive_vertex_data(input: TokenStream) -> TokenStream {
+    let source = input.to_string();
+    let ast = syn::parse_derive_input(&source).unwrap();
+    let expanded = impl_vertex_data(&ast);
+    expanded.parse().unwrap()
+}
+
+// Options that can be set for a field with `#[glitter(...)]`
+struct FieldOptions {
+    name: Option<String>,
+    normalized: bool,
+    integer: bool,
+    components: Option<u64>,
+    nested: bool,
+    skip: bool
+}
+
+fn impl_vertex_data(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        ast.generics.split_for_impl();
+
+    let fields = match ast.body {
+        syn::Body::Struct(syn::VariantData::Struct(ref fields)) => { fields },
+        _ => {
+            panic!("#[derive(VertexData)] can only be used with structs with named fields");
+        }
+    };
+
+    let is_packed = match repr_options(&ast.attrs) {
+        Ok(is_packed) => { is_packed },
+        Err(message) => { panic!("{}", message); }
+    };
+
+    let attributes = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let options = field_options(field);
+
+        let align = if is_packed {
+            quote! { 1 }
+        }
+        else {
+            quote! { ::std::mem::align_of::<#ty>() }
+        };
+
+        let visit = if options.skip {
+            quote! { }
+        }
+        else if options.nested {
+            quote! {
+                ::glitter::vertex_data::visit_nested_attributes::<#ty, _>(
+                    offset,
+                    stride,
+                    &mut f
+                );
+            }
+        }
+        else {
+            let field_name = options.name.unwrap_or_else(|| {
+                field.ident.as_ref().unwrap().to_string()
+            });
+            let normalize = if options.normalized {
+                quote! { ty.normalize = true; }
+            }
+            else {
+                quote! { }
+            };
+            let integer = if options.integer {
+                quote! { ty.integer = true; }
+            }
+            else {
+                quote! { }
+            };
+            let components = match options.components {
+                Some(components) => {
+                    check_components(&field_name, ty, components);
+                    let components = components as i8;
+                    quote! {
+                        assert!(#components <= ty.components,
+                                "#[glitter(components = {})] is more than the {} components of field {:?}",
+                                #components,
+                                ty.components,
+                                #field_name);
+                        ty.components = #components;
+                    }
+                },
+                None => { quote! { } }
+            };
+
+            quote! {
+                let mut ty = <#ty as ::glitter::VertexDatum>::attrib_type();
+                #normalize
+                #integer
+                #components
+                f(::glitter::VertexAttribute {
+                    ty: ty,
+                    name: #field_name.into(),
+                    offset: offset,
+                    stride: stride
+                });
+            }
+        };
+
+        // Fields of a `#[repr(C)]` struct are laid out in order, each
+        // starting at the next offset that satisfies its alignment
+        quote! {
+            let align = #align;
+            offset = (offset + align - 1) / align * align;
+            #visit
+            offset += ::std::mem::size_of::<#ty>();
+        }
+    });
+
+    quote! {
+        unsafe impl #impl_generics ::glitter::VertexData for #name #ty_generics
+            #where_clause
+        {
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            fn visit_attributes<F>(mut f: F)
+                where F: FnMut(::glitter::VertexAttribute)
+            {
+                let stride = ::std::mem::size_of::<Self>();
+                let mut offset: usize = 0;
+                #(#attributes)*
+            }
+        }
+    }
+}
+
+// Returns whether the struct is `#[repr(C, packed)]` (rather than just
+// `#[repr(C)]`). The offsets are computed by hand from the size and
+// alignment of each field, so any other representation (such as
+// `#[repr(C, packed(2))]` or `#[repr(C, align(16))]`) is an error, since the
+// computed offsets would be wrong.
+fn repr_options(attrs: &[syn::Attribute]) -> Result<bool, String> {
+    let mut is_repr_c = false;
+    let mut is_packed = false;
+    for attr in attrs {
+        let items = match attr.value {
+            syn::MetaItem::List(ref ident, ref items) if ident == "repr" => {
+                items
+            },
+            _ => { continue; }
+        };
+
+        for item in items {
+            match *item {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref word))
+                    if word == "C" =>
+                {
+                    is_repr_c = true;
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref word))
+                    if word == "packed" =>
+                {
+                    is_packed = true;
+                },
+                _ => {
+                    return Err(format!("#[derive(VertexData)] only supports #[repr(C)] and #[repr(C, packed)], not #[repr({})]",
+                                       quote! { #item }));
+                }
+            }
+        }
+    }
+
+    if is_repr_c {
+        Ok(is_packed)
+    }
+    else {
+        Err(String::from("#[derive(VertexData)] requires the struct to be #[repr(C)]"))
+    }
+}
+
+// Check a `#[glitter(components = N)]` option as far as possible without
+// knowing the field type's `VertexDatum` impl. An attribute has between 1
+// and 4 components, and an array field can't have more components than its
+// length. Otherwise, the generated code checks against the type's
+// component count.
+fn check_components(field_name: &str, ty: &syn::Ty, components: u64) {
+    if components < 1 || components > 4 {
+        panic!("#[glitter(components = {})] on field {:?} must be between 1 and 4",
+               components, field_name);
+    }
+
+    if let syn::Ty::Array(_, syn::ConstExpr::Lit(syn::Lit::Int(len, _))) = *ty {
+        if components > len {
+            panic!("#[glitter(components = {})] on field {:?} is more than the {} components of the field's type",
+                   components, field_name, len);
+        }
+    }
+}
+
+fn field_options(field: &syn::Field) -> FieldOptions {
+    let mut options = FieldOptions {
+        name: None,
+        normalized: false,
+        integer: false,
+        components: None,
+        nested: false,
+        skip: false
+    };
+
+    for attr in &field.attrs {
+        let items = match attr.value {
+            syn::MetaItem::List(ref ident, ref items) if ident == "glitter" => {
+                items
+            },
+            _ => { continue; }
+        };
+
+        for item in items {
+            match *item {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref word))
+                    if word == "normalized" =>
+                {
+                    options.normalized = true;
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref word))
+                    if word == "integer" =>
+                {
+                    options.integer = true;
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref word))
+                    if word == "nested" =>
+                {
+                    options.nested = true;
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref word))
+                    if word == "skip" =>
+                {
+                    options.skip = true;
+                },
+                syn::NestedMetaItem::MetaItem(
+                    syn::MetaItem::NameValue(ref key,
+                                             syn::Lit::Str(ref value, _))
+                ) if key == "name" => {
+                    options.name = Some(value.clone());
+                },
+                syn::NestedMetaItem::MetaItem(
+                    syn::MetaItem::NameValue(ref key,
+                                             syn::Lit::Int(value, _))
+                ) if key == "components" => {
+                    options.components = Some(value);
+                },
+                _ => {
+                    panic!("Unknown #[glitter(...)] option: {}",
+                           quote! { #item });
+                }
+            }
+        }
+    }
+
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::repr_options;
+    use syn;
+
+    fn repr_of(attrs: &str) -> Result<bool, String> {
+        let source = format!("{} struct Vertex {{ position: [f32; 2] }}",
+                             attrs);
+        let ast = syn::parse_derive_input(&source).unwrap();
+        repr_options(&ast.attrs)
+    }
+
+    #[test]
+    fn accepts_repr_c() {
+        assert_eq!(repr_of("#[repr(C)]"), Ok(false));
+        assert_eq!(repr_of("#[repr(C, packed)]"), Ok(true));
+        assert_eq!(repr_of("#[repr(C)] #[repr(packed)]"), Ok(true));
+    }
+
+    #[test]
+    fn rejects_other_representations() {
+        assert!(repr_of("").is_err());
+        assert!(repr_of("#[repr(packed)]").is_err());
+        assert!(repr_of("#[repr(C, packed(2))]").is_err());
+        assert!(repr_of("#[repr(C, align(16))]").is_err());
+        assert!(repr_of("#[repr(C)] #[repr(align(16))]").is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! Contains [`TextureAtlas`](struct.TextureAtlas.html), which packs many
+//! small images (such as font glyphs or sprites) into one texture. Enabled
+//! with the `atlas` feature.
+//!
+//! # Example
+//! ```no_run
+//! use glitter::prelude::*;
+//! use glitter::{Pixel, Pixels, ImageFormat};
+//! use glitter::atlas::TextureAtlas;
+//!
+//! let mut gl = unsafe { glitter::Context::current_context() };
+//! let mut atlas = TextureAtlas::new(&mut gl, ImageFormat::rgba8(), 512, 512)
+//!     .unwrap()
+//!     .padding(1);
+//!
+//! let glyph = Pixels::solid(12, 16, Pixel::rgb(0xFFFFFF));
+//! let region = atlas.allocate(12, 16).expect("The atlas is full");
+//!
+//! let (gl_tex_unit, gl) = gl.active_texture_0();
+//! atlas.upload(&gl, gl_tex_unit, &region, &glyph);
+//!
+//! let tex_coords = region.tex_coords();
+//! ```
+
+use context::{ContextTextureExt, ContextTextureBuilderExt,
+              TextureUnitBinding2d};
+use texture::{Texture2d, TEXTURE_2D, LINEAR, CLAMP_TO_EDGE};
+use image_data::{Image2d, Image2dBuf, ImageFormat};
+use types::{GLError, Rect};
+
+/// A region of a [`TextureAtlas`](struct.TextureAtlas.html), as returned
+/// by [`allocate`](struct.TextureAtlas.html#method.allocate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtlasRegion {
+    /// The region's position and size within the atlas texture, in texels.
+    pub rect: Rect,
+
+    atlas_width: u32,
+    atlas_height: u32
+}
+
+impl AtlasRegion {
+    /// Get the texture coordinates of the region's corners, as
+    /// `[u_min, v_min, u_max, v_max]`.
+    pub fn tex_coords(&self) -> [f32; 4] {
+        let (width, height) = (self.atlas_width as f32,
+                               self.atlas_height as f32);
+        [
+            self.rect.x as f32 / width,
+            self.rect.y as f32 / height,
+            (self.rect.x + self.rect.width) as f32 / width,
+            (self.rect.y + self.rect.height) as f32 / height
+        ]
+    }
+}
+
+// A row of the atlas. Regions are placed in a shelf from left to right,
+// and shelves are stacked from the bottom of the atlas to the top.
+struct Shelf {
+    y: u32,
+    height: u32,
+    next_x: u32
+}
+
+// Allocates space for a `TextureAtlas`, without touching the texture
+struct ShelfPacker {
+    width: u32,
+    height: u32,
+    padding: u32,
+    shelves: Vec<Shelf>
+}
+
+impl ShelfPacker {
+    fn new(width: u32, height: u32) -> Self {
+        ShelfPacker {
+            width: width,
+            height: height,
+            padding: 0,
+            shelves: vec![]
+        }
+    }
+
+    // Find space for a `width` by `height` region, returning its position.
+    // Sizes that would overflow a `u32` never fit, so huge sizes (or
+    // paddings) are rejected rather than wrapping around.
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let (atlas_width, padding) = (self.width, self.padding);
+        let best_shelf = self.shelves.iter_mut()
+            .filter(|shelf| {
+                shelf.height >= height
+                    && shelf.next_x.checked_add(width)
+                                   .map_or(false, |end| end <= atlas_width)
+            })
+            .min_by_key(|shelf| shelf.height - height);
+
+        if let Some(shelf) = best_shelf {
+            let x = shelf.next_x;
+            // A full shelf is fine, since nothing else will fit in it
+            shelf.next_x = x.saturating_add(width).saturating_add(padding);
+            return Some((x, shelf.y));
+        }
+
+        let next_y = match self.shelves.last() {
+            Some(shelf) => {
+                shelf.y.checked_add(shelf.height)
+                       .and_then(|y| y.checked_add(padding))
+            },
+            None => { Some(0) }
+        };
+        let y = match next_y {
+            Some(y) => { y },
+            None => { return None; }
+        };
+        let fits = width <= self.width
+            && y.checked_add(height).map_or(false, |end| end <= self.height);
+        if !fits {
+            return None;
+        }
+
+        self.shelves.push(Shelf {
+            y: y,
+            height: height,
+            next_x: width.saturating_add(padding)
+        });
+        Some((0, y))
+    }
+
+    fn reset(&mut self) {
+        self.shelves.clear();
+    }
+}
+
+/// A 2D texture that small images are packed into. Space is allocated
+/// using a "shelf" packer: images are placed next to each other in rows
+/// (shelves), and each image is placed in the shelf that wastes the least
+/// space, or in a new shelf if none fit. This works best when images have
+/// similar heights, like the glyphs of a font.
+///
+/// Space can't be freed individually, but the whole atlas can be cleared
+/// with [`reset`](struct.TextureAtlas.html#method.reset) (such as when
+/// a glyph cache is full).
+pub struct TextureAtlas {
+    texture: Texture2d,
+    packer: ShelfPacker
+}
+
+impl TextureAtlas {
+    /// Create a new atlas with an empty texture, which is cleared to all
+    /// zeros, and uses linear filtering without mipmaps.
+    ///
+    /// # Failures
+    /// An error will be returned if the texture could not be built (such
+    /// as if `width` or `height` is 0).
+    pub fn new<C>(gl: C, format: ImageFormat, width: u32, height: u32)
+        -> Result<Self, GLError>
+        where C: ContextTextureBuilderExt
+    {
+        let len = width as usize * height as usize * format.bytes_per_texel();
+        let zeros = Image2dBuf::new(vec![0; len],
+                                    width as usize,
+                                    height as usize,
+                                    format);
+        let texture = try!(gl.build_texture_2d()
+                             .image_2d(&zeros)
+                             .min_filter(LINEAR)
+                             .mag_filter(LINEAR)
+                             .wrap_s(CLAMP_TO_EDGE)
+                             .wrap_t(CLAMP_TO_EDGE)
+                             .try_unwrap());
+        Ok(TextureAtlas::from_texture(texture, width, height))
+    }
+
+    /// Create an atlas that allocates space within an existing texture,
+    /// which must be `width` by `height` texels.
+    pub fn from_texture(texture: Texture2d, width: u32, height: u32) -> Self {
+        TextureAtlas {
+            texture: texture,
+            packer: ShelfPacker::new(width, height)
+        }
+    }
+
+    /// Leave `padding` texels of space between each allocated region, so
+    /// that linear filtering doesn't blend neighboring images together.
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.packer.padding = padding;
+        self
+    }
+
+    /// Get the width and height of the atlas texture.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.packer.width, self.packer.height)
+    }
+
+    /// Get the atlas texture.
+    pub fn texture(&self) -> &Texture2d {
+        &self.texture
+    }
+
+    /// Get the atlas texture mutably, such as to bind it for drawing.
+    pub fn texture_mut(&mut self) -> &mut Texture2d {
+        &mut self.texture
+    }
+
+    /// Convert the atlas into its texture.
+    pub fn into_texture(self) -> Texture2d {
+        self.texture
+    }
+
+    /// Allocate a `width` by `height` region of the atlas, or return `None`
+    /// if there isn't enough space left (or if `width` or `height` is 0).
+    pub fn allocate(&mut self, width: u32, height: u32)
+        -> Option<AtlasRegion>
+    {
+        self.packer.allocate(width, height).map(|(x, y)| {
+            AtlasRegion {
+                rect: Rect::new(x, y, width, height),
+                atlas_width: self.packer.width,
+                atlas_height: self.packer.height
+            }
+        })
+    }
+
+    /// Upload an image to a region of the atlas, using [`gl.tex_sub_image_2d`]
+    /// (../context/texture_context/trait.ContextTextureExt.html#method.tex_sub_image_2d).
+    /// The atlas texture is bound to `gl_tex_unit` to upload the image.
+    ///
+    /// # Panics
+    /// This function will panic if the image's dimensions don't match the
+    /// region's dimensions.
+    pub fn upload<C, U, I: ?Sized>(&mut self,
+                                   gl: &C,
+                                   gl_tex_unit: U,
+                                   region: &AtlasRegion,
+                                   image: &I)
+        where C: ContextTextureExt, U: TextureUnitBinding2d, I: Image2d
+    {
+        assert!(image.width() == region.rect.width as usize
+                && image.height() == region.rect.height as usize,
+                "Expected a {}x{} image for the atlas region, got {}x{}",
+                region.rect.width, region.rect.height,
+                image.width(), image.height());
+
+        let (mut gl_texture, _) = gl_tex_unit.bind_texture_2d(&mut self.texture);
+        gl.tex_sub_image_2d(&mut gl_texture,
+                            TEXTURE_2D,
+                            0,
+                            region.rect.x,
+                            region.rect.y,
+                            image);
+    }
+
+    /// Free every region of the atlas, so that its space can be allocated
+    /// again. The texture's contents are left unchanged.
+    pub fn reset(&mut self) {
+        self.packer.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::u32;
+    use super::ShelfPacker;
+
+    #[test]
+    fn packs_regions_into_shelves() {
+        let mut packer = ShelfPacker::new(10, 10);
+        assert_eq!(packer.allocate(4, 3), Some((0, 0)));
+        assert_eq!(packer.allocate(4, 2), Some((4, 0)));
+        // Too wide for the first shelf, so a new one is started
+        assert_eq!(packer.allocate(4, 3), Some((0, 3)));
+        assert_eq!(packer.allocate(2, 3), Some((8, 0)));
+    }
+
+    #[test]
+    fn picks_the_shelf_that_wastes_the_least_space() {
+        let mut packer = ShelfPacker::new(10, 10);
+        assert_eq!(packer.allocate(2, 4), Some((0, 0)));
+        assert_eq!(packer.allocate(9, 2), Some((0, 4)));
+        // Fits in both shelves, but the second one is a closer fit
+        assert_eq!(packer.allocate(1, 2), Some((9, 4)));
+        assert_eq!(packer.allocate(1, 3), Some((2, 0)));
+    }
+
+    #[test]
+    fn leaves_padding_between_regions() {
+        let mut packer = ShelfPacker::new(10, 10);
+        packer.padding = 1;
+        assert_eq!(packer.allocate(4, 4), Some((0, 0)));
+        assert_eq!(packer.allocate(4, 4), Some((5, 0)));
+        assert_eq!(packer.allocate(4, 4), Some((0, 5)));
+    }
+
+    #[test]
+    fn rejects_regions_that_dont_fit() {
+        let mut packer = ShelfPacker::new(10, 10);
+        assert_eq!(packer.allocate(0, 4), None);
+        assert_eq!(packer.allocate(11, 1), None);
+        assert_eq!(packer.allocate(1, 11), None);
+        assert_eq!(packer.allocate(10, 6), Some((0, 0)));
+        assert_eq!(packer.allocate(10, 5), None);
+
+        packer.reset();
+        assert_eq!(packer.allocate(10, 10), Some((0, 0)));
+    }
+
+    #[test]
+    fn rejects_sizes_that_overflow() {
+        let mut packer = ShelfPacker::new(u32::MAX, u32::MAX);
+        packer.padding = u32::MAX;
+        assert_eq!(packer.allocate(u32::MAX, 1), Some((0, 0)));
+        assert_eq!(packer.allocate(1, 1), None);
+        assert_eq!(packer.allocate(u32::MAX, u32::MAX), None);
+
+        let mut packer = ShelfPacker::new(10, u32::MAX);
+        assert_eq!(packer.allocate(10, u32::MAX - 1), Some((0, 0)));
+        assert_eq!(packer.allocate(4, 2), None);
+        assert_eq!(packer.allocate(4, u32::MAX), None);
+    }
+}
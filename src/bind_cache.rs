@@ -0,0 +1,178 @@
+// Remembers the object bound to each binding target (along with the current
+// program) through glitter, so that binding an object that's already bound
+// can be skipped. The active texture unit is always tracked by the context,
+// and is passed along here so texture bindings can be cached per unit. Caching is enabled with
+// the `bind-cache` feature; otherwise, every bind is made.
+//
+// The cache is kept per thread, and is only correct as long as every bind
+// goes through glitter. It's cleared when a context is made current (since
+// each context has its own bindings) and after `ContextOf::with_raw`.
+
+use gl::types::*;
+use leaks::ObjectKind;
+
+#[cfg(feature = "bind-cache")]
+mod imp {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use gl;
+    use gl::types::*;
+    use leaks::ObjectKind;
+
+    struct BindCache {
+        // The object bound to each target, keyed by binding target and
+        // texture unit (which is always 0 for non-texture targets)
+        bound: HashMap<(GLenum, u32), GLuint>,
+
+        active_texture: Option<u32>,
+        program: Option<GLuint>
+    }
+
+    // Get the type of object that can be bound to a binding target
+    fn _target_kind(target: GLenum) -> Option<ObjectKind> {
+        match target {
+            gl::ARRAY_BUFFER | gl::ELEMENT_ARRAY_BUFFER
+            | gl::COPY_READ_BUFFER | gl::COPY_WRITE_BUFFER => {
+                Some(ObjectKind::Buffer)
+            },
+            // NOTE: 0x8D65 is `GL_TEXTURE_EXTERNAL_OES`
+            gl::TEXTURE_2D | gl::TEXTURE_CUBE_MAP | 0x8D65
+            | gl::TEXTURE_2D_MULTISAMPLE | gl::TEXTURE_2D_ARRAY
+            | gl::TEXTURE_3D => {
+                Some(ObjectKind::Texture)
+            },
+            gl::FRAMEBUFFER => { Some(ObjectKind::Framebuffer) },
+            gl::RENDERBUFFER => { Some(ObjectKind::Renderbuffer) },
+            _ => { None }
+        }
+    }
+
+    thread_local! {
+        static CACHE: RefCell<BindCache> = RefCell::new(BindCache {
+            bound: HashMap::new(),
+            active_texture: None,
+            program: None
+        })
+    }
+
+    fn _with_cache<F, V>(default: V, f: F) -> V
+        where F: FnOnce(&mut BindCache) -> V
+    {
+        // NOTE: `try_with` is used because objects can be dropped while
+        //       thread-locals are being destroyed
+        CACHE.try_with(|cache| f(&mut cache.borrow_mut())).unwrap_or(default)
+    }
+
+    pub fn bind(target: GLenum, id: GLuint) -> bool {
+        _with_cache(true, |cache| {
+            let key = match _target_kind(target) {
+                Some(ObjectKind::Texture) => {
+                    match cache.active_texture {
+                        Some(unit) => { (target, unit) },
+                        None => { return true; }
+                    }
+                },
+                Some(_) => { (target, 0) },
+                None => { return true; }
+            };
+
+            cache.bound.insert(key, id) != Some(id)
+        })
+    }
+
+    pub fn active_texture(idx: Option<u32>) {
+        _with_cache((), |cache| {
+            cache.active_texture = idx;
+        });
+    }
+
+    pub fn use_program(id: GLuint) -> bool {
+        _with_cache(true, |cache| {
+            let changed = cache.program != Some(id);
+            cache.program = Some(id);
+            changed
+        })
+    }
+
+    pub fn delete(kind: ObjectKind, id: GLuint) {
+        _with_cache((), |cache| {
+            // Deleting an object unbinds it from the current context (and
+            // its ID can be reused), so it's forgotten rather than reset
+            // to 0
+            cache.bound.retain(|&(target, _), &mut bound_id| {
+                bound_id != id || _target_kind(target) != Some(kind)
+            });
+            if kind == ObjectKind::Program && cache.program == Some(id) {
+                cache.program = None;
+            }
+        });
+    }
+
+    pub fn forget() {
+        _with_cache((), |cache| {
+            cache.bound.clear();
+            cache.active_texture = None;
+            cache.program = None;
+        });
+    }
+}
+
+#[cfg(not(feature = "bind-cache"))]
+mod imp {
+    use gl::types::*;
+    use leaks::ObjectKind;
+
+    #[inline(always)]
+    pub fn bind(_target: GLenum, _id: GLuint) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    pub fn active_texture(_idx: Option<u32>) {
+
+    }
+
+    #[inline(always)]
+    pub fn use_program(_id: GLuint) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    pub fn delete(_kind: ObjectKind, _id: GLuint) {
+
+    }
+
+    #[inline(always)]
+    pub fn forget() {
+
+    }
+}
+
+// Called before binding an object to `target` through glitter. Returns
+// `false` if the object is already bound, so the bind can be skipped.
+pub fn _bind(target: GLenum, id: GLuint) -> bool {
+    imp::bind(target, id)
+}
+
+// Called when the active texture unit changes, or becomes known (`None`
+// means the active texture unit is unknown)
+pub fn _active_texture(idx: Option<u32>) {
+    imp::active_texture(idx);
+}
+
+// Called before changing the current program. Returns `false` if the
+// program is already current.
+pub fn _use_program(id: GLuint) -> bool {
+    imp::use_program(id)
+}
+
+// Called when an object is deleted
+pub fn _delete(kind: ObjectKind, id: GLuint) {
+    imp::delete(kind, id);
+}
+
+// Called when the bindings may have been changed outside of glitter, or
+// when a different context is made current
+pub fn _forget() {
+    imp::forget();
+}
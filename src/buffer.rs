@@ -2,8 +2,13 @@
 
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
-use types::GLObject;
+use context::BaseContext;
+use types::{GLObject, GLError};
+use leaks::{self, ObjectKind};
+use bind_cache;
+use stats;
 
 /// An OpenGL buffer object.
 ///
@@ -32,8 +37,11 @@ pub struct Buffer {
 
 impl Drop for Buffer {
     fn drop(&mut self) {
+        leaks::_untrack(ObjectKind::Buffer, self.gl_id);
+        bind_cache::_delete(ObjectKind::Buffer, self.gl_id);
+        stats::_untrack(ObjectKind::Buffer, self.gl_id);
         unsafe {
-            gl::DeleteBuffers(1, &self.gl_id as *const GLuint);
+            gl_fns::DeleteBuffers(1, &self.gl_id as *const GLuint);
         }
     }
 }
@@ -42,6 +50,8 @@ impl GLObject for Buffer {
     type Id = GLuint;
 
     unsafe fn from_raw(id: Self::Id) -> Self {
+        leaks::_track(ObjectKind::Buffer, id);
+        stats::_track(ObjectKind::Buffer, id);
         Buffer {
             gl_id: id,
             _phantom: PhantomData
@@ -53,6 +63,39 @@ impl GLObject for Buffer {
     }
 }
 
+impl Buffer {
+    /// Take ownership of a buffer that was created outside of glitter (such
+    /// as by another library), after checking that `id` names a buffer
+    /// in the current context.
+    /// The buffer will be deleted when the returned object is dropped.
+    ///
+    /// Note that OpenGL doesn't create the buffer until the ID is first bound,
+    /// so an ID from `glGenBuffers` that was never bound is rejected.
+    ///
+    /// # Safety
+    /// The ID must not be owned by anything else (such as another glitter
+    /// object, or another library that will delete it). Otherwise, the
+    /// buffer will be deleted twice.
+    ///
+    /// # Failures
+    /// A [`GLError::InvalidObject`](../types/enum.GLError.html#variant.InvalidObject)
+    /// error will be returned if `id` is not a buffer.
+    ///
+    /// # See also
+    /// [`glIsBuffer`](http://docs.gl/es2/glIsBuffer) OpenGL docs
+    pub unsafe fn adopt<C: BaseContext>(id: GLuint, _gl: &C)
+        -> Result<Self, GLError>
+    {
+        let is_buffer = gl_fns::IsBuffer(id) == gl::TRUE;
+        if is_buffer {
+            Ok(Buffer::from_raw(id))
+        }
+        else {
+            Err(GLError::InvalidObject(ObjectKind::Buffer, id))
+        }
+    }
+}
+
 
 
 gl_enum! {
@@ -71,6 +114,33 @@ gl_enum! {
     }
 }
 
+bitflags! {
+    /// The ways a mapped range of a buffer may be accessed, used with
+    /// [`gl.map_buffer_range`]
+    /// (../context/buffer_context/trait.ContextBufferExt.html#method.map_buffer_range).
+    pub flags BufferMapAccess: ::gl::types::GLbitfield {
+        /// The mapped range may be read from.
+        const MAP_READ_BIT = ::gl::MAP_READ_BIT,
+
+        /// The mapped range may be written to.
+        const MAP_WRITE_BIT = ::gl::MAP_WRITE_BIT,
+
+        /// The previous contents of the mapped range may be discarded.
+        const MAP_INVALIDATE_RANGE_BIT = ::gl::MAP_INVALIDATE_RANGE_BIT,
+
+        /// The previous contents of the entire buffer may be discarded.
+        const MAP_INVALIDATE_BUFFER_BIT = ::gl::MAP_INVALIDATE_BUFFER_BIT,
+
+        /// Modified parts of the mapped range will be flushed explicitly,
+        /// rather than all at once when the buffer is unmapped.
+        const MAP_FLUSH_EXPLICIT_BIT = ::gl::MAP_FLUSH_EXPLICIT_BIT,
+
+        /// OpenGL should not wait for pending operations on the buffer
+        /// to finish before mapping it.
+        const MAP_UNSYNCHRONIZED_BIT = ::gl::MAP_UNSYNCHRONIZED_BIT
+    }
+}
+
 gl_enum! {
     /// All of the possible OpenGL targets for binding a buffer object.
     pub gl_enum BufferBindingTarget {
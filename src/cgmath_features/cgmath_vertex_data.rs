@@ -6,7 +6,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for cgmath::Vector2<T> {
         VertexAttributeType {
             data: T::data_type(),
             components: 2,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -16,7 +17,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for cgmath::Vector3<T> {
         VertexAttributeType {
             data: T::data_type(),
             components: 3,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -26,7 +28,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for cgmath::Vector4<T> {
         VertexAttributeType {
             data: T::data_type(),
             components: 4,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -5,28 +5,58 @@ use std::ptr;
 use std::marker::PhantomData;
 use std::borrow::BorrowMut;
 use gl;
+use gl_fns;
 use gl::types::*;
 use context::{ContextOf, BaseContext, AContext};
-use buffer::{Buffer, BufferDataUsage, BufferBindingTarget};
+use super::_current_extensions;
+use buffer::{Buffer, BufferDataUsage, BufferBindingTarget, BufferMapAccess};
 use program::{ProgramAttrib};
 use index_data::{IndexData, IndexDatum, IndexDatumType};
 use types::{DataType, DrawingMode, GLObject, GLError};
+use extensions::Extensions;
 use to_ref::{ToRef, ToMut};
+use validate::BindingCheck;
+use stats;
+use frame_stats;
+use bind_cache;
+
+// Panic if primitives can't be drawn with `mode` in the current context.
+// Drawing with an unsupported mode would otherwise only generate
+// a `GL_INVALID_ENUM` error, which isn't checked in release builds
+fn _check_drawing_mode(mode: DrawingMode, extensions: &Extensions) {
+    if let Err(e) = mode.check_supported(extensions) {
+        panic!("Can't draw with {:?}: {}", mode, e);
+    }
+}
 
 unsafe fn _draw_elements(mode: DrawingMode,
                          count: usize,
                          index_type: IndexDatumType,
                          indices: *const GLvoid)
 {
+    _check_drawing_mode(mode, &_current_extensions());
     let gl_index_type: GLenum = match index_type {
         IndexDatumType::UnsignedByte => gl::UNSIGNED_BYTE,
-        IndexDatumType::UnsignedShort => gl::UNSIGNED_SHORT
+        IndexDatumType::UnsignedShort => gl::UNSIGNED_SHORT,
+        IndexDatumType::UnsignedInt => {
+            debug_assert!(_current_extensions().has_element_index_uint(),
+                          "32-bit indices are not supported by the current context");
+            gl::UNSIGNED_INT
+        }
     };
-    gl::DrawElements(mode.gl_enum(),
+    let _span = gl_span!("glDrawElements",
+                         mode = ?mode,
+                         count = count,
+                         index_type = ?index_type);
+    gl_fns::DrawElements(mode.gl_enum(),
                      count as GLsizei,
                      gl_index_type,
                      indices);
+    frame_stats::_draw();
     dbg_gl_error! {
+        in "glDrawElements"(mode = mode,
+                            count = count,
+                            index_type = index_type);
         GLError::InvalidEnum => "`mode` or `type` is not an accepted value",
         GLError::InvalidValue => "`count` is negative",
         GLError::InvalidFramebufferOperation => "The current framebuffer is not framebuffer-complete",
@@ -34,10 +64,17 @@ unsafe fn _draw_elements(mode: DrawingMode,
     }
 }
 
-fn _bind_buffer(target: BufferBindingTarget, buffer: &mut Buffer) {
+fn _bind_buffer(target: BufferBindingTarget, id: GLuint) {
+    if !bind_cache::_bind(target.gl_enum(), id) {
+        return;
+    }
+
     unsafe {
-        gl::BindBuffer(target as GLuint, buffer.id());
+        gl_fns::BindBuffer(target as GLuint, id);
+        frame_stats::_state_change();
+        stats::_bind(target.gl_enum(), id);
         dbg_gl_sanity_check! {
+            in "glBindBuffer"(target = target, buffer = id);
             GLError::InvalidEnum => "`target` is not an allowed value",
             _ => "Unknown error"
         }
@@ -63,9 +100,10 @@ pub trait ContextBufferExt: BaseContext {
         let mut id : GLuint = 0;
 
         unsafe {
-            gl::GenBuffers(1, &mut id as *mut GLuint);
+            gl_fns::GenBuffers(1, &mut id as *mut GLuint);
         }
         dbg_gl_sanity_check! {
+            in "glGenBuffers"();
             GLError::InvalidValue => "`n` is negative",
             _ => "Unknown error"
         }
@@ -84,14 +122,61 @@ pub trait ContextBufferExt: BaseContext {
                        usage: BufferDataUsage)
         where B: BufferBinding
     {
+        gl_buffer.validate();
         let ptr = bytes.as_ptr();
         let size = bytes.len() * mem::size_of::<u8>();
+        let _span = gl_span!("glBufferData",
+                             target = ?gl_buffer.target(),
+                             bytes = size);
         unsafe {
-            gl::BufferData(gl_buffer.target().gl_enum(),
+            gl_fns::BufferData(gl_buffer.target().gl_enum(),
                            size as GLsizeiptr,
                            ptr as *const GLvoid,
                            usage.gl_enum());
+            stats::_set_size(gl_buffer.target().gl_enum(), None, size);
+            frame_stats::_buffer_upload(size);
+            dbg_gl_error! {
+                in "glBufferData"(target = gl_buffer.target(),
+                                  size = size,
+                                  usage = usage);
+                GLError::InvalidEnum => "Invalid `target` or `usage`",
+                GLError::InvalidValue => "`size` is negative",
+                GLError::InvalidOperation => "Object 0 is bound to buffer target",
+                GLError::OutOfMemory => "Unable to create a large enough buffer",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Allocate `size` bytes of storage for a buffer object, leaving the
+    /// contents of the buffer undefined. Note that this will replace the
+    /// buffer's current contents, if any. This is also useful for
+    /// "orphaning" a buffer that is updated every frame, which lets OpenGL
+    /// allocate new storage rather than waiting for previous draw calls to
+    /// finish reading from the old storage.
+    ///
+    /// # See also
+    /// [`glBufferData`](http://docs.gl/es2/glBufferData) OpenGL docs
+    fn allocate_buffer_bytes<B>(&self,
+                                gl_buffer: &mut B,
+                                size: usize,
+                                usage: BufferDataUsage)
+        where B: BufferBinding
+    {
+        gl_buffer.validate();
+        let _span = gl_span!("glBufferData",
+                             target = ?gl_buffer.target(),
+                             bytes = size);
+        unsafe {
+            gl_fns::BufferData(gl_buffer.target().gl_enum(),
+                           size as GLsizeiptr,
+                           ptr::null(),
+                           usage.gl_enum());
+            stats::_set_size(gl_buffer.target().gl_enum(), None, size);
             dbg_gl_error! {
+                in "glBufferData"(target = gl_buffer.target(),
+                                  size = size,
+                                  usage = usage);
                 GLError::InvalidEnum => "Invalid `target` or `usage`",
                 GLError::InvalidValue => "`size` is negative",
                 GLError::InvalidOperation => "Object 0 is bound to buffer target",
@@ -101,6 +186,196 @@ pub trait ContextBufferExt: BaseContext {
         }
     }
 
+    /// Reallocate a buffer object's storage to be `new_size` bytes, keeping
+    /// the first `old_size` bytes of its current contents. Any bytes past
+    /// `old_size` are left undefined. The contents are preserved by copying
+    /// them through a temporary buffer object bound to
+    /// `GL_COPY_WRITE_BUFFER`, which is left unbound afterwards.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if `old_size` isn't 0 and copying between
+    /// buffers is not supported by the current context (see
+    /// [`Extensions::has_copy_buffer`]
+    /// (../../extensions/struct.Extensions.html#method.has_copy_buffer)).
+    ///
+    /// # Panics
+    /// This function will panic in debug mode if `old_size` is greater than
+    /// `new_size`, or if `old_size` is greater than the buffer's current size.
+    ///
+    /// # See also
+    /// [`glCopyBufferSubData`](http://docs.gl/es3/glCopyBufferSubData)
+    /// OpenGL docs
+    fn grow_buffer_bytes<B>(&self,
+                            gl_buffer: &mut B,
+                            old_size: usize,
+                            new_size: usize,
+                            usage: BufferDataUsage)
+        -> Result<(), GLError>
+        where B: BufferBinding
+    {
+        gl_buffer.validate();
+        debug_assert!(old_size <= new_size);
+
+        let target = gl_buffer.target().gl_enum();
+        if old_size == 0 {
+            self.allocate_buffer_bytes(gl_buffer, new_size, usage);
+            return Ok(());
+        }
+
+        if !self._extensions().has_copy_buffer() {
+            return Err(GLError::Unsupported("copying between buffers"));
+        }
+
+        let _span = gl_span!("grow_buffer_bytes",
+                             target = ?gl_buffer.target(),
+                             old_bytes = old_size,
+                             new_bytes = new_size);
+        unsafe {
+            // `GL_COPY_WRITE_BUFFER` is not tracked by any context, so
+            // it's free to use for the temporary buffer
+            let mut temp: GLuint = 0;
+            gl_fns::GenBuffers(1, &mut temp as *mut GLuint);
+            bind_cache::_bind(gl::COPY_WRITE_BUFFER, temp);
+            gl_fns::BindBuffer(gl::COPY_WRITE_BUFFER, temp);
+            gl_fns::BufferData(gl::COPY_WRITE_BUFFER,
+                           old_size as GLsizeiptr,
+                           ptr::null(),
+                           gl::STREAM_COPY);
+            gl_fns::CopyBufferSubData(target,
+                                  gl::COPY_WRITE_BUFFER,
+                                  0,
+                                  0,
+                                  old_size as GLsizeiptr);
+            dbg_gl_error! {
+                in "glCopyBufferSubData"();
+                GLError::InvalidValue => "`old_size` is larger than the buffer",
+                GLError::OutOfMemory => "Unable to create a large enough buffer",
+                _ => "Unknown error"
+            }
+
+            gl_fns::BufferData(target,
+                           new_size as GLsizeiptr,
+                           ptr::null(),
+                           usage.gl_enum());
+            stats::_set_size(target, None, new_size);
+            gl_fns::CopyBufferSubData(gl::COPY_WRITE_BUFFER,
+                                  target,
+                                  0,
+                                  0,
+                                  old_size as GLsizeiptr);
+            dbg_gl_error! {
+                in "glCopyBufferSubData"();
+                GLError::InvalidOperation => "Object 0 is bound to buffer target",
+                GLError::OutOfMemory => "Unable to create a large enough buffer",
+                _ => "Unknown error"
+            }
+
+            bind_cache::_bind(gl::COPY_WRITE_BUFFER, 0);
+            gl_fns::BindBuffer(gl::COPY_WRITE_BUFFER, 0);
+            gl_fns::DeleteBuffers(1, &temp as *const GLuint);
+        }
+
+        Ok(())
+    }
+
+    /// Replace a range of a buffer object's data, starting `offset` bytes
+    /// from the start of the buffer. Unlike [`gl.buffer_bytes`]
+    /// (trait.ContextBufferExt.html#method.buffer_bytes), this does not
+    /// reallocate the buffer's storage, so the range must fit within the
+    /// buffer's current size.
+    ///
+    /// # Panics
+    /// This function will panic in debug mode if the range extends past the
+    /// end of the buffer.
+    ///
+    /// # See also
+    /// [`glBufferSubData`](http://docs.gl/es2/glBufferSubData) OpenGL docs
+    fn buffer_sub_data<B>(&self,
+                          gl_buffer: &mut B,
+                          offset: usize,
+                          bytes: &[u8])
+        where B: BufferBinding
+    {
+        gl_buffer.validate();
+        let _span = gl_span!("glBufferSubData",
+                             target = ?gl_buffer.target(),
+                             offset = offset,
+                             bytes = bytes.len());
+        unsafe {
+            gl_fns::BufferSubData(gl_buffer.target().gl_enum(),
+                              offset as GLintptr,
+                              bytes.len() as GLsizeiptr,
+                              bytes.as_ptr() as *const GLvoid);
+            frame_stats::_buffer_upload(bytes.len());
+            dbg_gl_error! {
+                in "glBufferSubData"(offset = offset, size = bytes.len());
+                GLError::InvalidEnum => "Invalid `target`",
+                GLError::InvalidValue => "`offset` or `size` is negative, or the range extends past the end of the buffer",
+                GLError::InvalidOperation => "Object 0 is bound to buffer target",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Map a range of a buffer object's data into client memory, returning
+    /// a pointer to the start of the range, or a null pointer if the range
+    /// could not be mapped. The buffer must be unmapped with
+    /// [`gl.unmap_buffer`](trait.ContextBufferExt.html#method.unmap_buffer)
+    /// before it is used for drawing. This function requires OpenGL 3.0 or
+    /// OpenGL ES 3.0.
+    ///
+    /// # Safety
+    /// The returned pointer is only valid until the buffer is unmapped, and
+    /// may only be used as allowed by `access` (for example, reading from
+    /// a range mapped with only `MAP_WRITE_BIT` is undefined behavior). The
+    /// pointer is not guaranteed to be aligned for any type other than `u8`.
+    ///
+    /// # See also
+    /// [`glMapBufferRange`](http://docs.gl/es3/glMapBufferRange) OpenGL docs
+    unsafe fn map_buffer_range<B>(&self,
+                                  gl_buffer: &mut B,
+                                  offset: usize,
+                                  length: usize,
+                                  access: BufferMapAccess)
+        -> *mut u8
+        where B: BufferBinding
+    {
+        gl_buffer.validate();
+        let ptr = gl_fns::MapBufferRange(gl_buffer.target().gl_enum(),
+                                     offset as GLintptr,
+                                     length as GLsizeiptr,
+                                     access.bits());
+        dbg_gl_error! {
+            in "glMapBufferRange"(offset = offset,
+                                  length = length,
+                                  access = access);
+            GLError::InvalidValue => "`offset` or `length` is negative, `length` is zero, the range extends past the end of the buffer, or `access` has invalid bits set",
+            GLError::InvalidOperation => "The buffer is already mapped, or `access` has an invalid combination of bits",
+            GLError::OutOfMemory => "Unable to map the buffer",
+            _ => "Unknown error"
+        }
+        ptr as *mut u8
+    }
+
+    /// Unmap a buffer object that was mapped with [`gl.map_buffer_range`]
+    /// (trait.ContextBufferExt.html#method.map_buffer_range). Returns
+    /// `false` if the buffer's contents were corrupted while it was mapped
+    /// (such as by a display mode change), in which case the contents
+    /// must be sent again.
+    ///
+    /// # Safety
+    /// Any pointers returned when mapping the buffer must not be used after
+    /// the buffer is unmapped.
+    ///
+    /// # See also
+    /// [`glUnmapBuffer`](http://docs.gl/es3/glUnmapBuffer) OpenGL docs
+    unsafe fn unmap_buffer<B>(&self, gl_buffer: &mut B) -> bool
+        where B: BufferBinding
+    {
+        _unmap_buffer(gl_buffer)
+    }
+
     /// Specify how an array of vertex data will be treated while rendering.
     /// Most uses of this function can be replaced by using a [`VertexBuffer`]
     /// (../../vertex_buffer/struct.VertexBuffer.html), which provides a nicer
@@ -108,7 +383,8 @@ pub trait ContextBufferExt: BaseContext {
     ///
     /// # Panics
     /// This function will panic in debug mode if `components` is less than 1 or
-    /// greater than 4.
+    /// greater than 4, or if `gl_type` is a packed type (such as
+    /// `INT_2_10_10_10_REV`) and `components` is not 4.
     ///
     /// # Safety
     /// Using this function can cause an OpenGL draw call to read uninitialized
@@ -125,15 +401,23 @@ pub trait ContextBufferExt: BaseContext {
                                     offset: usize)
     {
         debug_assert!(1 <= components && components <= 4);
+        debug_assert!(!gl_type.is_packed() || components == 4,
+                      "Packed vertex attributes must have 4 components");
 
         let gl_normalized = if normalized { gl::TRUE } else { gl::FALSE };
-        gl::VertexAttribPointer(attrib.gl_index,
+        gl_fns::VertexAttribPointer(attrib.gl_index,
                                 components as GLint,
                                 gl_type.gl_enum(),
                                 gl_normalized,
                                 stride as GLsizei,
                                 offset as *const GLvoid);
+        frame_stats::_state_change();
         dbg_gl_error! {
+            in "glVertexAttribPointer"(index = attrib.gl_index,
+                                       size = components,
+                                       gl_type = gl_type,
+                                       stride = stride,
+                                       offset = offset);
             GLError::InvalidEnum => "Illegal vertex attribute type",
             GLError::InvalidValue => "`stride` is negative, `size` is not in range, or `index` is >= GL_MAX_VERTEX_ATTRIBS",
             GLError::InvalidFramebufferOperation => "Currently bound framebuffer is not framebuffer complete",
@@ -141,6 +425,99 @@ pub trait ContextBufferExt: BaseContext {
         }
     }
 
+    /// Specify how an array of integer vertex data will be treated while
+    /// rendering. Unlike [`gl.vertex_attrib_pointer`]
+    /// (trait.ContextBufferExt.html#method.vertex_attrib_pointer), the
+    /// data is passed to the shader as integers (such as an `ivec4` or
+    /// `uvec4`), without being normalized or converted to floating-point
+    /// values.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if integer vertex attributes are not supported
+    /// (see [`Extensions::has_integer_vertex_attribs`]
+    /// (../../extensions/struct.Extensions.html#method.has_integer_vertex_attribs)).
+    ///
+    /// # Panics
+    /// This function will panic in debug mode if `components` is less than 1 or
+    /// greater than 4, or if `gl_type` is not an integral type (see
+    /// [`DataType::is_integral`]
+    /// (../../types/enum.DataType.html#method.is_integral)).
+    ///
+    /// # Safety
+    /// Using this function can cause an OpenGL draw call to read uninitialized
+    /// memory from a buffer.
+    ///
+    /// # See also
+    /// [`glVertexAttribIPointer`](http://docs.gl/es3/glVertexAttribIPointer) OpenGL docs
+    unsafe fn vertex_attrib_i_pointer(&self,
+                                      attrib: ProgramAttrib,
+                                      components: i8,
+                                      gl_type: DataType,
+                                      stride: usize,
+                                      offset: usize)
+        -> Result<(), GLError>
+    {
+        if !self._extensions().has_integer_vertex_attribs() {
+            return Err(GLError::Unsupported("integer vertex attributes"));
+        }
+
+        debug_assert!(1 <= components && components <= 4);
+        debug_assert!(gl_type.is_integral(),
+                      "Integer vertex attributes must use an integral type");
+
+        gl_fns::VertexAttribIPointer(attrib.gl_index,
+                                 components as GLint,
+                                 gl_type.gl_enum(),
+                                 stride as GLsizei,
+                                 offset as *const GLvoid);
+        dbg_gl_error! {
+            in "glVertexAttribIPointer"(index = attrib.gl_index,
+                                        size = components,
+                                        gl_type = gl_type,
+                                        stride = stride,
+                                        offset = offset);
+            GLError::InvalidEnum => "Illegal vertex attribute type",
+            GLError::InvalidValue => "`stride` is negative, `size` is not in range, or `index` is >= GL_MAX_VERTEX_ATTRIBS",
+            GLError::InvalidOperation => "A non-zero vertex array object is bound, no array buffer is bound, and `offset` is not 0",
+            _ => "Unknown error"
+        }
+        Ok(())
+    }
+
+    /// Set the rate at which a vertex attribute advances when drawing
+    /// instanced primitives. A `divisor` of 0 advances the attribute once per
+    /// vertex (the default); otherwise, the attribute advances once every
+    /// `divisor` instances.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if instanced arrays are not supported (see
+    /// [`Extensions::has_instanced_arrays`]
+    /// (../../extensions/struct.Extensions.html#method.has_instanced_arrays)).
+    ///
+    /// # See also
+    /// [`glVertexAttribDivisor`](http://docs.gl/es3/glVertexAttribDivisor) OpenGL docs
+    fn vertex_attrib_divisor(&self, attrib: ProgramAttrib, divisor: u32)
+        -> Result<(), GLError>
+    {
+        if !self._extensions().has_instanced_arrays() {
+            return Err(GLError::Unsupported("instanced arrays"));
+        }
+
+        unsafe {
+            gl_fns::VertexAttribDivisor(attrib.gl_index, divisor as GLuint);
+            frame_stats::_state_change();
+            dbg_gl_error! {
+                in "glVertexAttribDivisor"(index = attrib.gl_index,
+                                           divisor = divisor);
+                GLError::InvalidValue => "`index` is >= GL_MAX_VERTEX_ATTRIBS",
+                _ => "Unknown error"
+            }
+        }
+        Ok(())
+    }
+
     /// Use the vertex data from the provided array buffer binding to render
     /// primitives.
     ///
@@ -149,6 +526,11 @@ pub trait ContextBufferExt: BaseContext {
     /// - `first`: The index of the first vertex to read.
     /// - `count`: The number of vertices to read.
     ///
+    /// # Panics
+    /// This function will panic if `mode` is not supported by the current
+    /// context (see [`DrawingMode::check_supported`]
+    /// (../../types/enum.DrawingMode.html#method.check_supported)).
+    ///
     /// # Safety
     /// The vertex attributes for the need to be set up before calling this
     /// method by using the [`gl.vertex_attrib_pointer`]
@@ -162,14 +544,51 @@ pub trait ContextBufferExt: BaseContext {
                                 first: u32,
                                 count: usize)
     {
-        gl::DrawArrays(mode.gl_enum(), first as GLint, count as GLsizei);
+        _ab.validate();
+        _check_drawing_mode(mode, &self._extensions());
+        let _span = gl_span!("glDrawArrays",
+                             mode = ?mode,
+                             first = first,
+                             count = count);
+        gl_fns::DrawArrays(mode.gl_enum(), first as GLint, count as GLsizei);
+        frame_stats::_draw();
         dbg_gl_sanity_check! {
+            in "glDrawArrays"(mode = mode, first = first, count = count);
             GLError::InvalidEnum => "`mode` is not an accepted value",
             GLError::InvalidValue => "`count` is negative",
             _ => "Unknown error"
         }
     }
 
+    /// Return `true` if the current context supports drawing elements with
+    /// the given index type. `IndexDatumType::UnsignedInt` is only
+    /// supported in desktop OpenGL, OpenGL ES 3, or with the
+    /// `GL_OES_element_index_uint` extension in OpenGL ES 2; all other index
+    /// types are always supported.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    /// use glitter::IndexDatumType;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// if !gl.supports_index_datum_type(IndexDatumType::UnsignedInt) {
+    ///     // Split up the mesh into chunks with 65,536 vertices or fewer
+    /// }
+    /// # }
+    /// ```
+    fn supports_index_datum_type(&self, index_type: IndexDatumType) -> bool {
+        match index_type {
+            IndexDatumType::UnsignedByte => true,
+            IndexDatumType::UnsignedShort => true,
+            IndexDatumType::UnsignedInt => {
+                self._extensions().has_element_index_uint()
+            }
+        }
+    }
+
     /// Draw primitives specified by the provided element array buffer, treated
     /// as indices of the vertices from the provided array buffer.
     ///
@@ -182,6 +601,14 @@ pub trait ContextBufferExt: BaseContext {
     /// - `index_type`: Specifies the data type of the index (whether it is
     ///                 a byte or short, signed unsigned, etc).
     ///
+    /// # Panics
+    /// This function will panic in debug mode if `index_type` is not
+    /// supported by the current context (see [`gl.supports_index_datum_type`]
+    /// (trait.ContextBufferExt.html#method.supports_index_datum_type)), and
+    /// will panic if `mode` is not supported by the current context (see
+    /// [`DrawingMode::check_supported`]
+    /// (../../types/enum.DrawingMode.html#method.check_supported)).
+    ///
     /// # See also
     /// [`glDrawElements`](http://docs.gl/es2/glDrawElements) OpenGL docs
     unsafe fn draw_n_elements_buffered(&self,
@@ -191,7 +618,7 @@ pub trait ContextBufferExt: BaseContext {
                                        count: usize,
                                        index_type: IndexDatumType)
     {
-        _draw_elements(mode, count, index_type, ptr::null());
+        _draw_elements_buffered(_ab, _eab, mode, count, index_type);
     }
 
     /// Draw primitives specified by the provided index array, treated as
@@ -203,6 +630,11 @@ pub trait ContextBufferExt: BaseContext {
     /// - `count`: The number of indices to read.
     /// - `indices`: The index array to use.
     ///
+    /// # Panics
+    /// This function will panic if `mode` is not supported by the current
+    /// context (see [`DrawingMode::check_supported`]
+    /// (../../types/enum.DrawingMode.html#method.check_supported)).
+    ///
     /// # See also
     /// [`glDrawElements`](http://docs.gl/es2/glDrawElements) OpenGL docs
     unsafe fn draw_n_elements<I>(&self,
@@ -213,6 +645,7 @@ pub trait ContextBufferExt: BaseContext {
         where I: IndexDatum, [I]: IndexData
     {
         debug_assert!(count <= indices.len());
+        _ab.validate();
 
         let ptr = indices.index_bytes().as_ptr();
         let index_type = I::index_datum_type();
@@ -227,6 +660,11 @@ pub trait ContextBufferExt: BaseContext {
     /// - `mode`: The type of primitives to draw.
     /// - `indices`: The index array to use.
     ///
+    /// # Panics
+    /// This function will panic if `mode` is not supported by the current
+    /// context (see [`DrawingMode::check_supported`]
+    /// (../../types/enum.DrawingMode.html#method.check_supported)).
+    ///
     /// # See also
     /// [`glDrawElements`](http://docs.gl/es2/glDrawElements) OpenGL docs
     unsafe fn draw_elements<I>(&self,
@@ -243,6 +681,39 @@ impl<C: BaseContext> ContextBufferExt for C {
 
 }
 
+// Unmap a buffer (see `gl.unmap_buffer`). This only needs the binding, since
+// a binding can only exist while its context is current, so it can be used
+// by bindings that make OpenGL calls on their own (such as when a
+// `VertexWriter` is dropped).
+#[doc(hidden)]
+pub unsafe fn _unmap_buffer<B>(gl_buffer: &mut B) -> bool
+    where B: BufferBinding
+{
+    gl_buffer.validate();
+    let result = gl_fns::UnmapBuffer(gl_buffer.target().gl_enum());
+    dbg_gl_error! {
+        in "glUnmapBuffer"();
+        GLError::InvalidOperation => "The buffer is not mapped",
+        _ => "Unknown error"
+    }
+    result == gl::TRUE
+}
+
+// Draw using the bound vertex and index buffers (see
+// `gl.draw_n_elements_buffered`), for bindings that draw on their own (such
+// as a `MeshBinding`)
+#[doc(hidden)]
+pub unsafe fn _draw_elements_buffered(_ab: &ArrayBufferBinding,
+                                      _eab: &ElementArrayBufferBinding,
+                                      mode: DrawingMode,
+                                      count: usize,
+                                      index_type: IndexDatumType)
+{
+    _ab.validate();
+    _eab.validate();
+    _draw_elements(mode, count, index_type, ptr::null());
+}
+
 
 
 /// An OpenGL context that has a free `GL_ARRAY_BUFFER` binding.
@@ -268,6 +739,19 @@ pub trait ArrayBufferContext: AContext {
         let (mut binder, rest) = self.split_array_buffer();
         (binder.borrow_mut().bind(buffer), rest)
     }
+
+    /// Unbind this context's array buffer (by binding 0), returning the
+    /// freed binder and the remaining context.
+    ///
+    /// # See also
+    /// [`glBindBuffer`](http://docs.gl/es2/glBindBuffer) OpenGL docs
+    fn unbind_array_buffer(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_array_buffer();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
 /// An OpenGL context that has a free `GL_ELEMENT_ARRAY_BUFFER` binding.
@@ -294,6 +778,19 @@ pub trait ElementArrayBufferContext: AContext {
         let (mut binder, rest) = self.split_element_array_buffer();
         (binder.borrow_mut().bind(buffer), rest)
     }
+
+    /// Unbind this context's element array buffer (by binding 0), returning the
+    /// freed binder and the remaining context.
+    ///
+    /// # See also
+    /// [`glBindBuffer`](http://docs.gl/es2/glBindBuffer) OpenGL docs
+    fn unbind_element_array_buffer(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_element_array_buffer();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
 impl<BA, BE, F, P, R, T> ArrayBufferContext
@@ -470,11 +967,20 @@ pub trait BufferBinding {
     /// Returns the OpenGL binding target that this buffer binding
     /// references.
     fn target(&self) -> BufferBindingTarget;
+
+    /// Check that this buffer is still the one bound to its target. With
+    /// the `validate-bindings` feature, this queries the driver's binding
+    /// and panics if it differs from the buffer that this binding
+    /// references; otherwise, it does nothing.
+    fn validate(&self) {
+
+    }
 }
 
 /// Represents a buffer that has been bound to the `GL_ARRAY_BUFFER`
 /// binding target.
 pub struct ArrayBufferBinding<'a> {
+    _check: BindingCheck,
     _phantom_ref: PhantomData<&'a mut Buffer>,
     _phantom_ptr: PhantomData<*mut ()>
 }
@@ -483,11 +989,15 @@ impl<'a> BufferBinding for ArrayBufferBinding<'a> {
     fn target(&self) -> BufferBindingTarget {
         BufferBindingTarget::ArrayBuffer
     }
+    fn validate(&self) {
+        self._check.check(gl::ARRAY_BUFFER_BINDING, "GL_ARRAY_BUFFER");
+    }
 }
 
 /// Represents a buffer that has been bound to the `GL_ELEMENT_ARRAY_BUFFER`
 /// binding target.
 pub struct ElementArrayBufferBinding<'a> {
+    _check: BindingCheck,
     _phantom_ref: PhantomData<&'a mut Buffer>,
     _phantom_ptr: PhantomData<*mut ()>
 }
@@ -496,6 +1006,9 @@ impl<'a> BufferBinding for ElementArrayBufferBinding<'a> {
     fn target(&self) -> BufferBindingTarget {
         BufferBindingTarget::ElementArrayBuffer
     }
+    fn validate(&self) {
+        self._check.check(gl::ELEMENT_ARRAY_BUFFER_BINDING, "GL_ELEMENT_ARRAY_BUFFER");
+    }
 }
 
 
@@ -622,12 +1135,23 @@ impl ArrayBufferBinder {
     pub fn bind<'a>(&mut self, buffer: &'a mut Buffer) -> ArrayBufferBinding<'a>
     {
         let binding = ArrayBufferBinding {
+            _check: BindingCheck::new(buffer.id()),
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         };
-        _bind_buffer(binding.target(), buffer);
+        _bind_buffer(binding.target(), buffer.id());
+        binding.validate();
         binding
     }
+
+    /// Unbind the buffer bound to the `GL_ARRAY_BUFFER` target (by binding 0),
+    /// so that a deleted buffer isn't left bound.
+    ///
+    /// # See also
+    /// [`glBindBuffer`](http://docs.gl/es2/glBindBuffer) OpenGL docs
+    pub fn unbind(&mut self) {
+        _bind_buffer(BufferBindingTarget::ArrayBuffer, 0);
+    }
 }
 
 /// The OpenGL state representing the `GL_ELEMENT_ARRAY_BUFFER` target.
@@ -654,10 +1178,21 @@ impl ElementArrayBufferBinder {
         -> ElementArrayBufferBinding<'a>
     {
         let binding = ElementArrayBufferBinding {
+            _check: BindingCheck::new(buffer.id()),
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         };
-        _bind_buffer(binding.target(), buffer);
+        _bind_buffer(binding.target(), buffer.id());
+        binding.validate();
         binding
     }
+
+    /// Unbind the buffer bound to the `GL_ELEMENT_ARRAY_BUFFER` target (by binding 0),
+    /// so that a deleted buffer isn't left bound.
+    ///
+    /// # See also
+    /// [`glBindBuffer`](http://docs.gl/es2/glBindBuffer) OpenGL docs
+    pub fn unbind(&mut self) {
+        _bind_buffer(BufferBindingTarget::ElementArrayBuffer, 0);
+    }
 }
@@ -0,0 +1,185 @@
+//! Contains the [`ContextBuilder`](struct.ContextBuilder.html) type, for
+//! setting up a context's initial state.
+
+use context::{Context, ContextExt};
+use types::{Color, Viewport, Capability, BlendFactor, DepthFunc, GLError,
+            BuilderError, PACK_ALIGNMENT, UNPACK_ALIGNMENT};
+
+/// Provides a safe interface for setting up the initial state of a context,
+/// such as right after calling [`Context::current_context`]
+/// (struct.ContextOf.html#method.current_context). All of the settings are
+/// validated before any of them are applied, and the context is returned
+/// once they have been applied.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use] extern crate glitter;
+/// use glitter::prelude::*;
+/// use glitter::ContextBuilder;
+///
+/// # fn main() {
+/// let gl = unsafe { glitter::Context::current_context() };
+/// let mut gl = ContextBuilder::new(gl)
+///     .clear_color(glitter::Color::rgba(0.0, 0.0, 0.0, 1.0))
+///     .viewport(glitter::Viewport::new(0, 0, 800, 600))
+///     .enable(glitter::DEPTH_TEST)
+///     .enable(glitter::BLEND)
+///     .blend_func(glitter::SRC_ALPHA, glitter::ONE_MINUS_SRC_ALPHA)
+///     .unpack_alignment(1)
+///     .unwrap();
+///
+/// gl.clear(glitter::COLOR_BUFFER_BIT | glitter::DEPTH_BUFFER_BIT);
+/// # }
+/// ```
+pub struct ContextBuilder {
+    gl: Context,
+    clear_color: Option<Color>,
+    viewport: Option<Viewport>,
+    capabilities: Vec<(Capability, bool)>,
+    blend_func: Option<(BlendFactor, BlendFactor)>,
+    depth_func: Option<DepthFunc>,
+    pack_alignment: Option<u32>,
+    unpack_alignment: Option<u32>
+}
+
+impl ContextBuilder {
+    /// Create a new builder for the initial state of `gl`. Any state that
+    /// isn't set is left unchanged.
+    pub fn new(gl: Context) -> Self {
+        ContextBuilder {
+            gl: gl,
+            clear_color: None,
+            viewport: None,
+            capabilities: vec![],
+            blend_func: None,
+            depth_func: None,
+            pack_alignment: None,
+            unpack_alignment: None
+        }
+    }
+
+    /// Set the clear color (see [`gl.clear_color`]
+    /// (trait.ContextExt.html#method.clear_color)).
+    pub fn clear_color(mut self, color: Color) -> Self {
+        self.clear_color = Some(color);
+        self
+    }
+
+    /// Set the viewport (see [`gl.viewport`]
+    /// (trait.ContextExt.html#method.viewport)).
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Enable a capability. Capabilities are enabled and disabled in the
+    /// order that `enable` and `disable` are called.
+    pub fn enable(mut self, cap: Capability) -> Self {
+        self.capabilities.push((cap, true));
+        self
+    }
+
+    /// Disable a capability. Capabilities are enabled and disabled in the
+    /// order that `enable` and `disable` are called.
+    pub fn disable(mut self, cap: Capability) -> Self {
+        self.capabilities.push((cap, false));
+        self
+    }
+
+    /// Set the blending factors (see [`gl.blend_func`]
+    /// (trait.ContextExt.html#method.blend_func)).
+    pub fn blend_func(mut self, src: BlendFactor, dst: BlendFactor) -> Self {
+        self.blend_func = Some((src, dst));
+        self
+    }
+
+    /// Set the depth test comparison (see [`gl.depth_func`]
+    /// (trait.ContextExt.html#method.depth_func)).
+    pub fn depth_func(mut self, func: DepthFunc) -> Self {
+        self.depth_func = Some(func);
+        self
+    }
+
+    /// Set the row alignment used when reading pixel data back from
+    /// OpenGL. Must be 1, 2, 4, or 8.
+    pub fn pack_alignment(mut self, alignment: u32) -> Self {
+        self.pack_alignment = Some(alignment);
+        self
+    }
+
+    /// Set the row alignment used when sending pixel data to OpenGL. Must
+    /// be 1, 2, 4, or 8.
+    pub fn unpack_alignment(mut self, alignment: u32) -> Self {
+        self.unpack_alignment = Some(alignment);
+        self
+    }
+
+    /// Apply the provided state to the context and return it, or return
+    /// an error. No state is changed if an error is returned.
+    ///
+    /// # Failures
+    /// An error will be returned if a pixel alignment is not 1, 2, 4, or 8,
+    /// or if the viewport is larger than the context's
+    /// [`max_viewport_dims`](../limits/struct.ContextLimits.html#structfield.max_viewport_dims).
+    ///
+    /// # Panics
+    /// This function will panic if an OpenGL error is generated
+    /// and debug assertions are enabled.
+    pub fn try_unwrap(self) -> Result<Context, GLError> {
+        let mut gl = self.gl;
+
+        let alignments = [self.pack_alignment, self.unpack_alignment];
+        for alignment in alignments.iter().filter_map(|&a| a) {
+            match alignment {
+                1 | 2 | 4 | 8 => { },
+                _ => { return Err(BuilderError::InvalidPixelAlignment.into()); }
+            }
+        }
+
+        if let Some(viewport) = self.viewport {
+            let (max_width, max_height) = gl.limits().max_viewport_dims;
+            if viewport.width > max_width || viewport.height > max_height {
+                return Err(BuilderError::InvalidViewport.into());
+            }
+        }
+
+        if let Some(color) = self.clear_color {
+            gl.clear_color(color);
+        }
+        if let Some(viewport) = self.viewport {
+            gl.viewport(viewport);
+        }
+        for &(cap, enabled) in &self.capabilities {
+            if enabled {
+                gl.enable(cap);
+            }
+            else {
+                gl.disable(cap);
+            }
+        }
+        if let Some((src, dst)) = self.blend_func {
+            gl.blend_func(src, dst);
+        }
+        if let Some(func) = self.depth_func {
+            gl.depth_func(func);
+        }
+        if let Some(alignment) = self.pack_alignment {
+            gl.pixel_store(PACK_ALIGNMENT, alignment);
+        }
+        if let Some(alignment) = self.unpack_alignment {
+            gl.pixel_store(UNPACK_ALIGNMENT, alignment);
+        }
+
+        Ok(gl)
+    }
+
+    /// Apply the provided state to the context and return it, or panic.
+    ///
+    /// # Panics
+    /// This function will panic if the state is invalid (see [`try_unwrap`]
+    /// (struct.ContextBuilder.html#method.try_unwrap)), or if an OpenGL
+    /// error is generated and debug assertions are enabled.
+    pub fn unwrap(self) -> Context {
+        self.try_unwrap().unwrap()
+    }
+}
@@ -0,0 +1,518 @@
+//! Contains [`DynContext`](struct.DynContext.html), which tracks bindings at
+//! runtime rather than in the type system.
+
+use std::fmt;
+use std::error;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use context::{Context, BaseContext, AContext};
+use context::buffer_context::{ArrayBufferBinder, ArrayBufferBinding,
+                              ElementArrayBufferBinder,
+                              ElementArrayBufferBinding};
+use context::framebuffer_context::{FramebufferBinder, FramebufferBinding};
+use context::program_context::{ProgramBinder, ProgramBinding};
+use context::renderbuffer_context::{RenderbufferBinder, RenderbufferBinding};
+use context::texture_context::{Texture2dBinding, TextureCubeMapBinding,
+                               TextureExternalBinding,
+                               Texture2dMultisampleBinding,
+                               Texture2dArrayBinding, Texture3dBinding};
+use context::texture_units::{TextureUnitBinding2d, TextureUnitBindingCubeMap,
+                             TextureUnitBindingExternal,
+                             TextureUnitBinding2dMultisample,
+                             TextureUnitBinding2dArray, TextureUnitBinding3d,
+                             TextureSampler};
+use buffer::Buffer;
+use extensions::Extensions;
+use limits::ContextLimits;
+use framebuffer::Framebuffer;
+use program::Program;
+use renderbuffer::Renderbuffer;
+use texture::{Texture2d, TextureCubeMap, TextureExternal,
+              Texture2dMultisample, Texture2dArray, Texture3d};
+
+/// A binding slot of the OpenGL context, which can only be used by one
+/// binding at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynSlot {
+    /// The `GL_ARRAY_BUFFER` target.
+    ArrayBuffer,
+
+    /// The `GL_ELEMENT_ARRAY_BUFFER` target.
+    ElementArrayBuffer,
+
+    /// The `GL_FRAMEBUFFER` target.
+    Framebuffer,
+
+    /// The current program (set with `glUseProgram`).
+    Program,
+
+    /// The `GL_RENDERBUFFER` target.
+    Renderbuffer,
+
+    /// The `GL_TEXTURE_2D` target of the texture unit with the given index.
+    Texture2d(u32),
+
+    /// The `GL_TEXTURE_CUBE_MAP` target of the texture unit with the
+    /// given index.
+    TextureCubeMap(u32),
+
+    /// The `GL_TEXTURE_EXTERNAL_OES` target of the texture unit with the
+    /// given index.
+    TextureExternal(u32),
+
+    /// The `GL_TEXTURE_2D_MULTISAMPLE` target of the texture unit with the
+    /// given index.
+    Texture2dMultisample(u32),
+
+    /// The `GL_TEXTURE_2D_ARRAY` target of the texture unit with the given
+    /// index.
+    Texture2dArray(u32),
+
+    /// The `GL_TEXTURE_3D` target of the texture unit with the given index.
+    Texture3d(u32),
+
+    /// The active texture unit (set with `glActiveTexture`), which is held
+    /// by the live texture bindings for the texture unit with the given
+    /// index. Texture bindings act on whichever texture unit is active, so
+    /// a texture for another unit can't be bound until these bindings are
+    /// dropped.
+    ActiveTexture(u32)
+}
+
+impl DynSlot {
+    // Get the index of the texture unit used by a texture slot
+    fn _texture_unit(&self) -> Option<u32> {
+        match *self {
+            DynSlot::Texture2d(idx)
+            | DynSlot::TextureCubeMap(idx)
+            | DynSlot::TextureExternal(idx)
+            | DynSlot::Texture2dMultisample(idx)
+            | DynSlot::Texture2dArray(idx)
+            | DynSlot::Texture3d(idx) => Some(idx),
+            _ => None
+        }
+    }
+}
+
+/// An error generated when binding an object with a
+/// [`DynContext`](struct.DynContext.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynBindError {
+    /// The slot is already used by a live binding.
+    AlreadyBound(DynSlot),
+
+    /// The texture unit index is greater than or equal to the context's
+    /// [`max_combined_texture_image_units`]
+    /// (../../limits/struct.ContextLimits.html#structfield.max_combined_texture_image_units).
+    InvalidTextureUnit(u32)
+}
+
+impl fmt::Display for DynBindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DynBindError::AlreadyBound(slot) => {
+                write!(f, "{:?} is already bound", slot)
+            },
+            DynBindError::InvalidTextureUnit(idx) => {
+                write!(f, "Texture unit {} does not exist", idx)
+            }
+        }
+    }
+}
+
+impl error::Error for DynBindError {
+    fn description(&self) -> &str {
+        match *self {
+            DynBindError::AlreadyBound(_) => {
+                "The binding slot is already used by a live binding"
+            },
+            DynBindError::InvalidTextureUnit(_) => {
+                "The texture unit does not exist"
+            }
+        }
+    }
+}
+
+/// An OpenGL context that tracks which binding slots are in use at runtime,
+/// rather than with the type parameters of [`ContextOf`]
+/// (../struct.ContextOf.html). Each bind method takes the context by shared
+/// reference and returns an error if the slot is already used by a live
+/// binding; the slot is freed again when the binding is dropped.
+///
+/// This is useful when the lifetimes of bindings can't be expressed
+/// statically (such as when bindings are made by plugins), at the cost of a
+/// check for each binding. A `DynContext` can be created from a fresh
+/// [`Context`](../type.Context.html) with [`DynContext::new`]
+/// (struct.DynContext.html#method.new), and converted back with
+/// [`into_context`](struct.DynContext.html#method.into_context). Methods
+/// that take a typed context with free bindings (such as
+/// [`gl.build_texture_2d`]
+/// (../texture_context/trait.ContextTextureBuilderExt.html#method.build_texture_2d))
+/// require converting back first.
+///
+/// # Example
+/// ```no_run
+/// use glitter::prelude::*;
+/// use glitter::DynContext;
+///
+/// let gl = DynContext::new(unsafe { glitter::Context::current_context() });
+/// let mut buffer = gl.gen_buffer();
+/// let mut gl_buffer = gl.bind_array_buffer(&mut buffer).unwrap();
+/// gl.buffer_bytes(&mut *gl_buffer, &[1, 2, 3], glitter::STATIC_DRAW);
+///
+/// let mut other_buffer = gl.gen_buffer();
+/// assert!(gl.bind_array_buffer(&mut other_buffer).is_err());
+/// ```
+pub struct DynContext {
+    gl: Context,
+    bound: RefCell<HashSet<DynSlot>>
+}
+
+impl DynContext {
+    /// Create a `DynContext` from a context with all free bindings.
+    pub fn new(gl: Context) -> Self {
+        DynContext {
+            gl: gl,
+            bound: RefCell::new(HashSet::new())
+        }
+    }
+
+    /// Convert the `DynContext` back into a typed context. Since bindings
+    /// borrow the `DynContext`, no bindings can be alive at this point.
+    pub fn into_context(self) -> Context {
+        self.gl
+    }
+
+    /// Returns `true` if `slot` is currently used by a live binding.
+    pub fn is_bound(&self, slot: DynSlot) -> bool {
+        match slot {
+            DynSlot::ActiveTexture(idx) => {
+                self._active_texture_unit() == Some(idx)
+            },
+            slot => self.bound.borrow().contains(&slot)
+        }
+    }
+
+    // Get the texture unit used by the live texture bindings, if any
+    fn _active_texture_unit(&self) -> Option<u32> {
+        let bound = self.bound.borrow();
+        bound.iter().filter_map(|slot| slot._texture_unit()).next()
+    }
+
+    fn _take<'a>(&'a self, slot: DynSlot)
+        -> Result<DynSlotGuard<'a>, DynBindError>
+    {
+        if let Some(idx) = slot._texture_unit() {
+            if idx >= self.gl.limits().max_combined_texture_image_units {
+                return Err(DynBindError::InvalidTextureUnit(idx));
+            }
+
+            // Making another texture unit active would redirect the live
+            // texture bindings to that unit
+            match self._active_texture_unit() {
+                Some(active) if active != idx => {
+                    return Err(DynBindError::AlreadyBound(
+                        DynSlot::ActiveTexture(active)
+                    ));
+                },
+                _ => { }
+            }
+        }
+
+        if !self.bound.borrow_mut().insert(slot) {
+            return Err(DynBindError::AlreadyBound(slot));
+        }
+        Ok(DynSlotGuard { slot: slot, bound: &self.bound })
+    }
+
+    /// Bind a buffer to the `GL_ARRAY_BUFFER` target.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// array buffer binding is alive.
+    pub fn bind_array_buffer<'a>(&'a self, buffer: &'a mut Buffer)
+        -> Result<DynBinding<'a, ArrayBufferBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::ArrayBuffer));
+        let binding = unsafe { ArrayBufferBinder::current().bind(buffer) };
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Bind a buffer to the `GL_ELEMENT_ARRAY_BUFFER` target.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// element array buffer binding is alive.
+    pub fn bind_element_array_buffer<'a>(&'a self, buffer: &'a mut Buffer)
+        -> Result<DynBinding<'a, ElementArrayBufferBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::ElementArrayBuffer));
+        let binding = unsafe {
+            ElementArrayBufferBinder::current().bind(buffer)
+        };
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Bind a framebuffer to the `GL_FRAMEBUFFER` target.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// framebuffer binding is alive.
+    pub fn bind_framebuffer<'a>(&'a self, fbo: &'a mut Framebuffer)
+        -> Result<DynBinding<'a, FramebufferBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Framebuffer));
+        let binding = unsafe { FramebufferBinder::current().bind(fbo) };
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make a program the current program.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// program binding is alive.
+    pub fn use_program<'a>(&'a self, program: &'a mut Program)
+        -> Result<DynBinding<'a, ProgramBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Program));
+        let binding = unsafe { ProgramBinder::current().bind(program) };
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Bind a renderbuffer to the `GL_RENDERBUFFER` target.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// renderbuffer binding is alive.
+    pub fn bind_renderbuffer<'a>(&'a self, rbo: &'a mut Renderbuffer)
+        -> Result<DynBinding<'a, RenderbufferBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Renderbuffer));
+        let binding = unsafe { RenderbufferBinder::current().bind(rbo) };
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make the `unit`th texture unit active, and bind a 2D texture to it.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// 2D texture binding for the same texture unit is alive, and
+    /// a `DynBindError::InvalidTextureUnit` error will be returned if the
+    /// texture unit doesn't exist.
+    ///
+    /// Since there is only one active texture unit, a
+    /// `DynBindError::AlreadyBound(DynSlot::ActiveTexture(_))` error will
+    /// also be returned if a texture binding for a different texture unit
+    /// is alive. Texture bindings for the same texture unit (but different
+    /// targets) can be used together.
+    pub fn bind_texture_2d<'a>(&'a self, unit: u32, tex: &'a mut Texture2d)
+        -> Result<DynBinding<'a, Texture2dBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Texture2d(unit)));
+        let gl_tex_unit = unsafe { self.gl.tex_units.active_nth(unit) };
+        let (binding, _) = gl_tex_unit.bind_texture_2d(tex);
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make the `unit`th texture unit active, and bind a cubemap texture
+    /// to it.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// cubemap texture binding for the same texture unit is alive, and
+    /// a `DynBindError::InvalidTextureUnit` error will be returned if the
+    /// texture unit doesn't exist.
+    ///
+    /// As with [`bind_texture_2d`]
+    /// (struct.DynContext.html#method.bind_texture_2d), a
+    /// `DynBindError::AlreadyBound(DynSlot::ActiveTexture(_))` error will
+    /// be returned if a texture binding for a different texture unit is
+    /// alive.
+    pub fn bind_texture_cube_map<'a>(&'a self,
+                                     unit: u32,
+                                     tex: &'a mut TextureCubeMap)
+        -> Result<DynBinding<'a, TextureCubeMapBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::TextureCubeMap(unit)));
+        let gl_tex_unit = unsafe { self.gl.tex_units.active_nth(unit) };
+        let (binding, _) = gl_tex_unit.bind_texture_cube_map(tex);
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make the `unit`th texture unit active, and bind an external texture
+    /// to it.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// external texture binding for the same texture unit is alive, and
+    /// a `DynBindError::InvalidTextureUnit` error will be returned if the
+    /// texture unit doesn't exist.
+    ///
+    /// As with [`bind_texture_2d`]
+    /// (struct.DynContext.html#method.bind_texture_2d), a
+    /// `DynBindError::AlreadyBound(DynSlot::ActiveTexture(_))` error will
+    /// be returned if a texture binding for a different texture unit is
+    /// alive.
+    pub fn bind_texture_external<'a>(&'a self,
+                                     unit: u32,
+                                     tex: &'a mut TextureExternal)
+        -> Result<DynBinding<'a, TextureExternalBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::TextureExternal(unit)));
+        let gl_tex_unit = unsafe { self.gl.tex_units.active_nth(unit) };
+        let (binding, _) = gl_tex_unit.bind_texture_external(tex);
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make the `unit`th texture unit active, and bind a multisampled 2D
+    /// texture to it.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// multisampled 2D texture binding for the same texture unit is alive,
+    /// and a `DynBindError::InvalidTextureUnit` error will be returned if
+    /// the texture unit doesn't exist.
+    ///
+    /// As with [`bind_texture_2d`]
+    /// (struct.DynContext.html#method.bind_texture_2d), a
+    /// `DynBindError::AlreadyBound(DynSlot::ActiveTexture(_))` error will
+    /// be returned if a texture binding for a different texture unit is
+    /// alive.
+    pub fn bind_texture_2d_multisample<'a>(&'a self,
+                                           unit: u32,
+                                           tex: &'a mut Texture2dMultisample)
+        -> Result<DynBinding<'a, Texture2dMultisampleBinding<'a>>,
+                  DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Texture2dMultisample(unit)));
+        let gl_tex_unit = unsafe { self.gl.tex_units.active_nth(unit) };
+        let (binding, _) = gl_tex_unit.bind_texture_2d_multisample(tex);
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make the `unit`th texture unit active, and bind a 2D array texture
+    /// to it.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another
+    /// 2D array texture binding for the same texture unit is alive, and
+    /// a `DynBindError::InvalidTextureUnit` error will be returned if the
+    /// texture unit doesn't exist.
+    ///
+    /// As with [`bind_texture_2d`]
+    /// (struct.DynContext.html#method.bind_texture_2d), a
+    /// `DynBindError::AlreadyBound(DynSlot::ActiveTexture(_))` error will
+    /// be returned if a texture binding for a different texture unit is
+    /// alive.
+    pub fn bind_texture_2d_array<'a>(&'a self,
+                                     unit: u32,
+                                     tex: &'a mut Texture2dArray)
+        -> Result<DynBinding<'a, Texture2dArrayBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Texture2dArray(unit)));
+        let gl_tex_unit = unsafe { self.gl.tex_units.active_nth(unit) };
+        let (binding, _) = gl_tex_unit.bind_texture_2d_array(tex);
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Make the `unit`th texture unit active, and bind a 3D texture to it.
+    ///
+    /// # Failures
+    /// A `DynBindError::AlreadyBound` error will be returned if another 3D
+    /// texture binding for the same texture unit is alive, and
+    /// a `DynBindError::InvalidTextureUnit` error will be returned if the
+    /// texture unit doesn't exist.
+    ///
+    /// As with [`bind_texture_2d`]
+    /// (struct.DynContext.html#method.bind_texture_2d), a
+    /// `DynBindError::AlreadyBound(DynSlot::ActiveTexture(_))` error will
+    /// be returned if a texture binding for a different texture unit is
+    /// alive.
+    pub fn bind_texture_3d<'a>(&'a self, unit: u32, tex: &'a mut Texture3d)
+        -> Result<DynBinding<'a, Texture3dBinding<'a>>, DynBindError>
+    {
+        let guard = try!(self._take(DynSlot::Texture3d(unit)));
+        let gl_tex_unit = unsafe { self.gl.tex_units.active_nth(unit) };
+        let (binding, _) = gl_tex_unit.bind_texture_3d(tex);
+        Ok(DynBinding { binding: binding, _guard: guard })
+    }
+
+    /// Get the [`TextureSampler`](../texture_units/struct.TextureSampler.html)
+    /// for the `unit`th texture unit, which can be used to set a uniform
+    /// variable.
+    pub fn texture_sampler(&self, unit: u32) -> TextureSampler {
+        TextureSampler::from_idx(unit)
+    }
+}
+
+impl From<Context> for DynContext {
+    fn from(gl: Context) -> Self {
+        DynContext::new(gl)
+    }
+}
+
+impl Deref for DynContext {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        &self.gl
+    }
+}
+
+unsafe impl BaseContext for DynContext {
+    fn _extensions(&self) -> Rc<Extensions> {
+        self.gl._extensions()
+    }
+
+    fn _limits(&self) -> ContextLimits {
+        self.gl._limits()
+    }
+}
+
+impl AContext for DynContext {
+
+}
+
+// Frees a slot of a `DynContext` when dropped
+struct DynSlotGuard<'a> {
+    slot: DynSlot,
+    bound: &'a RefCell<HashSet<DynSlot>>
+}
+
+impl<'a> Drop for DynSlotGuard<'a> {
+    fn drop(&mut self) {
+        self.bound.borrow_mut().remove(&self.slot);
+    }
+}
+
+/// A binding made with a [`DynContext`](struct.DynContext.html). This
+/// dereferences to the underlying binding (such as an
+/// [`ArrayBufferBinding`](../buffer_context/struct.ArrayBufferBinding.html)),
+/// and frees its slot when dropped.
+pub struct DynBinding<'a, T> {
+    binding: T,
+    _guard: DynSlotGuard<'a>
+}
+
+impl<'a, T> DynBinding<'a, T> {
+    /// Get the slot that this binding uses.
+    pub fn slot(&self) -> DynSlot {
+        self._guard.slot
+    }
+}
+
+impl<'a, T> Deref for DynBinding<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.binding
+    }
+}
+
+impl<'a, T> DerefMut for DynBinding<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.binding
+    }
+}
@@ -1,16 +1,33 @@
 //! Contains all of the OpenGL state types related to framebuffer objects.
 
+use std::cmp;
 use std::marker::PhantomData;
 use std::collections::hash_map::{HashMap, Entry};
 use std::borrow::BorrowMut;
+use std::ptr;
+use std::slice;
 use gl;
+use gl_fns;
 use gl::types::*;
 use context::{AContext, BaseContext, ContextOf};
-use framebuffer::Framebuffer;
+use buffer::Buffer;
+use framebuffer::{Framebuffer, DefaultFramebuffer};
 use renderbuffer::{Renderbuffer, RenderbufferTarget};
-use texture::{Texture, TextureType, ImageTargetType,
-              Texture2d, Tx2dImageTarget};
-use types::{BufferBits, GLError, GLObject, GLFramebufferError};
+use texture::{Texture, TextureType, ImageTargetType, TextureBindingTarget,
+              ArrayTextureType, LayeredTextureType,
+              Texture2d, Tx2dImageTarget,
+              TextureCubeMap, TxCubeMapImageTarget,
+              Texture2dMultisample, Tx2dMultisampleImageTarget};
+use extensions::Extensions;
+use image_data::{Image2dBuf, ImageFormat};
+use types::{BufferBits, Color, Rect, Viewport, GLError, GLObject,
+            GLFramebufferError, FramebufferIncompleteError,
+            FramebufferAttachmentInfo, FramebufferImageKind,
+            COLOR_BUFFER_BIT, DEPTH_BUFFER_BIT, STENCIL_BUFFER_BIT};
+use validate::BindingCheck;
+use stats;
+use frame_stats;
+use bind_cache;
 
 /// An extension trait that includes framebuffer-related OpenGL methods.
 pub trait ContextFramebufferExt: BaseContext {
@@ -35,8 +52,9 @@ pub trait ContextFramebufferExt: BaseContext {
     unsafe fn gen_framebuffer(&self) -> Framebuffer {
         let mut id : GLuint = 0;
 
-        gl::GenFramebuffers(1, &mut id as *mut GLuint);
+        gl_fns::GenFramebuffers(1, &mut id as *mut GLuint);
         dbg_gl_sanity_check! {
+            in "glGenFramebuffers"();
             GLError::InvalidValue => "`n` is negative",
             _ => "Unknown error"
         }
@@ -48,27 +66,21 @@ pub trait ContextFramebufferExt: BaseContext {
     /// currently-bound framebuffer object. Returns `None` if the framebuffer
     /// is framebuffer-complete.
     ///
+    /// [`GLFramebufferError::IncompleteDimensions`]
+    /// (../../types/enum.GLFramebufferError.html#variant.IncompleteDimensions)
+    /// is only returned for OpenGL ES 2 contexts, since later versions
+    /// (and desktop OpenGL) allow attachments with different dimensions.
+    ///
     /// # See also
     /// [`glCheckFramebufferStatus`](http://docs.gl/es2/glCheckFramebufferStatus) OpenGL docs
     fn check_framebuffer_status(&self, gl_fbo: &FramebufferBinding)
         -> Option<GLFramebufferError>
     {
+        gl_fbo.validate();
         unsafe {
-            match gl::CheckFramebufferStatus(gl_fbo.target().gl_enum()) {
-                gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => {
-                    Some(GLFramebufferError::IncompleteAttachment)
-                },
-                // gl::FRAMEBUFFER_INCOMPLETE_DIMENSIONS => {
-                //     Some(GLFramebufferError::IncompleteDimensions)
-                // },
-                gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => {
-                    Some(GLFramebufferError::IncompleteMissingAttachment)
-                },
-                gl::FRAMEBUFFER_UNSUPPORTED => {
-                    Some(GLFramebufferError::Unsupported)
-                },
-                _ => { None }
-            }
+            let status =
+                gl_fns::CheckFramebufferStatus(gl_fbo.target().gl_enum());
+            _framebuffer_error(status, &self._extensions())
         }
     }
 
@@ -89,12 +101,14 @@ pub trait ContextFramebufferExt: BaseContext {
         // TODO: Should `renderbuffer_target` be an argument?
         let renderbuffer_target = RenderbufferTarget::Renderbuffer;
 
+        gl_fbo.validate();
         unsafe {
-            gl::FramebufferRenderbuffer(gl_fbo.target().gl_enum(),
+            gl_fns::FramebufferRenderbuffer(gl_fbo.target().gl_enum(),
                                         attachment.gl_enum(),
                                         renderbuffer_target.gl_enum(),
                                         renderbuffer.id());
             dbg_gl_sanity_check! {
+                in "glFramebufferRenderbuffer"();
                 GLError::InvalidEnum => "`target` is not `GL_FRAMEBUFFER`, `attachment` is not a valid attachment point, or `renderbuffer` is not `GL_RENDERBUFFER` and `renderbuffer` is not 0",
                 GLError::InvalidOperation => "Framebuffer 0 is bound, or `renderbuffer` is neither 0 nor the name of an existing renderbuffer object",
                 _ => "Unknown error"
@@ -127,13 +141,15 @@ pub trait ContextFramebufferExt: BaseContext {
     {
         debug_assert!(level == 0);
 
+        gl_fbo.validate();
         unsafe {
-            gl::FramebufferTexture2D(gl_fbo.target().gl_enum(),
+            gl_fns::FramebufferTexture2D(gl_fbo.target().gl_enum(),
                                      attachment.gl_enum(),
                                      tex_target.into().gl_enum(),
                                      texture.id(),
                                      level as GLint);
             dbg_gl_sanity_check! {
+                in "glFramebufferTexture2D"();
                 GLError::InvalidEnum => "`target` is not `GL_FRAMEBUFFER`, `attachment` is not an accepted attachment point, or `textarget` is not an accepted texture target and texture is not 0",
                 GLError::InvalidValue => "`level` is not 0 and `texture` is not 0",
                 GLError::InvalidOperation => "Framebuffer object 0 is bound, `texture` is neither 0 nor the name of an existing texture object, or `textarget` is not a valid target for `texture`",
@@ -142,6 +158,261 @@ pub trait ContextFramebufferExt: BaseContext {
         }
     }
 
+    /// Attach a single layer of a 2D array or 3D texture to a framebuffer
+    /// object's attachment point. Requires desktop OpenGL 3.0, OpenGL ES
+    /// 3.0, or `GL_EXT_texture_array` (see
+    /// [`Extensions::has_framebuffer_texture_layer`]
+    /// (../../extensions/struct.Extensions.html#method.has_framebuffer_texture_layer)).
+    ///
+    /// - `gl_fbo`: The binding of the framebuffer to attach to.
+    /// - `attachment`: Which attachment point of the framebuffer to attach to.
+    /// - `texture`: The texture to attach.
+    /// - `level`: The mipmap level of the texture to attach.
+    /// - `layer`: The layer of the texture to attach (the index of the 2D
+    ///   array element, or the depth of the 3D slice).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let mut cascades: glitter::Texture2dArray = unsafe {
+    ///     gl.gen_texture()
+    /// };
+    /// // ...allocate the cascades with `gl.tex_image_3d_sized`...
+    ///
+    /// let mut fbo = unsafe { gl.gen_framebuffer() };
+    /// for cascade in 0..4 {
+    ///     let (mut gl_fbo, gl) = (&mut gl).bind_framebuffer(&mut fbo);
+    ///     gl.framebuffer_texture_layer(&mut gl_fbo,
+    ///                                  glitter::DEPTH_ATTACHMENT,
+    ///                                  &mut cascades,
+    ///                                  0,
+    ///                                  cascade);
+    ///     // ...draw the cascade...
+    /// }
+    /// ```
+    ///
+    /// # See also
+    /// [`glFramebufferTextureLayer`](http://docs.gl/es3/glFramebufferTextureLayer) OpenGL docs
+    fn framebuffer_texture_layer<T>(&self,
+                                    gl_fbo: &mut FramebufferBinding,
+                                    attachment: FramebufferAttachment,
+                                    texture: &mut Texture<T>,
+                                    level: i32,
+                                    layer: u32)
+        where T: ArrayTextureType
+    {
+        gl_fbo.validate();
+        unsafe {
+            _framebuffer_texture_layer(gl_fbo.target(),
+                                       attachment,
+                                       texture.id(),
+                                       level,
+                                       layer);
+        }
+    }
+
+    /// Attach every layer of a texture (each face of a cubemap, each
+    /// element of a 2D array texture, or each slice of a 3D texture) to
+    /// a framebuffer object's attachment point, for layered rendering.
+    /// When drawing, a geometry shader selects the layer each primitive is
+    /// drawn to by writing to `gl_Layer`, so (for example) all six faces of
+    /// a point light's shadow cubemap can be drawn in a single pass.
+    /// Requires desktop OpenGL 3.2 or OpenGL ES 3.2 (see
+    /// [`Extensions::has_layered_framebuffer`]
+    /// (../../extensions/struct.Extensions.html#method.has_layered_framebuffer)).
+    ///
+    /// If any attachment of a framebuffer is layered, every attachment must
+    /// be layered (and have the same kind of texture), or the framebuffer
+    /// won't be framebuffer-complete.
+    ///
+    /// - `gl_fbo`: The binding of the framebuffer to attach to.
+    /// - `attachment`: Which attachment point of the framebuffer to attach to.
+    /// - `texture`: The texture to attach.
+    /// - `level`: The mipmap level of the texture to attach.
+    ///
+    /// # See also
+    /// [`glFramebufferTexture`](http://docs.gl/es3/glFramebufferTexture) OpenGL docs
+    fn framebuffer_texture<T>(&self,
+                              gl_fbo: &mut FramebufferBinding,
+                              attachment: FramebufferAttachment,
+                              texture: &mut Texture<T>,
+                              level: i32)
+        where T: LayeredTextureType
+    {
+        gl_fbo.validate();
+        unsafe {
+            _framebuffer_texture(gl_fbo.target(),
+                                 attachment,
+                                 texture.id(),
+                                 level);
+        }
+    }
+
+    /// Invalidate the contents of some of a framebuffer object's
+    /// attachments, telling OpenGL that they won't be used again (until
+    /// they are drawn to or cleared). On tile-based GPUs (which are common
+    /// on mobile devices), this avoids writing the attachments back to
+    /// memory, such as the depth buffer after the last pass that uses it.
+    ///
+    /// This uses `glInvalidateFramebuffer` (desktop OpenGL 4.3 or
+    /// OpenGL ES 3.0) or `glDiscardFramebufferEXT`. Invalidating is only
+    /// a hint, so nothing is done if neither is supported (see
+    /// [`Extensions::has_invalidate_framebuffer`]
+    /// (../../extensions/struct.Extensions.html#method.has_invalidate_framebuffer)).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// # let mut fbo: glitter::Framebuffer = unsafe { ::std::mem::uninitialized() };
+    /// let (mut gl_fbo, gl) = gl.bind_framebuffer(&mut fbo);
+    /// // ... draw the last pass that uses the depth buffer ...
+    /// gl.invalidate_framebuffer(&mut gl_fbo, &[glitter::DEPTH_ATTACHMENT]);
+    /// ```
+    ///
+    /// # See also
+    /// [`glInvalidateFramebuffer`](http://docs.gl/es3/glInvalidateFramebuffer)
+    /// OpenGL docs
+    fn invalidate_framebuffer(&self,
+                              gl_fbo: &mut FramebufferBinding,
+                              attachments: &[FramebufferAttachment])
+    {
+        gl_fbo.validate();
+        let attachments: Vec<GLenum> = attachments.iter()
+            .map(|attachment| attachment.gl_enum())
+            .collect();
+        _invalidate_framebuffer(gl_fbo.target(), &attachments);
+    }
+
+    /// Invalidate the contents of some of the default framebuffer's buffers,
+    /// such as its depth and stencil buffers at the end of a frame. See
+    /// [`gl.invalidate_framebuffer`]
+    /// (trait.ContextFramebufferExt.html#method.invalidate_framebuffer)
+    /// for more details.
+    ///
+    /// # See also
+    /// [`glInvalidateFramebuffer`](http://docs.gl/es3/glInvalidateFramebuffer)
+    /// OpenGL docs
+    fn invalidate_default_framebuffer(&self,
+                                      gl_fbo: &mut DefaultFramebufferBinding,
+                                      buffers: BufferBits)
+    {
+        gl_fbo.validate();
+
+        // NOTE: The default framebuffer's buffers are named with `GL_COLOR`,
+        //       `GL_DEPTH`, and `GL_STENCIL` (which have the same values
+        //       as `GL_COLOR_EXT`, etc. from `GL_EXT_discard_framebuffer`)
+        let mut attachments = Vec::with_capacity(3);
+        if buffers.contains(COLOR_BUFFER_BIT) {
+            attachments.push(gl::COLOR);
+        }
+        if buffers.contains(DEPTH_BUFFER_BIT) {
+            attachments.push(gl::DEPTH);
+        }
+        if buffers.contains(STENCIL_BUFFER_BIT) {
+            attachments.push(gl::STENCIL);
+        }
+        _invalidate_framebuffer(FramebufferTarget::Framebuffer, &attachments);
+    }
+
+    /// Set a framebuffer's draw buffer and read buffer to `GL_NONE`, so that
+    /// a framebuffer without a color attachment (such as one that only
+    /// renders depth for a shadow map) can be framebuffer-complete. Desktop
+    /// OpenGL requires this, since framebuffers draw to and read from
+    /// `GL_COLOR_ATTACHMENT0` by default. Contexts without `glDrawBuffers`
+    /// or `glReadBuffer` (such as OpenGL ES 2) don't need it, so the
+    /// respective call is skipped (see [`Extensions::has_draw_buffers`]
+    /// (../../extensions/struct.Extensions.html#method.has_draw_buffers) and
+    /// [`Extensions::has_read_buffer`]
+    /// (../../extensions/struct.Extensions.html#method.has_read_buffer)).
+    /// [`gl.build_framebuffer`]
+    /// (trait.ContextFramebufferBuilderExt.html#method.build_framebuffer)
+    /// does this automatically for framebuffers without a color attachment.
+    ///
+    /// # See also
+    /// [`glDrawBuffers`](http://docs.gl/es3/glDrawBuffers) and
+    /// [`glReadBuffer`](http://docs.gl/es3/glReadBuffer) OpenGL docs
+    fn disable_color_buffers(&self, gl_fbo: &mut FramebufferBinding) {
+        gl_fbo.validate();
+        let extensions = self._extensions();
+        if extensions.has_draw_buffers() {
+            _draw_buffers(&[gl::NONE]);
+        }
+        if extensions.has_read_buffer() {
+            _read_buffer(gl::NONE);
+        }
+    }
+
+    /// Select the color attachments of a framebuffer that a fragment
+    /// shader's outputs are written to, for rendering to multiple render
+    /// targets. Output `i` (such as `gl_FragData[i]`) is written to
+    /// `buffers[i]`, or is discarded if `buffers[i]` is `None`. With OpenGL
+    /// ES 3, `buffers[i]` must be either `None` or `COLOR_ATTACHMENTi`.
+    ///
+    /// # Panics
+    /// This function will panic if the context doesn't support
+    /// `glDrawBuffers` (see [`Extensions::has_draw_buffers`]
+    /// (../../extensions/struct.Extensions.html#method.has_draw_buffers)),
+    /// or if an attachment isn't a color attachment.
+    ///
+    /// # See also
+    /// [`glDrawBuffers`](http://docs.gl/es3/glDrawBuffers) OpenGL docs
+    fn draw_buffers(&self,
+                    gl_fbo: &mut FramebufferBinding,
+                    buffers: &[Option<FramebufferAttachment>])
+    {
+        gl_fbo.validate();
+        assert!(self._extensions().has_draw_buffers(),
+                "glDrawBuffers is not supported by the current context");
+
+        let buffers: Vec<GLenum> = buffers.iter().map(|buffer| {
+            match *buffer {
+                Some(attachment) => {
+                    assert!(attachment.color_index().is_some(),
+                            "{:?} is not a color attachment", attachment);
+                    attachment.gl_enum()
+                },
+                None => { gl::NONE }
+            }
+        }).collect();
+        _draw_buffers(&buffers);
+    }
+
+    /// Select the color attachment of a framebuffer that pixels are read
+    /// from (such as by [`gl.read_pixels`]
+    /// (trait.ContextFramebufferExt.html#method.read_pixels)), or `None` to
+    /// read from no color attachment.
+    ///
+    /// # Panics
+    /// This function will panic if the context doesn't support
+    /// `glReadBuffer` (see [`Extensions::has_read_buffer`]
+    /// (../../extensions/struct.Extensions.html#method.has_read_buffer)),
+    /// or if the attachment isn't a color attachment.
+    ///
+    /// # See also
+    /// [`glReadBuffer`](http://docs.gl/es3/glReadBuffer) OpenGL docs
+    fn read_buffer(&self,
+                   gl_fbo: &mut FramebufferBinding,
+                   buffer: Option<FramebufferAttachment>)
+    {
+        gl_fbo.validate();
+        assert!(self._extensions().has_read_buffer(),
+                "glReadBuffer is not supported by the current context");
+
+        let buffer = match buffer {
+            Some(attachment) => {
+                assert!(attachment.color_index().is_some(),
+                        "{:?} is not a color attachment", attachment);
+                attachment.gl_enum()
+            },
+            None => { gl::NONE }
+        };
+        _read_buffer(buffer);
+    }
+
     // TODO: Think about this function signature harder (and all draw calls).
     // Should this require a &mut FramebufferBinding, to prevent a
     // no-op glClear(), and for (future) multi-threaded safety?
@@ -153,10 +424,258 @@ pub trait ContextFramebufferExt: BaseContext {
     /// [`glClear`](http://docs.gl/es2/glClear) OpenGL docs
     fn clear(&self, buffers: BufferBits) {
         unsafe {
-            gl::Clear(buffers.bits());
+            gl_fns::Clear(buffers.bits());
+            dbg_gl_sanity_check! {
+                in "glClear"();
+                GLError::InvalidValue => "`mask` includes a bit other than an allowed value",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Clear only the area of the currently-bound drawing buffers inside of
+    /// `rect`. The color buffer is cleared to `color`, and the depth and
+    /// stencil buffers are cleared using the current clear values (see
+    /// [`gl.clear_depthf`](../trait.ContextExt.html#method.clear_depthf)
+    /// and [`gl.clear_stencil`](../trait.ContextExt.html#method.clear_stencil)).
+    ///
+    /// This temporarily enables [`SCISSOR_TEST`]
+    /// (../../types/constant.SCISSOR_TEST.html) and sets the scissor box
+    /// and clear color. The scissor test, scissor box, and clear color are
+    /// restored afterwards, so this can be used in the middle of drawing
+    /// without disturbing any other state. If the scissor test is already
+    /// enabled, only the part of `rect` inside of the current scissor box
+    /// is cleared (just like [`gl.clear`]
+    /// (trait.ContextFramebufferExt.html#method.clear)), and nothing is
+    /// cleared if they don't overlap.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// // Only redraw the part of the UI that changed
+    /// let damage = glitter::Rect::new(64, 32, 200, 24);
+    /// gl.clear_rect(damage,
+    ///               glitter::COLOR_BUFFER_BIT,
+    ///               glitter::Color::rgba(0.0, 0.0, 0.0, 0.0));
+    /// // ...draw the damaged widgets...
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`gl.clear`](trait.ContextFramebufferExt.html#method.clear):
+    /// Clear the whole drawing buffer (or the area inside of the scissor
+    /// box, if the scissor test is already enabled)
+    fn clear_rect(&self, rect: Rect, buffers: BufferBits, color: Color) {
+        unsafe {
+            let scissor_test = gl_fns::IsEnabled(gl::SCISSOR_TEST) == gl::TRUE;
+            let mut scissor_box: [GLint; 4] = [0; 4];
+            gl_fns::GetIntegerv(gl::SCISSOR_BOX, scissor_box.as_mut_ptr());
+            let rect = if scissor_test {
+                match rect.intersection(&_scissor_rect(scissor_box)) {
+                    Some(rect) => { rect },
+                    None => { return; }
+                }
+            }
+            else {
+                rect
+            };
+
+            let mut clear_color: [GLfloat; 4] = [0.0; 4];
+            if buffers.contains(COLOR_BUFFER_BIT) {
+                gl_fns::GetFloatv(gl::COLOR_CLEAR_VALUE,
+                                  clear_color.as_mut_ptr());
+                gl_fns::ClearColor(color.r, color.g, color.b, color.a);
+            }
+
+            if !scissor_test {
+                gl_fns::Enable(gl::SCISSOR_TEST);
+            }
+            gl_fns::Scissor(rect.x as GLint,
+                            rect.y as GLint,
+                            rect.width as GLsizei,
+                            rect.height as GLsizei);
+            gl_fns::Clear(buffers.bits());
             dbg_gl_sanity_check! {
+                in "glClear"();
                 GLError::InvalidValue => "`mask` includes a bit other than an allowed value",
-                _ => "Unkown error"
+                _ => "Unknown error"
+            }
+
+            gl_fns::Scissor(scissor_box[0],
+                            scissor_box[1],
+                            scissor_box[2],
+                            scissor_box[3]);
+            if !scissor_test {
+                gl_fns::Disable(gl::SCISSOR_TEST);
+            }
+            if buffers.contains(COLOR_BUFFER_BIT) {
+                gl_fns::ClearColor(clear_color[0],
+                                   clear_color[1],
+                                   clear_color[2],
+                                   clear_color[3]);
+            }
+        }
+    }
+
+    /// Read the pixels inside of `viewport` from the currently-bound read
+    /// framebuffer (or from its read buffer, see [`gl.read_buffer`]
+    /// (trait.ContextFramebufferExt.html#method.read_buffer)). The pixels
+    /// are returned as an [`rgba8`]
+    /// (../../image_data/struct.ImageFormat.html#method.rgba8) image, which
+    /// is the only format that every context can read. The rows are tightly
+    /// packed (regardless of the `PACK_ALIGNMENT`), and are returned in the
+    /// order OpenGL stores them: the bottom row first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    /// use glitter::Image2d;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let pixels = gl.read_pixels(glitter::Viewport::new(0, 0, 640, 480));
+    /// assert_eq!(pixels.texel_bytes().len(), 640 * 480 * 4);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glReadPixels`](http://docs.gl/es2/glReadPixels) OpenGL docs
+    fn read_pixels(&self, viewport: Viewport) -> Image2dBuf {
+        let format = ImageFormat::rgba8();
+        let (width, height) = (viewport.width as usize,
+                               viewport.height as usize);
+        let bytes = unsafe {
+            let stride = _pack_row_stride(width, format);
+            let mut bytes = vec![0; stride * height];
+            _read_pixels(viewport, format, bytes.as_mut_ptr() as *mut GLvoid);
+            _remove_row_padding(&bytes, width, height, stride, format)
+        };
+
+        Image2dBuf::new(bytes, width, height, format)
+    }
+
+    /// Start reading the pixels inside of `viewport` from the
+    /// currently-bound read framebuffer, without waiting for drawing to
+    /// finish. The pixels are copied into a buffer object (bound to
+    /// `GL_PIXEL_PACK_BUFFER`), and the returned [`PendingReadback`]
+    /// (struct.PendingReadback.html) can be resolved into an image once
+    /// the copy is done, usually a frame or two later.
+    ///
+    /// `format` must be [`rgba8`]
+    /// (../../image_data/struct.ImageFormat.html#method.rgba8) (which
+    /// every context can read), or the format reported by
+    /// `GL_IMPLEMENTATION_COLOR_READ_FORMAT` and
+    /// `GL_IMPLEMENTATION_COLOR_READ_TYPE` for the read framebuffer.
+    ///
+    /// If the context doesn't support pixel buffer objects (see
+    /// [`Extensions::has_pixel_buffer_object`]
+    /// (../../extensions/struct.Extensions.html#method.has_pixel_buffer_object)
+    /// and [`Extensions::has_map_buffer_range`]
+    /// (../../extensions/struct.Extensions.html#method.has_map_buffer_range)),
+    /// the pixels are read immediately instead (like [`gl.read_pixels`]
+    /// (trait.ContextFramebufferExt.html#method.read_pixels)), and the
+    /// readback is ready right away.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    /// use glitter::{ImageFormat, Image2d};
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// // Read the object ID under the cursor for color picking
+    /// let mut pending = Some(gl.read_pixels_async(
+    ///     glitter::Viewport::new(320, 240, 1, 1),
+    ///     ImageFormat::rgba8()
+    /// ));
+    ///
+    /// // ...then check on it each frame
+    /// if let Some(readback) = pending.take() {
+    ///     match readback.try_resolve(&gl) {
+    ///         Ok(pixel) => {
+    ///             let id = pixel.texel_bytes()[0];
+    ///         },
+    ///         Err(readback) => { pending = Some(readback); }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glReadPixels`](http://docs.gl/es3/glReadPixels) OpenGL docs
+    fn read_pixels_async(&self, viewport: Viewport, format: ImageFormat)
+        -> PendingReadback
+    {
+        let (width, height) = (viewport.width as usize,
+                               viewport.height as usize);
+        let extensions = self._extensions();
+        let stride = unsafe { _pack_row_stride(width, format) };
+        let size = stride * height;
+
+        let use_buffer = extensions.has_pixel_buffer_object()
+            && extensions.has_map_buffer_range();
+        if !use_buffer || size == 0 {
+            let bytes = unsafe {
+                let mut bytes = vec![0; size];
+                _read_pixels(viewport,
+                             format,
+                             bytes.as_mut_ptr() as *mut GLvoid);
+                _remove_row_padding(&bytes, width, height, stride, format)
+            };
+            return PendingReadback {
+                width: width,
+                height: height,
+                format: format,
+                stride: stride,
+                state: ReadbackState::Ready(bytes)
+            };
+        }
+
+        // NOTE: `GL_PIXEL_PACK_BUFFER` is not tracked by any context, so
+        //       it's free to use here, but it must be unbound afterwards,
+        //       since other calls to `glReadPixels` expect it to be 0
+        unsafe {
+            let mut id: GLuint = 0;
+            gl_fns::GenBuffers(1, &mut id as *mut GLuint);
+            let buffer = Buffer::from_raw(id);
+
+            _bind_pixel_pack_buffer(id);
+            gl_fns::BufferData(gl::PIXEL_PACK_BUFFER,
+                               size as GLsizeiptr,
+                               ptr::null(),
+                               gl::STREAM_READ);
+            stats::_set_size(gl::PIXEL_PACK_BUFFER, None, size);
+            dbg_gl_error! {
+                in "glBufferData"(size = size);
+                GLError::OutOfMemory => "Unable to create a large enough buffer",
+                _ => "Unknown error"
+            }
+            _read_pixels(viewport, format, ptr::null_mut());
+            _bind_pixel_pack_buffer(0);
+
+            let sync = if extensions.has_sync() {
+                let sync = gl_fns::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+                // Make sure the fence is sent to the GPU, so that it will
+                // eventually be signaled
+                gl_fns::Flush();
+                Some(SyncObject(sync))
+            }
+            else {
+                None
+            };
+
+            PendingReadback {
+                width: width,
+                height: height,
+                format: format,
+                stride: stride,
+                state: ReadbackState::Buffered(buffer, sync)
             }
         }
     }
@@ -166,18 +685,498 @@ impl<C: BaseContext> ContextFramebufferExt for C {
 
 }
 
+fn _invalidate_framebuffer(target: FramebufferTarget, attachments: &[GLenum]) {
+    if attachments.is_empty() || !gl_fns::InvalidateFramebuffer::is_loaded() {
+        return;
+    }
+
+    unsafe {
+        gl_fns::InvalidateFramebuffer(target.gl_enum(),
+                                      attachments.len() as GLsizei,
+                                      attachments.as_ptr());
+        dbg_gl_sanity_check! {
+            in "glInvalidateFramebuffer"();
+            GLError::InvalidEnum => "`target` is not `GL_FRAMEBUFFER`, or an attachment is not valid for the bound framebuffer",
+            GLError::InvalidOperation => "An attachment is `GL_COLOR_ATTACHMENTm`, where `m` is not less than `GL_MAX_COLOR_ATTACHMENTS`",
+            _ => "Unknown error"
+        }
+    }
+}
+
+// Convert a `GL_SCISSOR_BOX` value to a `Rect`, clipping off any part of
+// the box below or to the left of the origin (which can't be drawn to)
+fn _scissor_rect(scissor_box: [GLint; 4]) -> Rect {
+    let (x, y, width, height) = (scissor_box[0],
+                                 scissor_box[1],
+                                 scissor_box[2],
+                                 scissor_box[3]);
+    let (x, width) = if x < 0 { (0, width + x) } else { (x, width) };
+    let (y, height) = if y < 0 { (0, height + y) } else { (y, height) };
+    Rect::new(x as u32,
+              y as u32,
+              cmp::max(width, 0) as u32,
+              cmp::max(height, 0) as u32)
+}
+
+fn _draw_buffers(buffers: &[GLenum]) {
+    unsafe {
+        gl_fns::DrawBuffers(buffers.len() as GLsizei, buffers.as_ptr());
+        dbg_gl_sanity_check! {
+            in "glDrawBuffers"();
+            GLError::InvalidEnum => "A buffer is not an accepted value",
+            GLError::InvalidValue => "More buffers were given than `GL_MAX_DRAW_BUFFERS`",
+            GLError::InvalidOperation => "A buffer is not `GL_NONE` or the color attachment for its output",
+            _ => "Unknown error"
+        }
+    }
+}
+
+fn _read_buffer(buffer: GLenum) {
+    unsafe {
+        gl_fns::ReadBuffer(buffer);
+        dbg_gl_sanity_check! {
+            in "glReadBuffer"();
+            GLError::InvalidEnum => "`src` is not an accepted value",
+            GLError::InvalidOperation => "`src` is a color attachment that the framebuffer can't have",
+            _ => "Unknown error"
+        }
+    }
+}
+
+// Get the number of bytes between rows that OpenGL uses when writing image
+// data of a given width (rows are padded to `GL_PACK_ALIGNMENT`)
+unsafe fn _pack_row_stride(width: usize, format: ImageFormat) -> usize {
+    let mut alignment: GLint = 0;
+    gl_fns::GetIntegerv(gl::PACK_ALIGNMENT, &mut alignment);
+    let alignment = if alignment > 0 { alignment as usize } else { 1 };
+
+    let row_len = width * format.bytes_per_texel();
+    (row_len + alignment - 1) / alignment * alignment
+}
+
+// Read pixels from the current read framebuffer into `pixels`, which is
+// an offset into the bound `GL_PIXEL_PACK_BUFFER` if there is one
+unsafe fn _read_pixels(viewport: Viewport,
+                       format: ImageFormat,
+                       pixels: *mut GLvoid)
+{
+    gl_fns::ReadPixels(viewport.x as GLint,
+                       viewport.y as GLint,
+                       viewport.width as GLsizei,
+                       viewport.height as GLsizei,
+                       format.texel_format.gl_enum(),
+                       format.texel_type.gl_enum(),
+                       pixels);
+    dbg_gl_sanity_check! {
+        in "glReadPixels"(format = format);
+        GLError::InvalidEnum => "`format` or `type` is not an accepted value",
+        GLError::InvalidValue => "`width` or `height` is negative",
+        GLError::InvalidOperation => "`format` and `type` can't be read from the read buffer, or the pixel pack buffer is too small",
+        GLError::InvalidFramebufferOperation => "The read framebuffer is not framebuffer-complete",
+        _ => "Unknown error"
+    }
+}
+
+// Copy the rows of image data without the padding at the end of each row
+fn _remove_row_padding(bytes: &[u8],
+                       width: usize,
+                       height: usize,
+                       stride: usize,
+                       format: ImageFormat)
+    -> Vec<u8>
+{
+    let row_len = width * format.bytes_per_texel();
+    let mut packed = Vec::with_capacity(row_len * height);
+    for row in 0..height {
+        let start = row * stride;
+        packed.extend_from_slice(&bytes[start..(start + row_len)]);
+    }
+    packed
+}
+
+fn _bind_pixel_pack_buffer(id: GLuint) {
+    unsafe {
+        gl_fns::BindBuffer(gl::PIXEL_PACK_BUFFER, id);
+        stats::_bind(gl::PIXEL_PACK_BUFFER, id);
+    }
+}
+
+// A fence sync object, which is deleted when dropped
+struct SyncObject(GLsync);
+
+impl Drop for SyncObject {
+    fn drop(&mut self) {
+        unsafe {
+            gl_fns::DeleteSync(self.0);
+        }
+    }
+}
+
+enum ReadbackState {
+    Ready(Vec<u8>),
+    Buffered(Buffer, Option<SyncObject>)
+}
+
+/// Pixels that are being read from a framebuffer in the background, as
+/// started by [`gl.read_pixels_async`]
+/// (trait.ContextFramebufferExt.html#method.read_pixels_async). Once the
+/// pixels have been copied (see [`is_ready`]
+/// (struct.PendingReadback.html#method.is_ready)), the readback can be
+/// resolved into an image without stalling.
+///
+/// Like [`gl.read_pixels`]
+/// (trait.ContextFramebufferExt.html#method.read_pixels), the resolved
+/// image is tightly packed, and has the bottom row first.
+pub struct PendingReadback {
+    width: usize,
+    height: usize,
+    format: ImageFormat,
+    stride: usize,
+    state: ReadbackState
+}
+
+impl PendingReadback {
+    /// Get the width and height of the image being read, in pixels.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Get the format of the image being read.
+    pub fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    /// Returns `true` if the pixels have finished being copied, so that
+    /// [`resolve`](struct.PendingReadback.html#method.resolve) won't
+    /// wait for the GPU. This always returns `true` if the context doesn't
+    /// support fence sync objects (see [`Extensions::has_sync`]
+    /// (../../extensions/struct.Extensions.html#method.has_sync)), in which
+    /// case resolving may still wait.
+    pub fn is_ready<C: BaseContext>(&self, _gl: &C) -> bool {
+        match self.state {
+            ReadbackState::Buffered(_, Some(ref sync)) => unsafe {
+                let mut status: GLint = 0;
+                gl_fns::GetSynciv(sync.0,
+                                  gl::SYNC_STATUS,
+                                  1,
+                                  ptr::null_mut(),
+                                  &mut status);
+                status as GLenum == gl::SIGNALED
+            },
+            _ => { true }
+        }
+    }
+
+    /// Get the image that was read, waiting for the pixels to be copied if
+    /// they haven't been yet. The buffer used for the readback is bound to
+    /// `GL_PIXEL_PACK_BUFFER` while it's read, and is deleted afterwards.
+    ///
+    /// # Panics
+    /// This function will panic if the buffer used for the readback could
+    /// not be mapped.
+    pub fn resolve<C: BaseContext>(self, _gl: &C) -> Image2dBuf {
+        let (width, height, format) = (self.width, self.height, self.format);
+        let bytes = match self.state {
+            ReadbackState::Ready(bytes) => { bytes },
+            ReadbackState::Buffered(buffer, _sync) => unsafe {
+                let size = self.stride * height;
+                _bind_pixel_pack_buffer(buffer.id());
+                let ptr = gl_fns::MapBufferRange(gl::PIXEL_PACK_BUFFER,
+                                                 0,
+                                                 size as GLsizeiptr,
+                                                 gl::MAP_READ_BIT);
+                dbg_gl_error! {
+                    in "glMapBufferRange"(size = size);
+                    GLError::OutOfMemory => "Unable to map the buffer",
+                    _ => "Unknown error"
+                }
+                assert!(!ptr.is_null(), "Unable to map the readback buffer");
+
+                let mapped = slice::from_raw_parts(ptr as *const u8, size);
+                let bytes = _remove_row_padding(mapped,
+                                                width,
+                                                height,
+                                                self.stride,
+                                                format);
+                // NOTE: If the buffer's contents were lost while it was
+                //       mapped, there's no way to read the pixels again, so
+                //       the result of unmapping is ignored
+                gl_fns::UnmapBuffer(gl::PIXEL_PACK_BUFFER);
+                _bind_pixel_pack_buffer(0);
+                bytes
+            }
+        };
+
+        Image2dBuf::new(bytes, width, height, format)
+    }
+
+    /// Get the image that was read if the pixels have finished being
+    /// copied (see [`is_ready`](struct.PendingReadback.html#method.is_ready)),
+    /// or give back the pending readback otherwise.
+    pub fn try_resolve<C: BaseContext>(self, gl: &C)
+        -> Result<Image2dBuf, PendingReadback>
+    {
+        if self.is_ready(gl) {
+            Ok(self.resolve(gl))
+        }
+        else {
+            Err(self)
+        }
+    }
+}
 
 
 enum BuilderAttachment<'a> {
     Texture2d(&'a mut Texture2d, i32),
-    Renderbuffer(&'a mut Renderbuffer)
+    TextureCubeFace(&'a mut TextureCubeMap, TxCubeMapImageTarget, i32),
+    Texture2dMultisample(&'a mut Texture2dMultisample),
+    Renderbuffer(&'a mut Renderbuffer),
+
+    // NOTE: Layers of textures are stored by ID, since they can be attached
+    //       from different types of textures. The texture is still
+    //       borrowed by the builder for `'a`.
+    TextureLayer(GLuint, TextureBindingTarget, i32, u32),
+    TextureLayered(GLuint, TextureBindingTarget, i32)
+}
+
+// NOTE: `GL_FRAMEBUFFER_INCOMPLETE_DIMENSIONS` was removed in OpenGL ES 3,
+//       and was never part of core desktop OpenGL, so it's not included in
+//       the `gl` crate
+const FRAMEBUFFER_INCOMPLETE_DIMENSIONS: GLenum = 0x8CD9;
+
+// Map a status returned by `glCheckFramebufferStatus` to an error, or `None`
+// if the framebuffer is complete (or if the status is unknown)
+fn _framebuffer_error(status: GLenum, extensions: &Extensions)
+    -> Option<GLFramebufferError>
+{
+    let is_es2 = extensions.version().map_or(false, |version| {
+        version.es && !version.is_at_least(3, 0)
+    });
+
+    match status {
+        gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => {
+            Some(GLFramebufferError::IncompleteAttachment)
+        },
+        FRAMEBUFFER_INCOMPLETE_DIMENSIONS if is_es2 => {
+            Some(GLFramebufferError::IncompleteDimensions)
+        },
+        gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => {
+            Some(GLFramebufferError::IncompleteMissingAttachment)
+        },
+        gl::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => {
+            Some(GLFramebufferError::IncompleteMultisample)
+        },
+        gl::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => {
+            Some(GLFramebufferError::IncompleteDrawBuffer)
+        },
+        gl::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => {
+            Some(GLFramebufferError::IncompleteReadBuffer)
+        },
+        gl::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => {
+            Some(GLFramebufferError::IncompleteLayerTargets)
+        },
+        gl::FRAMEBUFFER_UNSUPPORTED => {
+            Some(GLFramebufferError::Unsupported)
+        },
+        _ => { None }
+    }
+}
+
+unsafe fn _framebuffer_texture_layer(target: FramebufferTarget,
+                                     attachment: FramebufferAttachment,
+                                     id: GLuint,
+                                     level: i32,
+                                     layer: u32)
+{
+    gl_fns::FramebufferTextureLayer(target.gl_enum(),
+                                    attachment.gl_enum(),
+                                    id,
+                                    level as GLint,
+                                    layer as GLint);
+    dbg_gl_sanity_check! {
+        in "glFramebufferTextureLayer"(level = level, layer = layer);
+        GLError::InvalidEnum => "`target` is not an accepted framebuffer target, or `attachment` is not an accepted attachment point",
+        GLError::InvalidValue => "`level` or `layer` is out of range for the texture",
+        GLError::InvalidOperation => "Framebuffer object 0 is bound, or `texture` is not the name of a 2D array or 3D texture",
+        _ => "Unknown error"
+    }
+}
+
+unsafe fn _framebuffer_texture(target: FramebufferTarget,
+                               attachment: FramebufferAttachment,
+                               id: GLuint,
+                               level: i32)
+{
+    gl_fns::FramebufferTexture(target.gl_enum(),
+                               attachment.gl_enum(),
+                               id,
+                               level as GLint);
+    dbg_gl_sanity_check! {
+        in "glFramebufferTexture"(level = level);
+        GLError::InvalidEnum => "`target` is not an accepted framebuffer target, or `attachment` is not an accepted attachment point",
+        GLError::InvalidValue => "`level` is out of range for the texture",
+        GLError::InvalidOperation => "Framebuffer object 0 is bound, or `texture` is neither 0 nor the name of an existing texture",
+        _ => "Unknown error"
+    }
+}
+
+// Describe an attachment of a `FramebufferBuilder`, without querying its
+// format or dimensions
+fn _attachment_info(attachment: FramebufferAttachment,
+                    attached: &BuilderAttachment)
+    -> FramebufferAttachmentInfo
+{
+    let (kind, id, level) = match *attached {
+        BuilderAttachment::Texture2d(ref texture, level) => {
+            (FramebufferImageKind::Texture2d, texture.id(), level)
+        },
+        BuilderAttachment::TextureCubeFace(ref texture, face, level) => {
+            (FramebufferImageKind::TextureCubeFace(face), texture.id(), level)
+        },
+        BuilderAttachment::Texture2dMultisample(ref texture) => {
+            (FramebufferImageKind::Texture2dMultisample, texture.id(), 0)
+        },
+        BuilderAttachment::Renderbuffer(ref renderbuffer) => {
+            (FramebufferImageKind::Renderbuffer, renderbuffer.id(), 0)
+        },
+        BuilderAttachment::TextureLayer(id, target, level, layer) => {
+            (FramebufferImageKind::TextureLayer(target, layer), id, level)
+        },
+        BuilderAttachment::TextureLayered(id, target, level) => {
+            (FramebufferImageKind::TextureLayered(target), id, level)
+        }
+    };
+    FramebufferAttachmentInfo {
+        attachment: attachment,
+        kind: kind,
+        id: id,
+        level: level,
+        internal_format: None,
+        dimensions: None,
+        samples: None
+    }
+}
+
+// Query the format, dimensions, and sample count of an attached image, if
+// the context supports it. The image is bound temporarily, and the previous
+// binding is restored afterwards, so the context's bindings (and the bind
+// cache) are left unchanged.
+unsafe fn _query_attachment_info(info: &mut FramebufferAttachmentInfo,
+                                 extensions: &Extensions)
+{
+    match info.kind {
+        FramebufferImageKind::Renderbuffer => {
+            let mut previous: GLint = 0;
+            gl_fns::GetIntegerv(gl::RENDERBUFFER_BINDING, &mut previous);
+            gl_fns::BindRenderbuffer(gl::RENDERBUFFER, info.id);
+
+            let param = |pname| {
+                let mut value: GLint = 0;
+                gl_fns::GetRenderbufferParameteriv(gl::RENDERBUFFER,
+                                                   pname,
+                                                   &mut value);
+                value
+            };
+            info.internal_format =
+                Some(param(gl::RENDERBUFFER_INTERNAL_FORMAT) as GLenum);
+            info.dimensions = Some((param(gl::RENDERBUFFER_WIDTH) as u32,
+                                    param(gl::RENDERBUFFER_HEIGHT) as u32));
+            if extensions.has_renderbuffer_multisample() {
+                info.samples = Some(param(gl::RENDERBUFFER_SAMPLES) as u32);
+            }
+            else {
+                info.samples = Some(0);
+            }
+
+            gl_fns::BindRenderbuffer(gl::RENDERBUFFER, previous as GLuint);
+        },
+        _ if extensions.has_tex_level_parameter() => {
+            let (target, binding, image_target) = match info.kind {
+                FramebufferImageKind::Texture2d => {
+                    (gl::TEXTURE_2D, gl::TEXTURE_BINDING_2D, gl::TEXTURE_2D)
+                },
+                FramebufferImageKind::TextureCubeFace(face) => {
+                    (gl::TEXTURE_CUBE_MAP,
+                     gl::TEXTURE_BINDING_CUBE_MAP,
+                     face.gl_enum())
+                },
+                FramebufferImageKind::Texture2dMultisample => {
+                    (gl::TEXTURE_2D_MULTISAMPLE,
+                     gl::TEXTURE_BINDING_2D_MULTISAMPLE,
+                     gl::TEXTURE_2D_MULTISAMPLE)
+                },
+                FramebufferImageKind::TextureLayer(target, _)
+                | FramebufferImageKind::TextureLayered(target) => {
+                    // NOTE: Every layer has the same format and size, so
+                    //       the first face of a cubemap is queried
+                    match target {
+                        TextureBindingTarget::TextureCubeMap => {
+                            (gl::TEXTURE_CUBE_MAP,
+                             gl::TEXTURE_BINDING_CUBE_MAP,
+                             gl::TEXTURE_CUBE_MAP_POSITIVE_X)
+                        },
+                        TextureBindingTarget::Texture2dArray => {
+                            (gl::TEXTURE_2D_ARRAY,
+                             gl::TEXTURE_BINDING_2D_ARRAY,
+                             gl::TEXTURE_2D_ARRAY)
+                        },
+                        _ => {
+                            (gl::TEXTURE_3D,
+                             gl::TEXTURE_BINDING_3D,
+                             gl::TEXTURE_3D)
+                        }
+                    }
+                },
+                FramebufferImageKind::Renderbuffer => { unreachable!() }
+            };
+            let mut previous: GLint = 0;
+            gl_fns::GetIntegerv(binding, &mut previous);
+            gl_fns::BindTexture(target, info.id);
+
+            let level = info.level;
+            let param = |pname| {
+                let mut value: GLint = 0;
+                gl_fns::GetTexLevelParameteriv(image_target,
+                                               level,
+                                               pname,
+                                               &mut value);
+                value
+            };
+            info.internal_format =
+                Some(param(gl::TEXTURE_INTERNAL_FORMAT) as GLenum);
+            info.dimensions = Some((param(gl::TEXTURE_WIDTH) as u32,
+                                    param(gl::TEXTURE_HEIGHT) as u32));
+            if info.kind == FramebufferImageKind::Texture2dMultisample {
+                info.samples = Some(param(gl::TEXTURE_SAMPLES) as u32);
+            }
+            else {
+                info.samples = Some(0);
+            }
+
+            gl_fns::BindTexture(target, previous as GLuint);
+        },
+        _ => { }
+    }
+    dbg_gl_sanity_check! {
+        GLError::InvalidEnum => "A parameter name is not supported by the context",
+        GLError::InvalidValue => "`level` is not a valid mipmap level",
+        _ => "Unknown error"
+    }
 }
 
+
 /// Provides a safe interface for building a framebuffer object that
 /// is checked to be framebuffer-complete. A `FramebufferBuilder` can
 /// be created using the [`gl.build_framebuffer`]
 /// (trait.ContextFramebufferBuilderExt.html#method.build_framebuffer) method.
 ///
+/// When more than one color attachment is used, each fragment shader output
+/// is written to the color attachment with the same index (see
+/// [`gl.draw_buffers`](trait.ContextFramebufferExt.html#method.draw_buffers)).
+/// When no color attachment is used, the framebuffer's draw and read buffers
+/// are disabled (see [`gl.disable_color_buffers`]
+/// (trait.ContextFramebufferExt.html#method.disable_color_buffers)).
+///
 /// # Note
 /// The current implementation of `FramebufferBuilder` uses heap-allocation
 /// during construction. This should be fixed in a future release, but
@@ -208,8 +1207,17 @@ impl<'a, C> FramebufferBuilder<'a, C>
     /// `level` should be 0, or unwrapping the framebuffer will fail.
     ///
     /// # Note
-    /// Currently, only [`Texture2d`](../../texture/type.Texture2d.html)
-    /// textures are supported using a `FramebufferBuilder`. To bind a different
+    /// Currently, only [`Texture2d`](../../texture/type.Texture2d.html),
+    /// [`TextureCubeMap`](../../texture/type.TextureCubeMap.html) (see
+    /// [`texture_cube_face`](struct.FramebufferBuilder.html#method.texture_cube_face)),
+    /// [`Texture2dMultisample`]
+    /// (../../texture/type.Texture2dMultisample.html), and layers of
+    /// [`Texture2dArray`](../../texture/type.Texture2dArray.html) and
+    /// [`Texture3d`](../../texture/type.Texture3d.html) (see
+    /// [`texture_layer`](struct.FramebufferBuilder.html#method.texture_layer)
+    /// and [`texture_layered`]
+    /// (struct.FramebufferBuilder.html#method.texture_layered)) textures are
+    /// supported using a `FramebufferBuilder`. To bind a different
     /// type of texture, use [`gl.framebuffer_texture_2d`](trait.ContextFramebufferExt.html#method.framebuffer_texture_2d)
     /// on an existing framebuffer object instead (generated either with
     /// a `FramebufferBuilder` or with [`gl.gen_framebuffer`](trait.ContextFramebufferExt.html#method.gen_framebuffer)).
@@ -228,6 +1236,66 @@ impl<'a, C> FramebufferBuilder<'a, C>
         self
     }
 
+    /// Add one face of a cubemap texture (at the mipmap level specified by
+    /// `level`) to the framebuffer's attachment point. Rendering to each
+    /// face of a cubemap is used for point light shadow maps and
+    /// environment maps.
+    ///
+    /// # Failures
+    /// `level` should be 0, or unwrapping the framebuffer will fail.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let mut cubemap: glitter::TextureCubeMap = unsafe {
+    ///     gl.gen_texture()
+    /// };
+    /// // ...allocate an image for each face of the cubemap...
+    ///
+    /// let mut fbo = gl.build_framebuffer()
+    ///     .texture_cube_face(glitter::COLOR_ATTACHMENT0,
+    ///                        &mut cubemap,
+    ///                        glitter::TEXTURE_CUBE_MAP_POSITIVE_X,
+    ///                        0)
+    ///     .unwrap();
+    /// ```
+    pub fn texture_cube_face(mut self,
+                             attachment: FramebufferAttachment,
+                             texture: &'a mut TextureCubeMap,
+                             face: TxCubeMapImageTarget,
+                             level: i32)
+        -> Self
+    {
+        let attached = BuilderAttachment::TextureCubeFace(texture,
+                                                          face,
+                                                          level);
+        match self.attachments.entry(attachment) {
+            Entry::Occupied(mut e) => { e.insert(attached); },
+            Entry::Vacant(e) => { e.insert(attached); }
+        };
+
+        self
+    }
+
+    /// Add a multisampled 2D texture to the framebuffer's attachment point.
+    /// Every attachment of a framebuffer must have the same number of
+    /// samples, or unwrapping the framebuffer will fail.
+    pub fn texture_2d_multisample(mut self,
+                                  attachment: FramebufferAttachment,
+                                  texture: &'a mut Texture2dMultisample)
+        -> Self
+    {
+        let attached = BuilderAttachment::Texture2dMultisample(texture);
+        match self.attachments.entry(attachment) {
+            Entry::Occupied(mut e) => { e.insert(attached); },
+            Entry::Vacant(e) => { e.insert(attached); }
+        };
+
+        self
+    }
+
     /// Add a renderbuffer to the framebuffer's attachment point.
     pub fn renderbuffer(mut self,
                         attachment: FramebufferAttachment,
@@ -243,12 +1311,84 @@ impl<'a, C> FramebufferBuilder<'a, C>
         self
     }
 
+    /// Add a single layer of a 2D array or 3D texture (at the mipmap level
+    /// specified by `level`) to the framebuffer's attachment point, such as
+    /// one cascade of a cascaded shadow map. See
+    /// [`gl.framebuffer_texture_layer`]
+    /// (trait.ContextFramebufferExt.html#method.framebuffer_texture_layer).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let mut cascades: glitter::Texture2dArray = unsafe {
+    ///     gl.gen_texture()
+    /// };
+    /// // ...allocate the cascades with `gl.tex_image_3d_sized`...
+    ///
+    /// let fbo = gl.build_framebuffer()
+    ///     .texture_layer(glitter::DEPTH_ATTACHMENT, &mut cascades, 0, 2)
+    ///     .unwrap();
+    /// ```
+    pub fn texture_layer<T>(mut self,
+                            attachment: FramebufferAttachment,
+                            texture: &'a mut Texture<T>,
+                            level: i32,
+                            layer: u32)
+        -> Self
+        where T: ArrayTextureType
+    {
+        let attached = BuilderAttachment::TextureLayer(texture.id(),
+                                                       T::target(),
+                                                       level,
+                                                       layer);
+        match self.attachments.entry(attachment) {
+            Entry::Occupied(mut e) => { e.insert(attached); },
+            Entry::Vacant(e) => { e.insert(attached); }
+        };
+
+        self
+    }
+
+    /// Add every layer of a cubemap, 2D array, or 3D texture (at the mipmap
+    /// level specified by `level`) to the framebuffer's attachment point,
+    /// for layered rendering with a geometry shader. See
+    /// [`gl.framebuffer_texture`]
+    /// (trait.ContextFramebufferExt.html#method.framebuffer_texture).
+    ///
+    /// # Failures
+    /// If one attachment is layered, every attachment must be layered, or
+    /// unwrapping the framebuffer will fail.
+    pub fn texture_layered<T>(mut self,
+                              attachment: FramebufferAttachment,
+                              texture: &'a mut Texture<T>,
+                              level: i32)
+        -> Self
+        where T: LayeredTextureType
+    {
+        let attached = BuilderAttachment::TextureLayered(texture.id(),
+                                                         T::target(),
+                                                         level);
+        match self.attachments.entry(attachment) {
+            Entry::Occupied(mut e) => { e.insert(attached); },
+            Entry::Vacant(e) => { e.insert(attached); }
+        };
+
+        self
+    }
+
     /// Create and return a framebuffer with the specified options, or
     /// return an error.
     ///
     /// # Failures
-    /// If the resulting framebuffer is not framebuffer-complete, an error
-    /// will be returned.
+    /// If the resulting framebuffer is not framebuffer-complete,
+    /// a [`GLError::FramebufferIncomplete`]
+    /// (../../types/enum.GLError.html#variant.FramebufferIncomplete) error
+    /// will be returned. The error includes the raw status, along with the
+    /// ID, format, and dimensions of each attachment (where the context
+    /// can query them), which is useful for finding out why a combination
+    /// of attachments is unsupported on a particular device.
     ///
     /// # Panics
     /// This function will panic if an OpenGL error was generated with
@@ -259,7 +1399,38 @@ impl<'a, C> FramebufferBuilder<'a, C>
         let fbo_status = {
             let (mut gl_fbo, gl) = gl.bind_framebuffer(&mut fbo);
 
+            let mut color_indices: Vec<u32> = self.attachments.keys()
+                .filter_map(|attachment| attachment.color_index())
+                .collect();
+            color_indices.sort();
+            if color_indices.is_empty() {
+                gl.disable_color_buffers(&mut gl_fbo);
+            }
+            else if color_indices != [0] {
+                // Write each fragment shader output to the color attachment
+                // with the same index, and read from the first attachment
+                let extensions = gl._extensions();
+                if extensions.has_draw_buffers() {
+                    let last = color_indices[color_indices.len() - 1];
+                    let buffers: Vec<_> = (0..last + 1).map(|index| {
+                        if color_indices.contains(&index) {
+                            FramebufferAttachment::color(index)
+                        }
+                        else {
+                            None
+                        }
+                    }).collect();
+                    gl.draw_buffers(&mut gl_fbo, &buffers);
+                }
+                if extensions.has_read_buffer() {
+                    let first = FramebufferAttachment::color(color_indices[0]);
+                    gl.read_buffer(&mut gl_fbo, first);
+                }
+            }
+
+            let mut attachment_infos = vec![];
             for (attachment, attached) in self.attachments.into_iter() {
+                attachment_infos.push(_attachment_info(attachment, &attached));
                 match attached {
                     BuilderAttachment::Texture2d(texture, level) => {
                         gl.framebuffer_texture_2d(&mut gl_fbo,
@@ -268,15 +1439,71 @@ impl<'a, C> FramebufferBuilder<'a, C>
                                                   texture,
                                                   level);
                     },
+                    BuilderAttachment::TextureCubeFace(texture, face, level) => {
+                        gl.framebuffer_texture_2d(&mut gl_fbo,
+                                                  attachment,
+                                                  face,
+                                                  texture,
+                                                  level);
+                    },
+                    BuilderAttachment::Texture2dMultisample(texture) => {
+                        gl.framebuffer_texture_2d(
+                            &mut gl_fbo,
+                            attachment,
+                            Tx2dMultisampleImageTarget::Texture2dMultisample,
+                            texture,
+                            0
+                        );
+                    },
                     BuilderAttachment::Renderbuffer(renderbuffer) => {
                         gl.framebuffer_renderbuffer(&mut gl_fbo,
                                                     attachment,
                                                     renderbuffer);
+                    },
+                    BuilderAttachment::TextureLayer(id, _, level, layer) => {
+                        unsafe {
+                            _framebuffer_texture_layer(gl_fbo.target(),
+                                                       attachment,
+                                                       id,
+                                                       level,
+                                                       layer);
+                        }
+                    },
+                    BuilderAttachment::TextureLayered(id, _, level) => {
+                        unsafe {
+                            _framebuffer_texture(gl_fbo.target(),
+                                                 attachment,
+                                                 id,
+                                                 level);
+                        }
                     }
                 }
             }
 
-            gl.check_framebuffer_status(&mut gl_fbo)
+            let status = unsafe {
+                gl_fns::CheckFramebufferStatus(gl_fbo.target().gl_enum())
+            };
+            if status == gl::FRAMEBUFFER_COMPLETE {
+                None
+            }
+            else {
+                // NOTE: The attachments are only queried when the
+                //       framebuffer is incomplete, since querying them
+                //       can stall the pipeline
+                let extensions = gl._extensions();
+                for info in &mut attachment_infos {
+                    unsafe { _query_attachment_info(info, &extensions); }
+                }
+                attachment_infos.sort_by_key(|info| {
+                    info.attachment.gl_enum()
+                });
+
+                Some(FramebufferIncompleteError {
+                    error: _framebuffer_error(status, &extensions),
+                    status: status,
+                    attachments: attachment_infos
+                })
+            }
         };
 
         match fbo_status {
@@ -332,6 +1559,38 @@ gl_enum! {
         pub const ColorAttachment0 as COLOR_ATTACHMENT0 =
             gl::COLOR_ATTACHMENT0,
 
+        /// The second color buffer attachment point. The additional color
+        /// attachment points can be used up to [`gl.limits().max_color_attachments`]
+        /// (../../limits/struct.ContextLimits.html#structfield.max_color_attachments)
+        /// (which is at least 4 with OpenGL ES 3 and at least 8 with desktop
+        /// OpenGL 3.0).
+        pub const ColorAttachment1 as COLOR_ATTACHMENT1 =
+            gl::COLOR_ATTACHMENT1,
+
+        /// The third color buffer attachment point.
+        pub const ColorAttachment2 as COLOR_ATTACHMENT2 =
+            gl::COLOR_ATTACHMENT2,
+
+        /// The fourth color buffer attachment point.
+        pub const ColorAttachment3 as COLOR_ATTACHMENT3 =
+            gl::COLOR_ATTACHMENT3,
+
+        /// The fifth color buffer attachment point.
+        pub const ColorAttachment4 as COLOR_ATTACHMENT4 =
+            gl::COLOR_ATTACHMENT4,
+
+        /// The sixth color buffer attachment point.
+        pub const ColorAttachment5 as COLOR_ATTACHMENT5 =
+            gl::COLOR_ATTACHMENT5,
+
+        /// The seventh color buffer attachment point.
+        pub const ColorAttachment6 as COLOR_ATTACHMENT6 =
+            gl::COLOR_ATTACHMENT6,
+
+        /// The eighth color buffer attachment point.
+        pub const ColorAttachment7 as COLOR_ATTACHMENT7 =
+            gl::COLOR_ATTACHMENT7,
+
         /// The depth buffer attachment point.
         pub const DepthAttachment as DEPTH_ATTACHMENT =
             gl::DEPTH_ATTACHMENT,
@@ -342,6 +1601,29 @@ gl_enum! {
     }
 }
 
+impl FramebufferAttachment {
+    /// Get the color attachment point with the given index (so `0` is
+    /// `COLOR_ATTACHMENT0`), or `None` if the index is 8 or more.
+    pub fn color(index: u32) -> Option<Self> {
+        if index < 8 {
+            FramebufferAttachment::from_gl(gl::COLOR_ATTACHMENT0 + index).ok()
+        }
+        else {
+            None
+        }
+    }
+
+    /// Get the index of a color attachment point, or `None` if the
+    /// attachment point isn't a color attachment.
+    pub fn color_index(&self) -> Option<u32> {
+        match *self {
+            FramebufferAttachment::DepthAttachment
+            | FramebufferAttachment::StencilAttachment => { None },
+            _ => { Some(self.gl_enum() - gl::COLOR_ATTACHMENT0) }
+        }
+    }
+}
+
 /// An OpenGL context that has a free `GL_FRAMEBUFFER` binding.
 pub trait FramebufferContext: AContext {
     /// The type of binder this context contains.
@@ -365,6 +1647,53 @@ pub trait FramebufferContext: AContext {
         let (mut binder, rest) = self.split_framebuffer();
         (binder.borrow_mut().bind(fbo), rest)
     }
+
+    /// Bind the default framebuffer (the window system's framebuffer) to
+    /// this context's framebuffer, returning a new context and a binding.
+    /// This is used to draw to the window again after drawing to
+    /// a framebuffer object.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// # let mut fbo: glitter::Framebuffer = unsafe { ::std::mem::uninitialized() };
+    /// {
+    ///     let (_gl_fbo, gl) = (&mut gl).bind_framebuffer(&mut fbo);
+    ///     // ... draw to the framebuffer object ...
+    /// }
+    ///
+    /// let window = glitter::DefaultFramebuffer::new();
+    /// let (_gl_window, gl) = gl.bind_default_framebuffer(window);
+    /// // ... draw to the window ...
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glBindFramebuffer`](http://docs.gl/es2/glBindFramebuffer) OpenGL docs
+    fn bind_default_framebuffer(self, fbo: DefaultFramebuffer)
+        -> (DefaultFramebufferBinding, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_framebuffer();
+        (binder.borrow_mut().bind_default(fbo), rest)
+    }
+
+    /// Unbind this context's framebuffer (by binding 0, the default
+    /// framebuffer), returning the freed binder and the remaining context.
+    ///
+    /// # See also
+    /// [`glBindFramebuffer`](http://docs.gl/es2/glBindFramebuffer) OpenGL docs
+    fn unbind_framebuffer(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_framebuffer();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
 impl<B, F, P, R, T> FramebufferContext for ContextOf<B, F, P, R, T>
@@ -395,6 +1724,7 @@ impl<'a, B, F, P, R, T> FramebufferContext for &'a mut ContextOf<B, F, P, R, T>
 /// Represents a framebuffer that has been bound to the `GL_FRAMEBUFFER`
 /// binding target.
 pub struct FramebufferBinding<'a> {
+    _check: BindingCheck,
     _phantom_ref: PhantomData<&'a mut Framebuffer>,
     _phantom_ptr: PhantomData<*mut ()>
 }
@@ -403,6 +1733,24 @@ impl<'a> FramebufferBinding<'a> {
     fn target(&self) -> FramebufferTarget {
         FramebufferTarget::Framebuffer
     }
+
+    fn validate(&self) {
+        self._check.check(gl::FRAMEBUFFER_BINDING, "GL_FRAMEBUFFER");
+    }
+}
+
+/// Represents the default framebuffer after it has been bound to the
+/// `GL_FRAMEBUFFER` binding target. See [`DefaultFramebuffer`]
+/// (../../framebuffer/struct.DefaultFramebuffer.html) for more details.
+pub struct DefaultFramebufferBinding {
+    _check: BindingCheck,
+    _phantom_ptr: PhantomData<*mut ()>
+}
+
+impl DefaultFramebufferBinding {
+    fn validate(&self) {
+        self._check.check(gl::FRAMEBUFFER_BINDING, "GL_FRAMEBUFFER");
+    }
 }
 
 /// The OpenGL state representing the `GL_FRAMEBUFFER` target.
@@ -430,6 +1778,7 @@ impl FramebufferBinder {
     /// binding.
     pub unsafe fn current_binding(&mut self) -> FramebufferBinding {
         FramebufferBinding {
+            _check: BindingCheck::current(gl::FRAMEBUFFER_BINDING),
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         }
@@ -440,16 +1789,48 @@ impl FramebufferBinder {
         -> FramebufferBinding<'a>
     {
         let binding = FramebufferBinding {
+            _check: BindingCheck::new(fbo.id()),
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         };
-        unsafe {
-            gl::BindFramebuffer(binding.target().gl_enum(), fbo.id());
-            dbg_gl_sanity_check! {
-                GLError::InvalidEnum => "`target` is not `GL_FRAMEBUFFER`",
-                _ => "Unknown error"
-            }
-        }
+        _bind_framebuffer(binding.target(), fbo.id());
+        binding.validate();
         binding
     }
+
+    /// Bind the default framebuffer to the `GL_FRAMEBUFFER` target,
+    /// returning a binding.
+    pub fn bind_default(&mut self, fbo: DefaultFramebuffer)
+        -> DefaultFramebufferBinding
+    {
+        let binding = DefaultFramebufferBinding {
+            _check: BindingCheck::new(fbo.id()),
+            _phantom_ptr: PhantomData
+        };
+        _bind_framebuffer(FramebufferTarget::Framebuffer, fbo.id());
+        binding.validate();
+        binding
+    }
+
+    /// Unbind the framebuffer bound to the `GL_FRAMEBUFFER` target, which
+    /// binds the default framebuffer.
+    pub fn unbind(&mut self) {
+        _bind_framebuffer(FramebufferTarget::Framebuffer, 0);
+    }
+}
+
+fn _bind_framebuffer(target: FramebufferTarget, id: GLuint) {
+    if !bind_cache::_bind(target.gl_enum(), id) {
+        return;
+    }
+
+    unsafe {
+        gl_fns::BindFramebuffer(target.gl_enum(), id);
+        frame_stats::_state_change();
+        dbg_gl_sanity_check! {
+            in "glBindFramebuffer"();
+            GLError::InvalidEnum => "`target` is not `GL_FRAMEBUFFER`",
+            _ => "Unknown error"
+        }
+    }
 }
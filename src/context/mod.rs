@@ -4,12 +4,32 @@
 
 use std::borrow::{Borrow, BorrowMut};
 use std::marker::PhantomData;
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::ffi::CStr;
+use std::thread::{self, ThreadId};
 use gl;
+use gl_fns;
 use gl::types::*;
-use types::{Color, Viewport, Capability, GLError};
+use types::{Color, Viewport, Rect, Capability, CullFaceMode, WindingOrder,
+            BlendFactor, DepthFunc, PixelStoreParam,
+            HintTarget, HintMode, GlVersion, GraphicsResetStatus, GLError,
+            ContextError};
 use program::ProgramAttrib;
-use shader::ContextShaderExt;
+use limits::{ContextLimits, GpuMemoryInfo};
+use gl_fns::GlFns;
+use extensions::Extensions;
+use transfer::ShareGroup;
+use shader::{ContextShaderExt, GlslVersion};
+use debug::ContextDebugExt;
 use to_ref::{ToRef, ToMut};
+use validate;
+#[cfg(feature = "leak-tracking")] use leaks::{self, LeakedObject};
+use stats;
+use bind_cache;
+use frame_stats;
+#[cfg(feature = "frame-stats")] use frame_stats::FrameStats;
+#[cfg(feature = "resource-stats")] use stats::ResourceStats;
 
 pub mod buffer_context;
 pub mod framebuffer_context;
@@ -17,6 +37,8 @@ pub mod program_context;
 pub mod renderbuffer_context;
 pub mod texture_context;
 pub mod texture_units;
+pub mod dyn_context;
+pub mod context_builder;
 
 pub use self::buffer_context::*;
 pub use self::framebuffer_context::*;
@@ -24,6 +46,8 @@ pub use self::program_context::*;
 pub use self::renderbuffer_context::*;
 pub use self::texture_context::*;
 pub use self::texture_units::*;
+pub use self::dyn_context::*;
+pub use self::context_builder::*;
 
 /// A "fresh" OpenGL context: one that essentially has no active bindings.
 /// See the [`ContextOf`](struct.ContextOf.html) docs for more details.
@@ -113,28 +137,272 @@ pub type Context = ContextOf<BufferBinder,
 /// - [`FramebufferContext`](framebuffer_context/trait.FramebufferContext.html)
 /// - [`RenderbufferContext`](renderbuffer_context/trait.RenderbufferContext.html)
 /// - [`TextureUnit0Context`](texture_units/trait.TextureUnit0Context.html)
-/// through [`TextureUnit7Context`](texture_units/trait.TextureUnit7Context.html)
+/// through [`TextureUnit15Context`](texture_units/trait.TextureUnit15Context.html)
 pub struct ContextOf<B, F, P, R, T> {
     buffers: B,
     framebuffer: F,
     program: P,
     renderbuffer: R,
     tex_units: T,
+    info: Rc<SharedInfo>,
     _phantom: PhantomData<*mut ()>
 }
 
+// Information about the current context that is queried once (when calling
+// `ContextOf::current_context`), and shared between all of the contexts
+// derived from it
+struct SharedInfo {
+    limits: ContextLimits,
+    extensions: Rc<Extensions>,
+    fns: GlFns,
+    share_group: Cell<ShareGroup>,
+
+    // The thread that the context was created on
+    thread: ThreadId,
+
+    // The texture unit that was last made active through glitter, or
+    // `None` if it isn't known yet
+    active_unit: Cell<Option<u32>>
+}
+
+impl SharedInfo {
+    unsafe fn current(fns: GlFns) -> Self {
+        gl_fns::set_current(fns);
+
+        // Errors from calls made before the context was handed to glitter
+        // would otherwise be reported by the queries below
+        validate::clear_errors();
+
+        SharedInfo {
+            limits: ContextLimits::current(),
+            extensions: Rc::new(Extensions::current()),
+            fns: fns,
+            share_group: Cell::new(ShareGroup::_new()),
+            thread: thread::current().id(),
+            active_unit: Cell::new(None)
+        }
+    }
+}
+
+/// A report of what the current context supports, returned by
+/// [`Context::load_and_query`](struct.ContextOf.html#method.load_and_query).
+#[derive(Debug, Clone)]
+pub struct ContextInfo {
+    /// The company responsible for the OpenGL implementation (see
+    /// [`gl.vendor`](trait.ContextExt.html#method.vendor)).
+    pub vendor: String,
+
+    /// The renderer used by the OpenGL implementation (see
+    /// [`gl.renderer`](trait.ContextExt.html#method.renderer)).
+    pub renderer: String,
+
+    /// The OpenGL version of the context.
+    pub version: GlVersion,
+
+    /// The GLSL version supported by the context, or `None` if the version
+    /// string could not be parsed.
+    pub glsl_version: Option<GlslVersion>,
+
+    /// The extensions supported by the context.
+    pub extensions: Extensions,
+
+    /// The implementation-dependent limits of the context.
+    pub limits: ContextLimits
+}
+
+// The functions that glitter can't work without, which are checked by
+// `ContextOf::load_and_query`
+fn _missing_core_fns(fns: GlFns) -> Vec<&'static str> {
+    let core_fns: &[(&'static str, fn(GlFns) -> bool)] = &[
+        ("glBindBuffer", gl_fns::BindBuffer::is_loaded_in),
+        ("glBindFramebuffer", gl_fns::BindFramebuffer::is_loaded_in),
+        ("glBindRenderbuffer", gl_fns::BindRenderbuffer::is_loaded_in),
+        ("glBindTexture", gl_fns::BindTexture::is_loaded_in),
+        ("glBufferData", gl_fns::BufferData::is_loaded_in),
+        ("glCompileShader", gl_fns::CompileShader::is_loaded_in),
+        ("glCreateProgram", gl_fns::CreateProgram::is_loaded_in),
+        ("glCreateShader", gl_fns::CreateShader::is_loaded_in),
+        ("glDrawArrays", gl_fns::DrawArrays::is_loaded_in),
+        ("glDrawElements", gl_fns::DrawElements::is_loaded_in),
+        ("glGenBuffers", gl_fns::GenBuffers::is_loaded_in),
+        ("glGenFramebuffers", gl_fns::GenFramebuffers::is_loaded_in),
+        ("glGenRenderbuffers", gl_fns::GenRenderbuffers::is_loaded_in),
+        ("glGenTextures", gl_fns::GenTextures::is_loaded_in),
+        ("glGetError", gl_fns::GetError::is_loaded_in),
+        ("glGetIntegerv", gl_fns::GetIntegerv::is_loaded_in),
+        ("glGetString", gl_fns::GetString::is_loaded_in),
+        ("glLinkProgram", gl_fns::LinkProgram::is_loaded_in),
+        ("glShaderSource", gl_fns::ShaderSource::is_loaded_in),
+        ("glTexImage2D", gl_fns::TexImage2D::is_loaded_in),
+        ("glUseProgram", gl_fns::UseProgram::is_loaded_in),
+        ("glVertexAttribPointer", gl_fns::VertexAttribPointer::is_loaded_in)
+    ];
+
+    core_fns.iter()
+        .filter(|&&(_, is_loaded_in)| !is_loaded_in(fns))
+        .map(|&(name, _)| name)
+        .collect()
+}
+
+thread_local! {
+    // The info for the context that is active on this thread (the one
+    // most recently returned by `ContextOf::current_context` or activated
+    // with `gl.activate`). Methods that have a context use the info stored
+    // in it (see `BaseContext::_extensions`); this is only used by
+    // functions that don't have one, such as builders and bindings, so
+    // they can check for extensions without querying them again.
+    static CURRENT_INFO: RefCell<Option<Rc<SharedInfo>>> = RefCell::new(None);
+
+    // The callbacks added with `gl.on_context_reset`, and whether the last
+    // call to `gl.graphics_reset_status` found a reset (so the callbacks
+    // are only called once per reset)
+    static RESET_CALLBACKS: RefCell<Vec<Box<FnMut(GraphicsResetStatus)>>> =
+        RefCell::new(vec![]);
+    static RESET_REPORTED: Cell<bool> = Cell::new(false)
+}
+
+// Get the extensions of the current context, querying them if
+// `ContextOf::current_context` hasn't been called on this thread
+unsafe fn _current_extensions() -> Rc<Extensions> {
+    let info = CURRENT_INFO.with(|current| current.borrow().clone());
+    match info {
+        Some(info) => { info.extensions.clone() },
+        None => { Rc::new(Extensions::current()) }
+    }
+}
+
+// Get the limits of the current context, querying them if
+// `ContextOf::current_context` hasn't been called on this thread
+unsafe fn _current_limits() -> ContextLimits {
+    let info = CURRENT_INFO.with(|current| current.borrow().clone());
+    match info {
+        Some(info) => { info.limits.clone() },
+        None => { ContextLimits::current() }
+    }
+}
+
+// Get the texture unit that is active in the current context, or `None` if
+// it isn't known (including when `ContextOf::current_context` hasn't been
+// called on this thread)
+fn _current_active_unit() -> Option<u32> {
+    CURRENT_INFO.with(|current| {
+        current.borrow().as_ref().and_then(|info| info.active_unit.get())
+    })
+}
+
+// Record that texture unit `idx` was made active in the current context.
+// Making an out-of-bounds unit active generates an error and leaves the
+// active unit unchanged, so it isn't recorded.
+fn _set_current_active_unit(idx: u32) {
+    CURRENT_INFO.with(|current| {
+        if let Some(ref info) = *current.borrow() {
+            if idx < info.limits.max_combined_texture_image_units {
+                info.active_unit.set(Some(idx));
+            }
+        }
+    });
+    bind_cache::_active_texture(_current_active_unit());
+}
+
 impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
     /// Use a function to load OpenGL function pointers. This function must
     /// be called before calling [`ContextOf::current_context`]
     /// (struct.ContextOf.html#method.current_context).
     ///
+    /// The function pointers are shared by every thread (and are also
+    /// loaded into the `gl` crate, for making raw OpenGL calls). Each
+    /// context keeps the function pointers that were loaded when it was
+    /// created, so calling `load_with` again only affects contexts that are
+    /// created afterwards. To use contexts from different OpenGL
+    /// implementations in one process, load a separate
+    /// [`GlFns`](../struct.GlFns.html) for each one instead.
+    ///
     /// # Safety
     /// `load_fn` takes an OpenGL function name, and must return a function
     /// pointer that can be used as this OpenGL function.
-    pub unsafe fn load_with<L>(load_fn: L)
+    pub unsafe fn load_with<L>(mut load_fn: L)
         where L: FnMut(&str) -> *const GLvoid
     {
-        gl::load_with(load_fn);
+        gl::load_with(|name| load_fn(name));
+        gl_fns::load_default(&mut load_fn);
+    }
+
+    /// Load OpenGL function pointers (like [`ContextOf::load_with`]
+    /// (struct.ContextOf.html#method.load_with)), get the current context
+    /// (like [`ContextOf::current_context`]
+    /// (struct.ContextOf.html#method.current_context)), and return it along
+    /// with a report of the context's version, extensions, and limits.
+    ///
+    /// Unlike calling those functions separately, this checks up front that
+    /// the context meets glitter's minimum requirements (OpenGL ES 2.0 or
+    /// OpenGL 2.0, with framebuffer objects), so an unsupported context is
+    /// reported with a descriptive error instead of a panic later on.
+    ///
+    /// # Failures
+    /// A [`GLError::ContextError`]
+    /// (../types/enum.GLError.html#variant.ContextError) error will be
+    /// returned if a function that glitter requires could not be loaded, if
+    /// no context is current, if the context's version is too old, or if
+    /// the context doesn't support framebuffer objects.
+    ///
+    /// # Safety
+    /// The same rules apply as for `ContextOf::load_with` and
+    /// `ContextOf::current_context`. A context must be current on the
+    /// current thread before calling this function.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # fn get_proc_address(_: &str) -> *const std::os::raw::c_void { unimplemented!() }
+    /// // ... make the window's context current ...
+    /// let (gl, info) = match unsafe { glitter::Context::load_and_query(get_proc_address) } {
+    ///     Ok(result) => { result },
+    ///     Err(e) => { panic!("Unsupported OpenGL context: {}", e); }
+    /// };
+    /// println!("Using {} ({:?})", info.renderer, info.version);
+    /// ```
+    pub unsafe fn load_and_query<L>(load_fn: L)
+        -> Result<(Context, ContextInfo), GLError>
+        where L: FnMut(&str) -> *const GLvoid
+    {
+        Context::load_with(load_fn);
+        let fns = gl_fns::default_fns()
+            .expect("Function pointers were not loaded");
+
+        let missing = _missing_core_fns(fns);
+        if !missing.is_empty() {
+            return Err(ContextError::MissingFunctions(missing).into());
+        }
+
+        gl_fns::with(fns, || validate::clear_errors());
+        let version_string = match gl_fns::with(fns, || _get_string(gl::VERSION)) {
+            Some(version_string) => { version_string },
+            None => { return Err(ContextError::NoVersion.into()); }
+        };
+        let version = match GlVersion::parse(&version_string) {
+            Some(version) => { version },
+            None => {
+                return Err(ContextError::InvalidVersion(version_string).into());
+            }
+        };
+        if !version.is_at_least(2, 0) {
+            return Err(ContextError::UnsupportedVersion(version).into());
+        }
+
+        let extensions = gl_fns::with(fns, || Extensions::current());
+        if !extensions.has_framebuffer_object() {
+            return Err(ContextError::MissingFramebufferObject.into());
+        }
+
+        let gl = Context::_current_context(fns);
+        let info = ContextInfo {
+            vendor: gl.vendor(),
+            renderer: gl.renderer(),
+            version: version,
+            glsl_version: gl.shading_language_version(),
+            extensions: gl.extensions().clone(),
+            limits: gl.limits().clone()
+        };
+        Ok((gl, info))
     }
 
     /// Get the current OpenGL context.
@@ -145,6 +413,11 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
     /// to be loaded** by calling the [`ContextOf::load_with`]
     /// (struct.ContextOf.html#method.load_with) function.
     ///
+    /// The returned context must only be used on the current thread. When
+    /// the `debug_assertions` configuration option is set, glitter will
+    /// panic (before making the call) if an OpenGL function is called on
+    /// a thread where no context was ever returned.
+    ///
     /// Additionally, special care needs to be taken with this function to
     /// maintain the invariants about bindings and targets. Here's an
     /// example of how this function can be abused:
@@ -182,13 +455,91 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
     /// // buffer_1: {uninitialized}
     /// // buffer_2: [4, 5, 6]
     /// ```
+    ///
+    /// # Note
+    /// Getting the current context isn't free: any pending OpenGL errors
+    /// are cleared (so errors from earlier calls made outside of glitter
+    /// aren't reported as glitter's), then the context's version,
+    /// extensions, and limits are queried, which takes several dozen
+    /// `glGet*` calls. These are stored in a reference-counted allocation
+    /// that is shared by the returned context (so `ContextOf` is not a
+    /// zero-sized type). Prefer getting the context once and passing it
+    /// (or the contexts split from it) around, rather than calling this
+    /// function repeatedly, such as once per frame.
     pub unsafe fn current_context() -> Context {
+        let fns = gl_fns::default_fns()
+            .expect("ContextOf::load_with was not called");
+        Context::_current_context(fns)
+    }
+
+    /// Get the current OpenGL context, which uses its own OpenGL function
+    /// pointers (rather than the ones loaded with [`ContextOf::load_with`]
+    /// (struct.ContextOf.html#method.load_with)). This allows contexts from
+    /// different OpenGL implementations to be used in one process.
+    ///
+    /// The function pointers are owned by the returned context, and are
+    /// used for every OpenGL call made through glitter on the current
+    /// thread while the context is active. Getting another context (with
+    /// `current_context_with` or [`ContextOf::current_context`]
+    /// (struct.ContextOf.html#method.current_context)) makes that context
+    /// active instead, and [`gl.activate`]
+    /// (struct.ContextOf.html#method.activate) switches back. Note that the
+    /// `gl` crate's function pointers aren't loaded, so any raw OpenGL calls
+    /// (such as in [`gl.with_raw`](struct.ContextOf.html#method.with_raw))
+    /// need to load their own.
+    ///
+    /// # Safety
+    /// The same rules apply as for [`ContextOf::current_context`]
+    /// (struct.ContextOf.html#method.current_context), except that
+    /// `ContextOf::load_with` doesn't need to be called. `fns` must have
+    /// been loaded for the current context's OpenGL implementation.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # fn get_proc_address(_: &str) -> *const std::os::raw::c_void { unimplemented!() }
+    /// // ... make the window's context current ...
+    /// let fns = unsafe { glitter::GlFns::load_with(get_proc_address) };
+    /// let gl = unsafe { glitter::Context::current_context_with(fns) };
+    /// ```
+    pub unsafe fn current_context_with(fns: GlFns) -> Context {
+        Context::_current_context(fns)
+    }
+
+    /// Make glitter use this context's function pointers and extensions on
+    /// the current thread again. When switching between multiple contexts
+    /// on one thread, call this after making the context current with the
+    /// window system (such as with `eglMakeCurrent`).
+    ///
+    /// # Safety
+    /// This context must be current on the current thread.
+    ///
+    /// # Panics
+    /// This function will panic if it is called on a different thread than
+    /// the one that created the context.
+    pub unsafe fn activate(&self) {
+        validate::check_owner_thread(self.info.thread);
+        gl_fns::set_current(self.info.fns);
+        bind_cache::_forget();
+        bind_cache::_active_texture(self.info.active_unit.get());
+        CURRENT_INFO.with(|current| {
+            *current.borrow_mut() = Some(self.info.clone());
+        });
+    }
+
+    unsafe fn _current_context(fns: GlFns) -> Context {
+        let info = Rc::new(SharedInfo::current(fns));
+        bind_cache::_forget();
+        CURRENT_INFO.with(|current| {
+            *current.borrow_mut() = Some(info.clone());
+        });
+
         ContextOf {
             buffers: BufferBinder::current(),
             framebuffer: FramebufferBinder::current(),
             program: ProgramBinder::current(),
             renderbuffer: RenderbufferBinder::current(),
             tex_units: TextureUnits::current(),
+            info: info,
             _phantom: PhantomData
         }
     }
@@ -203,7 +554,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
     /// was generated).
     pub fn get_error() -> Option<GLError> {
         unsafe {
-            match gl::GetError() {
+            match gl_fns::GetError() {
                 gl::INVALID_ENUM =>
                     Some(GLError::InvalidEnum),
                 gl::INVALID_VALUE =>
@@ -214,12 +565,173 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
                     Some(GLError::InvalidFramebufferOperation),
                 gl::OUT_OF_MEMORY =>
                     Some(GLError::OutOfMemory),
+                gl::CONTEXT_LOST =>
+                    Some(GLError::ContextLost),
                 _ =>
                     None
             }
         }
     }
 
+    /// Get the implementation-dependent limits of the OpenGL context, which
+    /// were queried when getting the current context.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let max_size = gl.limits().max_texture_size;
+    /// println!("Textures can be up to {}x{}", max_size, max_size);
+    /// ```
+    pub fn limits(&self) -> &ContextLimits {
+        &self.info.limits
+    }
+
+    /// Get the index of the texture unit that is currently active. glitter
+    /// remembers which texture unit it last made active, and skips making
+    /// a texture unit active when it already is, so this only queries
+    /// OpenGL if no texture unit has been made active through glitter yet.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// {
+    ///     let (_gl_tex_unit, _) = (&mut gl).active_texture_3();
+    /// }
+    /// assert_eq!(gl.current_active_unit(), 3);
+    /// ```
+    ///
+    /// # See also
+    /// [`glGet`](http://docs.gl/es2/glGet) OpenGL docs, with
+    /// `GL_ACTIVE_TEXTURE`
+    pub fn current_active_unit(&self) -> u32 {
+        if let Some(unit) = self.info.active_unit.get() {
+            return unit;
+        }
+
+        let mut active_texture: GLint = 0;
+        unsafe {
+            gl_fns::GetIntegerv(gl::ACTIVE_TEXTURE, &mut active_texture);
+        }
+        let unit = (active_texture as GLenum).saturating_sub(gl::TEXTURE0);
+        self.info.active_unit.set(Some(unit as u32));
+        bind_cache::_active_texture(Some(unit as u32));
+        unit as u32
+    }
+
+    /// Get the set of extensions supported by the OpenGL context, which
+    /// were queried when getting the current context. See the
+    /// [`Extensions`](../extensions/struct.Extensions.html) docs for
+    /// more details.
+    pub fn extensions(&self) -> &Extensions {
+        &self.info.extensions
+    }
+
+    /// Get the share group of the context, which identifies the contexts
+    /// that share objects with it. Objects can be sent between the
+    /// contexts of a share group with a [`Transfer`]
+    /// (../transfer/struct.Transfer.html).
+    pub fn share_group(&self) -> ShareGroup {
+        self.info.share_group.get()
+    }
+
+    /// Make the context part of another share group. Each context starts
+    /// out in its own share group, so this should be called for each
+    /// context that was created to share objects with another context
+    /// (such as with the `share_context` argument of `eglCreateContext`),
+    /// passing the share group of the other context.
+    ///
+    /// # Safety
+    /// The context must share objects with the contexts in `group`, or
+    /// objects sent with a [`Transfer`](../transfer/struct.Transfer.html)
+    /// will refer to the wrong objects (or no object at all).
+    pub unsafe fn join_share_group(&self, group: ShareGroup) {
+        self.info.share_group.set(group);
+    }
+
+    /// List the OpenGL objects (buffers, textures, framebuffers,
+    /// renderbuffers, programs, and shaders) created on the current thread
+    /// that haven't been dropped yet. When called after all of the objects
+    /// should have been dropped (such as right before shutting down),
+    /// any objects returned were leaked. Each object includes a backtrace
+    /// of where it was created when debug assertions are enabled.
+    ///
+    /// Objects are still listed if their IDs were taken with
+    /// [`GLObject::into_raw`](../types/trait.GLObject.html#method.into_raw),
+    /// since they haven't been deleted.
+    ///
+    /// This method requires the `leak-tracking` feature.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// // ...
+    /// for leak in gl.report_leaks() {
+    ///     println!("{}", leak);
+    /// }
+    /// ```
+    #[cfg(feature = "leak-tracking")]
+    pub fn report_leaks(&self) -> Vec<LeakedObject> {
+        leaks::_live_objects()
+    }
+
+    /// Get the number of live buffers, textures, and renderbuffers created
+    /// on the current thread, along with an estimate of how much memory
+    /// they use (based on the sizes and formats of the data uploaded to
+    /// them). See the [`ResourceStats`](../struct.ResourceStats.html) docs
+    /// for more details.
+    ///
+    /// This method requires the `resource-stats` feature.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let stats = gl.resource_stats();
+    /// println!("{} textures ({} KiB)",
+    ///          stats.textures,
+    ///          stats.texture_bytes / 1024);
+    /// ```
+    #[cfg(feature = "resource-stats")]
+    pub fn resource_stats(&self) -> ResourceStats {
+        stats::_resource_stats()
+    }
+
+    /// Get the number of draw calls, state changes, buffer uploads, and
+    /// texture binds made through glitter on the current thread since the
+    /// last call to [`gl.reset_frame_stats`]
+    /// (struct.ContextOf.html#method.reset_frame_stats). See the
+    /// [`FrameStats`](../struct.FrameStats.html) docs for more details.
+    ///
+    /// This method requires the `frame-stats` feature.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// loop {
+    ///     gl.reset_frame_stats();
+    ///     // ... draw the frame ...
+    ///     let stats = gl.frame_stats();
+    ///     println!("{} draw calls, {} state changes",
+    ///              stats.draw_calls,
+    ///              stats.state_changes);
+    /// }
+    /// ```
+    #[cfg(feature = "frame-stats")]
+    pub fn frame_stats(&self) -> FrameStats {
+        frame_stats::_frame_stats()
+    }
+
+    /// Reset the counters returned by [`gl.frame_stats`]
+    /// (struct.ContextOf.html#method.frame_stats) to 0. This is usually
+    /// called at the start of each frame.
+    ///
+    /// This method requires the `frame-stats` feature.
+    #[cfg(feature = "frame-stats")]
+    pub fn reset_frame_stats(&mut self) {
+        frame_stats::_reset();
+    }
+
     /// Return a new `ContextOf`, where the type parameters of the new context
     /// are borrows of the current context. This function shouldn't be
     /// necessary in most circumstances, and will likely be removed from
@@ -238,6 +750,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
             program: self.program.borrow(),
             renderbuffer: self.renderbuffer.borrow(),
             tex_units: self.tex_units.borrow(),
+            info: self.info.clone(),
             _phantom: PhantomData
         }
     }
@@ -264,6 +777,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
             program: self.program.borrow_mut(),
             renderbuffer: self.renderbuffer.borrow_mut(),
             tex_units: self.tex_units.borrow_mut(),
+            info: self.info.clone(),
             _phantom: PhantomData
         }
     }
@@ -290,6 +804,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
                 program: self.program,
                 renderbuffer: self.renderbuffer,
                 tex_units: self.tex_units,
+                info: self.info,
                 _phantom: PhantomData
             }
         )
@@ -317,6 +832,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
                 program: self.program,
                 renderbuffer: self.renderbuffer,
                 tex_units: self.tex_units,
+                info: self.info,
                 _phantom: PhantomData
             }
         )
@@ -344,6 +860,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
                 program: new_program,
                 renderbuffer: self.renderbuffer,
                 tex_units: self.tex_units,
+                info: self.info,
                 _phantom: PhantomData
             }
         )
@@ -371,6 +888,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
                 program: self.program,
                 renderbuffer: new_renderbuffer,
                 tex_units: self.tex_units,
+                info: self.info,
                 _phantom: PhantomData
             }
         )
@@ -398,12 +916,67 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
                 program: self.program,
                 renderbuffer: self.renderbuffer,
                 tex_units: new_tex_units,
+                info: self.info,
                 _phantom: PhantomData
             }
         )
     }
 }
 
+impl Context {
+    /// Run a function that makes OpenGL calls directly (such as a function
+    /// from another library), returning its result. This is only available
+    /// on a [`Context`](type.Context.html) that hasn't been split by binding
+    /// anything, so no bindings can be alive while the function runs (a
+    /// binding borrows the context it was made from, or takes part of it).
+    /// glitter's own bookkeeping about the driver's state is refreshed when
+    /// the function returns. This includes the
+    /// active texture unit (see [`gl.current_active_unit`]
+    /// (struct.ContextOf.html#method.current_active_unit)) and, with the
+    /// `bind-cache` feature, which objects are bound, so raw calls don't
+    /// cause glitter to skip a call that's needed.
+    ///
+    /// Any OpenGL errors generated by the function are cleared afterwards,
+    /// so that they aren't reported by the next glitter call. To handle
+    /// them, call [`Context::get_error`]
+    /// (struct.ContextOf.html#method.get_error) within the function.
+    ///
+    /// # Example
+    /// ```no_run
+    /// extern crate gl;
+    /// # extern crate glitter;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// gl.with_raw(|| unsafe {
+    ///     gl::BindTexture(gl::TEXTURE_2D, 0);
+    ///     gl::ActiveTexture(gl::TEXTURE3);
+    /// });
+    /// # }
+    /// ```
+    pub fn with_raw<W, V>(&mut self, f: W) -> V
+        where W: FnOnce() -> V
+    {
+        let result = f();
+
+        unsafe {
+            validate::clear_errors();
+
+            let mut active_texture: GLint = 0;
+            gl_fns::GetIntegerv(gl::ACTIVE_TEXTURE, &mut active_texture);
+            let active_texture = (active_texture as GLenum)
+                .saturating_sub(gl::TEXTURE0);
+            let active_texture = active_texture as u32;
+            stats::_forget_bindings(active_texture);
+            bind_cache::_forget();
+            bind_cache::_active_texture(Some(active_texture));
+            self.info.active_unit.set(Some(active_texture));
+        }
+
+        result
+    }
+}
+
 impl<'a, B, F, P, R, T> ToRef<'a> for ContextOf<B, F, P, R, T>
     where B: 'a + ToRef<'a>,
           F: 'a + ToRef<'a>,
@@ -420,6 +993,7 @@ impl<'a, B, F, P, R, T> ToRef<'a> for ContextOf<B, F, P, R, T>
             program: self.program.to_ref(),
             renderbuffer: self.renderbuffer.to_ref(),
             tex_units: self.tex_units.to_ref(),
+            info: self.info.clone(),
             _phantom: PhantomData
         }
     }
@@ -441,6 +1015,7 @@ impl<'a, B, F, P, R, T> ToMut<'a> for ContextOf<B, F, P, R, T>
             program: self.program.to_mut(),
             renderbuffer: self.renderbuffer.to_mut(),
             tex_units: self.tex_units.to_mut(),
+            info: self.info.clone(),
             _phantom: PhantomData
         }
     }
@@ -448,6 +1023,27 @@ impl<'a, B, F, P, R, T> ToMut<'a> for ContextOf<B, F, P, R, T>
 
 
 
+// Get an OpenGL string (using `glGetString`), or `None` if the string
+// is not available
+unsafe fn _get_string(name: GLenum) -> Option<String> {
+    let string = gl_fns::GetString(name);
+    dbg_gl_sanity_check! {
+        in "glGetString"();
+        GLError::InvalidEnum => "`name` is not an accepted value",
+        _ => "Unknown error"
+    }
+
+    if string.is_null() {
+        None
+    }
+    else {
+        let string = CStr::from_ptr(string as *const _);
+        Some(string.to_string_lossy().into_owned())
+    }
+}
+
+
+
 /// A marker trait for types that represent an active OpenGL context.
 ///
 /// # Safety
@@ -455,17 +1051,42 @@ impl<'a, B, F, P, R, T> ToMut<'a> for ContextOf<B, F, P, R, T>
 /// an OpenGL context will be available for the lifetime of an instance
 /// of the type.
 pub unsafe trait BaseContext {
+    // Get the extensions supported by this context. Types that don't hold
+    // the context's info fall back to the context that is active on the
+    // current thread.
+    #[doc(hidden)]
+    fn _extensions(&self) -> Rc<Extensions> {
+        unsafe { _current_extensions() }
+    }
 
+    // Get the limits of this context, with the same fallback as
+    // `_extensions`
+    #[doc(hidden)]
+    fn _limits(&self) -> ContextLimits {
+        unsafe { _current_limits() }
+    }
 }
 
 unsafe impl<B, F, P, R, T> BaseContext for ContextOf<B, F, P, R, T> {
+    fn _extensions(&self) -> Rc<Extensions> {
+        self.info.extensions.clone()
+    }
 
+    fn _limits(&self) -> ContextLimits {
+        self.info.limits.clone()
+    }
 }
 
 unsafe impl<'a, B, F, P, R, T> BaseContext
     for &'a mut ContextOf<B, F, P, R, T>
 {
+    fn _extensions(&self) -> Rc<Extensions> {
+        self.info.extensions.clone()
+    }
 
+    fn _limits(&self) -> ContextLimits {
+        self.info.limits.clone()
+    }
 }
 
 
@@ -492,7 +1113,49 @@ pub trait ContextExt: BaseContext {
     /// ```
     fn clear_color(&mut self, color: Color) {
         unsafe {
-            gl::ClearColor(color.r, color.g, color.b, color.a);
+            gl_fns::ClearColor(color.r, color.g, color.b, color.a);
+            frame_stats::_state_change();
+        }
+    }
+
+    /// Set the clear value when clearing a depth buffer with
+    /// [`gl.clear(glitter::DEPTH_BUFFER_BIT)`]
+    /// (framebuffer_context/trait.ContextFramebufferExt.html#method.clear).
+    /// The value is clamped between `0.0` and `1.0`, and is initially `1.0`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // With a reversed depth range, the far plane is at 0.0
+    /// gl.clear_depthf(0.0);
+    /// gl.clear(glitter::DEPTH_BUFFER_BIT);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glClearDepthf`](http://docs.gl/es2/glClearDepthf) OpenGL docs
+    fn clear_depthf(&mut self, depth: f32) {
+        unsafe {
+            gl_fns::ClearDepthf(depth);
+            frame_stats::_state_change();
+        }
+    }
+
+    /// Set the clear value when clearing a stencil buffer with
+    /// [`gl.clear(glitter::STENCIL_BUFFER_BIT)`]
+    /// (framebuffer_context/trait.ContextFramebufferExt.html#method.clear).
+    /// The value is initially `0`.
+    ///
+    /// # See also
+    /// [`glClearStencil`](http://docs.gl/es2/glClearStencil) OpenGL docs
+    fn clear_stencil(&mut self, stencil: i32) {
+        unsafe {
+            gl_fns::ClearStencil(stencil);
+            frame_stats::_state_change();
         }
     }
 
@@ -511,8 +1174,10 @@ pub trait ContextExt: BaseContext {
     /// ```
     fn enable(&mut self, cap: Capability) {
         unsafe {
-            gl::Enable(cap.gl_enum());
+            gl_fns::Enable(cap.gl_enum());
+            frame_stats::_state_change();
             dbg_gl_sanity_check! {
+                in "glEnable"(cap = cap);
                 GLError::InvalidEnum => "`cap` is not a valid OpenGL capability",
                 _ => "Unknown error"
             }
@@ -534,14 +1199,107 @@ pub trait ContextExt: BaseContext {
     /// ```
     fn disable(&mut self, cap: Capability) {
         unsafe {
-            gl::Disable(cap.gl_enum());
+            gl_fns::Disable(cap.gl_enum());
+            frame_stats::_state_change();
             dbg_gl_sanity_check! {
+                in "glDisable"(cap = cap);
                 GLError::InvalidEnum => "`cap` is not a valid OpenGL capability",
                 _ => "Unknown error"
             }
         }
     }
 
+    /// Set the factors that the source (incoming) and destination (stored)
+    /// colors are multiplied by when [`BLEND`](../types/constant.BLEND.html)
+    /// is enabled. Initially, `src` is `ONE` and `dst` is `ZERO`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // Conventional alpha blending
+    /// gl.enable(glitter::BLEND);
+    /// gl.blend_func(glitter::SRC_ALPHA, glitter::ONE_MINUS_SRC_ALPHA);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glBlendFunc`](http://docs.gl/es2/glBlendFunc) OpenGL docs
+    fn blend_func(&mut self, src: BlendFactor, dst: BlendFactor) {
+        unsafe {
+            gl_fns::BlendFunc(src.gl_enum(), dst.gl_enum());
+            frame_stats::_state_change();
+            dbg_gl_sanity_check! {
+                in "glBlendFunc"(src = src, dst = dst);
+                GLError::InvalidEnum => "`src` or `dst` is not a valid blend factor",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Set the comparison used for the depth test, when [`DEPTH_TEST`]
+    /// (../types/constant.DEPTH_TEST.html) is enabled. Initially, the
+    /// comparison is `LESS`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// gl.enable(glitter::DEPTH_TEST);
+    /// gl.depth_func(glitter::LEQUAL);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glDepthFunc`](http://docs.gl/es2/glDepthFunc) OpenGL docs
+    fn depth_func(&mut self, func: DepthFunc) {
+        unsafe {
+            gl_fns::DepthFunc(func.gl_enum());
+            frame_stats::_state_change();
+            dbg_gl_sanity_check! {
+                in "glDepthFunc"(func = func);
+                GLError::InvalidEnum => "`func` is not a valid depth function",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Set the row alignment used when reading or writing pixel data.
+    /// `alignment` must be 1, 2, 4, or 8, and is initially 4.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // Upload tightly-packed RGB images
+    /// gl.pixel_store(glitter::UNPACK_ALIGNMENT, 1);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glPixelStorei`](http://docs.gl/es2/glPixelStorei) OpenGL docs
+    fn pixel_store(&mut self, param: PixelStoreParam, alignment: u32) {
+        unsafe {
+            gl_fns::PixelStorei(param.gl_enum(), alignment as GLint);
+            frame_stats::_state_change();
+            dbg_gl_sanity_check! {
+                in "glPixelStorei"(param = param, alignment = alignment);
+                GLError::InvalidEnum => "`param` is not a valid pixel store parameter",
+                GLError::InvalidValue => "`alignment` is not 1, 2, 4, or 8",
+                _ => "Unknown error"
+            }
+        }
+    }
+
     /// Enable the vertex attribute array to be used while drawing with
     /// [`gl.draw_arrays_range`](buffer_context/trait.ContextBufferExt.html#method.draw_arrays_range),
     /// [`gl.draw_elements`](buffer_context/trait.ContextBufferExt.html#method.draw_elements),
@@ -552,28 +1310,463 @@ pub trait ContextExt: BaseContext {
     /// This function will panics on an OpenGL error in debug mode.
     fn enable_vertex_attrib_array(&self, attrib: ProgramAttrib) {
         unsafe {
-            gl::EnableVertexAttribArray(attrib.gl_index);
+            gl_fns::EnableVertexAttribArray(attrib.gl_index);
+            frame_stats::_state_change();
             dbg_gl_error! {
+                in "glEnableVertexAttribArray"(index = attrib.gl_index);
                 GLError::InvalidValue => "`index` is >= GL_MAX_VERTEX_ATTRIBS",
                 _ => "Unknown error"
             }
         }
     }
 
+    /// Set which polygons are culled when [`CULL_FACE`]
+    /// (../types/enum.Capability.html#variant.CullFace) is enabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // Cull clockwise-wound polygons
+    /// gl.enable(glitter::CULL_FACE);
+    /// gl.front_face(glitter::CCW);
+    /// gl.cull_face(glitter::BACK);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glCullFace`](http://docs.gl/es2/glCullFace) OpenGL docs
+    fn cull_face(&mut self, mode: CullFaceMode) {
+        unsafe {
+            gl_fns::CullFace(mode.gl_enum());
+            frame_stats::_state_change();
+            dbg_gl_sanity_check! {
+                in "glCullFace"();
+                GLError::InvalidEnum => "`mode` is not an accepted value",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Set the winding order of front-facing polygons, which is used to
+    /// determine which polygons are culled (see [`gl.cull_face`]
+    /// (trait.ContextExt.html#method.cull_face)).
+    ///
+    /// # See also
+    /// [`glFrontFace`](http://docs.gl/es2/glFrontFace) OpenGL docs
+    fn front_face(&mut self, winding: WindingOrder) {
+        unsafe {
+            gl_fns::FrontFace(winding.gl_enum());
+            frame_stats::_state_change();
+            dbg_gl_sanity_check! {
+                in "glFrontFace"();
+                GLError::InvalidEnum => "`mode` is not an accepted value",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Set the width of rasterized lines, in pixels. The width is clamped to
+    /// the range of supported line widths (see
+    /// [`gl.get_aliased_line_width_range`]
+    /// (trait.ContextExt.html#method.get_aliased_line_width_range)). Note
+    /// that a width of `1.0` is the only width that all implementations
+    /// are required to support.
+    ///
+    /// # Panics
+    /// This function will panic in debug mode if `width` is less than or
+    /// equal to `0.0`.
+    ///
+    /// # See also
+    /// [`glLineWidth`](http://docs.gl/es2/glLineWidth) OpenGL docs
+    fn line_width(&mut self, width: f32) {
+        unsafe {
+            gl_fns::LineWidth(width);
+            frame_stats::_state_change();
+            dbg_gl_error! {
+                in "glLineWidth"();
+                GLError::InvalidValue => "`width` is less than or equal to 0",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Get the range of supported widths for aliased lines, as a
+    /// `(min, max)` pair.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let (_, max_width) = gl.get_aliased_line_width_range();
+    /// gl.line_width(max_width.min(4.0));
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glGet`](http://docs.gl/es2/glGet) OpenGL docs (for
+    /// `GL_ALIASED_LINE_WIDTH_RANGE`)
+    fn get_aliased_line_width_range(&self) -> (f32, f32) {
+        let mut range: [GLfloat; 2] = [0.0, 0.0];
+        unsafe {
+            gl_fns::GetFloatv(gl::ALIASED_LINE_WIDTH_RANGE, range.as_mut_ptr());
+            dbg_gl_sanity_check! {
+                in "glGetFloatv"();
+                GLError::InvalidEnum => "`pname` is not an accepted value",
+                _ => "Unknown error"
+            }
+        }
+        (range[0], range[1])
+    }
+
+    /// Set the coverage value used when multisampling with
+    /// [`SAMPLE_COVERAGE`](../types/enum.Capability.html#variant.SampleCoverage)
+    /// enabled. The fragment coverage is combined with a temporary coverage
+    /// mask generated from `value` (which is clamped between `0.0` and
+    /// `1.0`). If `invert` is `true`, the temporary coverage mask is
+    /// inverted first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // Cover roughly half of the samples of each fragment
+    /// gl.enable(glitter::SAMPLE_COVERAGE);
+    /// gl.sample_coverage(0.5, false);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glSampleCoverage`](http://docs.gl/es2/glSampleCoverage) OpenGL docs
+    fn sample_coverage(&mut self, value: f32, invert: bool) {
+        let gl_invert = if invert { gl::TRUE } else { gl::FALSE };
+        unsafe {
+            gl_fns::SampleCoverage(value, gl_invert);
+            frame_stats::_state_change();
+        }
+    }
+
+    /// Make sure that all previous OpenGL commands will finish in a finite
+    /// amount of time, without waiting for them to finish.
+    ///
+    /// # See also
+    /// [`glFlush`](http://docs.gl/es2/glFlush) OpenGL docs
+    fn flush(&self) {
+        unsafe {
+            gl_fns::Flush();
+        }
+    }
+
+    /// Block until all previous OpenGL commands have finished. To send
+    /// objects to a context on another thread without blocking, use
+    /// a [`Transfer`](../transfer/struct.Transfer.html) instead.
+    ///
+    /// # See also
+    /// [`glFinish`](http://docs.gl/es2/glFinish) OpenGL docs
+    fn finish(&self) {
+        unsafe {
+            gl_fns::Finish();
+        }
+    }
+
+    /// Give a hint for how an implementation-specific behavior should be
+    /// handled. Hints are only suggestions, and may be ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// gl.hint(glitter::FRAGMENT_SHADER_DERIVATIVE_HINT, glitter::NICEST);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glHint`](http://docs.gl/es2/glHint) OpenGL docs
+    fn hint(&mut self, target: HintTarget, mode: HintMode) {
+        unsafe {
+            gl_fns::Hint(target.gl_enum(), mode.gl_enum());
+            frame_stats::_state_change();
+            dbg_gl_error! {
+                in "glHint"(target = target, mode = mode);
+                GLError::InvalidEnum => "`target` or `mode` is not supported by the current context",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Set the scissor box. When [`SCISSOR_TEST`](../types/constant.SCISSOR_TEST.html)
+    /// is enabled, fragments outside of the scissor box are discarded. The
+    /// scissor test also applies when clearing buffers with [`gl.clear`]
+    /// (framebuffer_context/trait.ContextFramebufferExt.html#method.clear),
+    /// so only the area inside of the scissor box will be cleared. Initially,
+    /// the scissor box covers the entire window.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // Clip drawing (and clearing) to a 100x100 area in the
+    /// // lower-left corner
+    /// gl.enable(glitter::SCISSOR_TEST);
+    /// gl.scissor(glitter::Rect::new(0, 0, 100, 100));
+    /// gl.clear(glitter::COLOR_BUFFER_BIT);
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glScissor`](http://docs.gl/es2/glScissor) OpenGL docs
+    fn scissor(&mut self, rect: Rect) {
+        unsafe {
+            gl_fns::Scissor(rect.x as GLint,
+                        rect.y as GLint,
+                        rect.width as GLsizei,
+                        rect.height as GLsizei);
+            frame_stats::_state_change();
+            dbg_gl_sanity_check! {
+                in "glScissor"();
+                GLError::InvalidValue => "`width` or `height` is negative",
+                _ => "Unknown error"
+            }
+        }
+    }
+
+    /// Get the set of extensions supported by the current context. When
+    /// using a [`ContextOf`](struct.ContextOf.html) directly, [`gl.extensions`]
+    /// (struct.ContextOf.html#method.extensions) can be used instead.
+    fn get_extensions(&self) -> Rc<Extensions> {
+        self._extensions()
+    }
+
+    /// Get the limits of the current context. When using a [`ContextOf`]
+    /// (struct.ContextOf.html) directly, [`gl.limits`]
+    /// (struct.ContextOf.html#method.limits) can be used instead.
+    fn get_limits(&self) -> ContextLimits {
+        self._limits()
+    }
+
+    /// Get the GPU's current memory usage, or `None` if it isn't reported by
+    /// the driver (see [`Extensions::has_gpu_memory_info`]
+    /// (../extensions/struct.Extensions.html#method.has_gpu_memory_info)).
+    /// This is usually only available on desktop NVIDIA and AMD GPUs.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// if let Some(info) = gl.gpu_memory_info() {
+    ///     println!("{} KB of video memory available", info.available_kb);
+    /// }
+    /// # }
+    /// ```
+    fn gpu_memory_info(&self) -> Option<GpuMemoryInfo> {
+        unsafe { GpuMemoryInfo::current(&self.get_extensions()) }
+    }
+
+    /// Get the name of the company responsible for the OpenGL
+    /// implementation.
+    ///
+    /// # See also
+    /// [`glGetString`](http://docs.gl/es2/glGetString) OpenGL docs
+    fn vendor(&self) -> String {
+        unsafe { _get_string(gl::VENDOR).unwrap_or(String::new()) }
+    }
+
+    /// Get the name of the renderer (usually the GPU) used by the OpenGL
+    /// implementation.
+    ///
+    /// # See also
+    /// [`glGetString`](http://docs.gl/es2/glGetString) OpenGL docs
+    fn renderer(&self) -> String {
+        unsafe { _get_string(gl::RENDERER).unwrap_or(String::new()) }
+    }
+
+    /// Get the full, unparsed version string of the OpenGL implementation.
+    /// See [`gl.version`](trait.ContextExt.html#method.version) for a
+    /// parsed version.
+    ///
+    /// # See also
+    /// [`glGetString`](http://docs.gl/es2/glGetString) OpenGL docs
+    fn version_string(&self) -> String {
+        unsafe { _get_string(gl::VERSION).unwrap_or(String::new()) }
+    }
+
+    /// Get the OpenGL version of the current context, or `None` if the
+    /// version string could not be parsed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let version = gl.version().unwrap();
+    /// if gl.vendor().contains("Qualcomm") && version.es && version.major == 2 {
+    ///     // Work around a driver bug
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glGetString`](http://docs.gl/es2/glGetString) OpenGL docs
+    fn version(&self) -> Option<GlVersion> {
+        unsafe {
+            _get_string(gl::VERSION).and_then(|version| {
+                GlVersion::parse(&version)
+            })
+        }
+    }
+
+    /// Get the GLSL version supported by the current context, or `None`
+    /// if the version string could not be parsed. This is the same as
+    /// [`gl.get_glsl_version`]
+    /// (../shader/trait.ContextShaderExt.html#method.get_glsl_version).
+    ///
+    /// # See also
+    /// [`glGetString`](http://docs.gl/es2/glGetString) OpenGL docs
+    fn shading_language_version(&self) -> Option<GlslVersion> {
+        unsafe {
+            _get_string(gl::SHADING_LANGUAGE_VERSION).and_then(|version| {
+                GlslVersion::parse(&version)
+            })
+        }
+    }
+
     /// Set the OpenGL viewport dimensions, which maps from device coordinates
     /// to window coordinates.
     fn viewport(&self, viewport: Viewport) {
         unsafe {
-            gl::Viewport(viewport.x as GLint,
+            gl_fns::Viewport(viewport.x as GLint,
                          viewport.y as GLint,
                          viewport.width as GLsizei,
                          viewport.height as GLsizei);
+            frame_stats::_state_change();
             dbg_gl_sanity_check! {
+                in "glViewport"();
                 GLError::InvalidValue => "`width` or `height` is negative",
                 _ => "Unknown error"
             }
         }
     }
+
+    /// Check if the context has been reset (such as after a GPU crash, or
+    /// when an Android app is sent to the background), returning the cause
+    /// of the reset. Returns `None` if the context hasn't been reset, or if
+    /// resets can't be detected (see [`Extensions::has_robustness`]
+    /// (../extensions/struct.Extensions.html#method.has_robustness)).
+    ///
+    /// After a reset, the context is lost: every OpenGL call fails with
+    /// [`GLError::ContextLost`]
+    /// (../types/enum.GLError.html#variant.ContextLost), and all OpenGL
+    /// objects are invalid. Objects from a lost context are still safe to
+    /// drop, but they should be recreated with a new context. Callbacks
+    /// added with [`gl.on_context_reset`]
+    /// (trait.ContextExt.html#method.on_context_reset) are called the first
+    /// time that this function finds a reset.
+    ///
+    /// Contexts are only guaranteed to report resets if they were created
+    /// with a reset notification strategy of `GL_LOSE_CONTEXT_ON_RESET`
+    /// (which is done by the window system API).
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// if let Some(status) = gl.graphics_reset_status() {
+    ///     println!("Context was lost: {:?}", status);
+    ///     // Recreate the context and all of its objects...
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glGetGraphicsResetStatus`](http://docs.gl/gl4/glGetGraphicsResetStatus)
+    /// OpenGL docs
+    fn graphics_reset_status(&self) -> Option<GraphicsResetStatus> {
+        if !self.get_extensions().has_robustness()
+            || !gl_fns::GetGraphicsResetStatus::is_loaded()
+        {
+            return None;
+        }
+
+        let status = unsafe {
+            let status = gl_fns::GetGraphicsResetStatus();
+            GraphicsResetStatus::from_gl(status).ok()
+        };
+
+        // The status goes back to `GL_NO_ERROR` once the reset finishes, so
+        // the callbacks are called again for the next reset
+        let already_reported = RESET_REPORTED.with(|reported| {
+            let already_reported = reported.get();
+            reported.set(status.is_some());
+            already_reported
+        });
+        if let (false, Some(status)) = (already_reported, status) {
+            RESET_CALLBACKS.with(|callbacks| {
+                for callback in callbacks.borrow_mut().iter_mut() {
+                    callback(status);
+                }
+            });
+        }
+
+        status
+    }
+
+    /// Add a function that will be called when [`gl.graphics_reset_status`]
+    /// (trait.ContextExt.html#method.graphics_reset_status) finds that the
+    /// context has been reset. This can be used to mark resources (such as
+    /// cached textures or buffers) as invalid, so they can be recreated
+    /// with a new context. Callbacks are kept for the rest of the thread's
+    /// lifetime.
+    ///
+    /// # Panics
+    /// This function will panic if it is called from within a reset
+    /// callback.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use std::rc::Rc;
+    /// use std::cell::Cell;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let textures_valid = Rc::new(Cell::new(true));
+    /// let valid = textures_valid.clone();
+    /// gl.on_context_reset(move |_status| {
+    ///     valid.set(false);
+    /// });
+    /// # }
+    /// ```
+    fn on_context_reset<F>(&mut self, callback: F)
+        where F: FnMut(GraphicsResetStatus) + 'static
+    {
+        RESET_CALLBACKS.with(|callbacks| {
+            callbacks.borrow_mut().push(Box::new(callback));
+        });
+    }
 }
 
 impl<C: BaseContext> ContextExt for C {
@@ -586,6 +1779,7 @@ pub mod ext {
     pub use BaseContext;
     pub use ContextExt;
     pub use ContextBufferExt;
+    pub use ContextDebugExt;
     pub use ContextFramebufferExt;
     pub use ContextProgramExt;
     pub use ContextRenderbufferExt;
@@ -598,6 +1792,7 @@ pub mod ext {
 /// `ContextOf<_, _, _, _, _>`, as well as for `&mut ContextOf<_, _, _, _, _>`.
 pub trait AContext: ContextExt +
                     ContextBufferExt +
+                    ContextDebugExt +
                     ContextFramebufferExt +
                     ContextProgramExt +
                     ContextRenderbufferExt +
@@ -1,25 +1,33 @@
 //! Contains all of the OpenGL state types related to shader programs.
 
 use std::ptr;
+use std::mem;
 use std::error;
 use std::fmt;
 use std::borrow::BorrowMut;
 use std::marker::PhantomData;
 use std::ffi::CString;
 use gl;
+use gl_fns;
 use gl::types::*;
 use types::{GLObject, GLError};
 use context::{AContext, BaseContext, ContextOf};
+use validate::BindingCheck;
+use frame_stats;
+use bind_cache;
 use program::{Program, ProgramAttrib, ProgramUniform};
-use shader::Shader;
-use uniform_data::{UniformData, UniformDatumType, UniformPrimitiveType};
+use shader::{Shader, ShaderType, ShaderSource, InfoLog};
+use shader::ContextShaderBuilderExt;
+use uniform_data::{UniformData, UniformDatum, UniformDatumType,
+                   UniformPrimitiveType};
 
-unsafe fn _get_program_iv(program: &Program,
+unsafe fn _get_program_iv(program_id: GLuint,
                           pname: GLenum,
                           params: *mut GLint)
 {
-    gl::GetProgramiv(program.id(), pname, params);
+    gl_fns::GetProgramiv(program_id, pname, params);
     dbg_gl_sanity_check! {
+        in "glGetProgramiv"();
         GLError::InvalidEnum => "`pname` is not an accepted value",
         GLError::InvalidValue => "`program` is not a value generated by OpenGL",
         GLError::InvalidOperation => "`program` does not refer to a program object",
@@ -27,6 +35,34 @@ unsafe fn _get_program_iv(program: &Program,
     }
 }
 
+unsafe fn _get_program_info_log(program_id: GLuint) -> Option<String> {
+    let mut info_length : GLint = 0;
+    _get_program_iv(program_id,
+                    gl::INFO_LOG_LENGTH,
+                    &mut info_length as *mut GLint);
+
+    if info_length > 0 {
+        let mut bytes = Vec::<u8>::with_capacity(info_length as usize);
+
+        gl_fns::GetProgramInfoLog(program_id,
+                              info_length,
+                              ptr::null_mut(),
+                              bytes.as_mut_ptr() as *mut GLchar);
+        dbg_gl_sanity_check! {
+            in "glGetProgramInfoLog"();
+            GLError::InvalidValue => "`program` is not a value generated by OpenGL, or `maxLength` < 0",
+            GLError::InvalidOperation => "`program` is not a program object",
+            _ => "Unknown error"
+        }
+        bytes.set_len((info_length - 1) as usize);
+
+        String::from_utf8(bytes).ok()
+    }
+    else {
+        None
+    }
+}
+
 /// Provides a safe interface for creating program objects. A
 /// `ProgramBuilder` can be created using the [`gl.build_program`]
 /// (trait.ContextProgramBuilderExt.html#method.build_program) method.
@@ -34,7 +70,9 @@ pub struct ProgramBuilder<'a, C>
     where C: AContext + 'a
 {
     gl: &'a C,
-    shaders: &'a [Shader]
+    shaders: &'a [Shader],
+    sources: Vec<(ShaderType, ShaderSource<'a>)>,
+    attrib_locations: Vec<(&'a str, ProgramAttrib)>
 }
 
 impl<'a, C> ProgramBuilder<'a, C>
@@ -44,14 +82,96 @@ impl<'a, C> ProgramBuilder<'a, C>
     pub fn new(gl: &'a C, shaders: &'a [Shader])
         -> Self
     {
-        ProgramBuilder { gl: gl, shaders: shaders }
+        ProgramBuilder {
+            gl: gl,
+            shaders: shaders,
+            sources: Vec::new(),
+            attrib_locations: Vec::new()
+        }
+    }
+
+    /// Bind the vertex attribute `name` to the attribute index `index`
+    /// before linking the program. Binding the same attribute names to the
+    /// same indices across several programs allows them to share a single
+    /// [`AttribBinder`](../../vertex_buffer/struct.AttribBinder.html).
+    ///
+    /// # Failures
+    /// Unwrapping the program will fail if `name` contains a nul byte.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// # let shaders: Vec<glitter::Shader> = vec![];
+    /// let program = gl.build_program(&shaders)
+    ///                 .bind_attrib_location("position", 0)
+    ///                 .bind_attrib_location("color", 1)
+    ///                 .unwrap();
+    /// # }
+    /// ```
+    pub fn bind_attrib_location(mut self, name: &'a str, index: u32) -> Self {
+        self.attrib_locations.push((name, ProgramAttrib { gl_index: index }));
+        self
+    }
+
+    /// Compile a vertex shader from `source` and attach it to the program.
+    /// The source will have a `#version` and precision preamble matching
+    /// the current context prepended, unless it already starts with
+    /// a `#version` directive. See [`GlslVersion::preamble`]
+    /// (../../shader/struct.GlslVersion.html#method.preamble)
+    /// for more details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let vertex_source = r##"
+    ///     attribute vec4 position;
+    ///     void main() {
+    ///         gl_Position = position;
+    ///     }
+    /// "##;
+    /// let fragment_source = r##"
+    ///     void main() {
+    ///         gl_FragColor = vec4(1.0, 1.0, 1.0, 1.0);
+    ///     }
+    /// "##;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let program = gl.build_program(&[])
+    ///                 .vertex_shader(vertex_source)
+    ///                 .fragment_shader(fragment_source)
+    ///                 .unwrap();
+    /// # }
+    /// ```
+    pub fn vertex_shader<S>(mut self, source: S) -> Self
+        where S: Into<ShaderSource<'a>>
+    {
+        self.sources.push((ShaderType::VertexShader, source.into()));
+        self
+    }
+
+    /// Compile a fragment shader from `source` and attach it to the
+    /// program. See [`vertex_shader`]
+    /// (struct.ProgramBuilder.html#method.vertex_shader) for more details.
+    pub fn fragment_shader<S>(mut self, source: S) -> Self
+        where S: Into<ShaderSource<'a>>
+    {
+        self.sources.push((ShaderType::FragmentShader, source.into()));
+        self
     }
 
     /// Create and link the program object with the provided shaders, or
     /// return an error.
     ///
     /// # Failures
-    /// An error will be returned if there was an error linking the program
+    /// An error will be returned if there was an error compiling any
+    /// of the shader sources, or if there was an error linking the program
     /// object.
     ///
     /// # Panics
@@ -61,8 +181,7 @@ impl<'a, C> ProgramBuilder<'a, C>
         unsafe {
             let mut program = try! {
                 self.gl.create_program().or_else(|_| {
-                    let msg = "Error creating OpenGL program";
-                    Err(GLError::Message(msg.to_owned()))
+                    Err(GLError::ObjectCreationFailed("program"))
                 })
             };
 
@@ -70,6 +189,22 @@ impl<'a, C> ProgramBuilder<'a, C>
                 self.gl.attach_shader(&mut program, shader);
             }
 
+            // The compiled shaders only need to live until the program is
+            // linked, since OpenGL defers deleting attached shaders
+            let version = self.gl.get_glsl_version();
+            for (ty, source) in self.sources {
+                let source = match version {
+                    Some(version) => { source.version(version) },
+                    None => { source }
+                };
+                let shader = try!(self.gl.build_shader(ty, source).try_unwrap());
+                self.gl.attach_shader(&mut program, &shader);
+            }
+
+            for &(name, attrib) in &self.attrib_locations {
+                try!(self.gl.bind_attrib_location(&mut program, attrib, name));
+            }
+
             try!(self.gl.link_program(&mut program));
             Ok(program)
         }
@@ -122,7 +257,7 @@ pub trait ContextProgramExt: BaseContext {
     /// [`gl.build_program`](trait.ContextProgramBuilderExt.html#method.build_program):
     /// A safe wrapper for creating a program object.
     unsafe fn create_program(&self) -> Result<Program, ()> {
-        let id = gl::CreateProgram();
+        let id = gl_fns::CreateProgram();
         if id > 0 {
             Ok(Program::from_raw(id))
         }
@@ -141,8 +276,9 @@ pub trait ContextProgramExt: BaseContext {
     /// [`glAttachShader`](http://docs.gl/es2/glAttachShader) OpenGL docs
     fn attach_shader(&self, program: &mut Program, shader: &Shader) {
         unsafe {
-            gl::AttachShader(program.id(), shader.id());
+            gl_fns::AttachShader(program.id(), shader.id());
             dbg_gl_error! {
+                in "glAttachShader"();
                 GLError::InvalidValue => "One of either `program` or `shader` is not an OpenGL object",
                 GLError::InvalidOperation => "`shader` is already attached to `program`, `shader` is not a shader object, or `program` is not a program object",
                 _ => "Unknown error"
@@ -150,12 +286,60 @@ pub trait ContextProgramExt: BaseContext {
         }
     }
 
+    /// Associate a vertex attribute index with a named attribute variable
+    /// in a program object. The binding takes effect the next time the
+    /// program is linked.
+    ///
+    /// # Failures
+    /// An error will be returned if `name` contains a nul byte.
+    ///
+    /// # Panics
+    /// This function will panic if an OpenGL error is generated and debug
+    /// assertions are enabled (such as when `attrib` is greater than or
+    /// equal to `GL_MAX_VERTEX_ATTRIBS`, or when `name` starts with
+    /// the reserved prefix `"gl_"`).
+    ///
+    /// # See also
+    /// [`glBindAttribLocation`](http://docs.gl/es2/glBindAttribLocation)
+    /// OpenGL docs
+    ///
+    /// [`ProgramBuilder::bind_attrib_location`](struct.ProgramBuilder.html#method.bind_attrib_location):
+    /// Bind attribute locations while building a program.
+    fn bind_attrib_location(&self,
+                            program: &mut Program,
+                            attrib: ProgramAttrib,
+                            name: &str)
+        -> Result<(), GLError>
+    {
+        let c_str = match CString::new(name) {
+            Ok(s) => { s },
+            Err(_) => {
+                return Err(GLError::InvalidName(name.to_owned()));
+            }
+        };
+
+        unsafe {
+            gl_fns::BindAttribLocation(program.id(),
+                                   attrib.gl_index,
+                                   c_str.as_ptr() as *const GLchar);
+            dbg_gl_error! {
+                in "glBindAttribLocation"(index = attrib.gl_index,
+                                          name = name);
+                GLError::InvalidValue => "`index` is >= GL_MAX_VERTEX_ATTRIBS, or `program` is not a value generated by OpenGL",
+                GLError::InvalidOperation => "`name` starts with the reserved prefix \"gl_\", or `program` is not a program object",
+                _ => "Unknown error"
+            }
+        }
+
+        Ok(())
+    }
+
     /// Link the program object, so that it can be used for rendering. Returns
     /// an error if the program could not be linked.
     ///
     /// # Failures
     /// If the `GL_LINK_STATUS` after linking the program was not `GL_TRUE`,
-    /// then an error object containing the program's info log will
+    /// then a `GLError::LinkError` containing the program's info log will
     /// be returned. Refer to the [`glLinkProgram`]
     /// (http://docs.gl/es2/glLinkProgram) OpenGL docs for the possible
     /// causes of failure.
@@ -167,16 +351,18 @@ pub trait ContextProgramExt: BaseContext {
     /// # See also
     /// [`glLinkProgram`](http://docs.gl/es2/glLinkProgram) OpenGL docs
     fn link_program(&self, program: &mut Program) -> Result<(), GLError> {
+        let _span = gl_span!("glLinkProgram", program = program.id());
         let success = unsafe {
-            gl::LinkProgram(program.id());
+            gl_fns::LinkProgram(program.id());
             dbg_gl_error! {
+                in "glLinkProgram"();
                 GLError::InvalidValue => "`program` is not a value from OpenGL",
                 GLError::InvalidOperation => "`program` is not a program object",
                 _ => "Unknown error"
             }
 
             let mut link_status : GLint = 0;
-            _get_program_iv(program,
+            _get_program_iv(program.id(),
                             gl::LINK_STATUS,
                             &mut link_status as *mut GLint);
 
@@ -191,7 +377,7 @@ pub trait ContextProgramExt: BaseContext {
                 Some(s) => { s },
                 None => { String::from("[Unknown program error]") }
             };
-            Err(GLError::Message(msg))
+            Err(GLError::LinkError(InfoLog::parse(msg)))
         }
     }
 
@@ -205,31 +391,68 @@ pub trait ContextProgramExt: BaseContext {
     /// # See also
     /// [`glGetProgramInfoLog`](http://docs.gl/es2/glGetProgramInfoLog) OpenGL docs
     fn get_program_info_log(&self, program: &Program) -> Option<String> {
-        unsafe {
-            let mut info_length : GLint = 0;
-            _get_program_iv(program,
-                            gl::INFO_LOG_LENGTH,
-                            &mut info_length as *mut GLint);
-
-            if info_length > 0 {
-                let mut bytes = Vec::<u8>::with_capacity(info_length as usize);
-
-                gl::GetProgramInfoLog(program.id(),
-                                      info_length,
-                                      ptr::null_mut(),
-                                      bytes.as_mut_ptr() as *mut GLchar);
-                dbg_gl_sanity_check! {
-                    GLError::InvalidValue => "`program` is not a value generated by OpenGL, or `maxLength` < 0",
-                    GLError::InvalidOperation => "`program` is not a program object",
-                    _ => "Unknown error"
-                }
-                bytes.set_len((info_length - 1) as usize);
+        unsafe { _get_program_info_log(program.id()) }
+    }
 
-                String::from_utf8(bytes).ok()
-            }
-            else {
-                None
+    /// Check whether the bound program can execute given the current
+    /// OpenGL state, returning an error containing the program's info log if
+    /// it cannot. This is primarily useful as a debugging aid right before
+    /// a draw call, to catch problems such as two samplers of different
+    /// types referring to the same texture unit.
+    ///
+    /// # Failures
+    /// If the `GL_VALIDATE_STATUS` after validating the program was not
+    /// `GL_TRUE`, then a `GLError::ValidationError` containing the program's
+    /// info log will be returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// # let mut program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+    /// let (gl_program, gl) = gl.use_program(&mut program);
+    /// if cfg!(debug_assertions) {
+    ///     gl.validate_program(&gl_program).unwrap();
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glValidateProgram`](http://docs.gl/es2/glValidateProgram) OpenGL docs
+    fn validate_program(&self, gl_program: &ProgramBinding)
+        -> Result<(), GLError>
+    {
+        gl_program.validate();
+        let program_id = gl_program.gl_id;
+        let success = unsafe {
+            gl_fns::ValidateProgram(program_id);
+            dbg_gl_error! {
+                in "glValidateProgram"();
+                GLError::InvalidValue => "`program` is not a value generated by OpenGL",
+                GLError::InvalidOperation => "`program` is not a program object",
+                _ => "Unknown error"
             }
+
+            let mut validate_status: GLint = 0;
+            _get_program_iv(program_id,
+                            gl::VALIDATE_STATUS,
+                            &mut validate_status as *mut GLint);
+
+            validate_status == gl::TRUE as GLint
+        };
+
+        if success {
+            Ok(())
+        }
+        else {
+            let msg = match unsafe { _get_program_info_log(program_id) } {
+                Some(s) => { s },
+                None => { String::from("[Unknown program validation error]") }
+            };
+            Err(GLError::ValidationError(InfoLog::parse(msg)))
         }
     }
 
@@ -255,8 +478,9 @@ pub trait ContextProgramExt: BaseContext {
 
         let str_ptr = c_str.as_ptr() as *const GLchar;
         unsafe {
-            let index = gl::GetAttribLocation(program.id(), str_ptr);
+            let index = gl_fns::GetAttribLocation(program.id(), str_ptr);
             dbg_gl_error! {
+                in "glGetAttribLocation"();
                 GLError::InvalidOperation => "`program` has not been linked, `program` is not a program object, or `program` is not a value generated by OpenGL",
                 _ => "Unknown error"
             }
@@ -292,8 +516,9 @@ pub trait ContextProgramExt: BaseContext {
 
         let str_ptr = c_str.as_ptr() as *const GLchar;
         unsafe {
-            let index = gl::GetUniformLocation(program.id(), str_ptr);
+            let index = gl_fns::GetUniformLocation(program.id(), str_ptr);
             dbg_gl_error! {
+                in "glGetUniformLocation"();
                 GLError::InvalidValue => "`program` is not a value generated by OpenGL",
                 GLError::InvalidOperation => "`program` is not a program object, or has not been successfully linked",
                 _ => "Unknown error"
@@ -331,78 +556,83 @@ pub trait ContextProgramExt: BaseContext {
                       uniform: ProgramUniform,
                       val: T)
         where T: UniformData
+    {
+        _set_uniform(_gl_program, uniform, val);
+    }
+
+    /// Read back the current value of a uniform variable within the
+    /// provided program object.
+    ///
+    /// - `program`: The program object to query. The program must have
+    ///              been successfully linked.
+    /// - `uniform`: The location of the uniform variable. This value
+    ///              can be retrieved using [`gl.get_uniform_location`]
+    ///              (trait.ContextProgramExt.html#method.get_uniform_location)
+    ///              method.
+    ///
+    /// The type parameter `T` determines whether the value is read using
+    /// `glGetUniformfv` or `glGetUniformiv`.
+    ///
+    /// # Safety
+    /// OpenGL writes as many values as the uniform variable has, without
+    /// knowing the size of `T`. `T` must match the type of the uniform
+    /// variable as declared in the program (such as `[f32; 4]` for a
+    /// `vec4`), or OpenGL may write past the end of the value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// # let program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+    /// let color = gl.get_uniform_location(&program, "color").unwrap();
+    /// let value: [f32; 4] = unsafe { gl.get_uniform(&program, color) };
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// This function will panic if an OpenGL error is generated and
+    /// debug assertions are enabled.
+    ///
+    /// # See also
+    /// [`glGetUniform`](http://docs.gl/es2/glGetUniform) OpenGL docs
+    unsafe fn get_uniform<T>(&self, program: &Program, uniform: ProgramUniform)
+        -> T
+        where T: UniformDatum
     {
         let idx = uniform.gl_index as GLint;
-        let count = val.uniform_elements() as GLsizei;
-        let ptr = val.uniform_bytes().as_ptr();
-        unsafe {
-            match T::uniform_datum_type() {
-                UniformDatumType::Vec1(p) => {
-                    match p {
-                        UniformPrimitiveType::Float => {
-                            gl::Uniform1fv(idx, count, ptr as *const GLfloat);
-                        },
-                        UniformPrimitiveType::Int => {
-                            gl::Uniform1iv(idx, count, ptr as *const GLint);
-                        }
-                    }
-                },
-                UniformDatumType::Vec2(p) => {
-                    match p {
-                        UniformPrimitiveType::Float => {
-                            gl::Uniform2fv(idx, count, ptr as *const GLfloat);
-                        },
-                        UniformPrimitiveType::Int => {
-                            gl::Uniform2iv(idx, count, ptr as *const GLint);
-                        }
-                    }
-                },
-                UniformDatumType::Vec3(p) => {
-                    match p {
-                        UniformPrimitiveType::Float => {
-                            gl::Uniform3fv(idx, count, ptr as *const GLfloat);
-                        },
-                        UniformPrimitiveType::Int => {
-                            gl::Uniform3iv(idx, count, ptr as *const GLint);
-                        }
-                    }
-                },
-                UniformDatumType::Vec4(p) => {
-                    match p {
-                        UniformPrimitiveType::Float => {
-                            gl::Uniform4fv(idx, count, ptr as *const GLfloat);
-                        },
-                        UniformPrimitiveType::Int => {
-                            gl::Uniform4iv(idx, count, ptr as *const GLint);
-                        }
-                    }
-                },
-                UniformDatumType::Matrix2x2 => {
-                    gl::UniformMatrix2fv(idx,
-                                         count,
-                                         gl::FALSE,
-                                         ptr as *const GLfloat);
-                },
-                UniformDatumType::Matrix3x3 => {
-                    gl::UniformMatrix3fv(idx,
-                                         count,
-                                         gl::FALSE,
-                                         ptr as *const GLfloat);
-                },
-                UniformDatumType::Matrix4x4 => {
-                    gl::UniformMatrix4fv(idx,
-                                         count,
-                                         gl::FALSE,
-                                         ptr as *const GLfloat);
-                },
+        let mut val: T = mem::zeroed();
+        let ptr = &mut val as *mut T;
+        let primitive = match T::uniform_datum_type() {
+            UniformDatumType::Vec1(p) |
+            UniformDatumType::Vec2(p) |
+            UniformDatumType::Vec3(p) |
+            UniformDatumType::Vec4(p) => { p },
+            UniformDatumType::Matrix2x2 |
+            UniformDatumType::Matrix3x3 |
+            UniformDatumType::Matrix4x4 => {
+                UniformPrimitiveType::Float
             }
+        };
 
-            dbg_gl_error! {
-                GLError::InvalidOperation => "Invalid uniform operation",
-                GLError::InvalidValue => "`count` < 0 or `transpose` is not GL_FALSE",
-                _ => "Unknown error"
+        match primitive {
+            UniformPrimitiveType::Float => {
+                gl_fns::GetUniformfv(program.id(), idx, ptr as *mut GLfloat);
+            },
+            UniformPrimitiveType::Int => {
+                gl_fns::GetUniformiv(program.id(), idx, ptr as *mut GLint);
             }
         }
+        dbg_gl_error! {
+            in "glGetUniform*"(location = idx);
+            GLError::InvalidValue => "`program` is not a value generated by OpenGL",
+            GLError::InvalidOperation => "`program` is not a program object, has not been successfully linked, or `location` is not a valid uniform location",
+            _ => "Unknown error"
+        }
+
+        val
     }
 }
 
@@ -410,6 +640,91 @@ impl<C: BaseContext> ContextProgramExt for C {
 
 }
 
+// Set a uniform value (see `gl.set_uniform`). This only needs the program
+// binding, since a binding can only exist while its context is current, so
+// it can be used when setting uniforms for another binding (such as by
+// `easy::Uniforms`).
+#[doc(hidden)]
+pub fn _set_uniform<T>(_gl_program: &ProgramBinding,
+                       uniform: ProgramUniform,
+                       val: T)
+    where T: UniformData
+{
+    _gl_program.validate();
+    let idx = uniform.gl_index as GLint;
+    let count = val.uniform_elements() as GLsizei;
+    let ptr = val.uniform_bytes().as_ptr();
+    unsafe {
+        match T::uniform_datum_type() {
+            UniformDatumType::Vec1(p) => {
+                match p {
+                    UniformPrimitiveType::Float => {
+                        gl_fns::Uniform1fv(idx, count, ptr as *const GLfloat);
+                    },
+                    UniformPrimitiveType::Int => {
+                        gl_fns::Uniform1iv(idx, count, ptr as *const GLint);
+                    }
+                }
+            },
+            UniformDatumType::Vec2(p) => {
+                match p {
+                    UniformPrimitiveType::Float => {
+                        gl_fns::Uniform2fv(idx, count, ptr as *const GLfloat);
+                    },
+                    UniformPrimitiveType::Int => {
+                        gl_fns::Uniform2iv(idx, count, ptr as *const GLint);
+                    }
+                }
+            },
+            UniformDatumType::Vec3(p) => {
+                match p {
+                    UniformPrimitiveType::Float => {
+                        gl_fns::Uniform3fv(idx, count, ptr as *const GLfloat);
+                    },
+                    UniformPrimitiveType::Int => {
+                        gl_fns::Uniform3iv(idx, count, ptr as *const GLint);
+                    }
+                }
+            },
+            UniformDatumType::Vec4(p) => {
+                match p {
+                    UniformPrimitiveType::Float => {
+                        gl_fns::Uniform4fv(idx, count, ptr as *const GLfloat);
+                    },
+                    UniformPrimitiveType::Int => {
+                        gl_fns::Uniform4iv(idx, count, ptr as *const GLint);
+                    }
+                }
+            },
+            UniformDatumType::Matrix2x2 => {
+                gl_fns::UniformMatrix2fv(idx,
+                                     count,
+                                     gl::FALSE,
+                                     ptr as *const GLfloat);
+            },
+            UniformDatumType::Matrix3x3 => {
+                gl_fns::UniformMatrix3fv(idx,
+                                     count,
+                                     gl::FALSE,
+                                     ptr as *const GLfloat);
+            },
+            UniformDatumType::Matrix4x4 => {
+                gl_fns::UniformMatrix4fv(idx,
+                                     count,
+                                     gl::FALSE,
+                                     ptr as *const GLfloat);
+            },
+        }
+
+        dbg_gl_error! {
+            in "glUniform*"(location = idx, count = count);
+            GLError::InvalidOperation => "Invalid uniform operation",
+            GLError::InvalidValue => "`count` < 0 or `transpose` is not GL_FALSE",
+            _ => "Unknown error"
+        }
+    }
+}
+
 
 
 /// An OpenGL context that has a free program binding.
@@ -432,6 +747,16 @@ pub trait ProgramContext: AContext {
         let (mut binder, rest) = self.split_program();
         (binder.borrow_mut().bind(program), rest)
     }
+
+    /// Unbind this context's program (with `glUseProgram(0)`), returning
+    /// the freed binder and the remaining context.
+    fn unbind_program(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_program();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
 impl<B, F, P, R, T> ProgramContext for ContextOf<B, F, P, R, T>
@@ -461,10 +786,18 @@ impl<'a, B, F, P, R, T> ProgramContext for &'a mut ContextOf<B, F, P, R, T>
 
 /// Represents a program that has been bound to the context.
 pub struct ProgramBinding<'a> {
+    gl_id: GLuint,
+    _check: BindingCheck,
     _phantom_ref: PhantomData<&'a mut Program>,
     _phantom_ptr: PhantomData<*mut ()>
 }
 
+impl<'a> ProgramBinding<'a> {
+    fn validate(&self) {
+        self._check.check(gl::CURRENT_PROGRAM, "the current program");
+    }
+}
+
 /// The OpenGL state representing the active program target.
 pub struct ProgramBinder {
     _phantom: PhantomData<*mut ()>
@@ -482,19 +815,37 @@ impl ProgramBinder {
     pub fn bind<'a>(&mut self, program: &'a mut Program) -> ProgramBinding<'a>
     {
         let binding = ProgramBinding {
+            gl_id: program.id(),
+            _check: BindingCheck::new(program.id()),
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         };
-        unsafe {
-            gl::UseProgram(program.id());
-            dbg_gl_error! {
-                GLError::InvalidValue => "`program` is neither 0 nor an object generated by OpenGL",
-                GLError::InvalidOperation => "`program` is not a program object or `program` could not be made part of the current state",
-                _ => "Unknown error"
-            }
-        }
+        _use_program(program.id());
+        binding.validate();
         binding
     }
+
+    /// Unbind the current program, using `glUseProgram(0)`.
+    pub fn unbind(&mut self) {
+        _use_program(0);
+    }
+}
+
+fn _use_program(id: GLuint) {
+    if !bind_cache::_use_program(id) {
+        return;
+    }
+
+    unsafe {
+        gl_fns::UseProgram(id);
+        frame_stats::_state_change();
+        dbg_gl_error! {
+            in "glUseProgram"();
+            GLError::InvalidValue => "`program` is neither 0 nor an object generated by OpenGL",
+            GLError::InvalidOperation => "`program` is not a program object or `program` could not be made part of the current state",
+            _ => "Unknown error"
+        }
+    }
 }
 
 
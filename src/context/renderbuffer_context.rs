@@ -1,13 +1,20 @@
 //! Contains all of the OpenGL state types related to renderbuffers.
 
+use std::cmp;
 use std::marker::PhantomData;
 use std::borrow::BorrowMut;
 use gl;
+use gl_fns;
 use gl::types::*;
 use context::{AContext, BaseContext, ContextOf};
+use super::{_current_extensions, _current_limits};
 use renderbuffer::{Renderbuffer, RenderbufferTarget};
 use image_data::{RenderbufferFormat};
-use types::{GLObject, GLError};
+use types::{GLObject, GLError, BuilderError};
+use validate::BindingCheck;
+use stats;
+use frame_stats;
+use bind_cache;
 
 /// Provides a safe wrapper for creating renderbuffer objects. A
 /// `RenderbufferBuilder` can be created using the [`gl.build_renderbuffer`]
@@ -17,7 +24,8 @@ pub struct RenderbufferBuilder<C>
     where C: RenderbufferContext
 {
     gl: C,
-    storage_params: Option<(RenderbufferFormat, u32, u32)>
+    storage_params: Option<(RenderbufferFormat, u32, u32)>,
+    samples: u32
 }
 
 impl<C> RenderbufferBuilder<C>
@@ -26,7 +34,8 @@ impl<C> RenderbufferBuilder<C>
     fn new(gl: C) -> Self {
         RenderbufferBuilder {
             gl: gl,
-            storage_params: None
+            storage_params: None,
+            samples: 0
         }
     }
 
@@ -41,31 +50,69 @@ impl<C> RenderbufferBuilder<C>
         self
     }
 
+    /// Set the number of samples of the renderbuffer, for multisample
+    /// anti-aliasing. Defaults to 0, which creates a renderbuffer that
+    /// isn't multisampled. The renderbuffer may be given more samples than
+    /// requested, which can be up to [`gl.limits().max_samples`]
+    /// (../../limits/struct.ContextLimits.html#structfield.max_samples).
+    pub fn samples(mut self, samples: u32) -> Self {
+        self.samples = samples;
+        self
+    }
+
     /// Create and return a renderbuffer with the provided storage options,
     /// or return an error.
     ///
     /// # Failures
-    /// An error will be returned if no storage options were provided.
+    /// An error will be returned if no storage options were provided, if
+    /// the format isn't supported by the context, or if the number of
+    /// samples is more than the context supports.
     ///
     /// # Panics
     /// This function will panic if an OpenGL error is generated
     /// and debug assertions are enabled.
     pub fn try_unwrap(self) -> Result<Renderbuffer, GLError> {
+        if let Some((format, _, _)) = self.storage_params {
+            let extensions = unsafe { _current_extensions() };
+            if !format.is_supported(&extensions) {
+                return Err(BuilderError::UnsupportedRenderbufferFormat.into());
+            }
+        }
+
+        if self.samples > 0 {
+            let supported = unsafe {
+                _current_extensions().has_renderbuffer_multisample()
+                    && self.samples <= _current_limits().max_samples
+            };
+            if !supported {
+                return Err(BuilderError::UnsupportedRenderbufferSamples.into());
+            }
+        }
+
         let gl = self.gl;
+        let samples = self.samples;
         let mut rbo = unsafe { gl.gen_renderbuffer() };
 
         match self.storage_params {
             Some((format, width, height)) => {
                 {
                     let (mut gl_rbo, gl) = gl.bind_renderbuffer(&mut rbo);
-                    gl.storage(&mut gl_rbo, format, width, height);
+                    if samples > 0 {
+                        gl.storage_multisample(&mut gl_rbo,
+                                               samples,
+                                               format,
+                                               width,
+                                               height);
+                    }
+                    else {
+                        gl.storage(&mut gl_rbo, format, width, height);
+                    }
                 }
 
                 Ok(rbo)
             },
             None => {
-                let msg = "Error building renderbuffer: no format or dimensions provided";
-                Err(GLError::Message(msg.to_owned()))
+                Err(BuilderError::MissingRenderbufferStorage.into())
             }
         }
     }
@@ -73,7 +120,8 @@ impl<C> RenderbufferBuilder<C>
     /// Create a renderbuffer with the provided storage options, or panic.
     ///
     /// # Panics
-    /// This function will panic if no storage options were provided
+    /// This function will panic if no storage options were provided, if the
+    /// format or number of samples isn't supported by the context,
     /// or if an OpenGL error was generated and debug assertions are enabled.
     pub fn unwrap(self) -> Renderbuffer {
         self.try_unwrap().unwrap()
@@ -110,8 +158,9 @@ pub trait ContextRenderbufferExt: BaseContext {
     unsafe fn gen_renderbuffer(&self) -> Renderbuffer {
         let mut id : GLuint = 0;
 
-        gl::GenRenderbuffers(1, &mut id as *mut GLuint);
+        gl_fns::GenRenderbuffers(1, &mut id as *mut GLuint);
         dbg_gl_sanity_check! {
+            in "glGenRenderbuffers"();
             GLError::InvalidValue => "`n` is negative",
             _ => "Unknown error"
         }
@@ -135,12 +184,20 @@ pub trait ContextRenderbufferExt: BaseContext {
                width: u32,
                height: u32)
     {
+        gl_rbo.validate();
         unsafe {
-            gl::RenderbufferStorage(gl_rbo.target().gl_enum(),
+            gl_fns::RenderbufferStorage(gl_rbo.target().gl_enum(),
                                     format.gl_enum(),
                                     width as GLint,
                                     height as GLint);
+            stats::_set_size(gl_rbo.target().gl_enum(),
+                             None,
+                             (width * height) as usize
+                                 * format.bytes_per_pixel());
             dbg_gl_sanity_check! {
+                in "glRenderbufferStorage"(format = format,
+                                           width = width,
+                                           height = height);
                 GLError::InvalidEnum => "`target` is not `GL_RENDERBUFFER` or `internalformat` is not an accepted format",
                 GLError::InvalidValue => "`width` or `height` is less than zero or greater than `GL_MAX_RENDERBUFFER_SIZE`",
                 GLError::OutOfMemory => "Unable to allocate enough memory for requested size",
@@ -149,6 +206,57 @@ pub trait ContextRenderbufferExt: BaseContext {
             }
         }
     }
+
+    /// Initialize a multisampled renderbuffer object's storage, which can
+    /// be attached to a framebuffer to render with multisample
+    /// anti-aliasing. Requires [`has_renderbuffer_multisample`]
+    /// (../../extensions/struct.Extensions.html#method.has_renderbuffer_multisample).
+    ///
+    /// - `gl_rbo`: The binding of the renderbuffer to set up storage for.
+    /// - `samples`: The number of samples to use. This must be at most
+    ///   [`gl.limits().max_samples`]
+    ///   (../../limits/struct.ContextLimits.html#structfield.max_samples).
+    ///   The implementation may use more samples than requested, and 0
+    ///   means the renderbuffer isn't multisampled.
+    /// - `format`: The storage format to use for the renderbuffer.
+    /// - `width`: The storage width of the renderbuffer, in pixels.
+    /// - `height`: The storage height of the renderbuffer, in pixels.
+    ///
+    /// # See also
+    /// [`glRenderbufferStorageMultisample`](http://docs.gl/es3/glRenderbufferStorageMultisample)
+    /// OpenGL docs
+    fn storage_multisample(&self,
+                           gl_rbo: &mut RenderbufferBinding,
+                           samples: u32,
+                           format: RenderbufferFormat,
+                           width: u32,
+                           height: u32)
+    {
+        gl_rbo.validate();
+        unsafe {
+            gl_fns::RenderbufferStorageMultisample(gl_rbo.target().gl_enum(),
+                                                   samples as GLsizei,
+                                                   format.gl_enum(),
+                                                   width as GLint,
+                                                   height as GLint);
+            stats::_set_size(gl_rbo.target().gl_enum(),
+                             None,
+                             (width * height) as usize
+                                 * format.bytes_per_pixel()
+                                 * cmp::max(samples, 1) as usize);
+            dbg_gl_sanity_check! {
+                in "glRenderbufferStorageMultisample"(samples = samples,
+                                                      format = format,
+                                                      width = width,
+                                                      height = height);
+                GLError::InvalidEnum => "`target` is not `GL_RENDERBUFFER` or `internalformat` is not an accepted format",
+                GLError::InvalidValue => "`samples` is greater than `GL_MAX_SAMPLES`, or `width` or `height` is less than zero or greater than `GL_MAX_RENDERBUFFER_SIZE`",
+                GLError::OutOfMemory => "Unable to allocate enough memory for requested size",
+                GLError::InvalidOperation => "Renderbuffer object 0 is bound, or `samples` is greater than the maximum number of samples supported for `internalformat`",
+                _ => "Unknown error"
+            }
+        }
+    }
 }
 
 impl<C: BaseContext> ContextRenderbufferExt for C {
@@ -181,6 +289,20 @@ pub trait RenderbufferContext: AContext {
         let (mut binder, rest) = self.split_renderbuffer();
         (binder.borrow_mut().bind(rbo), rest)
     }
+
+    /// Unbind this context's renderbuffer (by binding 0), returning the
+    /// freed binder and the remaining context.
+    ///
+    /// # See also
+    /// [`glBindRenderbuffer`](http://docs.gl/es2/glBindRenderbuffer)
+    /// OpenGL docs
+    fn unbind_renderbuffer(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_renderbuffer();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
 impl<B, F, P, R, T> RenderbufferContext for ContextOf<B, F, P, R, T>
@@ -212,6 +334,7 @@ impl<'a, B, F, P, R, T> RenderbufferContext
 /// Represents a renderbuffer that has been bound to the `GL_RENDERBUFFER`
 /// binding target.
 pub struct RenderbufferBinding<'a> {
+    _check: BindingCheck,
     _phantom_ref: PhantomData<&'a mut Renderbuffer>,
     _phantom_ptr: PhantomData<*mut ()>
 }
@@ -220,6 +343,10 @@ impl<'a> RenderbufferBinding<'a> {
     fn target(&self) -> RenderbufferTarget {
         RenderbufferTarget::Renderbuffer
     }
+
+    fn validate(&self) {
+        self._check.check(gl::RENDERBUFFER_BINDING, "GL_RENDERBUFFER");
+    }
 }
 
 /// The OpenGL state representing the `GL_RENDERBUFFER` target.
@@ -246,17 +373,35 @@ impl RenderbufferBinder {
         -> RenderbufferBinding<'a>
     {
         let binding = RenderbufferBinding {
+            _check: BindingCheck::new(renderbuffer.id()),
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         };
-        unsafe {
-            gl::BindRenderbuffer(binding.target().gl_enum(),
-                                 renderbuffer.id());
-            dbg_gl_sanity_check! {
-                GLError::InvalidEnum => "`target` is not `GL_RENDERBUFFER`",
-                _ => "Unknown error"
-            }
-        }
+        _bind_renderbuffer(binding.target(), renderbuffer.id());
+        binding.validate();
         binding
     }
+
+    /// Unbind the renderbuffer bound to the `GL_RENDERBUFFER` target (by
+    /// binding 0), so that a deleted renderbuffer isn't left bound.
+    pub fn unbind(&mut self) {
+        _bind_renderbuffer(RenderbufferTarget::Renderbuffer, 0);
+    }
+}
+
+fn _bind_renderbuffer(target: RenderbufferTarget, id: GLuint) {
+    if !bind_cache::_bind(target.gl_enum(), id) {
+        return;
+    }
+
+    unsafe {
+        gl_fns::BindRenderbuffer(target.gl_enum(), id);
+        frame_stats::_state_change();
+        stats::_bind(target.gl_enum(), id);
+        dbg_gl_sanity_check! {
+            in "glBindRenderbuffer"();
+            GLError::InvalidEnum => "`target` is not `GL_RENDERBUFFER`",
+            _ => "Unknown error"
+        }
+    }
 }
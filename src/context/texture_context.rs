@@ -1,16 +1,54 @@
 //! Contains all of the OpenGL state types related to texture bindings.
 
 use std::ptr;
+use std::cmp;
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
-use context::{BaseContext, TextureUnit0Context, TextureUnitBinding2d};
+use context::{BaseContext, SomeTextureUnitContext, TextureUnitBinding2d};
+use super::_current_extensions;
 use texture::{TextureMipmapFilter, TextureFilter, TextureWrapMode,
-              Texture, Texture2d, TextureCubeMap,
-              Tx2d, TxCubeMap, TextureType, Tx2dImageTarget,
-              ImageTargetType, TextureBindingTarget};
-use image_data::{Image2d, TexelFormat, ImageFormat};
-use types::{GLObject, GLError};
+              Texture, Texture2d, TextureCubeMap, TextureExternal,
+              Texture2dMultisample, Texture2dArray, Texture3d, Tx2d,
+              TxCubeMap, TxExternal, Tx2dMultisample, Tx2dArray, Tx3d,
+              TextureType, ArrayTextureType, Tx2dImageTarget,
+              ImageTargetType, TextureBindingTarget, TextureCopyRegion};
+use image_data::{Image2d, Image2dView, TexelFormat, ImageFormat,
+                 CompressedFormat, CompressedImageFormat, RenderbufferFormat};
+use types::{GLObject, GLError, BuilderError};
+use extensions::Extensions;
+use stats;
+use frame_stats;
+use bind_cache;
+
+// Upload an image to a level of a texture being built, flipping it first
+// if the builder was set to flip images
+fn _upload_image<C>(gl: &C,
+                    gl_tex: &mut Texture2dBinding,
+                    level: u32,
+                    image: &Image2d,
+                    flip_y: bool)
+    where C: ContextTextureExt
+{
+    if flip_y {
+        let view = Image2dView::new(image.texel_bytes(),
+                                    image.width(),
+                                    image.height(),
+                                    image.row_pitch(),
+                                    image.format());
+        let view = if image.is_flipped_y() {
+            view
+        }
+        else {
+            view.flip_y()
+        };
+        gl.tex_image_2d(gl_tex, Tx2dImageTarget::Texture2d, level, &view);
+    }
+    else {
+        gl.tex_image_2d(gl_tex, Tx2dImageTarget::Texture2d, level, image);
+    }
+}
 
 /// Provide a safe interface for building a 2D texture
 /// object that is checked to be complete. A `Texture2dBuilder`
@@ -18,20 +56,29 @@ use types::{GLObject, GLError};
 /// (trait.ContextTextureBuilderExt.html#method.build_texture_2d)
 /// method.
 pub struct Texture2dBuilder<'a, C>
-    where C: 'a + TextureUnit0Context
+    where C: 'a + SomeTextureUnitContext
 {
     gl: C,
     min_filter: Option<TextureMipmapFilter>,
     mag_filter: Option<TextureFilter>,
     wrap_s: Option<TextureWrapMode>,
     wrap_t: Option<TextureWrapMode>,
+    min_lod: Option<f32>,
+    max_lod: Option<f32>,
+    lod_bias: Option<f32>,
+    base_level: Option<u32>,
+    max_level: Option<u32>,
     gen_mipmap: bool,
     image: Option<&'a Image2d>,
-    empty_params: Option<(ImageFormat, u32, u32)>
+    mip_images: Option<&'a [&'a Image2d]>,
+    flip_y: bool,
+    empty_params: Option<(ImageFormat, u32, u32)>,
+    sized_params: Option<(RenderbufferFormat, u32, u32)>,
+    depth_only: bool
 }
 
 impl<'a, C> Texture2dBuilder<'a, C>
-    where C: TextureUnit0Context
+    where C: SomeTextureUnitContext
 {
     fn new(gl: C) -> Self {
         Texture2dBuilder {
@@ -40,9 +87,18 @@ impl<'a, C> Texture2dBuilder<'a, C>
             mag_filter: None,
             wrap_s: None,
             wrap_t: None,
+            min_lod: None,
+            max_lod: None,
+            lod_bias: None,
+            base_level: None,
+            max_level: None,
             gen_mipmap: false,
             image: None,
-            empty_params: None
+            mip_images: None,
+            flip_y: false,
+            empty_params: None,
+            sized_params: None,
+            depth_only: false
         }
     }
 
@@ -52,6 +108,43 @@ impl<'a, C> Texture2dBuilder<'a, C>
         self
     }
 
+    /// Set the 2D image data for each mipmap level of the texture, starting
+    /// with level 0. Each level should be half the size of the previous
+    /// level (rounded down, but at least 1), such as the levels returned by
+    /// [`mipmap_chain`](../../image_data/fn.mipmap_chain.html). The levels
+    /// are uploaded as-is, so [`generate_mipmap`]
+    /// (struct.Texture2dBuilder.html#method.generate_mipmap) has no effect.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::{Image2d, Pixels, LINEAR_MIPMAP_LINEAR};
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let image = Pixels::new(256, 256);
+    /// let mips = glitter::mipmap_chain(&image);
+    ///
+    /// let mut levels: Vec<&Image2d> = vec![&image];
+    /// levels.extend(mips.iter().map(|mip| mip as &Image2d));
+    /// let texture = gl.build_texture_2d()
+    ///     .image_2d_with_mips(&levels)
+    ///     .min_filter(LINEAR_MIPMAP_LINEAR)
+    ///     .unwrap();
+    /// ```
+    pub fn image_2d_with_mips(mut self, images: &'a [&'a Image2d]) -> Self {
+        self.mip_images = Some(images);
+        self
+    }
+
+    /// Flip the image data vertically when uploading it, so that an image
+    /// that stores its top row first (like most image files) appears
+    /// upright with texture coordinates that start at the bottom-left. See
+    /// [`Image2d::is_flipped_y`](../../image_data/trait.Image2d.html#method.is_flipped_y).
+    pub fn flip_y(mut self) -> Self {
+        self.flip_y = true;
+        self
+    }
+
     /// Set the parameters for creating an empty texture.
     pub fn empty(mut self, format: ImageFormat, width: u32, height: u32)
         -> Self
@@ -60,6 +153,37 @@ impl<'a, C> Texture2dBuilder<'a, C>
         self
     }
 
+    /// Set the parameters for creating an empty texture with a sized
+    /// format, such as a floating-point texture to render to. On OpenGL
+    /// ES 2, only formats with 8-bit or packed components (and depth formats)
+    /// are supported. See [`gl.tex_image_2d_sized`]
+    /// (trait.ContextTextureExt.html#method.tex_image_2d_sized) for more
+    /// details.
+    pub fn empty_sized(mut self,
+                       format: RenderbufferFormat,
+                       width: u32,
+                       height: u32)
+        -> Self
+    {
+        self.sized_params = Some((format, width, height));
+        self.depth_only = false;
+        self
+    }
+
+    /// Set the parameters for creating an empty depth texture, such as for
+    /// a shadow map. `format` must be `DEPTH_COMPONENT16`,
+    /// `DEPTH_COMPONENT24`, or `DEPTH_COMPONENT32F`. See
+    /// [`gl.tex_image_2d_depth`]
+    /// (trait.ContextTextureExt.html#method.tex_image_2d_depth) for more
+    /// details.
+    pub fn depth(mut self, format: RenderbufferFormat, width: u32, height: u32)
+        -> Self
+    {
+        self.sized_params = Some((format, width, height));
+        self.depth_only = true;
+        self
+    }
+
     /// Automatically generate mipmaps for the texture.
     pub fn generate_mipmap(mut self) -> Self {
         self.gen_mipmap = true;
@@ -92,6 +216,45 @@ impl<'a, C> Texture2dBuilder<'a, C>
         self
     }
 
+    /// Set the texture's minimum level of detail (see
+    /// [`gl.set_min_lod`](trait.ContextTextureExt.html#method.set_min_lod)).
+    pub fn min_lod(mut self, lod: f32) -> Self {
+        self.min_lod = Some(lod);
+        self
+    }
+
+    /// Set the texture's maximum level of detail (see
+    /// [`gl.set_max_lod`](trait.ContextTextureExt.html#method.set_max_lod)).
+    pub fn max_lod(mut self, lod: f32) -> Self {
+        self.max_lod = Some(lod);
+        self
+    }
+
+    /// Set the texture's level of detail bias (see
+    /// [`gl.set_lod_bias`](trait.ContextTextureExt.html#method.set_lod_bias)).
+    pub fn lod_bias(mut self, bias: f32) -> Self {
+        self.lod_bias = Some(bias);
+        self
+    }
+
+    /// Set the texture's base mipmap level (see
+    /// [`gl.set_base_level`](trait.ContextTextureExt.html#method.set_base_level)).
+    pub fn base_level(mut self, level: u32) -> Self {
+        self.base_level = Some(level);
+        self
+    }
+
+    /// Set the texture's max mipmap level (see
+    /// [`gl.set_max_level`](trait.ContextTextureExt.html#method.set_max_level)).
+    /// When streaming in a texture's mipmap levels, setting the max level
+    /// to the last level supplied with [`image_2d_with_mips`]
+    /// (struct.Texture2dBuilder.html#method.image_2d_with_mips) allows
+    /// a mipmap filter to be used before the smallest levels are uploaded.
+    pub fn max_level(mut self, level: u32) -> Self {
+        self.max_level = Some(level);
+        self
+    }
+
     /// Create and return a texture with the specified options,
     /// or return an error.
     ///
@@ -103,21 +266,81 @@ impl<'a, C> Texture2dBuilder<'a, C>
     /// - The texture was set to be empty, but either the width
     ///   or height were 0.
     /// - The texture was neither set to be empty with [`empty`]
-    ///   (struct.Texture2dBuilder.html#method.empty), nor had
+    ///   (struct.Texture2dBuilder.html#method.empty), [`empty_sized`]
+    ///   (struct.Texture2dBuilder.html#method.empty_sized), or [`depth`]
+    ///   (struct.Texture2dBuilder.html#method.depth), nor had
     ///   any image data supplied with [`image_2d`]
-    ///   (struct.Texture2dBuilder.html#method.image_2d).
+    ///   (struct.Texture2dBuilder.html#method.image_2d) or
+    ///   [`image_2d_with_mips`]
+    ///   (struct.Texture2dBuilder.html#method.image_2d_with_mips).
+    /// - A mipmap level supplied with [`image_2d_with_mips`]
+    ///   (struct.Texture2dBuilder.html#method.image_2d_with_mips) was not
+    ///   half the size of the previous level.
     /// - The texture had a mipmap filter set for the [`min_filter`]
     ///   (struct.Texture2dBuilder.html#method.min_filter), but
     ///   mimaps were not generated using [`generate_mipmaps`]
-    ///   (struct.Texture2dBuilder.html#method.generate_mipmap).
+    ///   (struct.Texture2dBuilder.html#method.generate_mipmap), and
+    ///   the levels supplied with [`image_2d_with_mips`]
+    ///   (struct.Texture2dBuilder.html#method.image_2d_with_mips) (if any)
+    ///   didn't go down to 1x1 or to the [`max_level`]
+    ///   (struct.Texture2dBuilder.html#method.max_level).
+    /// - The [`base_level`](struct.Texture2dBuilder.html#method.base_level)
+    ///   was above the [`max_level`]
+    ///   (struct.Texture2dBuilder.html#method.max_level) or had no image,
+    ///   or the [`min_lod`](struct.Texture2dBuilder.html#method.min_lod) was
+    ///   above the [`max_lod`](struct.Texture2dBuilder.html#method.max_lod).
+    /// - A level of detail or mipmap level parameter was set, but the
+    ///   context doesn't support it (see [`Extensions::has_texture_lod`]
+    ///   (../../extensions/struct.Extensions.html#method.has_texture_lod)
+    ///   and [`Extensions::has_texture_lod_bias`]
+    ///   (../../extensions/struct.Extensions.html#method.has_texture_lod_bias)).
+    /// - The texture was set to have a sized format that the context
+    ///   doesn't support for textures (see [`Extensions::has_depth_texture`]
+    ///   (../../extensions/struct.Extensions.html#method.has_depth_texture)
+    ///   for depth formats), or was set to be a depth texture, but the
+    ///   format isn't a depth format.
     pub fn try_unwrap(self) -> Result<Texture2d, GLError> {
         use TextureMipmapFilter::MipmapFilter;
 
+        let has_lod = self.min_lod.is_some()
+            || self.max_lod.is_some()
+            || self.base_level.is_some()
+            || self.max_level.is_some();
+        if has_lod || self.lod_bias.is_some() {
+            let extensions = unsafe { _current_extensions() };
+            if (has_lod && !extensions.has_texture_lod())
+                || (self.lod_bias.is_some()
+                    && !extensions.has_texture_lod_bias())
+            {
+                return Err(BuilderError::UnsupportedTextureParameter.into());
+            }
+        }
+        if let Some((format, _, _)) = self.sized_params {
+            let extensions = unsafe { _current_extensions() };
+            if (self.depth_only && !_is_depth_format(format))
+                || !_is_sized_format_supported(format, &extensions)
+            {
+                return Err(BuilderError::UnsupportedTextureFormat.into());
+            }
+        }
+        match (self.base_level, self.max_level) {
+            (Some(base), Some(max)) if base > max => {
+                return Err(BuilderError::InvalidMipmapLevels.into());
+            },
+            _ => { }
+        }
+        match (self.min_lod, self.max_lod) {
+            (Some(min), Some(max)) if min > max => {
+                return Err(BuilderError::InvalidMipmapLevels.into());
+            },
+            _ => { }
+        }
+
         let gl = self.gl;
         let mut texture = unsafe { gl.gen_texture() };
 
         {
-            let (gl_tex_unit, gl) = gl.active_texture_0();
+            let (gl_tex_unit, gl) = gl.active_some_texture();
             let (mut gl_tex, _) = gl_tex_unit.bind_texture_2d(&mut texture);
 
             if let Some(min_filter) = self.min_filter {
@@ -132,14 +355,62 @@ impl<'a, C> Texture2dBuilder<'a, C>
             if let Some(wrap_t) = self.wrap_t {
                 gl.set_wrap_t(&mut gl_tex, wrap_t);
             }
+            if let Some(min_lod) = self.min_lod {
+                gl.set_min_lod(&mut gl_tex, min_lod);
+            }
+            if let Some(max_lod) = self.max_lod {
+                gl.set_max_lod(&mut gl_tex, max_lod);
+            }
+            if let Some(lod_bias) = self.lod_bias {
+                gl.set_lod_bias(&mut gl_tex, lod_bias);
+            }
+            if let Some(base_level) = self.base_level {
+                gl.set_base_level(&mut gl_tex, base_level);
+            }
+            if let Some(max_level) = self.max_level {
+                gl.set_max_level(&mut gl_tex, max_level);
+            }
 
             // TODO: Find out what conditions lead to a non-complete texture
             //       (e.g. if either width or height are 0)
-            if let Some(image) = self.image {
-                gl.tex_image_2d(&mut gl_tex,
-                                Tx2dImageTarget::Texture2d,
-                                0,
-                                image);
+            let mut has_mipmap = self.gen_mipmap;
+            let mut level_count = 1;
+            if let Some(images) = self.mip_images {
+                if images.is_empty() {
+                    return Err(BuilderError::MissingTextureImage.into());
+                }
+
+                let (width, height) = (images[0].width(), images[0].height());
+                for (level, image) in images.iter().enumerate() {
+                    let level = level as u32;
+                    let level_width = width.checked_shr(level).unwrap_or(0);
+                    let level_height = height.checked_shr(level).unwrap_or(0);
+                    let level_width = cmp::max(1, level_width);
+                    let level_height = cmp::max(1, level_height);
+                    if image.width() != level_width
+                        || image.height() != level_height
+                    {
+                        return Err(BuilderError::InvalidMipmapDimensions.into());
+                    }
+                }
+
+                for (level, image) in images.iter().enumerate() {
+                    _upload_image(&gl,
+                                  &mut gl_tex,
+                                  level as u32,
+                                  *image,
+                                  self.flip_y);
+                }
+
+                let last = images[images.len() - 1];
+                has_mipmap = last.width() == 1 && last.height() == 1;
+                if let Some(max_level) = self.max_level {
+                    has_mipmap |= (max_level as usize) < images.len();
+                }
+                level_count = images.len();
+            }
+            else if let Some(image) = self.image {
+                _upload_image(&gl, &mut gl_tex, 0, image, self.flip_y);
             }
             else if let Some((format, width, height)) = self.empty_params {
                 gl.tex_image_2d_empty(&mut gl_tex,
@@ -150,21 +421,40 @@ impl<'a, C> Texture2dBuilder<'a, C>
                                       height);
 
                 if !(width > 0 && height > 0) {
-                    let msg = "Error building texture: texture must have positive dimensions";
-                    return Err(GLError::Message(msg.to_owned()))
+                    return Err(BuilderError::InvalidTextureDimensions.into());
+                }
+            }
+            else if let Some((format, width, height)) = self.sized_params {
+                gl.tex_image_2d_sized(&mut gl_tex,
+                                      Tx2dImageTarget::Texture2d,
+                                      0,
+                                      format,
+                                      width,
+                                      height);
+
+                if !(width > 0 && height > 0) {
+                    return Err(BuilderError::InvalidTextureDimensions.into());
                 }
             }
             else {
-                let msg = "Error building texture: neither an image nor a format were provided";
-                return Err(GLError::Message(msg.to_owned()))
+                return Err(BuilderError::MissingTextureImage.into());
+            }
+
+            // NOTE: Mipmaps are generated from the base level, so the base
+            //       level always needs an image
+            if let Some(base_level) = self.base_level {
+                if base_level as usize >= level_count {
+                    return Err(BuilderError::InvalidMipmapLevels.into());
+                }
             }
 
-            if self.gen_mipmap {
+            if self.gen_mipmap && self.mip_images.is_none() {
                 gl.generate_mipmap(&mut gl_tex);
             }
             else if let Some(MipmapFilter {..}) = self.min_filter {
-                    let msg = "Error building texture: texture uses a mipmap filter but does not have a mipmap";
-                    return Err(GLError::Message(msg.to_owned()));
+                if !has_mipmap {
+                    return Err(BuilderError::MissingMipmap.into());
+                }
             }
         }
 
@@ -182,15 +472,11 @@ impl<'a, C> Texture2dBuilder<'a, C>
     }
 }
 
-// NOTE: There is currently no way to express "a context with
-//       one free texure unit"; this design should be explored for
-//       cases like this (where the actual unit number doesn't matter)
 /// The extension trait for contexts that adds the `build_texture_2d` method.
-///
-/// # Note
-/// Currently, this trait is only implemented for contexts where the
-/// 0th texture unit is free.
-pub trait ContextTextureBuilderExt: TextureUnit0Context + Sized {
+/// This trait is implemented for contexts with any free texture unit (see
+/// [`SomeTextureUnitContext`]
+/// (../texture_units/trait.SomeTextureUnitContext.html)).
+pub trait ContextTextureBuilderExt: SomeTextureUnitContext + Sized {
     /// Create a new 2D texture builder, providing a safe interface
     /// for constructing a 2D texture object. See the [`Texture2dBuilder`]
     /// (struct.Texture2dBuilder.html) docs for more details.
@@ -200,7 +486,7 @@ pub trait ContextTextureBuilderExt: TextureUnit0Context + Sized {
 }
 
 impl<'a, C: 'a> ContextTextureBuilderExt for &'a mut C
-    where &'a mut C: TextureUnit0Context
+    where &'a mut C: SomeTextureUnitContext
 {
 
 }
@@ -224,8 +510,9 @@ pub trait ContextTextureExt: BaseContext {
     unsafe fn gen_texture<TX: TextureType>(&self) -> Texture<TX> {
         let mut id : GLuint =  0;
 
-        gl::GenTextures(1, &mut id as *mut GLuint);
+        gl_fns::GenTextures(1, &mut id as *mut GLuint);
         dbg_gl_sanity_check! {
+            in "glGenTextures"();
             GLError::InvalidValue => "`n` is negative",
             _ => "Unknown error"
         }
@@ -233,6 +520,45 @@ pub trait ContextTextureExt: BaseContext {
         Texture::from_raw(id)
     }
 
+    /// Get the list of compressed texture formats that the OpenGL
+    /// implementation supports. The list may be empty, and it may not
+    /// include every format that an extension adds (for example, some
+    /// drivers leave out the ASTC formats), so the [`Extensions`]
+    /// (../../extensions/struct.Extensions.html) can be checked as well.
+    ///
+    /// # See also
+    /// [`glGet`](http://docs.gl/es2/glGet) OpenGL docs (see
+    /// `GL_COMPRESSED_TEXTURE_FORMATS`)
+    fn compressed_formats(&self) -> Vec<CompressedFormat> {
+        unsafe {
+            let mut count: GLint = 0;
+            gl_fns::GetIntegerv(gl::NUM_COMPRESSED_TEXTURE_FORMATS,
+                                &mut count);
+            dbg_gl_sanity_check! {
+                in "glGetIntegerv"();
+                GLError::InvalidEnum => "`pname` is not an accepted value",
+                _ => "Unknown error"
+            }
+
+            if count <= 0 {
+                return vec![];
+            }
+
+            let mut formats: Vec<GLint> = vec![0; count as usize];
+            gl_fns::GetIntegerv(gl::COMPRESSED_TEXTURE_FORMATS,
+                                formats.as_mut_ptr());
+            dbg_gl_sanity_check! {
+                in "glGetIntegerv"();
+                GLError::InvalidEnum => "`pname` is not an accepted value",
+                _ => "Unknown error"
+            }
+
+            formats.into_iter().map(|format| {
+                CompressedFormat { gl_enum: format as GLenum }
+            }).collect()
+        }
+    }
+
     /// Set a texture's minifying filter.
     ///
     /// # See also
@@ -293,6 +619,116 @@ pub trait ContextTextureExt: BaseContext {
         }
     }
 
+    /// Set a texture's minimum level of detail, which clamps the lowest
+    /// (largest) mipmap level that can be sampled. Requires desktop
+    /// OpenGL 1.2 or OpenGL ES 3.0 (see [`Extensions::has_texture_lod`]
+    /// (../../extensions/struct.Extensions.html#method.has_texture_lod)).
+    ///
+    /// # See also
+    /// [`glTexParameter`](http://docs.gl/es3/glTexParameter) OpenGL docs
+    fn set_min_lod<T>(&self, gl_texture: &mut T, lod: f32)
+        where T: TextureBinding
+    {
+        unsafe {
+            _tex_parameter_fv(gl_texture.target(),
+                              gl::TEXTURE_MIN_LOD,
+                              &lod as *const GLfloat);
+        }
+    }
+
+    /// Set a texture's maximum level of detail, which clamps the highest
+    /// (smallest) mipmap level that can be sampled. Requires desktop
+    /// OpenGL 1.2 or OpenGL ES 3.0 (see [`Extensions::has_texture_lod`]
+    /// (../../extensions/struct.Extensions.html#method.has_texture_lod)).
+    ///
+    /// # See also
+    /// [`glTexParameter`](http://docs.gl/es3/glTexParameter) OpenGL docs
+    fn set_max_lod<T>(&self, gl_texture: &mut T, lod: f32)
+        where T: TextureBinding
+    {
+        unsafe {
+            _tex_parameter_fv(gl_texture.target(),
+                              gl::TEXTURE_MAX_LOD,
+                              &lod as *const GLfloat);
+        }
+    }
+
+    /// Set a bias that is added to a texture's level of detail before
+    /// selecting a mipmap level. Requires desktop OpenGL 1.4 or
+    /// `GL_EXT_texture_lod_bias` (see [`Extensions::has_texture_lod_bias`]
+    /// (../../extensions/struct.Extensions.html#method.has_texture_lod_bias)).
+    ///
+    /// # See also
+    /// [`glTexParameter`](http://docs.gl/gl4/glTexParameter) OpenGL docs
+    fn set_lod_bias<T>(&self, gl_texture: &mut T, bias: f32)
+        where T: TextureBinding
+    {
+        unsafe {
+            _tex_parameter_fv(gl_texture.target(),
+                              gl::TEXTURE_LOD_BIAS,
+                              &bias as *const GLfloat);
+        }
+    }
+
+    /// Set a texture's base mipmap level, which is the largest mipmap level
+    /// that will be sampled (and the level that mipmaps are generated from).
+    /// This allows a texture to be used while its larger levels are still
+    /// being uploaded. Requires desktop OpenGL 1.2 or OpenGL ES 3.0 (see
+    /// [`Extensions::has_texture_lod`]
+    /// (../../extensions/struct.Extensions.html#method.has_texture_lod)).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::{Image2d, TEXTURE_2D};
+    ///
+    /// # let mut gl = unsafe { glitter::Context::current_context() };
+    /// # let mut texture: glitter::Texture2d = unsafe { gl.gen_texture() };
+    /// # let levels: Vec<glitter::Image2dBuf> = vec![];
+    /// let (gl_tex_unit, gl) = gl.active_texture_0();
+    /// let (mut gl_texture, _) = gl_tex_unit.bind_texture_2d(&mut texture);
+    ///
+    /// // Upload the largest levels last, and start sampling each level
+    /// // once it's been uploaded
+    /// for (level, image) in levels.iter().enumerate().rev() {
+    ///     gl.tex_image_2d(&mut gl_texture, TEXTURE_2D, level as u32, image);
+    ///     gl.set_base_level(&mut gl_texture, level as u32);
+    /// }
+    /// ```
+    ///
+    /// # See also
+    /// [`glTexParameter`](http://docs.gl/es3/glTexParameter) OpenGL docs
+    fn set_base_level<T>(&self, gl_texture: &mut T, level: u32)
+        where T: TextureBinding
+    {
+        let gl_int = level as GLint;
+        unsafe {
+            _tex_parameter_iv(gl_texture.target(),
+                              gl::TEXTURE_BASE_LEVEL,
+                              &gl_int as *const GLint);
+        }
+    }
+
+    /// Set a texture's max mipmap level, which is the smallest mipmap level
+    /// that will be sampled. A texture with a mipmap filter is complete as
+    /// long as every level from the base level to the max level has an
+    /// image. Requires desktop OpenGL 1.2 or OpenGL ES 3.0 (see
+    /// [`Extensions::has_texture_lod`]
+    /// (../../extensions/struct.Extensions.html#method.has_texture_lod)).
+    ///
+    /// # See also
+    /// [`glTexParameter`](http://docs.gl/es3/glTexParameter) OpenGL docs
+    fn set_max_level<T>(&self, gl_texture: &mut T, level: u32)
+        where T: TextureBinding
+    {
+        let gl_int = level as GLint;
+        unsafe {
+            _tex_parameter_iv(gl_texture.target(),
+                              gl::TEXTURE_MAX_LEVEL,
+                              &gl_int as *const GLint);
+        }
+    }
+
     /// Generate a set of mipmaps for a texture object.
     ///
     /// # See also
@@ -301,18 +737,25 @@ pub trait ContextTextureExt: BaseContext {
         where T: TextureBinding
     {
         unsafe {
-            gl::GenerateMipmap(gl_texture.target().gl_enum())
+            gl_fns::GenerateMipmap(gl_texture.target().gl_enum())
         }
+        stats::_generate_mipmap(gl_texture.target().gl_enum());
     }
 
     /// Upload 2D image data to a texture object's image target.
     ///
-    /// - `_gl_texture`: The binding of the texture object.
+    /// - `gl_texture`: The binding of the texture object.
     /// - `target`: The texture's 2D image target to upload the image data to.
     /// - `level`: The mipmap level to upload the image data to.
-    /// - `img`: The image data to upload.
+    /// - `img`: The image data to upload. If the image is flipped (see
+    ///          [`is_flipped_y`](../../image_data/trait.Image2d.html#method.is_flipped_y)),
+    ///          its rows are copied in reverse order before uploading.
+    ///
+    /// # Panics
+    /// This function will panic if `img` has fewer bytes than its width,
+    /// height, row pitch, and format require.
     fn tex_image_2d<T, U, I: ?Sized>(&self,
-                                     _gl_texture: &mut T,
+                                     gl_texture: &mut T,
                                      target: U,
                                      level: u32,
                                      img: &I)
@@ -320,22 +763,182 @@ pub trait ContextTextureExt: BaseContext {
               U: Into<<T::TextureType as TextureType>::ImageTargetType>,
               I: Image2d
     {
+        let target = target.into();
+        let image = (target.gl_enum(), level);
+        let format = img.format();
+        _check_image_len(img);
+        if img.is_flipped_y() {
+            let flipped = _flip_rows(img);
+            let row_pitch = img.width() * format.bytes_per_texel();
+            let view = Image2dView::new(&flipped,
+                                        img.width(),
+                                        img.height(),
+                                        row_pitch,
+                                        format);
+            self.tex_image_2d(gl_texture, target, level, &view);
+            return;
+        }
         unsafe {
-            _tex_image_2d(target.into(),
-                          level,
-                          img.format().texel_format,
-                          img.width() as u32,
-                          img.height() as u32,
-                          0,
-                          img.format(),
-                          img.texel_bytes().as_ptr());
+            if img.row_pitch() == _unpack_row_stride(img.width(), format) {
+                _tex_image_2d(target,
+                              level,
+                              format.texel_format,
+                              img.width() as u32,
+                              img.height() as u32,
+                              0,
+                              format,
+                              img.texel_bytes().as_ptr());
+            }
+            else {
+                // The rows aren't laid out the way OpenGL expects, so the
+                // image is allocated first and then uploaded with
+                // `glTexSubImage2D`, which can handle any row pitch
+                _tex_image_2d(target,
+                              level,
+                              format.texel_format,
+                              img.width() as u32,
+                              img.height() as u32,
+                              0,
+                              format,
+                              ptr::null());
+                _tex_sub_image_2d(image.0, level, 0, 0, img);
+            }
+        }
+        stats::_set_size(gl_texture.target().gl_enum(),
+                         Some(image),
+                         img.width() * img.height() * format.bytes_per_texel());
+    }
+
+    /// Upload 2D image data to a region of a texture object's image
+    /// target, starting at texel (`x`, `y`). The image's row pitch is
+    /// respected, so a region of a larger image (see [`sub_image`]
+    /// (../../image_data/trait.Image2d.html#method.sub_image)) can be
+    /// uploaded without copying it.
+    ///
+    /// - `gl_texture`: The binding of the texture object.
+    /// - `target`: The texture's 2D image target to upload the image data to.
+    /// - `level`: The mipmap level to upload the image data to.
+    /// - `x`: The column of the texture to start the region at.
+    /// - `y`: The row of the texture to start the region at.
+    /// - `img`: The image data to upload. If the image is flipped (see
+    ///          [`is_flipped_y`](../../image_data/trait.Image2d.html#method.is_flipped_y)),
+    ///          its rows are copied in reverse order before uploading, and
+    ///          its first row ends up at the top of the region.
+    ///
+    /// # Panics
+    /// This function will panic if `img` has fewer bytes than its width,
+    /// height, row pitch, and format require.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::{Image2d, Pixels, TEXTURE_2D};
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let atlas = Pixels::new(256, 256);
+    /// let mut texture = gl.build_texture_2d().image_2d(&atlas).unwrap();
+    ///
+    /// // Replace the top-left corner of the texture with another sprite
+    /// let sprite = atlas.sub_image(64, 64, 32, 32);
+    /// let (gl_tex_unit, gl) = gl.active_texture_0();
+    /// let (mut gl_texture, _) = gl_tex_unit.bind_texture_2d(&mut texture);
+    /// gl.tex_sub_image_2d(&mut gl_texture, TEXTURE_2D, 0, 0, 0, &sprite);
+    /// ```
+    ///
+    /// # See also
+    /// [`glTexSubImage2D`](http://docs.gl/es2/glTexSubImage2D) OpenGL docs
+    fn tex_sub_image_2d<T, U, I: ?Sized>(&self,
+                                         _gl_texture: &mut T,
+                                         target: U,
+                                         level: u32,
+                                         x: u32,
+                                         y: u32,
+                                         img: &I)
+        where T: TextureBinding,
+              U: Into<<T::TextureType as TextureType>::ImageTargetType>,
+              I: Image2d
+    {
+        _check_image_len(img);
+        if img.is_flipped_y() {
+            let flipped = _flip_rows(img);
+            let row_pitch = img.width() * img.format().bytes_per_texel();
+            let view = Image2dView::new(&flipped,
+                                        img.width(),
+                                        img.height(),
+                                        row_pitch,
+                                        img.format());
+            unsafe {
+                _tex_sub_image_2d(target.into().gl_enum(), level, x, y, &view);
+            }
+            return;
+        }
+        unsafe {
+            _tex_sub_image_2d(target.into().gl_enum(), level, x, y, img);
+        }
+    }
+
+    /// Copy regions of image data from one 2D texture to another, without
+    /// reading the data back from OpenGL. The textures don't need to be
+    /// bound.
+    ///
+    /// When the context supports `glCopyImageSubData` (see
+    /// [`has_copy_image`]
+    /// (../../extensions/struct.Extensions.html#method.has_copy_image)),
+    /// the data is copied directly, which requires both textures to be
+    /// complete (so a texture with a mipmap filter needs all of its mipmap
+    /// levels). Otherwise, `src` is attached to a temporary framebuffer and
+    /// copied with `glCopyTexSubImage2D`, which requires `src` to have a
+    /// color-renderable format. Any bindings changed by the fallback are
+    /// restored afterwards.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::{Rect, TextureCopyRegion, ImageFormat, LINEAR};
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let page = gl.build_texture_2d()
+    ///     .empty(ImageFormat::rgba8(), 512, 512)
+    ///     .min_filter(LINEAR)
+    ///     .unwrap();
+    /// let mut new_page = gl.build_texture_2d()
+    ///     .empty(ImageFormat::rgba8(), 1024, 1024)
+    ///     .min_filter(LINEAR)
+    ///     .unwrap();
+    ///
+    /// // Copy the old atlas page into the corner of the bigger one
+    /// let region = TextureCopyRegion::new(Rect::new(0, 0, 512, 512), 0, 0);
+    /// gl.copy_texture(&page, &mut new_page, &[region]);
+    /// ```
+    ///
+    /// # See also
+    /// [`glCopyImageSubData`](http://docs.gl/es3/glCopyImageSubData) and
+    /// [`glCopyTexSubImage2D`](http://docs.gl/es2/glCopyTexSubImage2D)
+    /// OpenGL docs
+    fn copy_texture(&self,
+                    src: &Texture2d,
+                    dst: &mut Texture2d,
+                    regions: &[TextureCopyRegion])
+    {
+        let _span = gl_span!("copy_texture", regions = regions.len());
+        let has_copy_image = gl_fns::CopyImageSubData::is_loaded()
+            && self._extensions().has_copy_image();
+        unsafe {
+            if has_copy_image {
+                for region in regions {
+                    _copy_image_sub_data(src.id(), dst.id(), region);
+                }
+            }
+            else {
+                _copy_tex_sub_image_2d(src.id(), dst.id(), regions);
+            }
         }
     }
 
     /// Set a texture object's image target to an empty image
     /// with the specified parameters.
     ///
-    /// - `_gl_texture`: The binding of the texture object.
+    /// - `gl_texture`: The binding of the texture object.
     /// - `target`: The texture's 2D image target to set.
     /// - `level`: The mipmap level to set.
     /// - `format`: The image format to use to use for the
@@ -343,7 +946,7 @@ pub trait ContextTextureExt: BaseContext {
     /// - `width`: The width to set for the texture's data store.
     /// - `height`: The height to set for the texture's data store.
     fn tex_image_2d_empty<T, I>(&self,
-                                _gl_texture: &mut T,
+                                gl_texture: &mut T,
                                 target: I,
                                 level: u32,
                                 format: ImageFormat,
@@ -351,6 +954,7 @@ pub trait ContextTextureExt: BaseContext {
                                 height: u32)
         where T: TextureBinding, I: ImageTargetType
     {
+        let image = (target.gl_enum(), level);
         unsafe {
             _tex_image_2d(target,
                           level,
@@ -361,6 +965,447 @@ pub trait ContextTextureExt: BaseContext {
                           format,
                           ptr::null());
         }
+        stats::_set_size(gl_texture.target().gl_enum(),
+                         Some(image),
+                         (width * height) as usize * format.bytes_per_texel());
+    }
+
+    /// Set a texture object's image target to an empty image with a sized
+    /// format, such as a floating-point format for rendering to. On
+    /// OpenGL ES 2, textures only support unsized internal formats, so the
+    /// unsized format with the same components is used instead (and
+    /// `format` only selects the type of each component).
+    ///
+    /// - `gl_texture`: The binding of the texture object.
+    /// - `target`: The texture's 2D image target to set.
+    /// - `level`: The mipmap level to set.
+    /// - `format`: The sized format to use for the texture's data store.
+    /// - `width`: The width to set for the texture's data store.
+    /// - `height`: The height to set for the texture's data store.
+    ///
+    /// # Panics
+    /// This function will panic if `format` is `STENCIL_INDEX8`, which
+    /// can't be used for textures.
+    fn tex_image_2d_sized<T, I>(&self,
+                                gl_texture: &mut T,
+                                target: I,
+                                level: u32,
+                                format: RenderbufferFormat,
+                                width: u32,
+                                height: u32)
+        where T: TextureBinding, I: ImageTargetType
+    {
+        let (texel_format, texel_type) = match _sized_format_params(format) {
+            Some(params) => { params },
+            None => { panic!("{:?} can't be used for textures", format); }
+        };
+        let internal_format = if unsafe { _is_es2() } {
+            texel_format
+        }
+        else {
+            format.gl_enum()
+        };
+
+        let image = (target.gl_enum(), level);
+        unsafe {
+            gl_fns::TexImage2D(target.gl_enum(),
+                               level as GLint,
+                               internal_format as GLint,
+                               width as GLint,
+                               height as GLint,
+                               0,
+                               texel_format,
+                               texel_type,
+                               ptr::null());
+            dbg_gl_sanity_check! {
+                in "glTexImage2D"(target = target.gl_enum(),
+                                  level = level,
+                                  width = width,
+                                  height = height,
+                                  format = format);
+                GLError::InvalidEnum => "`target` is not an accepted value, or the format is not supported",
+                GLError::InvalidValue => "`target`, `level`, `width`, or `height` is an invalid value",
+                GLError::InvalidOperation => "The format is not supported",
+                _ => "Unknown error"
+            }
+        }
+        stats::_set_size(gl_texture.target().gl_enum(),
+                         Some(image),
+                         (width * height) as usize * format.bytes_per_pixel());
+    }
+
+    /// Set a texture object's image target to an empty depth image, such as
+    /// for rendering a shadow map. On OpenGL ES 2, depth textures require
+    /// the `GL_OES_depth_texture` extension, which only supports an unsized
+    /// internal format (so `format` only selects the precision of each
+    /// texel). See [`gl.tex_image_2d_sized`]
+    /// (trait.ContextTextureExt.html#method.tex_image_2d_sized) for more
+    /// details.
+    ///
+    /// # Panics
+    /// This function will panic if `format` is not `DEPTH_COMPONENT16`,
+    /// `DEPTH_COMPONENT24`, or `DEPTH_COMPONENT32F`.
+    ///
+    /// # See also
+    /// [`Extensions::has_depth_texture`]
+    /// (../../extensions/struct.Extensions.html#method.has_depth_texture):
+    /// Checks if the context supports depth textures.
+    fn tex_image_2d_depth<T, I>(&self,
+                                gl_texture: &mut T,
+                                target: I,
+                                level: u32,
+                                format: RenderbufferFormat,
+                                width: u32,
+                                height: u32)
+        where T: TextureBinding, I: ImageTargetType
+    {
+        assert!(_is_depth_format(format),
+                "{:?} is not a depth texture format", format);
+        self.tex_image_2d_sized(gl_texture, target, level, format, width, height);
+    }
+
+    /// Upload compressed image data to a texture object's image target.
+    ///
+    /// - `gl_texture`: The binding of the texture object.
+    /// - `target`: The texture's 2D image target to upload the image data to.
+    /// - `level`: The mipmap level to upload the image data to.
+    /// - `format`: The compressed format of the image data.
+    /// - `width`: The width of the image, in texels.
+    /// - `height`: The height of the image, in texels.
+    /// - `data`: The compressed image data.
+    ///
+    /// # Panics
+    /// This function will panic if the length of `data` doesn't match the
+    /// size that `format` requires for an image with the given dimensions
+    /// (see [`CompressedImageFormat::image_size`]
+    /// (../../image_data/enum.CompressedImageFormat.html#method.image_size)).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::{COMPRESSED_RGB8_ETC2, TEXTURE_2D};
+    ///
+    /// # let mut gl = unsafe { glitter::Context::current_context() };
+    /// # let mut texture: glitter::Texture2d = unsafe { gl.gen_texture() };
+    /// # let data = vec![0; 128];
+    /// let (gl_tex_unit, gl) = gl.active_texture_0();
+    /// let (mut gl_texture, _) = gl_tex_unit.bind_texture_2d(&mut texture);
+    /// gl.compressed_tex_image_2d(&mut gl_texture,
+    ///                            TEXTURE_2D,
+    ///                            0,
+    ///                            COMPRESSED_RGB8_ETC2,
+    ///                            16, 16,
+    ///                            &data);
+    /// ```
+    ///
+    /// # See also
+    /// [`glCompressedTexImage2D`](http://docs.gl/es3/glCompressedTexImage2D)
+    /// OpenGL docs
+    fn compressed_tex_image_2d<T, U>(&self,
+                                     gl_texture: &mut T,
+                                     target: U,
+                                     level: u32,
+                                     format: CompressedImageFormat,
+                                     width: u32,
+                                     height: u32,
+                                     data: &[u8])
+        where T: TextureBinding,
+              U: Into<<T::TextureType as TextureType>::ImageTargetType>
+    {
+        let expected_len = format.image_size(width, height);
+        assert!(data.len() == expected_len,
+                "Expected {} bytes of {:?} data for a {}x{} image, got {}",
+                expected_len, format, width, height, data.len());
+
+        let target = target.into().gl_enum();
+        unsafe {
+            gl_fns::CompressedTexImage2D(target,
+                                         level as GLint,
+                                         format.gl_enum(),
+                                         width as GLsizei,
+                                         height as GLsizei,
+                                         0,
+                                         data.len() as GLsizei,
+                                         data.as_ptr() as *const GLvoid);
+            dbg_gl_sanity_check! {
+                in "glCompressedTexImage2D"(target = target,
+                                            level = level,
+                                            format = format,
+                                            width = width,
+                                            height = height);
+                GLError::InvalidEnum => "`target` or `internalformat` is not an accepted value",
+                GLError::InvalidValue => "`level`, `width`, `height`, or `imageSize` is invalid",
+                GLError::InvalidOperation => "`internalformat` is not supported, or `imageSize` doesn't match the format and dimensions",
+                _ => "Unknown error"
+            }
+        }
+        stats::_set_size(gl_texture.target().gl_enum(),
+                         Some((target, level)),
+                         data.len());
+    }
+
+    /// Attach an EGL image to an external texture, so that the texture
+    /// samples the image's contents. This is how the output of a camera or
+    /// video decoder (such as an Android `SurfaceTexture` or `MediaCodec`)
+    /// is made available to shaders. Requires the
+    /// `GL_OES_EGL_image_external` extension (see
+    /// [`Extensions::has_egl_image_external`]
+    /// (../../extensions/struct.Extensions.html#method.has_egl_image_external)).
+    ///
+    /// # Safety
+    /// `image` must be a valid `EGLImageKHR` that was created for the
+    /// context's EGL display, and it must not be destroyed while it's
+    /// attached to the texture.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// # let egl_image = ::std::ptr::null();
+    /// assert!(gl.extensions().has_egl_image_external());
+    ///
+    /// let mut texture: glitter::TextureExternal = unsafe {
+    ///     gl.gen_texture()
+    /// };
+    /// let (gl_tex_unit, gl) = gl.active_texture_0();
+    /// let (mut gl_texture, _) = gl_tex_unit.bind_texture_external(&mut texture);
+    /// unsafe { gl.egl_image_target_texture(&mut gl_texture, egl_image); }
+    /// gl.set_min_filter(&mut gl_texture, glitter::LINEAR);
+    /// gl.set_wrap_s(&mut gl_texture, glitter::CLAMP_TO_EDGE);
+    /// gl.set_wrap_t(&mut gl_texture, glitter::CLAMP_TO_EDGE);
+    /// ```
+    ///
+    /// # See also
+    /// [`GL_OES_EGL_image_external`](https://www.khronos.org/registry/OpenGL/extensions/OES/OES_EGL_image_external.txt)
+    /// extension specification
+    unsafe fn egl_image_target_texture(&self,
+                                       gl_texture: &mut TextureExternalBinding,
+                                       image: GLeglImageOES)
+    {
+        let target = gl_texture.target().gl_enum();
+        gl_fns::EGLImageTargetTexture2DOES(target, image);
+        dbg_gl_sanity_check! {
+            in "glEGLImageTargetTexture2DOES"(target = target);
+            GLError::InvalidEnum => "`target` is not `GL_TEXTURE_EXTERNAL_OES`",
+            GLError::InvalidOperation => "`image` is not a valid EGL image, or can't be used as an external texture",
+            _ => "Unknown error"
+        }
+    }
+
+    /// Allocate storage for a multisampled 2D texture. The storage is
+    /// immutable, so it can't be reallocated later. Requires desktop
+    /// OpenGL 4.3 or OpenGL ES 3.1.
+    ///
+    /// - `gl_texture`: The binding of the texture to allocate storage for.
+    /// - `samples`: The number of samples per texel. This must be at most
+    ///   [`gl.limits().max_samples`]
+    ///   (../../limits/struct.ContextLimits.html#structfield.max_samples).
+    /// - `format`: The format of the texture, which must be renderable.
+    /// - `width`: The width of the texture, in texels.
+    /// - `height`: The height of the texture, in texels.
+    /// - `fixed_sample_locations`: If `true`, every texel uses the same
+    ///   sample locations, and the same number of samples is used
+    ///   regardless of `format`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let mut texture: glitter::Texture2dMultisample = unsafe {
+    ///     gl.gen_texture()
+    /// };
+    /// {
+    ///     let (gl_tex_unit, gl) = (&mut gl).active_texture_0();
+    ///     let (mut gl_texture, _) =
+    ///         gl_tex_unit.bind_texture_2d_multisample(&mut texture);
+    ///     gl.tex_storage_2d_multisample(&mut gl_texture,
+    ///                                   4,
+    ///                                   glitter::RGBA4,
+    ///                                   800,
+    ///                                   600,
+    ///                                   true);
+    /// }
+    ///
+    /// let fbo = gl.build_framebuffer()
+    ///     .texture_2d_multisample(glitter::COLOR_ATTACHMENT0, &mut texture)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # See also
+    /// [`glTexStorage2DMultisample`](http://docs.gl/es3/glTexStorage2DMultisample)
+    /// OpenGL docs
+    fn tex_storage_2d_multisample(&self,
+                                  gl_texture: &mut Texture2dMultisampleBinding,
+                                  samples: u32,
+                                  format: RenderbufferFormat,
+                                  width: u32,
+                                  height: u32,
+                                  fixed_sample_locations: bool)
+    {
+        let target = gl_texture.target().gl_enum();
+        let fixed = if fixed_sample_locations { gl::TRUE } else { gl::FALSE };
+        unsafe {
+            gl_fns::TexStorage2DMultisample(target,
+                                            samples as GLsizei,
+                                            format.gl_enum(),
+                                            width as GLsizei,
+                                            height as GLsizei,
+                                            fixed);
+            dbg_gl_sanity_check! {
+                in "glTexStorage2DMultisample"(samples = samples,
+                                               format = format,
+                                               width = width,
+                                               height = height);
+                GLError::InvalidEnum => "`internalformat` is not a color-renderable, depth-renderable, or stencil-renderable format",
+                GLError::InvalidValue => "`width` or `height` is less than 1 or greater than `GL_MAX_TEXTURE_SIZE`",
+                GLError::InvalidOperation => "`samples` is greater than the maximum number of samples for `internalformat`, or the texture's storage is immutable",
+                _ => "Unknown error"
+            }
+        }
+        stats::_set_size(target,
+                         Some((target, 0)),
+                         (width * height) as usize
+                             * format.bytes_per_pixel()
+                             * cmp::max(samples, 1) as usize);
+    }
+
+    /// Allocate storage for a multisampled 2D texture. Unlike
+    /// [`gl.tex_storage_2d_multisample`]
+    /// (trait.ContextTextureExt.html#method.tex_storage_2d_multisample),
+    /// the storage can be reallocated later, but this is only available on
+    /// desktop OpenGL 3.2. The parameters are the same as for
+    /// `gl.tex_storage_2d_multisample`.
+    ///
+    /// # See also
+    /// [`glTexImage2DMultisample`](http://docs.gl/gl4/glTexImage2DMultisample)
+    /// OpenGL docs
+    fn tex_image_2d_multisample(&self,
+                                gl_texture: &mut Texture2dMultisampleBinding,
+                                samples: u32,
+                                format: RenderbufferFormat,
+                                width: u32,
+                                height: u32,
+                                fixed_sample_locations: bool)
+    {
+        let target = gl_texture.target().gl_enum();
+        let fixed = if fixed_sample_locations { gl::TRUE } else { gl::FALSE };
+        unsafe {
+            gl_fns::TexImage2DMultisample(target,
+                                          samples as GLsizei,
+                                          format.gl_enum(),
+                                          width as GLsizei,
+                                          height as GLsizei,
+                                          fixed);
+            dbg_gl_sanity_check! {
+                in "glTexImage2DMultisample"(samples = samples,
+                                             format = format,
+                                             width = width,
+                                             height = height);
+                GLError::InvalidOperation => "`internalformat` is not renderable, or `samples` is greater than the maximum number of samples for `internalformat`",
+                GLError::InvalidValue => "`samples` is 0, or `width` or `height` is negative or greater than `GL_MAX_TEXTURE_SIZE`",
+                _ => "Unknown error"
+            }
+        }
+        stats::_set_size(target,
+                         Some((target, 0)),
+                         (width * height) as usize
+                             * format.bytes_per_pixel()
+                             * cmp::max(samples, 1) as usize);
+    }
+
+    /// Set a mipmap level of a 2D array or 3D texture to an empty image
+    /// with a sized format, such as a depth format for the cascades of
+    /// a cascaded shadow map. As with [`gl.tex_image_2d_sized`]
+    /// (trait.ContextTextureExt.html#method.tex_image_2d_sized), the
+    /// unsized format with the same components is used instead on
+    /// OpenGL ES 2 (where 3D textures require `GL_OES_texture_3D`, and 2D
+    /// array textures aren't supported).
+    ///
+    /// - `gl_texture`: The binding of the texture object.
+    /// - `level`: The mipmap level to set.
+    /// - `format`: The sized format to use for the texture's data store.
+    /// - `width`: The width of each layer, in texels.
+    /// - `height`: The height of each layer, in texels.
+    /// - `depth`: The number of layers.
+    ///
+    /// # Panics
+    /// This function will panic if `format` is `STENCIL_INDEX8`, which
+    /// can't be used for textures.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let mut cascades: glitter::Texture2dArray = unsafe {
+    ///     gl.gen_texture()
+    /// };
+    /// {
+    ///     let (gl_tex_unit, gl) = (&mut gl).active_texture_0();
+    ///     let (mut gl_cascades, _) =
+    ///         gl_tex_unit.bind_texture_2d_array(&mut cascades);
+    ///     gl.tex_image_3d_sized(&mut gl_cascades,
+    ///                           0,
+    ///                           glitter::DEPTH_COMPONENT24,
+    ///                           1024,
+    ///                           1024,
+    ///                           4);
+    /// }
+    /// ```
+    ///
+    /// # See also
+    /// [`glTexImage3D`](http://docs.gl/es3/glTexImage3D) OpenGL docs
+    fn tex_image_3d_sized<T>(&self,
+                             gl_texture: &mut T,
+                             level: u32,
+                             format: RenderbufferFormat,
+                             width: u32,
+                             height: u32,
+                             depth: u32)
+        where T: TextureBinding, T::TextureType: ArrayTextureType
+    {
+        let (texel_format, texel_type) = match _sized_format_params(format) {
+            Some(params) => { params },
+            None => { panic!("{:?} can't be used for textures", format); }
+        };
+        let internal_format = if unsafe { _is_es2() } {
+            texel_format
+        }
+        else {
+            format.gl_enum()
+        };
+
+        let target = gl_texture.target().gl_enum();
+        unsafe {
+            gl_fns::TexImage3D(target,
+                               level as GLint,
+                               internal_format as GLint,
+                               width as GLsizei,
+                               height as GLsizei,
+                               depth as GLsizei,
+                               0,
+                               texel_format,
+                               texel_type,
+                               ptr::null());
+            dbg_gl_sanity_check! {
+                in "glTexImage3D"(target = target,
+                                  level = level,
+                                  width = width,
+                                  height = height,
+                                  depth = depth,
+                                  format = format);
+                GLError::InvalidEnum => "`target` is not an accepted value, or the format is not supported",
+                GLError::InvalidValue => "`level`, `width`, `height`, or `depth` is an invalid value",
+                GLError::InvalidOperation => "The format is not supported, or can't be used with `target`",
+                _ => "Unknown error"
+            }
+        }
+        stats::_set_size(target,
+                         Some((target, level)),
+                         (width * height * depth) as usize
+                             * format.bytes_per_pixel());
     }
 }
 
@@ -374,13 +1419,26 @@ unsafe fn _tex_parameter_iv(target: TextureBindingTarget,
                             pname: GLenum,
                             params: *const GLint)
 {
-    gl::TexParameteriv(target.gl_enum(), pname, params);
+    gl_fns::TexParameteriv(target.gl_enum(), pname, params);
     dbg_gl_sanity_check! {
+        in "glTexParameteriv"();
         GLError::InvalidEnum => "`target` or `pname` is not an accepted defined value, or `params` should have defined a symbolic constant and does not",
         _ => "Unknown error"
     }
 }
 
+unsafe fn _tex_parameter_fv(target: TextureBindingTarget,
+                            pname: GLenum,
+                            params: *const GLfloat)
+{
+    gl_fns::TexParameterfv(target.gl_enum(), pname, params);
+    dbg_gl_sanity_check! {
+        in "glTexParameterfv"();
+        GLError::InvalidEnum => "`target` or `pname` is not an accepted defined value",
+        _ => "Unknown error"
+    }
+}
+
 unsafe fn _tex_image_2d<T: ImageTargetType>(target: T,
                                             level: u32,
                                             internal_format: TexelFormat,
@@ -390,7 +1448,10 @@ unsafe fn _tex_image_2d<T: ImageTargetType>(target: T,
                                             format: ImageFormat,
                                             image_ptr: *const u8) {
     debug_assert!(internal_format == format.texel_format);
-    gl::TexImage2D(target.gl_enum(),
+    debug_assert!(format.is_valid(),
+                  "{:?} can't be used with {:?}",
+                  format.texel_type, format.texel_format);
+    gl_fns::TexImage2D(target.gl_enum(),
                    level as GLint,
                    internal_format.gl_enum() as GLint,
                    width as GLint,
@@ -400,6 +1461,12 @@ unsafe fn _tex_image_2d<T: ImageTargetType>(target: T,
                    format.texel_type.gl_enum(),
                    image_ptr as *const GLvoid);
     dbg_gl_sanity_check! {
+        in "glTexImage2D"(target = target.gl_enum(),
+                          level = level,
+                          width = width,
+                          height = height,
+                          format = format.texel_format,
+                          texel_type = format.texel_type);
         GLError::InvalidEnum => "`target`, `format`, or `type` is not an accepted value",
         GLError::InvalidValue => "`target`, `level`, `internalformat`, `width`, `height`, or `border` is an invalid value",
         GLError::InvalidOperation => "`format` conflicts with either `internalformat` or `type`",
@@ -407,6 +1474,306 @@ unsafe fn _tex_image_2d<T: ImageTargetType>(target: T,
     }
 }
 
+// Returns `true` if the current context is OpenGL ES 2, which only supports
+// unsized internal formats for textures
+unsafe fn _is_es2() -> bool {
+    _current_extensions().version().map_or(false, |version| {
+        version.es && !version.is_at_least(3, 0)
+    })
+}
+
+fn _is_depth_format(format: RenderbufferFormat) -> bool {
+    match format {
+        RenderbufferFormat::DepthComponent16
+        | RenderbufferFormat::DepthComponent24
+        | RenderbufferFormat::DepthComponent32F => true,
+        _ => false
+    }
+}
+
+// Get the pixel format and type to allocate a texture with a sized format,
+// or `None` if the format can't be used for textures. No image data is
+// uploaded, but the pair still has to be compatible with the format.
+fn _sized_format_params(format: RenderbufferFormat)
+    -> Option<(GLenum, GLenum)>
+{
+    let params = match format {
+        RenderbufferFormat::RGBA4 => (gl::RGBA, gl::UNSIGNED_SHORT_4_4_4_4),
+        RenderbufferFormat::RGB565 => (gl::RGB, gl::UNSIGNED_SHORT_5_6_5),
+        RenderbufferFormat::RGB5A1 => (gl::RGBA, gl::UNSIGNED_SHORT_5_5_5_1),
+        RenderbufferFormat::RGB8 => (gl::RGB, gl::UNSIGNED_BYTE),
+        RenderbufferFormat::RGBA8
+        | RenderbufferFormat::SRGB8Alpha8 => (gl::RGBA, gl::UNSIGNED_BYTE),
+        RenderbufferFormat::R8 => (gl::RED, gl::UNSIGNED_BYTE),
+        RenderbufferFormat::RG8 => (gl::RG, gl::UNSIGNED_BYTE),
+        RenderbufferFormat::RGB10A2 => {
+            (gl::RGBA, gl::UNSIGNED_INT_2_10_10_10_REV)
+        },
+        RenderbufferFormat::R16F => (gl::RED, gl::HALF_FLOAT),
+        RenderbufferFormat::RG16F => (gl::RG, gl::HALF_FLOAT),
+        RenderbufferFormat::RGBA16F => (gl::RGBA, gl::HALF_FLOAT),
+        RenderbufferFormat::R32F => (gl::RED, gl::FLOAT),
+        RenderbufferFormat::RG32F => (gl::RG, gl::FLOAT),
+        RenderbufferFormat::RGBA32F => (gl::RGBA, gl::FLOAT),
+        RenderbufferFormat::R11FG11FB10F => {
+            (gl::RGB, gl::UNSIGNED_INT_10F_11F_11F_REV)
+        },
+        RenderbufferFormat::DepthComponent16 => {
+            (gl::DEPTH_COMPONENT, gl::UNSIGNED_SHORT)
+        },
+        RenderbufferFormat::DepthComponent24 => {
+            (gl::DEPTH_COMPONENT, gl::UNSIGNED_INT)
+        },
+        RenderbufferFormat::DepthComponent32F => {
+            (gl::DEPTH_COMPONENT, gl::FLOAT)
+        },
+        RenderbufferFormat::Depth24Stencil8 => {
+            (gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8)
+        },
+        RenderbufferFormat::Depth32FStencil8 => {
+            (gl::DEPTH_STENCIL, gl::FLOAT_32_UNSIGNED_INT_24_8_REV)
+        },
+        RenderbufferFormat::StencilIndex8 => { return None; }
+    };
+    Some(params)
+}
+
+// Returns `true` if a texture with a sized format can be built with the
+// current context. On OpenGL ES 2, only the formats that have an unsized
+// equivalent are supported.
+fn _is_sized_format_supported(format: RenderbufferFormat,
+                              extensions: &Extensions)
+    -> bool
+{
+    match format {
+        RenderbufferFormat::DepthComponent16
+        | RenderbufferFormat::DepthComponent24 => {
+            extensions.has_depth_texture()
+        },
+        RenderbufferFormat::DepthComponent32F
+        | RenderbufferFormat::Depth32FStencil8 => {
+            extensions.has_depth_texture()
+                && extensions.has_depth_buffer_float()
+        },
+        RenderbufferFormat::Depth24Stencil8 => {
+            extensions.has_depth_texture()
+                && extensions.has_packed_depth_stencil()
+        },
+        RenderbufferFormat::StencilIndex8 => false,
+        _ if unsafe { _is_es2() } => {
+            match format {
+                RenderbufferFormat::RGBA4
+                | RenderbufferFormat::RGB565
+                | RenderbufferFormat::RGB5A1
+                | RenderbufferFormat::RGB8
+                | RenderbufferFormat::RGBA8 => true,
+                RenderbufferFormat::R8
+                | RenderbufferFormat::RG8 => extensions.has_texture_rg(),
+                _ => false
+            }
+        },
+        _ => { format.is_supported(extensions) }
+    }
+}
+
+unsafe fn _copy_image_sub_data(src: GLuint,
+                               dst: GLuint,
+                               region: &TextureCopyRegion)
+{
+    let rect = region.src_rect;
+    gl_fns::CopyImageSubData(src,
+                             gl::TEXTURE_2D,
+                             region.src_level as GLint,
+                             rect.x as GLint,
+                             rect.y as GLint,
+                             0,
+                             dst,
+                             gl::TEXTURE_2D,
+                             region.dst_level as GLint,
+                             region.dst_x as GLint,
+                             region.dst_y as GLint,
+                             0,
+                             rect.width as GLsizei,
+                             rect.height as GLsizei,
+                             1);
+    dbg_gl_sanity_check! {
+        in "glCopyImageSubData"(src = src, dst = dst, region = region);
+        GLError::InvalidValue => "A level doesn't exist, or a region is not within its texture",
+        GLError::InvalidOperation => "A texture is not complete, or the textures' formats aren't compatible",
+        _ => "Unknown error"
+    }
+}
+
+// Copy regions between textures without `glCopyImageSubData`, by attaching
+// the source texture to a temporary framebuffer and copying from it with
+// `glCopyTexSubImage2D`. The framebuffer and texture bindings are restored
+// afterwards, so the context's bindings are left as they were.
+unsafe fn _copy_tex_sub_image_2d(src: GLuint,
+                                 dst: GLuint,
+                                 regions: &[TextureCopyRegion])
+{
+    let mut prev_framebuffer: GLint = 0;
+    let mut prev_texture: GLint = 0;
+    gl_fns::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut prev_framebuffer);
+    gl_fns::GetIntegerv(gl::TEXTURE_BINDING_2D, &mut prev_texture);
+
+    let mut framebuffer: GLuint = 0;
+    gl_fns::GenFramebuffers(1, &mut framebuffer as *mut GLuint);
+    gl_fns::BindFramebuffer(gl::FRAMEBUFFER, framebuffer);
+    gl_fns::BindTexture(gl::TEXTURE_2D, dst);
+
+    for region in regions {
+        let rect = region.src_rect;
+        gl_fns::FramebufferTexture2D(gl::FRAMEBUFFER,
+                                     gl::COLOR_ATTACHMENT0,
+                                     gl::TEXTURE_2D,
+                                     src,
+                                     region.src_level as GLint);
+        gl_fns::CopyTexSubImage2D(gl::TEXTURE_2D,
+                                  region.dst_level as GLint,
+                                  region.dst_x as GLint,
+                                  region.dst_y as GLint,
+                                  rect.x as GLint,
+                                  rect.y as GLint,
+                                  rect.width as GLsizei,
+                                  rect.height as GLsizei);
+        dbg_gl_sanity_check! {
+            in "glCopyTexSubImage2D"(src = src, dst = dst, region = region);
+            GLError::InvalidValue => "A level doesn't exist, or a region is not within its texture",
+            GLError::InvalidOperation => "The destination texture's format isn't compatible with the source texture's format",
+            GLError::InvalidFramebufferOperation => "The source texture's format is not color-renderable",
+            _ => "Unknown error"
+        }
+    }
+
+    gl_fns::BindTexture(gl::TEXTURE_2D, prev_texture as GLuint);
+    gl_fns::BindFramebuffer(gl::FRAMEBUFFER, prev_framebuffer as GLuint);
+    gl_fns::DeleteFramebuffers(1, &framebuffer as *const GLuint);
+}
+
+// Panic if an image has fewer bytes than its size, row pitch, and format
+// require, since OpenGL would read past the end of the image's data
+fn _check_image_len<I: ?Sized + Image2d>(img: &I) {
+    let format = img.format();
+    let len = if img.width() == 0 || img.height() == 0 {
+        0
+    }
+    else {
+        (img.height() - 1) * img.row_pitch()
+            + img.width() * format.bytes_per_texel()
+    };
+    assert!(img.texel_bytes().len() >= len,
+            "Image data is {} bytes, but a {}x{} image with format {:?} and row pitch {} needs at least {} bytes",
+            img.texel_bytes().len(), img.width(), img.height(), format,
+            img.row_pitch(), len);
+}
+
+// Copy the rows of a flipped image in reverse order (and without any row
+// padding), so that they're in the order OpenGL expects
+fn _flip_rows<I: ?Sized + Image2d>(img: &I) -> Vec<u8> {
+    let row_len = img.width() * img.format().bytes_per_texel();
+    let bytes = img.texel_bytes();
+
+    let mut flipped = Vec::with_capacity(row_len * img.height());
+    for row in (0..img.height()).rev() {
+        let start = row * img.row_pitch();
+        flipped.extend_from_slice(&bytes[start..(start + row_len)]);
+    }
+    flipped
+}
+
+// Get the number of bytes between rows that OpenGL uses when reading image
+// data of a given width (rows are padded to `GL_UNPACK_ALIGNMENT`)
+unsafe fn _unpack_row_stride(width: usize, format: ImageFormat) -> usize {
+    let mut alignment: GLint = 0;
+    gl_fns::GetIntegerv(gl::UNPACK_ALIGNMENT, &mut alignment);
+    let alignment = if alignment > 0 { alignment as usize } else { 1 };
+
+    let row_len = width * format.bytes_per_texel();
+    (row_len + alignment - 1) / alignment * alignment
+}
+
+unsafe fn _tex_sub_image_2d<I: ?Sized + Image2d>(target: GLenum,
+                                                 level: u32,
+                                                 x: u32,
+                                                 y: u32,
+                                                 img: &I)
+{
+    let format = img.format();
+    let bytes_per_texel = format.bytes_per_texel();
+    let (width, height) = (img.width(), img.height());
+    let row_pitch = img.row_pitch();
+    let bytes = img.texel_bytes().as_ptr();
+
+    let stride = _unpack_row_stride(width, format);
+    if height <= 1 || row_pitch == stride {
+        _tex_sub_image_2d_raw(target, level, x, y, width, height, format, bytes);
+        return;
+    }
+
+    // Padded rows can be uploaded at once by setting the row length (in
+    // texels), as long as the padding still lines up with the alignment
+    let row_length = row_pitch / bytes_per_texel;
+    let row_length_fits = row_pitch % bytes_per_texel == 0
+        && _unpack_row_stride(row_length, format) == row_pitch;
+    if row_length_fits && _current_extensions().has_unpack_row_length() {
+        gl_fns::PixelStorei(gl::UNPACK_ROW_LENGTH, row_length as GLint);
+        _tex_sub_image_2d_raw(target, level, x, y, width, height, format, bytes);
+        gl_fns::PixelStorei(gl::UNPACK_ROW_LENGTH, 0);
+    }
+    else {
+        // Otherwise, each row is uploaded separately, which doesn't
+        // depend on the row length or alignment
+        for row in 0..height {
+            let row_bytes = bytes.offset((row * row_pitch) as isize);
+            _tex_sub_image_2d_raw(target,
+                                  level,
+                                  x,
+                                  y + row as u32,
+                                  width,
+                                  1,
+                                  format,
+                                  row_bytes);
+        }
+    }
+}
+
+unsafe fn _tex_sub_image_2d_raw(target: GLenum,
+                                level: u32,
+                                x: u32,
+                                y: u32,
+                                width: usize,
+                                height: usize,
+                                format: ImageFormat,
+                                image_ptr: *const u8) {
+    debug_assert!(format.is_valid(),
+                  "{:?} can't be used with {:?}",
+                  format.texel_type, format.texel_format);
+    gl_fns::TexSubImage2D(target,
+                          level as GLint,
+                          x as GLint,
+                          y as GLint,
+                          width as GLsizei,
+                          height as GLsizei,
+                          format.texel_format.gl_enum(),
+                          format.texel_type.gl_enum(),
+                          image_ptr as *const GLvoid);
+    dbg_gl_sanity_check! {
+        in "glTexSubImage2D"(target = target,
+                             level = level,
+                             x = x,
+                             y = y,
+                             width = width,
+                             height = height,
+                             format = format.texel_format,
+                             texel_type = format.texel_type);
+        GLError::InvalidEnum => "`target`, `format`, or `type` is not an accepted value",
+        GLError::InvalidValue => "`level` is invalid, or the region is not within the texture image",
+        GLError::InvalidOperation => "The texture image was never defined, or `format` and `type` don't match the texture image's format",
+        _ => "Unknown error"
+    }
+}
+
 /// Represents a texture that has been bound to a texture unit.
 pub trait TextureBinding {
     /// The type of texture that this binding represents.
@@ -447,10 +1814,80 @@ impl<'a> TextureBinding for TextureCubeMapBinding<'a> {
 }
 
 
+/// Represents a texture that has been bound to the `GL_TEXTURE_EXTERNAL_OES`
+/// binding target of a texture unit.
+pub struct TextureExternalBinding<'a> {
+    _phantom_ref: PhantomData<&'a mut TextureExternal>,
+    _phantom_ptr: PhantomData<*mut ()>
+}
+
+impl<'a> TextureBinding for TextureExternalBinding<'a> {
+    type TextureType = TxExternal;
+
+    fn target(&self) -> TextureBindingTarget {
+        TxExternal::target()
+    }
+}
+
+
+/// Represents a texture that has been bound to the
+/// `GL_TEXTURE_2D_MULTISAMPLE` binding target of a texture unit.
+pub struct Texture2dMultisampleBinding<'a> {
+    _phantom_ref: PhantomData<&'a mut Texture2dMultisample>,
+    _phantom_ptr: PhantomData<*mut ()>
+}
+
+impl<'a> TextureBinding for Texture2dMultisampleBinding<'a> {
+    type TextureType = Tx2dMultisample;
+
+    fn target(&self) -> TextureBindingTarget {
+        Tx2dMultisample::target()
+    }
+}
+
+
+/// Represents a texture that has been bound to the `GL_TEXTURE_2D_ARRAY`
+/// binding target of a texture unit.
+pub struct Texture2dArrayBinding<'a> {
+    _phantom_ref: PhantomData<&'a mut Texture2dArray>,
+    _phantom_ptr: PhantomData<*mut ()>
+}
+
+impl<'a> TextureBinding for Texture2dArrayBinding<'a> {
+    type TextureType = Tx2dArray;
+
+    fn target(&self) -> TextureBindingTarget {
+        Tx2dArray::target()
+    }
+}
+
+
+/// Represents a texture that has been bound to the `GL_TEXTURE_3D` binding
+/// target of a texture unit.
+pub struct Texture3dBinding<'a> {
+    _phantom_ref: PhantomData<&'a mut Texture3d>,
+    _phantom_ptr: PhantomData<*mut ()>
+}
+
+impl<'a> TextureBinding for Texture3dBinding<'a> {
+    type TextureType = Tx3d;
+
+    fn target(&self) -> TextureBindingTarget {
+        Tx3d::target()
+    }
+}
+
+
+unsafe fn _bind_texture(target: TextureBindingTarget, id: GLuint) {
+    if !bind_cache::_bind(target.gl_enum(), id) {
+        return;
+    }
 
-unsafe fn _bind_texture<T: TextureType>(texture: &mut Texture<T>) {
-    gl::BindTexture(T::target().gl_enum(), texture.id());
+    gl_fns::BindTexture(target.gl_enum(), id);
+    frame_stats::_texture_bind();
+    stats::_bind(target.gl_enum(), id);
     dbg_gl_error! {
+        in "glBindTexture"();
         GLError::InvalidEnum => "`target` is not one of the allowed values",
         GLError::InvalidOperation => "`texture` was created with a target that doesn't match `target`",
         _ => "Unknown error"
@@ -482,13 +1919,21 @@ impl Texture2dBinder {
         -> Texture2dBinding<'a>
     {
         unsafe {
-            _bind_texture(texture);
+            _bind_texture(TextureBindingTarget::Texture2d, texture.id());
         }
         Texture2dBinding {
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         }
     }
+
+    /// Unbind the texture bound to the `GL_TEXTURE_2D` target of the
+    /// active texture unit (by binding 0).
+    pub fn unbind(&mut self) {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture2d, 0);
+        }
+    }
 }
 
 /// The OpenGL texture unit state that represents the `GL_TEXTURE_CUBE_MAP`
@@ -516,11 +1961,187 @@ impl TextureCubeMapBinder {
         -> TextureCubeMapBinding<'a>
     {
         unsafe {
-            _bind_texture(texture);
+            _bind_texture(TextureBindingTarget::TextureCubeMap, texture.id());
         }
         TextureCubeMapBinding {
             _phantom_ref: PhantomData,
             _phantom_ptr: PhantomData
         }
     }
+
+    /// Unbind the texture bound to the `GL_TEXTURE_CUBE_MAP` target of the
+    /// active texture unit (by binding 0).
+    pub fn unbind(&mut self) {
+        unsafe {
+            _bind_texture(TextureBindingTarget::TextureCubeMap, 0);
+        }
+    }
+}
+
+/// The OpenGL texture unit state that represents the
+/// `GL_TEXTURE_EXTERNAL_OES` target.
+pub struct TextureExternalBinder {
+    _phantom: PhantomData<*mut ()>
+}
+
+impl TextureExternalBinder {
+    /// Get the current `GL_TEXTURE_EXTERNAL_OES` binder.
+    ///
+    /// # Safety
+    /// The same rules apply to this method as the
+    /// [`ContextOf::current_context()`]
+    /// (../struct.ContextOf.html#method.current_context) method.
+    pub unsafe fn current() -> Self {
+        TextureExternalBinder {
+            _phantom: PhantomData
+        }
+    }
+
+    /// Bind a texture to the `GL_TEXTURE_EXTERNAL_OES` target,
+    /// returning a binding.
+    pub fn bind<'a>(&mut self, texture: &'a mut TextureExternal)
+        -> TextureExternalBinding<'a>
+    {
+        unsafe {
+            _bind_texture(TextureBindingTarget::TextureExternal, texture.id());
+        }
+        TextureExternalBinding {
+            _phantom_ref: PhantomData,
+            _phantom_ptr: PhantomData
+        }
+    }
+
+    /// Unbind the texture bound to the `GL_TEXTURE_EXTERNAL_OES` target of
+    /// the active texture unit (by binding 0).
+    pub fn unbind(&mut self) {
+        unsafe {
+            _bind_texture(TextureBindingTarget::TextureExternal, 0);
+        }
+    }
+}
+
+/// The OpenGL texture unit state that represents the
+/// `GL_TEXTURE_2D_MULTISAMPLE` target.
+pub struct Texture2dMultisampleBinder {
+    _phantom: PhantomData<*mut ()>
+}
+
+impl Texture2dMultisampleBinder {
+    /// Get the current `GL_TEXTURE_2D_MULTISAMPLE` binder.
+    ///
+    /// # Safety
+    /// The same rules apply to this method as the
+    /// [`ContextOf::current_context()`]
+    /// (../struct.ContextOf.html#method.current_context) method.
+    pub unsafe fn current() -> Self {
+        Texture2dMultisampleBinder {
+            _phantom: PhantomData
+        }
+    }
+
+    /// Bind a texture to the `GL_TEXTURE_2D_MULTISAMPLE` target,
+    /// returning a binding.
+    pub fn bind<'a>(&mut self, texture: &'a mut Texture2dMultisample)
+        -> Texture2dMultisampleBinding<'a>
+    {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture2dMultisample,
+                          texture.id());
+        }
+        Texture2dMultisampleBinding {
+            _phantom_ref: PhantomData,
+            _phantom_ptr: PhantomData
+        }
+    }
+
+    /// Unbind the texture bound to the `GL_TEXTURE_2D_MULTISAMPLE` target
+    /// of the active texture unit (by binding 0).
+    pub fn unbind(&mut self) {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture2dMultisample, 0);
+        }
+    }
+}
+
+/// The OpenGL texture unit state that represents the `GL_TEXTURE_2D_ARRAY`
+/// target.
+pub struct Texture2dArrayBinder {
+    _phantom: PhantomData<*mut ()>
+}
+
+impl Texture2dArrayBinder {
+    /// Get the current `GL_TEXTURE_2D_ARRAY` binder.
+    ///
+    /// # Safety
+    /// The same rules apply to this method as the
+    /// [`ContextOf::current_context()`]
+    /// (../struct.ContextOf.html#method.current_context) method.
+    pub unsafe fn current() -> Self {
+        Texture2dArrayBinder {
+            _phantom: PhantomData
+        }
+    }
+
+    /// Bind a texture to the `GL_TEXTURE_2D_ARRAY` target, returning
+    /// a binding.
+    pub fn bind<'a>(&mut self, texture: &'a mut Texture2dArray)
+        -> Texture2dArrayBinding<'a>
+    {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture2dArray, texture.id());
+        }
+        Texture2dArrayBinding {
+            _phantom_ref: PhantomData,
+            _phantom_ptr: PhantomData
+        }
+    }
+
+    /// Unbind the texture bound to the `GL_TEXTURE_2D_ARRAY` target of the
+    /// active texture unit (by binding 0).
+    pub fn unbind(&mut self) {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture2dArray, 0);
+        }
+    }
+}
+
+/// The OpenGL texture unit state that represents the `GL_TEXTURE_3D`
+/// target.
+pub struct Texture3dBinder {
+    _phantom: PhantomData<*mut ()>
+}
+
+impl Texture3dBinder {
+    /// Get the current `GL_TEXTURE_3D` binder.
+    ///
+    /// # Safety
+    /// The same rules apply to this method as the
+    /// [`ContextOf::current_context()`]
+    /// (../struct.ContextOf.html#method.current_context) method.
+    pub unsafe fn current() -> Self {
+        Texture3dBinder {
+            _phantom: PhantomData
+        }
+    }
+
+    /// Bind a texture to the `GL_TEXTURE_3D` target, returning a binding.
+    pub fn bind<'a>(&mut self, texture: &'a mut Texture3d)
+        -> Texture3dBinding<'a>
+    {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture3d, texture.id());
+        }
+        Texture3dBinding {
+            _phantom_ref: PhantomData,
+            _phantom_ptr: PhantomData
+        }
+    }
+
+    /// Unbind the texture bound to the `GL_TEXTURE_3D` target of the active
+    /// texture unit (by binding 0).
+    pub fn unbind(&mut self) {
+        unsafe {
+            _bind_texture(TextureBindingTarget::Texture3d, 0);
+        }
+    }
 }
@@ -3,21 +3,38 @@
 use std::borrow::BorrowMut;
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
 use context::{AContext, ContextOf,
-              Texture2dBinder, TextureCubeMapBinder,
-              Texture2dBinding, TextureCubeMapBinding};
-use texture::{Texture2d, TextureCubeMap};
+              Texture2dBinder, TextureCubeMapBinder, TextureExternalBinder,
+              Texture2dMultisampleBinder, Texture2dArrayBinder,
+              Texture3dBinder,
+              Texture2dBinding, TextureCubeMapBinding,
+              TextureExternalBinding, Texture2dMultisampleBinding,
+              Texture2dArrayBinding, Texture3dBinding};
+use texture::{Texture2d, TextureCubeMap, TextureExternal,
+              Texture2dMultisample, Texture2dArray, Texture3d};
 use uniform_data::{UniformDatum, UniformDatumType, UniformPrimitiveType};
 use types::GLError;
+use super::{_current_active_unit, _set_current_active_unit};
 use to_ref::{ToRef, ToMut};
+use stats;
+use frame_stats;
 
 unsafe fn _active_texture(idx: u32) {
-    gl::ActiveTexture(gl::TEXTURE0 + (idx as GLenum));
+    if _current_active_unit() == Some(idx) {
+        return;
+    }
+
+    gl_fns::ActiveTexture(gl::TEXTURE0 + (idx as GLenum));
+    frame_stats::_state_change();
+    stats::_active_texture(idx);
     dbg_gl_error! {
-        GLError::InvalidEnum => "`texture` is out of bounds (expected to be GL_TEXTUREi, 0 <= i < GL_MAX_TEXTURE_IMAGE_UNITS)",
+        in "glActiveTexture"(unit = idx);
+        GLError::InvalidEnum => "`texture` is out of bounds (expected to be GL_TEXTUREi, 0 <= i < GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS; see `gl.limits().max_combined_texture_image_units`)",
         _ => "Unknown error"
     }
+    _set_current_active_unit(idx);
 }
 
 /// A trait that represents a 'texture unit', which is a piece of OpenGL state
@@ -36,1325 +53,522 @@ pub trait TextureUnit {
     }
 }
 
-// TODO: Use a macro, or const generic parameters:
-// https://github.com/rust-lang/rfcs/issues/273
-// https://github.com/rust-lang/rfcs/issues/1038
-/// The 0th texture unit.
-pub struct TextureUnit0 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 1st texture unit.
-pub struct TextureUnit1 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 2nd texture unit.
-pub struct TextureUnit2 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 3rd texture unit.
-pub struct TextureUnit3 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 4th texture unit.
-pub struct TextureUnit4 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 5th texture unit.
-pub struct TextureUnit5 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 6th texture unit.
-pub struct TextureUnit6 {
-    _phantom: PhantomData<*mut ()>
-}
-
-/// The 7th texture unit.
-pub struct TextureUnit7 {
-    _phantom: PhantomData<*mut ()>
-}
-
-
-impl TextureUnit for TextureUnit0 { fn idx(&self) -> u32 { 0 } }
-impl TextureUnit for TextureUnit1 { fn idx(&self) -> u32 { 1 } }
-impl TextureUnit for TextureUnit2 { fn idx(&self) -> u32 { 2 } }
-impl TextureUnit for TextureUnit3 { fn idx(&self) -> u32 { 3 } }
-impl TextureUnit for TextureUnit4 { fn idx(&self) -> u32 { 4 } }
-impl TextureUnit for TextureUnit5 { fn idx(&self) -> u32 { 5 } }
-impl TextureUnit for TextureUnit6 { fn idx(&self) -> u32 { 6 } }
-impl TextureUnit for TextureUnit7 { fn idx(&self) -> u32 { 7 } }
-
-// NOTE: Ensure the number of each texture unit matches its index in the tuple
-// TODO: Use macros + integer-level types to refactor this
-/// This type holds all of the OpenGL texture units. Each type parameter
-/// is the current type of a texture unit. See the [`ContextOf`]
-/// (../struct.ContextOf.html) docs for more details.
-pub struct TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>(pub T0,
-                                                          pub T1,
-                                                          pub T2,
-                                                          pub T3,
-                                                          pub T4,
-                                                          pub T5,
-                                                          pub T6,
-                                                          pub T7);
-
-/// A part of the OpenGL context that has all free texture units.
-pub type TextureUnits = TextureUnitsOf<TextureUnit0,
-                                       TextureUnit1,
-                                       TextureUnit2,
-                                       TextureUnit3,
-                                       TextureUnit4,
-                                       TextureUnit5,
-                                       TextureUnit6,
-                                       TextureUnit7>;
-
-impl<T0, T1, T2, T3, T4, T5, T6, T7> TextureUnitsOf<T0,
-                                                    T1,
-                                                    T2,
-                                                    T3,
-                                                    T4,
-                                                    T5,
-                                                    T6,
-                                                    T7>
-{
-    /// Get the current texture units.
-    ///
-    /// # Safety
-    /// The same rules apply to this method as the
-    /// [`ContextOf::current_context()` method]
-    /// (../struct.ContextOf.html#method.current_context).
-    pub unsafe fn current() -> TextureUnits {
-        TextureUnitsOf(TextureUnit0 { _phantom: PhantomData },
-                       TextureUnit1 { _phantom: PhantomData },
-                       TextureUnit2 { _phantom: PhantomData },
-                       TextureUnit3 { _phantom: PhantomData },
-                       TextureUnit4 { _phantom: PhantomData },
-                       TextureUnit5 { _phantom: PhantomData },
-                       TextureUnit6 { _phantom: PhantomData },
-                       TextureUnit7 { _phantom: PhantomData })
-    }
-
-    fn borrowed_mut<'a, B0, B1, B2, B3, B4, B5, B6, B7>(&'a mut self)
-        -> TextureUnitsOf<&'a mut B0,
-                          &'a mut B1,
-                          &'a mut B2,
-                          &'a mut B3,
-                          &'a mut B4,
-                          &'a mut B5,
-                          &'a mut B6,
-                          &'a mut B7>
-        where T0: BorrowMut<B0>,
-              T1: BorrowMut<B1>,
-              T2: BorrowMut<B2>,
-              T3: BorrowMut<B3>,
-              T4: BorrowMut<B4>,
-              T5: BorrowMut<B5>,
-              T6: BorrowMut<B6>,
-              T7: BorrowMut<B7>
-    {
-        TextureUnitsOf(self.0.borrow_mut(),
-                       self.1.borrow_mut(),
-                       self.2.borrow_mut(),
-                       self.3.borrow_mut(),
-                       self.4.borrow_mut(),
-                       self.5.borrow_mut(),
-                       self.6.borrow_mut(),
-                       self.7.borrow_mut())
-    }
-
-    /// Replace the 0th texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_0<N0>(self, new_unit: N0)
-        -> (T0, TextureUnitsOf<N0, T1, T2, T3, T4, T5, T6, T7>)
-    {
-        (
-            self.0,
-            TextureUnitsOf(new_unit,
-                           self.1,
-                           self.2,
-                           self.3,
-                           self.4,
-                           self.5,
-                           self.6,
-                           self.7)
-        )
-    }
-
-    /// Replace the 1st texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_1<N1>(self, new_unit: N1)
-        -> (T1, TextureUnitsOf<T0, N1, T2, T3, T4, T5, T6, T7>)
-    {
-        (
-            self.1,
-            TextureUnitsOf(self.0,
-                           new_unit,
-                           self.2,
-                           self.3,
-                           self.4,
-                           self.5,
-                           self.6,
-                           self.7)
-        )
-    }
-
-    /// Replace the 2nd texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_2<N2>(self, new_unit: N2)
-        -> (T2, TextureUnitsOf<T0, T1, N2, T3, T4, T5, T6, T7>)
-    {
-        (
-            self.2,
-            TextureUnitsOf(self.0,
-                           self.1,
-                           new_unit,
-                           self.3,
-                           self.4,
-                           self.5,
-                           self.6,
-                           self.7)
-        )
-    }
-
-    /// Replace the 3rd texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_3<N3>(self, new_unit: N3)
-        -> (T3, TextureUnitsOf<T0, T1, T2, N3, T4, T5, T6, T7>)
-    {
-        (
-            self.3,
-            TextureUnitsOf(self.0,
-                           self.1,
-                           self.2,
-                           new_unit,
-                           self.4,
-                           self.5,
-                           self.6,
-                           self.7)
-        )
-    }
-
-    /// Replace the 4th texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_4<N4>(self, new_unit: N4)
-        -> (T4, TextureUnitsOf<T0, T1, T2, T3, N4, T5, T6, T7>)
-    {
-        (
-            self.4,
-            TextureUnitsOf(self.0,
-                           self.1,
-                           self.2,
-                           self.3,
-                           new_unit,
-                           self.5,
-                           self.6,
-                           self.7)
-        )
-    }
-
-    /// Replace the 5th texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_5<N5>(self, new_unit: N5)
-        -> (T5, TextureUnitsOf<T0, T1, T2, T3, T4, N5, T6, T7>)
-    {
-        (
-            self.5,
-            TextureUnitsOf(self.0,
-                           self.1,
-                           self.2,
-                           self.3,
-                           self.4,
-                           new_unit,
-                           self.6,
-                           self.7)
-        )
-    }
-
-    /// Replace the 6th texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_6<N6>(self, new_unit: N6)
-        -> (T6, TextureUnitsOf<T0, T1, T2, T3, T4, T5, N6, T7>)
-    {
-        (
-            self.6,
-            TextureUnitsOf(self.0,
-                           self.1,
-                           self.2,
-                           self.3,
-                           self.4,
-                           self.5,
-                           new_unit,
-                           self.7)
-        )
-    }
-
-    /// Replace the 7th texture unit context with a new value, returning the
-    /// old value and a new set of texture units
-    pub fn swap_7<N7>(self, new_unit: N7)
-        -> (T7, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, N7>)
-    {
-        (
-            self.7,
-            TextureUnitsOf(self.0,
-                           self.1,
-                           self.2,
-                           self.3,
-                           self.4,
-                           self.5,
-                           self.6,
-                           new_unit)
-        )
-    }
-
-    /// Make the `idx`th texture unit the active one, returning a new binding.
-    ///
-    /// # Safety
-    /// For convenience, this function takes `self` by shared reference, not
-    /// mutable reference. Thus, this function can be to create multiple
-    /// live bindings. Special care must be taken to ensure that two bindings
-    /// do not conflict; since there can only ever be one active texture unit
-    /// in OpenGL, using this function may result in unexpected or undefined
-    /// behavior, and it should only be used as a fallback when glitter's
-    /// safe texture unit interface is not sufficient.
-    pub unsafe fn active_nth(&self, idx: u32) -> TextureUnitBinding {
-        _active_texture(idx);
-        TextureUnitBinding::current_at_idx(idx)
-    }
-}
-
-impl<'a, T0, T1, T2, T3, T4, T5, T6, T7> ToRef<'a>
-    for TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>
-    where T0: 'a + ToRef<'a>,
-          T1: 'a + ToRef<'a>,
-          T2: 'a + ToRef<'a>,
-          T3: 'a + ToRef<'a>,
-          T4: 'a + ToRef<'a>,
-          T5: 'a + ToRef<'a>,
-          T6: 'a + ToRef<'a>,
-          T7: 'a + ToRef<'a>
-{
-    type Ref = TextureUnitsOf<T0::Ref,
-                              T1::Ref,
-                              T2::Ref,
-                              T3::Ref,
-                              T4::Ref,
-                              T5::Ref,
-                              T6::Ref,
-                              T7::Ref>;
-
-    fn to_ref(&'a self) -> Self::Ref {
-        TextureUnitsOf(
-            self.0.to_ref(),
-            self.1.to_ref(),
-            self.2.to_ref(),
-            self.3.to_ref(),
-            self.4.to_ref(),
-            self.5.to_ref(),
-            self.6.to_ref(),
-            self.7.to_ref()
-        )
-    }
-}
-
-impl<'a, T0, T1, T2, T3, T4, T5, T6, T7> ToMut<'a>
-    for TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>
-    where T0: 'a + ToMut<'a>,
-          T1: 'a + ToMut<'a>,
-          T2: 'a + ToMut<'a>,
-          T3: 'a + ToMut<'a>,
-          T4: 'a + ToMut<'a>,
-          T5: 'a + ToMut<'a>,
-          T6: 'a + ToMut<'a>,
-          T7: 'a + ToMut<'a>
-{
-    type Mut = TextureUnitsOf<T0::Mut,
-                              T1::Mut,
-                              T2::Mut,
-                              T3::Mut,
-                              T4::Mut,
-                              T5::Mut,
-                              T6::Mut,
-                              T7::Mut>;
-
-    fn to_mut(&'a mut self) -> Self::Mut {
-        TextureUnitsOf(
-            self.0.to_mut(),
-            self.1.to_mut(),
-            self.2.to_mut(),
-            self.3.to_mut(),
-            self.4.to_mut(),
-            self.5.to_mut(),
-            self.6.to_mut(),
-            self.7.to_mut()
-        )
-    }
-}
-
-
-
-/// An OpenGL context with the 0th texture unit free.
-pub trait TextureUnit0Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit0>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 0th texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_0(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 0th texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_0(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_0();
-        (unit.borrow_mut().active(), rest)
-    }
-}
-
-/// An OpenGL context with the 1st texture unit free.
-pub trait TextureUnit1Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit1>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 1st texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_1(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 1st texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_1(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_1();
-        (unit.borrow_mut().active(), rest)
-    }
+// Expands to `()`, to replace each type parameter in a list (since
+// a repetition has to use at least one variable)
+macro_rules! unit_ty {
+    ($T:ident) => { () }
 }
 
-/// An OpenGL context with the 2nd texture unit free.
-pub trait TextureUnit2Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit2>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 2nd texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_2(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 2nd texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_2(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_2();
-        (unit.borrow_mut().active(), rest)
-    }
-}
-
-/// An OpenGL context with the 3rd texture unit free.
-pub trait TextureUnit3Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit3>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 3rd texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_3(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 3rd texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_3(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_3();
-        (unit.borrow_mut().active(), rest)
-    }
-}
-
-/// An OpenGL context with the 4th texture unit free.
-pub trait TextureUnit4Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit4>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 4th texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_4(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 4th texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_4(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_4();
-        (unit.borrow_mut().active(), rest)
-    }
-}
-
-/// An OpenGL context with the 5th texture unit free.
-pub trait TextureUnit5Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit5>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 5th texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_5(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 5th texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_5(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_5();
-        (unit.borrow_mut().active(), rest)
-    }
-}
-
-/// An OpenGL context with the 6th texture unit free.
-pub trait TextureUnit6Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit6>;
-
+/// An OpenGL context with at least one free texture unit, for code that
+/// needs to make a texture unit active, but doesn't care which one (such as
+/// [`gl.build_texture_2d`]
+/// (../texture_context/trait.ContextTextureBuilderExt.html#method.build_texture_2d)).
+///
+/// The first free texture unit is used. Texture units that have been
+/// taken (where the type in [`TextureUnitsOf`](struct.TextureUnitsOf.html)
+/// is `()`) are skipped, so this composes with code that has already made
+/// some texture units active.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use] extern crate glitter;
+/// use glitter::prelude::*;
+///
+/// # fn main() {
+/// let gl = unsafe { glitter::Context::current_context() };
+/// let (gl_tex_unit_0, gl) = gl.active_texture_0();
+///
+/// // Uses the 1st texture unit, since the 0th is taken
+/// let (gl_tex_unit, gl) = gl.active_some_texture();
+/// assert_eq!(gl_tex_unit.gl_idx(), 1);
+/// # }
+/// ```
+pub trait SomeTextureUnitContext: AContext {
     /// The OpenGL context that will be returned after making the
     /// texture unit active.
     type Rest: AContext;
 
-    /// Split the 6th texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_6(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 6th texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_6(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_6();
-        (unit.borrow_mut().active(), rest)
-    }
+    /// Make the first free texture unit active, returning a binding and the
+    /// remaining context.
+    fn active_some_texture(self) -> (TextureUnitBinding, Self::Rest);
 }
 
-/// An OpenGL context with the 7th texture unit free.
-pub trait TextureUnit7Context: AContext {
-    /// The type of unit this context contains.
-    type Unit: BorrowMut<TextureUnit7>;
-
-    /// The OpenGL context that will be returned after making the
-    /// texture unit active.
-    type Rest: AContext;
-
-    /// Split the 7th texture unit from the context, returning the unit
-    /// and the remaining context.
-    fn split_tex_unit_7(self) -> (Self::Unit, Self::Rest);
-
-    /// Make the 7th texture unit active, returning a binding and the
-    /// remaining context
-    fn active_texture_7(self) -> (TextureUnitBinding, Self::Rest)
-        where Self: Sized
-    {
-        let (mut unit, rest) = self.split_tex_unit_7();
-        (unit.borrow_mut().active(), rest)
-    }
-}
-
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit0Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T0: BorrowMut<TextureUnit0>
-{
-    type Unit = T0;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<(),
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>;
-
-    fn split_tex_unit_0(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_0(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
-
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit0Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T0: BorrowMut<TextureUnit0>
-{
-    type Unit = &'a mut TextureUnit0;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<(),
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_0(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_0(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
-
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit0Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T0: BorrowMut<TextureUnit0>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit0;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<(),
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_0(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_0(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
-
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit1Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T1: BorrowMut<TextureUnit1>
-{
-    type Unit = T1;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     (),
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>;
-
-    fn split_tex_unit_1(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_1(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+// Generates the texture unit types. Each entry is one texture unit, with
+// the name of its type parameter in `TextureUnitsOf`, a name to use for a
+// borrowed type parameter, and its index, followed by the names and docs
+// for the unit type, its context trait, and its methods.
+//
+// Each texture unit is still a distinct type: const generics would let this
+// be a single `TextureUnit<N>`, but they need Rust 1.51, and glitter still
+// supports Rust 1.26. Adding more units only requires adding more entries
+// below.
+macro_rules! texture_units {
+    ($(
+        ($T:ident, $B:ident, $idx:tt)
+        $Unit:ident [$(#[$unit_attr:meta])*]
+        $Ctx:ident [$(#[$ctx_attr:meta])*]
+        $split:ident, $active:ident, $swap:ident [$(#[$swap_attr:meta])*];
+    )+) => {
+        $(
+            $(#[$unit_attr])*
+            pub struct $Unit {
+                _phantom: PhantomData<*mut ()>
+            }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit1Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T1: BorrowMut<TextureUnit1>
-{
-    type Unit = &'a mut TextureUnit1;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         (),
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_1(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_1(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
+            impl TextureUnit for $Unit { fn idx(&self) -> u32 { $idx } }
+        )+
+
+        /// This type holds all of the OpenGL texture units. Each type
+        /// parameter is the current type of a texture unit. See the
+        /// [`ContextOf`](../struct.ContextOf.html) docs for more details.
+        ///
+        /// glitter always has the same number of texture units, but the
+        /// number of texture units that an implementation actually supports
+        /// is given by [`gl.limits().max_combined_texture_image_units`]
+        /// (../../limits/struct.ContextLimits.html#structfield.max_combined_texture_image_units)
+        /// (which is at least 8 for OpenGL ES 2, and at least 32 for OpenGL
+        /// ES 3). Making a texture unit past that limit active will fail.
+        pub struct TextureUnitsOf<$($T),+>($(pub $T),+);
+
+        /// A part of the OpenGL context that has all free texture units.
+        pub type TextureUnits = TextureUnitsOf<$($Unit),+>;
+
+        impl<$($T),+> TextureUnitsOf<$($T),+> {
+            /// Get the current texture units.
+            ///
+            /// # Safety
+            /// The same rules apply to this method as the
+            /// [`ContextOf::current_context()` method]
+            /// (../struct.ContextOf.html#method.current_context).
+            pub unsafe fn current() -> TextureUnits {
+                TextureUnitsOf($($Unit { _phantom: PhantomData }),+)
+            }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit1Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T1: BorrowMut<TextureUnit1>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit1;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         (),
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_1(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_1(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+            fn borrowed_mut<'a, $($B),+>(&'a mut self)
+                -> TextureUnitsOf<$(&'a mut $B),+>
+                where $($T: BorrowMut<$B>),+
+            {
+                TextureUnitsOf($(self.$idx.borrow_mut()),+)
+            }
 
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit2Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T2: BorrowMut<TextureUnit2>
-{
-    type Unit = T2;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     (),
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>;
-
-    fn split_tex_unit_2(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_2(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+            /// Make the `idx`th texture unit the active one, returning a new
+            /// binding.
+            ///
+            /// # Safety
+            /// For convenience, this function takes `self` by shared
+            /// reference, not mutable reference. Thus, this function can be to
+            /// create multiple live bindings. Special care must be taken to
+            /// ensure that two bindings do not conflict; since there can only
+            /// ever be one active texture unit in OpenGL, using this function
+            /// may result in unexpected or undefined behavior, and it should
+            /// only be used as a fallback when glitter's safe texture unit
+            /// interface is not sufficient.
+            pub unsafe fn active_nth(&self, idx: u32) -> TextureUnitBinding {
+                _active_texture(idx);
+                TextureUnitBinding::current_at_idx(idx)
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit2Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T2: BorrowMut<TextureUnit2>
-{
-    type Unit = &'a mut TextureUnit2;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         (),
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_2(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_2(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
+        impl<'a, $($T),+> ToRef<'a> for TextureUnitsOf<$($T),+>
+            where $($T: 'a + ToRef<'a>),+
+        {
+            type Ref = TextureUnitsOf<$($T::Ref),+>;
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit2Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T2: BorrowMut<TextureUnit2>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit2;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         (),
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_2(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_2(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+            fn to_ref(&'a self) -> Self::Ref {
+                TextureUnitsOf($(self.$idx.to_ref()),+)
+            }
+        }
 
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit3Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T3: BorrowMut<TextureUnit3>
-{
-    type Unit = T3;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     (),
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>;
-
-    fn split_tex_unit_3(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_3(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        impl<'a, $($T),+> ToMut<'a> for TextureUnitsOf<$($T),+>
+            where $($T: 'a + ToMut<'a>),+
+        {
+            type Mut = TextureUnitsOf<$($T::Mut),+>;
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit3Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T3: BorrowMut<TextureUnit3>
-{
-    type Unit = &'a mut TextureUnit3;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         (),
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_3(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_3(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
+            fn to_mut(&'a mut self) -> Self::Mut {
+                TextureUnitsOf($(self.$idx.to_mut()),+)
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit3Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T3: BorrowMut<TextureUnit3>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit3;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         (),
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_3(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_3(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        texture_units! {
+            @each [] $(
+                ($T, $B, $idx)
+                $Unit [$(#[$unit_attr])*]
+                $Ctx [$(#[$ctx_attr])*]
+                $split, $active, $swap [$(#[$swap_attr])*];
+            )+
+        }
+    };
+
+    // Generate the items for the first unit in the list, where `$bT` are
+    // the type parameters of the units before it, and `$aT` are the type
+    // parameters of the units after it
+    (
+        @each [$(($bT:ident, $bidx:tt))*]
+        ($T:ident, $B:ident, $idx:tt)
+        $Unit:ident [$(#[$unit_attr:meta])*]
+        $Ctx:ident [$(#[$ctx_attr:meta])*]
+        $split:ident, $active:ident, $swap:ident [$(#[$swap_attr:meta])*];
+        $(
+            ($aT:ident, $aB:ident, $aidx:tt)
+            $aUnit:ident [$(#[$a_unit_attr:meta])*]
+            $aCtx:ident [$(#[$a_ctx_attr:meta])*]
+            $a_split:ident, $a_active:ident, $a_swap:ident [$(#[$a_swap_attr:meta])*];
+        )*
+    ) => {
+        impl<$($bT,)* $T $(, $aT)*> TextureUnitsOf<$($bT,)* $T $(, $aT)*> {
+            $(#[$swap_attr])*
+            pub fn $swap<N>(self, new_unit: N)
+                -> ($T, TextureUnitsOf<$($bT,)* N $(, $aT)*>)
+            {
+                (
+                    self.$idx,
+                    TextureUnitsOf($(self.$bidx,)* new_unit $(, self.$aidx)*)
+                )
+            }
+        }
 
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit4Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T4: BorrowMut<TextureUnit4>
-{
-    type Unit = T4;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     (),
-                                                     T5,
-                                                     T6,
-                                                     T7>>;
-
-    fn split_tex_unit_4(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_4(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        $(#[$ctx_attr])*
+        pub trait $Ctx: AContext {
+            /// The type of unit this context contains.
+            type Unit: BorrowMut<$Unit>;
+
+            /// The OpenGL context that will be returned after making the
+            /// texture unit active.
+            type Rest: AContext;
+
+            /// Split the texture unit from the context, returning the unit
+            /// and the remaining context.
+            fn $split(self) -> (Self::Unit, Self::Rest);
+
+            /// Make the texture unit active, returning a binding and the
+            /// remaining context
+            fn $active(self) -> (TextureUnitBinding, Self::Rest)
+                where Self: Sized
+            {
+                let (mut unit, rest) = self.$split();
+                (unit.borrow_mut().active(), rest)
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit4Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T4: BorrowMut<TextureUnit4>
-{
-    type Unit = &'a mut TextureUnit4;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         (),
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_4(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_4(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
+        impl<B, F, P, R, $($bT,)* $T $(, $aT)*> $Ctx
+            for ContextOf<B, F, P, R, TextureUnitsOf<$($bT,)* $T $(, $aT)*>>
+            where $T: BorrowMut<$Unit>
+        {
+            type Unit = $T;
+            type Rest = ContextOf<B, F, P, R,
+                                  TextureUnitsOf<$($bT,)* () $(, $aT)*>>;
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit4Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T4: BorrowMut<TextureUnit4>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit4;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         (),
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_4(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_4(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+            fn $split(self) -> (Self::Unit, Self::Rest) {
+                let (tex_units, gl) = self.swap_tex_units(());
+                let (unit, rest_tex_units) = tex_units.$swap(());
+                let ((), gl) = gl.swap_tex_units(rest_tex_units);
 
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit5Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T5: BorrowMut<TextureUnit5>
-{
-    type Unit = T5;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     (),
-                                                     T6,
-                                                     T7>>;
-
-    fn split_tex_unit_5(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_5(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+                (unit, gl)
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit5Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T5: BorrowMut<TextureUnit5>
-{
-    type Unit = &'a mut TextureUnit5;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         (),
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_5(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_5(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
+        impl<'a, B, F, P, R, $($bT,)* $T $(, $aT)*> $Ctx
+            for &'a mut ContextOf<B, F, P, R,
+                                  TextureUnitsOf<$($bT,)* $T $(, $aT)*>>
+            where $T: BorrowMut<$Unit>
+        {
+            type Unit = &'a mut $Unit;
+            type Rest = ContextOf<&'a mut B,
+                                  &'a mut F,
+                                  &'a mut P,
+                                  &'a mut R,
+                                  TextureUnitsOf<$(&'a mut $bT,)*
+                                                 ()
+                                                 $(, &'a mut $aT)*>>;
+
+            fn $split(self) -> (Self::Unit, Self::Rest) {
+                let gl = self.borrowed_mut();
+                let (tex_units, gl) = gl.swap_tex_units(());
+                let tex_units = tex_units.borrowed_mut();
+                let (tex_unit, rest_tex_units) = tex_units.$swap(());
+                let ((), gl) = gl.swap_tex_units(rest_tex_units);
+
+                (tex_unit, gl)
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit5Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T5: BorrowMut<TextureUnit5>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit5;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         (),
-                                         &'a mut T6,
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_5(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_5(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        impl<'a, B, F, P, R, $($bT,)* $T $(, $aT)*> $Ctx
+            for &'a mut ContextOf<B, F, P, R,
+                                  &'a mut TextureUnitsOf<$($bT,)*
+                                                         $T
+                                                         $(, $aT)*>>
+            where $T: BorrowMut<$Unit>,
+                  B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
+        {
+            type Unit = &'a mut $Unit;
+            type Rest = ContextOf<B::Mut,
+                                  F::Mut,
+                                  P::Mut,
+                                  R::Mut,
+                                  TextureUnitsOf<$(&'a mut $bT,)*
+                                                 ()
+                                                 $(, &'a mut $aT)*>>;
+
+            fn $split(self) -> (Self::Unit, Self::Rest) {
+                let gl = self.to_mut();
+                let (tex_units, gl): (&mut TextureUnitsOf<$($bT,)* $T $(, $aT)*>, _) =
+                    gl.swap_tex_units(());
+                let tex_units = tex_units.borrowed_mut();
+                let (unit, rest_tex_units) = tex_units.$swap(());
+                let ((), gl) = gl.swap_tex_units(rest_tex_units);
+
+                (unit, gl)
+            }
+        }
 
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit6Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T6: BorrowMut<TextureUnit6>
-{
-    type Unit = T6;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     (),
-                                                     T7>>;
-
-    fn split_tex_unit_6(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_6(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        // A context only uses this unit as "some" unit if all of the units
+        // before it have been taken
+        impl<B, F, P, R, $T $(, $aT)*> SomeTextureUnitContext
+            for ContextOf<B, F, P, R,
+                          TextureUnitsOf<$(unit_ty!($bT),)* $T $(, $aT)*>>
+            where $T: BorrowMut<$Unit>
+        {
+            type Rest = <Self as $Ctx>::Rest;
+
+            fn active_some_texture(self) -> (TextureUnitBinding, Self::Rest) {
+                self.$active()
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit6Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T6: BorrowMut<TextureUnit6>
-{
-    type Unit = &'a mut TextureUnit6;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         (),
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_6(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_6(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
-}
+        impl<'a, B, F, P, R, $T $(, $aT)*> SomeTextureUnitContext
+            for &'a mut ContextOf<B, F, P, R,
+                                  TextureUnitsOf<$(unit_ty!($bT),)*
+                                                 $T
+                                                 $(, $aT)*>>
+            where $T: BorrowMut<$Unit>
+        {
+            type Rest = <Self as $Ctx>::Rest;
+
+            fn active_some_texture(self) -> (TextureUnitBinding, Self::Rest) {
+                self.$active()
+            }
+        }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit6Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T6: BorrowMut<TextureUnit6>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit6;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         (),
-                                         &'a mut T7>>;
-
-    fn split_tex_unit_6(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_6(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        impl<'a, B, F, P, R, $T $(, $aT)*> SomeTextureUnitContext
+            for &'a mut ContextOf<B, F, P, R,
+                                  &'a mut TextureUnitsOf<$(unit_ty!($bT),)*
+                                                         $T
+                                                         $(, $aT)*>>
+            where $T: BorrowMut<$Unit>,
+                  B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
+        {
+            type Rest = <Self as $Ctx>::Rest;
+
+            fn active_some_texture(self) -> (TextureUnitBinding, Self::Rest) {
+                self.$active()
+            }
+        }
 
-impl<B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit7Context
-    for ContextOf<B, F, P, R, TextureUnitsOf<T0, T1, T2, T3, T4, T5, T6, T7>>
-    where T7: BorrowMut<TextureUnit7>
-{
-    type Unit = T7;
-    type Rest = ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     ()>>;
-
-    fn split_tex_unit_7(self) -> (Self::Unit, Self::Rest) {
-        let (tex_units, gl) = self.swap_tex_units(());
-        let (unit, rest_tex_units) = tex_units.swap_7(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
-}
+        texture_units! {
+            @each [$(($bT, $bidx))* ($T, $idx)] $(
+                ($aT, $aB, $aidx)
+                $aUnit [$(#[$a_unit_attr])*]
+                $aCtx [$(#[$a_ctx_attr])*]
+                $a_split, $a_active, $a_swap [$(#[$a_swap_attr])*];
+            )*
+        }
+    };
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit7Context
-    for &'a mut ContextOf<B, F, P, R, TextureUnitsOf<T0,
-                                                     T1,
-                                                     T2,
-                                                     T3,
-                                                     T4,
-                                                     T5,
-                                                     T6,
-                                                     T7>>
-    where T7: BorrowMut<TextureUnit7>
-{
-    type Unit = &'a mut TextureUnit7;
-    type Rest = ContextOf<&'a mut B,
-                          &'a mut F,
-                          &'a mut P,
-                          &'a mut R,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         ()>>;
-
-    fn split_tex_unit_7(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.borrowed_mut();
-        let (tex_units, gl) = gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (tex_unit, rest_tex_units) = tex_units.swap_7(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (tex_unit, gl)
-    }
+    (@each [$(($bT:ident, $bidx:tt))*]) => { };
 }
 
-impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit7Context
-    for &'a mut ContextOf<B, F, P, R, &'a mut TextureUnitsOf<T0,
-                                                             T1,
-                                                             T2,
-                                                             T3,
-                                                             T4,
-                                                             T5,
-                                                             T6,
-                                                             T7>>
-    where T7: BorrowMut<TextureUnit7>,
-          B: ToMut<'a>, F: ToMut<'a>, P: ToMut<'a>, R: ToMut<'a>
-{
-    type Unit = &'a mut TextureUnit7;
-    type Rest = ContextOf<B::Mut,
-                          F::Mut,
-                          P::Mut,
-                          R::Mut,
-                          TextureUnitsOf<&'a mut T0,
-                                         &'a mut T1,
-                                         &'a mut T2,
-                                         &'a mut T3,
-                                         &'a mut T4,
-                                         &'a mut T5,
-                                         &'a mut T6,
-                                         ()>>;
-
-    fn split_tex_unit_7(self) -> (Self::Unit, Self::Rest) {
-        let gl = self.to_mut();
-        let (tex_units, gl): (&mut TextureUnitsOf<_, _, _, _, _, _, _, _>, _) =
-            gl.swap_tex_units(());
-        let tex_units = tex_units.borrowed_mut();
-        let (unit, rest_tex_units) = tex_units.swap_7(());
-        let ((), gl) = gl.swap_tex_units(rest_tex_units);
-
-        (unit, gl)
-    }
+texture_units! {
+    (T0, B0, 0)
+    TextureUnit0 [
+        /// The 0th texture unit.
+    ]
+    TextureUnit0Context [
+        /// An OpenGL context with the 0th texture unit free.
+    ]
+    split_tex_unit_0, active_texture_0, swap_0 [
+        /// Replace the 0th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T1, B1, 1)
+    TextureUnit1 [
+        /// The 1st texture unit.
+    ]
+    TextureUnit1Context [
+        /// An OpenGL context with the 1st texture unit free.
+    ]
+    split_tex_unit_1, active_texture_1, swap_1 [
+        /// Replace the 1st texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T2, B2, 2)
+    TextureUnit2 [
+        /// The 2nd texture unit.
+    ]
+    TextureUnit2Context [
+        /// An OpenGL context with the 2nd texture unit free.
+    ]
+    split_tex_unit_2, active_texture_2, swap_2 [
+        /// Replace the 2nd texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T3, B3, 3)
+    TextureUnit3 [
+        /// The 3rd texture unit.
+    ]
+    TextureUnit3Context [
+        /// An OpenGL context with the 3rd texture unit free.
+    ]
+    split_tex_unit_3, active_texture_3, swap_3 [
+        /// Replace the 3rd texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T4, B4, 4)
+    TextureUnit4 [
+        /// The 4th texture unit.
+    ]
+    TextureUnit4Context [
+        /// An OpenGL context with the 4th texture unit free.
+    ]
+    split_tex_unit_4, active_texture_4, swap_4 [
+        /// Replace the 4th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T5, B5, 5)
+    TextureUnit5 [
+        /// The 5th texture unit.
+    ]
+    TextureUnit5Context [
+        /// An OpenGL context with the 5th texture unit free.
+    ]
+    split_tex_unit_5, active_texture_5, swap_5 [
+        /// Replace the 5th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T6, B6, 6)
+    TextureUnit6 [
+        /// The 6th texture unit.
+    ]
+    TextureUnit6Context [
+        /// An OpenGL context with the 6th texture unit free.
+    ]
+    split_tex_unit_6, active_texture_6, swap_6 [
+        /// Replace the 6th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T7, B7, 7)
+    TextureUnit7 [
+        /// The 7th texture unit.
+    ]
+    TextureUnit7Context [
+        /// An OpenGL context with the 7th texture unit free.
+    ]
+    split_tex_unit_7, active_texture_7, swap_7 [
+        /// Replace the 7th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T8, B8, 8)
+    TextureUnit8 [
+        /// The 8th texture unit.
+    ]
+    TextureUnit8Context [
+        /// An OpenGL context with the 8th texture unit free.
+    ]
+    split_tex_unit_8, active_texture_8, swap_8 [
+        /// Replace the 8th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T9, B9, 9)
+    TextureUnit9 [
+        /// The 9th texture unit.
+    ]
+    TextureUnit9Context [
+        /// An OpenGL context with the 9th texture unit free.
+    ]
+    split_tex_unit_9, active_texture_9, swap_9 [
+        /// Replace the 9th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T10, B10, 10)
+    TextureUnit10 [
+        /// The 10th texture unit.
+    ]
+    TextureUnit10Context [
+        /// An OpenGL context with the 10th texture unit free.
+    ]
+    split_tex_unit_10, active_texture_10, swap_10 [
+        /// Replace the 10th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T11, B11, 11)
+    TextureUnit11 [
+        /// The 11th texture unit.
+    ]
+    TextureUnit11Context [
+        /// An OpenGL context with the 11th texture unit free.
+    ]
+    split_tex_unit_11, active_texture_11, swap_11 [
+        /// Replace the 11th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T12, B12, 12)
+    TextureUnit12 [
+        /// The 12th texture unit.
+    ]
+    TextureUnit12Context [
+        /// An OpenGL context with the 12th texture unit free.
+    ]
+    split_tex_unit_12, active_texture_12, swap_12 [
+        /// Replace the 12th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T13, B13, 13)
+    TextureUnit13 [
+        /// The 13th texture unit.
+    ]
+    TextureUnit13Context [
+        /// An OpenGL context with the 13th texture unit free.
+    ]
+    split_tex_unit_13, active_texture_13, swap_13 [
+        /// Replace the 13th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T14, B14, 14)
+    TextureUnit14 [
+        /// The 14th texture unit.
+    ]
+    TextureUnit14Context [
+        /// An OpenGL context with the 14th texture unit free.
+    ]
+    split_tex_unit_14, active_texture_14, swap_14 [
+        /// Replace the 14th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
+
+    (T15, B15, 15)
+    TextureUnit15 [
+        /// The 15th texture unit.
+    ]
+    TextureUnit15Context [
+        /// An OpenGL context with the 15th texture unit free.
+    ]
+    split_tex_unit_15, active_texture_15, swap_15 [
+        /// Replace the 15th texture unit context with a new value, returning
+        /// the old value and a new set of texture units
+    ];
 }
 
 
@@ -1362,23 +576,35 @@ impl<'a, B, F, P, R, T0, T1, T2, T3, T4, T5, T6, T7> TextureUnit7Context
 // TODO: Make `idx` a type-level integer parameter
 /// A texture unit that has been made active, and can have textures
 /// bound to it.
-pub struct TextureUnitBindingOf<T2, TC> {
+pub struct TextureUnitBindingOf<T2, TC, TE, TM, TA, T3> {
     idx: u32,
     texture_2d: T2,
     texture_cube_map: TC,
+    texture_external: TE,
+    texture_2d_multisample: TM,
+    texture_2d_array: TA,
+    texture_3d: T3,
     _phantom: PhantomData<*mut ()>
 }
 
 /// A fresh texture unit binding, that has all free texture bindings.
 pub type TextureUnitBinding = TextureUnitBindingOf<Texture2dBinder,
-                                                   TextureCubeMapBinder>;
+                                                   TextureCubeMapBinder,
+                                                   TextureExternalBinder,
+                                                   Texture2dMultisampleBinder,
+                                                   Texture2dArrayBinder,
+                                                   Texture3dBinder>;
 
-impl<T2, TC> TextureUnitBindingOf<T2, TC> {
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBindingOf<T2, TC, TE, TM, TA, T3> {
     unsafe fn current_at_idx(idx: u32) -> TextureUnitBinding {
         TextureUnitBinding {
             idx: idx,
             texture_2d: Texture2dBinder::current(),
             texture_cube_map: TextureCubeMapBinder::current(),
+            texture_external: TextureExternalBinder::current(),
+            texture_2d_multisample: Texture2dMultisampleBinder::current(),
+            texture_2d_array: Texture2dArrayBinder::current(),
+            texture_3d: Texture3dBinder::current(),
             _phantom: PhantomData
         }
     }
@@ -1395,39 +621,132 @@ impl<T2, TC> TextureUnitBindingOf<T2, TC> {
         TextureSampler { idx: self.idx as i32 }
     }
 
-    fn split_texture_2d(self) -> (T2, TextureUnitBindingOf<(), TC>) {
+    fn split_texture_2d(self)
+        -> (T2, TextureUnitBindingOf<(), TC, TE, TM, TA, T3>)
+    {
         (
             self.texture_2d,
             TextureUnitBindingOf {
                 idx: self.idx,
                 texture_2d: (),
                 texture_cube_map: self.texture_cube_map,
+                texture_external: self.texture_external,
+                texture_2d_multisample: self.texture_2d_multisample,
+                texture_2d_array: self.texture_2d_array,
+                texture_3d: self.texture_3d,
                 _phantom: PhantomData
             }
         )
     }
 
-    fn split_texture_cube_map(self) -> (TC, TextureUnitBindingOf<T2, ()>) {
+    fn split_texture_cube_map(self)
+        -> (TC, TextureUnitBindingOf<T2, (), TE, TM, TA, T3>)
+    {
         (
             self.texture_cube_map,
             TextureUnitBindingOf {
                 idx: self.idx,
                 texture_2d: self.texture_2d,
                 texture_cube_map: (),
+                texture_external: self.texture_external,
+                texture_2d_multisample: self.texture_2d_multisample,
+                texture_2d_array: self.texture_2d_array,
+                texture_3d: self.texture_3d,
+                _phantom: PhantomData
+            }
+        )
+    }
+
+    fn split_texture_external(self)
+        -> (TE, TextureUnitBindingOf<T2, TC, (), TM, TA, T3>)
+    {
+        (
+            self.texture_external,
+            TextureUnitBindingOf {
+                idx: self.idx,
+                texture_2d: self.texture_2d,
+                texture_cube_map: self.texture_cube_map,
+                texture_external: (),
+                texture_2d_multisample: self.texture_2d_multisample,
+                texture_2d_array: self.texture_2d_array,
+                texture_3d: self.texture_3d,
+                _phantom: PhantomData
+            }
+        )
+    }
+
+    fn split_texture_2d_multisample(self)
+        -> (TM, TextureUnitBindingOf<T2, TC, TE, (), TA, T3>)
+    {
+        (
+            self.texture_2d_multisample,
+            TextureUnitBindingOf {
+                idx: self.idx,
+                texture_2d: self.texture_2d,
+                texture_cube_map: self.texture_cube_map,
+                texture_external: self.texture_external,
+                texture_2d_multisample: (),
+                texture_2d_array: self.texture_2d_array,
+                texture_3d: self.texture_3d,
+                _phantom: PhantomData
+            }
+        )
+    }
+
+    fn split_texture_2d_array(self)
+        -> (TA, TextureUnitBindingOf<T2, TC, TE, TM, (), T3>)
+    {
+        (
+            self.texture_2d_array,
+            TextureUnitBindingOf {
+                idx: self.idx,
+                texture_2d: self.texture_2d,
+                texture_cube_map: self.texture_cube_map,
+                texture_external: self.texture_external,
+                texture_2d_multisample: self.texture_2d_multisample,
+                texture_2d_array: (),
+                texture_3d: self.texture_3d,
                 _phantom: PhantomData
             }
         )
     }
 
-    fn borrowed_mut<'a, B2, BC>(&'a mut self)
-        -> TextureUnitBindingOf<&'a mut B2, &'a mut BC>
+    fn split_texture_3d(self)
+        -> (T3, TextureUnitBindingOf<T2, TC, TE, TM, TA, ()>)
+    {
+        (
+            self.texture_3d,
+            TextureUnitBindingOf {
+                idx: self.idx,
+                texture_2d: self.texture_2d,
+                texture_cube_map: self.texture_cube_map,
+                texture_external: self.texture_external,
+                texture_2d_multisample: self.texture_2d_multisample,
+                texture_2d_array: self.texture_2d_array,
+                texture_3d: (),
+                _phantom: PhantomData
+            }
+        )
+    }
+
+    fn borrowed_mut<'a, B2, BC, BE, BM, BA, B3>(&'a mut self)
+        -> TextureUnitBindingOf<&'a mut B2, &'a mut BC, &'a mut BE, &'a mut BM,
+                                &'a mut BA, &'a mut B3>
         where T2: BorrowMut<B2>,
-              TC: BorrowMut<BC>
+              TC: BorrowMut<BC>,
+              TE: BorrowMut<BE>,
+              TM: BorrowMut<BM>,
+              TA: BorrowMut<BA>,
+              T3: BorrowMut<B3>
     {
         TextureUnitBindingOf {
             idx: self.idx,
             texture_2d: self.texture_2d.borrow_mut(),
             texture_cube_map: self.texture_cube_map.borrow_mut(),
+            texture_external: self.texture_external.borrow_mut(),
+            texture_2d_multisample: self.texture_2d_multisample.borrow_mut(),
+            texture_2d_array: self.texture_2d_array.borrow_mut(),
+            texture_3d: self.texture_3d.borrow_mut(),
             _phantom: PhantomData
         }
     }
@@ -1445,12 +764,14 @@ pub unsafe trait ATextureUnitBinding {
 
 }
 
-unsafe impl<T2, TC> ATextureUnitBinding for TextureUnitBindingOf<T2, TC> {
+unsafe impl<T2, TC, TE, TM, TA, T3> ATextureUnitBinding
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+{
 
 }
 
-unsafe impl<'a, T2, TC> ATextureUnitBinding
-    for &'a mut TextureUnitBindingOf<T2, TC>
+unsafe impl<'a, T2, TC, TE, TM, TA, T3> ATextureUnitBinding
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
 {
 
 }
@@ -1475,6 +796,16 @@ pub trait TextureUnitBinding2d: ATextureUnitBinding {
         let (mut binder, rest) = self.split_texture_2d();
         (binder.borrow_mut().bind(tex), rest)
     }
+
+    /// Unbind this texture unit's 2D texture (by binding 0), returning the
+    /// freed binder and the remaining texture unit.
+    fn unbind_texture_2d(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_2d();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
 /// A texture unit binding that has a free `GL_TEXTURE_CUBE_MAP` binding.
@@ -1497,25 +828,167 @@ pub trait TextureUnitBindingCubeMap: ATextureUnitBinding {
         let (mut binder, rest) = self.split_texture_cube_map();
         (binder.borrow_mut().bind(tex), rest)
     }
+
+    /// Unbind this texture unit's cubemap texture (by binding 0), returning
+    /// the freed binder and the remaining texture unit.
+    fn unbind_texture_cube_map(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_cube_map();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
 }
 
-impl<T2, TC> TextureUnitBinding2d for TextureUnitBindingOf<T2, TC>
+/// A texture unit binding that has a free `GL_TEXTURE_EXTERNAL_OES` binding.
+pub trait TextureUnitBindingExternal: ATextureUnitBinding {
+    /// The type of binder this texture unit contains.
+    type Binder: BorrowMut<TextureExternalBinder>;
+
+    /// The texture unit that will be returned after binding the texture.
+    type Rest: ATextureUnitBinding;
+
+    /// Split the texture unit into a binder and the remaining texture unit.
+    fn split_texture_external(self) -> (Self::Binder, Self::Rest);
+
+    /// Bind an external texture to this texture unit, returning a binding
+    /// and the remaining texture unit.
+    fn bind_texture_external<'a>(self, tex: &'a mut TextureExternal)
+        -> (TextureExternalBinding<'a>, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_external();
+        (binder.borrow_mut().bind(tex), rest)
+    }
+
+    /// Unbind this texture unit's external texture (by binding 0),
+    /// returning the freed binder and the remaining texture unit.
+    fn unbind_texture_external(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_external();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
+}
+
+/// A texture unit binding that has a free `GL_TEXTURE_2D_MULTISAMPLE`
+/// binding.
+pub trait TextureUnitBinding2dMultisample: ATextureUnitBinding {
+    /// The type of binder this texture unit contains.
+    type Binder: BorrowMut<Texture2dMultisampleBinder>;
+
+    /// The texture unit that will be returned after binding the texture.
+    type Rest: ATextureUnitBinding;
+
+    /// Split the texture unit into a binder and the remaining texture unit.
+    fn split_texture_2d_multisample(self) -> (Self::Binder, Self::Rest);
+
+    /// Bind a multisampled 2D texture to this texture unit, returning
+    /// a binding and the remaining texture unit.
+    fn bind_texture_2d_multisample<'a>(self,
+                                       tex: &'a mut Texture2dMultisample)
+        -> (Texture2dMultisampleBinding<'a>, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_2d_multisample();
+        (binder.borrow_mut().bind(tex), rest)
+    }
+
+    /// Unbind this texture unit's multisampled 2D texture (by binding 0),
+    /// returning the freed binder and the remaining texture unit.
+    fn unbind_texture_2d_multisample(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_2d_multisample();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
+}
+
+/// A texture unit binding that has a free `GL_TEXTURE_2D_ARRAY` binding.
+pub trait TextureUnitBinding2dArray: ATextureUnitBinding {
+    /// The type of binder this texture unit contains.
+    type Binder: BorrowMut<Texture2dArrayBinder>;
+
+    /// The texture unit that will be returned after binding the texture.
+    type Rest: ATextureUnitBinding;
+
+    /// Split the texture unit into a binder and the remaining texture unit.
+    fn split_texture_2d_array(self) -> (Self::Binder, Self::Rest);
+
+    /// Bind a 2D array texture to this texture unit, returning a binding
+    /// and the remaining texture unit.
+    fn bind_texture_2d_array<'a>(self, tex: &'a mut Texture2dArray)
+        -> (Texture2dArrayBinding<'a>, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_2d_array();
+        (binder.borrow_mut().bind(tex), rest)
+    }
+
+    /// Unbind this texture unit's 2D array texture (by binding 0),
+    /// returning the freed binder and the remaining texture unit.
+    fn unbind_texture_2d_array(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_2d_array();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
+}
+
+/// A texture unit binding that has a free `GL_TEXTURE_3D` binding.
+pub trait TextureUnitBinding3d: ATextureUnitBinding {
+    /// The type of binder this texture unit contains.
+    type Binder: BorrowMut<Texture3dBinder>;
+
+    /// The texture unit that will be returned after binding the texture.
+    type Rest: ATextureUnitBinding;
+
+    /// Split the texture unit into a binder and the remaining texture unit.
+    fn split_texture_3d(self) -> (Self::Binder, Self::Rest);
+
+    /// Bind a 3D texture to this texture unit, returning a binding and the
+    /// remaining texture unit.
+    fn bind_texture_3d<'a>(self, tex: &'a mut Texture3d)
+        -> (Texture3dBinding<'a>, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_3d();
+        (binder.borrow_mut().bind(tex), rest)
+    }
+
+    /// Unbind this texture unit's 3D texture (by binding 0), returning the
+    /// freed binder and the remaining texture unit.
+    fn unbind_texture_3d(self) -> (Self::Binder, Self::Rest)
+        where Self: Sized
+    {
+        let (mut binder, rest) = self.split_texture_3d();
+        binder.borrow_mut().unbind();
+        (binder, rest)
+    }
+}
+
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBinding2d
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
     where T2: BorrowMut<Texture2dBinder>
 {
     type Binder = T2;
-    type Rest = TextureUnitBindingOf<(), TC>;
+    type Rest = TextureUnitBindingOf<(), TC, TE, TM, TA, T3>;
 
     fn split_texture_2d(self) -> (Self::Binder, Self::Rest) {
         self.split_texture_2d()
     }
 }
 
-impl<'a, T2, TC> TextureUnitBinding2d
-    for &'a mut TextureUnitBindingOf<T2, TC>
+impl<'a, T2, TC, TE, TM, TA, T3> TextureUnitBinding2d
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
     where T2: BorrowMut<Texture2dBinder>
 {
     type Binder = &'a mut Texture2dBinder;
-    type Rest = TextureUnitBindingOf<(), &'a mut TC>;
+    type Rest = TextureUnitBindingOf<(), &'a mut TC, &'a mut TE, &'a mut TM,
+                                     &'a mut TA, &'a mut T3>;
 
     fn split_texture_2d(self) -> (Self::Binder, Self::Rest) {
         let gl_tex_unit = self.borrowed_mut();
@@ -1523,23 +996,25 @@ impl<'a, T2, TC> TextureUnitBinding2d
     }
 }
 
-impl<T2, TC> TextureUnitBindingCubeMap for TextureUnitBindingOf<T2, TC>
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBindingCubeMap
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
     where TC: BorrowMut<TextureCubeMapBinder>
 {
     type Binder = TC;
-    type Rest = TextureUnitBindingOf<T2, ()>;
+    type Rest = TextureUnitBindingOf<T2, (), TE, TM, TA, T3>;
 
     fn split_texture_cube_map(self) -> (Self::Binder, Self::Rest) {
         self.split_texture_cube_map()
     }
 }
 
-impl<'a, T2, TC> TextureUnitBindingCubeMap
-    for &'a mut TextureUnitBindingOf<T2, TC>
+impl<'a, T2, TC, TE, TM, TA, T3> TextureUnitBindingCubeMap
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
     where TC: BorrowMut<TextureCubeMapBinder>
 {
     type Binder = &'a mut TextureCubeMapBinder;
-    type Rest = TextureUnitBindingOf<&'a mut T2, ()>;
+    type Rest = TextureUnitBindingOf<&'a mut T2, (), &'a mut TE, &'a mut TM,
+                                     &'a mut TA, &'a mut T3>;
 
     fn split_texture_cube_map(self) -> (Self::Binder, Self::Rest) {
         let gl_tex_unit = self.borrowed_mut();
@@ -1547,6 +1022,110 @@ impl<'a, T2, TC> TextureUnitBindingCubeMap
     }
 }
 
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBindingExternal
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where TE: BorrowMut<TextureExternalBinder>
+{
+    type Binder = TE;
+    type Rest = TextureUnitBindingOf<T2, TC, (), TM, TA, T3>;
+
+    fn split_texture_external(self) -> (Self::Binder, Self::Rest) {
+        self.split_texture_external()
+    }
+}
+
+impl<'a, T2, TC, TE, TM, TA, T3> TextureUnitBindingExternal
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where TE: BorrowMut<TextureExternalBinder>
+{
+    type Binder = &'a mut TextureExternalBinder;
+    type Rest = TextureUnitBindingOf<&'a mut T2, &'a mut TC, (), &'a mut TM,
+                                     &'a mut TA, &'a mut T3>;
+
+    fn split_texture_external(self) -> (Self::Binder, Self::Rest) {
+        let gl_tex_unit = self.borrowed_mut();
+        gl_tex_unit.split_texture_external()
+    }
+}
+
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBinding2dMultisample
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where TM: BorrowMut<Texture2dMultisampleBinder>
+{
+    type Binder = TM;
+    type Rest = TextureUnitBindingOf<T2, TC, TE, (), TA, T3>;
+
+    fn split_texture_2d_multisample(self) -> (Self::Binder, Self::Rest) {
+        self.split_texture_2d_multisample()
+    }
+}
+
+impl<'a, T2, TC, TE, TM, TA, T3> TextureUnitBinding2dMultisample
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where TM: BorrowMut<Texture2dMultisampleBinder>
+{
+    type Binder = &'a mut Texture2dMultisampleBinder;
+    type Rest = TextureUnitBindingOf<&'a mut T2, &'a mut TC, &'a mut TE, (),
+                                     &'a mut TA, &'a mut T3>;
+
+    fn split_texture_2d_multisample(self) -> (Self::Binder, Self::Rest) {
+        let gl_tex_unit = self.borrowed_mut();
+        gl_tex_unit.split_texture_2d_multisample()
+    }
+}
+
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBinding2dArray
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where TA: BorrowMut<Texture2dArrayBinder>
+{
+    type Binder = TA;
+    type Rest = TextureUnitBindingOf<T2, TC, TE, TM, (), T3>;
+
+    fn split_texture_2d_array(self) -> (Self::Binder, Self::Rest) {
+        self.split_texture_2d_array()
+    }
+}
+
+impl<'a, T2, TC, TE, TM, TA, T3> TextureUnitBinding2dArray
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where TA: BorrowMut<Texture2dArrayBinder>
+{
+    type Binder = &'a mut Texture2dArrayBinder;
+    type Rest = TextureUnitBindingOf<&'a mut T2, &'a mut TC, &'a mut TE,
+                                     &'a mut TM, (), &'a mut T3>;
+
+    fn split_texture_2d_array(self) -> (Self::Binder, Self::Rest) {
+        let gl_tex_unit = self.borrowed_mut();
+        gl_tex_unit.split_texture_2d_array()
+    }
+}
+
+impl<T2, TC, TE, TM, TA, T3> TextureUnitBinding3d
+    for TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where T3: BorrowMut<Texture3dBinder>
+{
+    type Binder = T3;
+    type Rest = TextureUnitBindingOf<T2, TC, TE, TM, TA, ()>;
+
+    fn split_texture_3d(self) -> (Self::Binder, Self::Rest) {
+        self.split_texture_3d()
+    }
+}
+
+impl<'a, T2, TC, TE, TM, TA, T3> TextureUnitBinding3d
+    for &'a mut TextureUnitBindingOf<T2, TC, TE, TM, TA, T3>
+    where T3: BorrowMut<Texture3dBinder>
+{
+    type Binder = &'a mut Texture3dBinder;
+    type Rest = TextureUnitBindingOf<&'a mut T2, &'a mut TC, &'a mut TE,
+                                     &'a mut TM, &'a mut TA, ()>;
+
+    fn split_texture_3d(self) -> (Self::Binder, Self::Rest) {
+        let gl_tex_unit = self.borrowed_mut();
+        gl_tex_unit.split_texture_3d()
+    }
+}
+
 /// A newtype wrapper representing a texture sampler, which can be
 /// used to set a uniform variable, using [`gl.set_uniform`]
 /// (../program_context/trait.ContextProgramExt.html#method.set_uniform).
@@ -1554,6 +1133,13 @@ impl<'a, T2, TC> TextureUnitBindingCubeMap
 #[derive(Debug, Clone, Copy)]
 pub struct TextureSampler { idx: i32 }
 
+impl TextureSampler {
+    /// Get the sampler for the `idx`th texture unit.
+    pub fn from_idx(idx: u32) -> Self {
+        TextureSampler { idx: idx as i32 }
+    }
+}
+
 unsafe impl UniformDatum for TextureSampler {
     fn uniform_datum_type() -> UniformDatumType {
         UniformDatumType::Vec1(UniformPrimitiveType::Int)
@@ -0,0 +1,992 @@
+//! Contains the types used for debugging OpenGL calls, such as receiving
+//! debug output from the OpenGL driver (using `KHR_debug`), and collecting
+//! errors with an [`ErrorScope`](struct.ErrorScope.html), or inspecting the
+//! driver's state with a [`GLStateSnapshot`](struct.GLStateSnapshot.html).
+//! Frame captures can also be annotated with debug groups and markers (see
+//! [`DebugGroup`](struct.DebugGroup.html)).
+
+use std::cell::RefCell;
+use std::error;
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::thread;
+use gl;
+use gl_fns;
+use gl::types::*;
+use context::{Context, BaseContext, ContextExt};
+use extensions::Extensions;
+use types::{Color, Viewport, Rect, Capability, CullFaceMode, WindingOrder,
+            GLError};
+
+gl_enum! {
+    /// The part of the system that generated a debug message.
+    pub gl_enum DebugSource {
+        /// The message was generated by a call to the OpenGL API.
+        pub const Api as DEBUG_SOURCE_API = gl::DEBUG_SOURCE_API,
+
+        /// The message was generated by the window system API.
+        pub const WindowSystem as DEBUG_SOURCE_WINDOW_SYSTEM =
+            gl::DEBUG_SOURCE_WINDOW_SYSTEM,
+
+        /// The message was generated by the shader compiler.
+        pub const ShaderCompiler as DEBUG_SOURCE_SHADER_COMPILER =
+            gl::DEBUG_SOURCE_SHADER_COMPILER,
+
+        /// The message was generated by a third-party tool (such as
+        /// a debugger).
+        pub const ThirdParty as DEBUG_SOURCE_THIRD_PARTY =
+            gl::DEBUG_SOURCE_THIRD_PARTY,
+
+        /// The message was inserted by the application.
+        pub const Application as DEBUG_SOURCE_APPLICATION =
+            gl::DEBUG_SOURCE_APPLICATION,
+
+        /// The message was generated by some other source.
+        pub const OtherSource as DEBUG_SOURCE_OTHER = gl::DEBUG_SOURCE_OTHER
+    }
+}
+
+gl_enum! {
+    /// The kind of event that a debug message describes.
+    pub gl_enum DebugType {
+        /// An OpenGL error was generated.
+        pub const Error as DEBUG_TYPE_ERROR = gl::DEBUG_TYPE_ERROR,
+
+        /// Deprecated functionality was used.
+        pub const DeprecatedBehavior as DEBUG_TYPE_DEPRECATED_BEHAVIOR =
+            gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR,
+
+        /// Functionality with undefined behavior was used.
+        pub const UndefinedBehavior as DEBUG_TYPE_UNDEFINED_BEHAVIOR =
+            gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR,
+
+        /// Functionality that isn't portable between implementations
+        /// was used.
+        pub const Portability as DEBUG_TYPE_PORTABILITY =
+            gl::DEBUG_TYPE_PORTABILITY,
+
+        /// Functionality was used in a way that may hurt performance.
+        pub const Performance as DEBUG_TYPE_PERFORMANCE =
+            gl::DEBUG_TYPE_PERFORMANCE,
+
+        /// An annotation in the command stream.
+        pub const Marker as DEBUG_TYPE_MARKER = gl::DEBUG_TYPE_MARKER,
+
+        /// A debug group was pushed.
+        pub const PushGroup as DEBUG_TYPE_PUSH_GROUP =
+            gl::DEBUG_TYPE_PUSH_GROUP,
+
+        /// A debug group was popped.
+        pub const PopGroup as DEBUG_TYPE_POP_GROUP = gl::DEBUG_TYPE_POP_GROUP,
+
+        /// Some other kind of event.
+        pub const OtherType as DEBUG_TYPE_OTHER = gl::DEBUG_TYPE_OTHER
+    }
+}
+
+gl_enum! {
+    /// How important a debug message is.
+    pub gl_enum DebugSeverity {
+        /// An error or dangerous undefined behavior.
+        pub const High as DEBUG_SEVERITY_HIGH = gl::DEBUG_SEVERITY_HIGH,
+
+        /// A major performance warning, or use of deprecated functionality.
+        pub const Medium as DEBUG_SEVERITY_MEDIUM = gl::DEBUG_SEVERITY_MEDIUM,
+
+        /// A redundant state change, or other minor performance warning.
+        pub const Low as DEBUG_SEVERITY_LOW = gl::DEBUG_SEVERITY_LOW,
+
+        /// Anything that isn't an error or a performance warning.
+        pub const Notification as DEBUG_SEVERITY_NOTIFICATION =
+            gl::DEBUG_SEVERITY_NOTIFICATION
+    }
+}
+
+type DebugCallback = FnMut(DebugSource, DebugType, DebugSeverity, u32, &str);
+
+thread_local! {
+    // The callback set with `gl.debug_message_callback`. Debug output is
+    // always made synchronous, so the driver calls `_debug_callback` on the
+    // same thread that made the OpenGL call that generated the message.
+    static DEBUG_CALLBACK: RefCell<Option<Box<DebugCallback>>> =
+        RefCell::new(None)
+}
+
+extern "system" fn _debug_callback(source: GLenum,
+                                   gl_type: GLenum,
+                                   id: GLuint,
+                                   severity: GLenum,
+                                   length: GLsizei,
+                                   message: *const GLchar,
+                                   _user_param: *mut GLvoid)
+{
+    let source = DebugSource::from_gl(source)
+        .unwrap_or(DebugSource::OtherSource);
+    let gl_type = DebugType::from_gl(gl_type)
+        .unwrap_or(DebugType::OtherType);
+    let severity = DebugSeverity::from_gl(severity)
+        .unwrap_or(DebugSeverity::Notification);
+    let message = unsafe {
+        if message.is_null() {
+            String::new()
+        }
+        else if length < 0 {
+            CStr::from_ptr(message).to_string_lossy().into_owned()
+        }
+        else {
+            let bytes = slice::from_raw_parts(message as *const u8,
+                                              length as usize);
+            String::from_utf8_lossy(bytes).into_owned()
+        }
+    };
+
+    // Unwinding into the driver is undefined behavior, so any panic from
+    // the callback stops here (the panic message is still printed)
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        _call_debug_callback(source, gl_type, severity, id, &message);
+    }));
+}
+
+// Call the callback set with `gl.debug_message_callback`, returning `false`
+// if no callback is set
+fn _call_debug_callback(source: DebugSource,
+                        gl_type: DebugType,
+                        severity: DebugSeverity,
+                        id: u32,
+                        message: &str)
+    -> bool
+{
+    DEBUG_CALLBACK.with(|callback| {
+        // The callback can't be borrowed if it generated a debug message
+        // itself, so that message is dropped
+        match callback.try_borrow_mut() {
+            Ok(mut callback) => {
+                match *callback {
+                    Some(ref mut callback) => {
+                        callback(source, gl_type, severity, id, message);
+                        true
+                    },
+                    None => false
+                }
+            },
+            Err(_) => true
+        }
+    })
+}
+
+// The maximum number of errors to drain with `glGetError` at once. This
+// avoids looping forever if the context is lost, since some drivers then
+// report the same error indefinitely.
+const MAX_DRAINED_ERRORS: usize = 64;
+
+// Call `glGetError` until no errors are left, returning all of the errors
+fn _drain_errors() -> Vec<GLError> {
+    let mut errors = vec![];
+    while errors.len() < MAX_DRAINED_ERRORS {
+        match Context::get_error() {
+            Some(error) => { errors.push(error); },
+            None => { break; }
+        }
+    }
+    errors
+}
+
+/// A guard that collects the OpenGL errors generated within a named scope,
+/// created with [`gl.error_scope`]
+/// (trait.ContextDebugExt.html#method.error_scope). Any errors are reported
+/// when the scope ends, either by calling [`finish`]
+/// (struct.ErrorScope.html#method.finish), or by dropping the guard.
+///
+/// # Panics
+/// When a scope is dropped without calling `finish`, its errors are sent to
+/// the callback set with [`gl.debug_message_callback`]
+/// (trait.ContextDebugExt.html#method.debug_message_callback) (as an error
+/// from `DebugSource::Application`). If no callback is set, dropping the
+/// scope will panic, or print the errors to stderr if the thread is already
+/// panicking. Use `finish` to handle the errors without panicking.
+#[must_use]
+pub struct ErrorScope {
+    name: String,
+    finished: bool
+}
+
+impl ErrorScope {
+    /// Get the name of the scope.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// End the scope, returning an error that lists all of the OpenGL
+    /// errors generated within the scope (if there were any).
+    pub fn finish(mut self) -> Result<(), ErrorScopeError> {
+        self.finished = true;
+
+        let errors = _drain_errors();
+        if errors.is_empty() {
+            Ok(())
+        }
+        else {
+            Err(ErrorScopeError {
+                name: mem::replace(&mut self.name, String::new()),
+                errors: errors
+            })
+        }
+    }
+}
+
+impl Drop for ErrorScope {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        let errors = _drain_errors();
+        if !errors.is_empty() {
+            let error = ErrorScopeError {
+                name: mem::replace(&mut self.name, String::new()),
+                errors: errors
+            };
+            let reported = _call_debug_callback(DebugSource::Application,
+                                                DebugType::Error,
+                                                DebugSeverity::High,
+                                                0,
+                                                &error.to_string());
+            if !reported {
+                if thread::panicking() {
+                    eprintln!("{}", error);
+                }
+                else {
+                    panic!("{}", error);
+                }
+            }
+        }
+    }
+}
+
+/// The error returned by [`ErrorScope::finish`]
+/// (struct.ErrorScope.html#method.finish) when OpenGL errors were generated
+/// within the scope.
+#[derive(Debug)]
+pub struct ErrorScopeError {
+    /// The name of the scope.
+    pub name: String,
+
+    /// The errors generated within the scope, in the order they were
+    /// reported by `glGetError`.
+    pub errors: Vec<GLError>
+}
+
+impl fmt::Display for ErrorScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "OpenGL errors in scope `{}`: ", self.name));
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                try!(write!(f, ", "));
+            }
+            try!(write!(f, "{}", error));
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ErrorScopeError {
+    fn description(&self) -> &str {
+        "OpenGL errors were generated within an error scope"
+    }
+}
+
+// The OpenGL functions used to annotate frame captures. `KHR_debug` is
+// preferred, but `EXT_debug_marker` is more common on OpenGL ES 2 devices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DebugMarkerBackend {
+    Khr,
+    Ext,
+    None
+}
+
+fn _debug_marker_backend(extensions: &Extensions) -> DebugMarkerBackend {
+    if extensions.has_debug_groups() && gl_fns::PushDebugGroup::is_loaded() {
+        DebugMarkerBackend::Khr
+    }
+    else if extensions.has_debug_marker()
+        && gl_fns::PushGroupMarkerEXT::is_loaded()
+    {
+        DebugMarkerBackend::Ext
+    }
+    else {
+        DebugMarkerBackend::None
+    }
+}
+
+fn _push_debug_group(backend: DebugMarkerBackend, name: &str) {
+    let ptr = name.as_ptr() as *const GLchar;
+    let len = name.len() as GLsizei;
+    unsafe {
+        match backend {
+            DebugMarkerBackend::Khr => {
+                gl_fns::PushDebugGroup(gl::DEBUG_SOURCE_APPLICATION, 0, len, ptr);
+                dbg_gl_sanity_check! {
+                    in "glPushDebugGroup"();
+                    GLError::InvalidValue => "`length` is greater than `GL_MAX_DEBUG_MESSAGE_LENGTH`",
+                    _ => "Unknown error"
+                }
+            },
+            DebugMarkerBackend::Ext => {
+                gl_fns::PushGroupMarkerEXT(len, ptr);
+            },
+            DebugMarkerBackend::None => { }
+        }
+    }
+}
+
+fn _pop_debug_group(backend: DebugMarkerBackend) {
+    unsafe {
+        match backend {
+            DebugMarkerBackend::Khr => {
+                gl_fns::PopDebugGroup();
+                dbg_gl_sanity_check! {
+                    in "glPopDebugGroup"();
+                    _ => "Unknown error"
+                }
+            },
+            DebugMarkerBackend::Ext => {
+                gl_fns::PopGroupMarkerEXT();
+            },
+            DebugMarkerBackend::None => { }
+        }
+    }
+}
+
+/// A guard that marks a named group of OpenGL calls in frame captures
+/// (such as from RenderDoc or Xcode), created with [`gl.debug_group`]
+/// (trait.ContextDebugExt.html#method.debug_group). The group is popped
+/// when the guard is dropped.
+///
+/// Groups are annotated with `KHR_debug` if it's supported, falling back to
+/// `EXT_debug_marker`. If neither is supported, debug groups do nothing.
+#[must_use]
+pub struct DebugGroup {
+    backend: DebugMarkerBackend
+}
+
+impl Drop for DebugGroup {
+    fn drop(&mut self) {
+        _pop_debug_group(self.backend);
+    }
+}
+
+/// A snapshot of the OpenGL state, as reported by the driver. Created with
+/// [`gl.dump_state`](trait.ContextDebugExt.html#method.dump_state).
+///
+/// Unlike the bindings tracked by a [`ContextOf`]
+/// (../context/struct.ContextOf.html), these values are queried directly
+/// from the driver. Comparing the two can help track down bugs where
+/// glitter's view of the state no longer matches the real state (such as
+/// after misusing [`Context::current_context`]
+/// (../context/struct.ContextOf.html#method.current_context), or after
+/// making raw OpenGL calls).
+///
+/// The blend, depth, and stencil functions are stored as raw OpenGL enum
+/// values. The `Display` implementation prints these values by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GLStateSnapshot {
+    /// The buffer bound to `GL_ARRAY_BUFFER` (or 0).
+    pub array_buffer: GLuint,
+
+    /// The buffer bound to `GL_ELEMENT_ARRAY_BUFFER` (or 0).
+    pub element_array_buffer: GLuint,
+
+    /// The bound framebuffer (or 0 for the default framebuffer).
+    pub framebuffer: GLuint,
+
+    /// The bound renderbuffer (or 0).
+    pub renderbuffer: GLuint,
+
+    /// The program currently in use (or 0).
+    pub program: GLuint,
+
+    /// The index of the active texture unit.
+    pub active_texture_unit: u32,
+
+    /// The 2D texture bound to the active texture unit (or 0).
+    pub texture_2d: GLuint,
+
+    /// The cube map texture bound to the active texture unit (or 0).
+    pub texture_cube_map: GLuint,
+
+    /// The capabilities that are currently enabled.
+    pub enabled: Vec<Capability>,
+
+    /// The viewport.
+    pub viewport: Viewport,
+
+    /// The scissor box.
+    pub scissor_box: Rect,
+
+    /// The color used when clearing the color buffer.
+    pub clear_color: Color,
+
+    /// The red, green, blue, and alpha color write masks.
+    pub color_write_mask: [bool; 4],
+
+    /// The RGB and alpha blend equations.
+    pub blend_equation: (GLenum, GLenum),
+
+    /// The RGB source, RGB destination, alpha source, and alpha
+    /// destination blend factors.
+    pub blend_func: (GLenum, GLenum, GLenum, GLenum),
+
+    /// The constant blend color.
+    pub blend_color: Color,
+
+    /// The depth comparison function.
+    pub depth_func: GLenum,
+
+    /// Whether writing to the depth buffer is enabled.
+    pub depth_write_mask: bool,
+
+    /// The near and far values of the depth range.
+    pub depth_range: (f32, f32),
+
+    /// The value used when clearing the depth buffer.
+    pub clear_depth: f32,
+
+    /// The front-facing stencil comparison function.
+    pub stencil_func: GLenum,
+
+    /// The front-facing stencil reference value.
+    pub stencil_ref: i32,
+
+    /// The front-facing stencil comparison mask.
+    pub stencil_value_mask: u32,
+
+    /// The front-facing stencil write mask.
+    pub stencil_write_mask: u32,
+
+    /// The front-facing stencil operations when the stencil test fails,
+    /// when the depth test fails, and when both tests pass.
+    pub stencil_op: (GLenum, GLenum, GLenum),
+
+    /// Which faces are culled (when `CULL_FACE` is enabled).
+    pub cull_face: Option<CullFaceMode>,
+
+    /// The winding order of front-facing polygons.
+    pub front_face: Option<WindingOrder>
+}
+
+impl GLStateSnapshot {
+    /// Query the state of the current OpenGL context.
+    ///
+    /// # Safety
+    /// An OpenGL context must be current, and OpenGL function pointers must
+    /// be loaded (see [`Context::current_context`]
+    /// (../context/struct.ContextOf.html#method.current_context)).
+    pub unsafe fn current() -> Self {
+        let all_capabilities = [
+            Capability::Blend,
+            Capability::CullFace,
+            Capability::DepthTest,
+            Capability::Dither,
+            Capability::PolygonOffsetFill,
+            Capability::SampleAlphaToCoverage,
+            Capability::SampleCoverage,
+            Capability::ScisscorTest,
+            Capability::StencilTest
+        ];
+        let enabled = all_capabilities.iter().cloned().filter(|cap| {
+            gl_fns::IsEnabled(cap.gl_enum()) == gl::TRUE
+        }).collect();
+
+        let viewport = _get_ints::<[GLint; 4]>(gl::VIEWPORT);
+        let scissor_box = _get_ints::<[GLint; 4]>(gl::SCISSOR_BOX);
+        let clear_color = _get_floats::<[f32; 4]>(gl::COLOR_CLEAR_VALUE);
+        let blend_color = _get_floats::<[f32; 4]>(gl::BLEND_COLOR);
+        let depth_range = _get_floats::<[f32; 2]>(gl::DEPTH_RANGE);
+
+        let mut color_write_mask: [GLboolean; 4] = [0; 4];
+        gl_fns::GetBooleanv(gl::COLOR_WRITEMASK, color_write_mask.as_mut_ptr());
+        let mut depth_write_mask: GLboolean = 0;
+        gl_fns::GetBooleanv(gl::DEPTH_WRITEMASK, &mut depth_write_mask);
+
+        let snapshot = GLStateSnapshot {
+            array_buffer: _get_int(gl::ARRAY_BUFFER_BINDING) as GLuint,
+            element_array_buffer:
+                _get_int(gl::ELEMENT_ARRAY_BUFFER_BINDING) as GLuint,
+            framebuffer: _get_int(gl::FRAMEBUFFER_BINDING) as GLuint,
+            renderbuffer: _get_int(gl::RENDERBUFFER_BINDING) as GLuint,
+            program: _get_int(gl::CURRENT_PROGRAM) as GLuint,
+            active_texture_unit: (_get_int(gl::ACTIVE_TEXTURE) as u32)
+                .saturating_sub(gl::TEXTURE0),
+            texture_2d: _get_int(gl::TEXTURE_BINDING_2D) as GLuint,
+            texture_cube_map:
+                _get_int(gl::TEXTURE_BINDING_CUBE_MAP) as GLuint,
+            enabled: enabled,
+            viewport: Viewport::new(viewport[0] as u32,
+                                    viewport[1] as u32,
+                                    viewport[2] as u32,
+                                    viewport[3] as u32),
+            scissor_box: Rect::new(scissor_box[0] as u32,
+                                   scissor_box[1] as u32,
+                                   scissor_box[2] as u32,
+                                   scissor_box[3] as u32),
+            clear_color: Color::rgba(clear_color[0],
+                                     clear_color[1],
+                                     clear_color[2],
+                                     clear_color[3]),
+            color_write_mask: [color_write_mask[0] == gl::TRUE,
+                               color_write_mask[1] == gl::TRUE,
+                               color_write_mask[2] == gl::TRUE,
+                               color_write_mask[3] == gl::TRUE],
+            blend_equation: (_get_int(gl::BLEND_EQUATION_RGB) as GLenum,
+                             _get_int(gl::BLEND_EQUATION_ALPHA) as GLenum),
+            blend_func: (_get_int(gl::BLEND_SRC_RGB) as GLenum,
+                         _get_int(gl::BLEND_DST_RGB) as GLenum,
+                         _get_int(gl::BLEND_SRC_ALPHA) as GLenum,
+                         _get_int(gl::BLEND_DST_ALPHA) as GLenum),
+            blend_color: Color::rgba(blend_color[0],
+                                     blend_color[1],
+                                     blend_color[2],
+                                     blend_color[3]),
+            depth_func: _get_int(gl::DEPTH_FUNC) as GLenum,
+            depth_write_mask: depth_write_mask == gl::TRUE,
+            depth_range: (depth_range[0], depth_range[1]),
+            clear_depth: _get_floats::<[f32; 1]>(gl::DEPTH_CLEAR_VALUE)[0],
+            stencil_func: _get_int(gl::STENCIL_FUNC) as GLenum,
+            stencil_ref: _get_int(gl::STENCIL_REF),
+            stencil_value_mask: _get_int(gl::STENCIL_VALUE_MASK) as u32,
+            stencil_write_mask: _get_int(gl::STENCIL_WRITEMASK) as u32,
+            stencil_op: (_get_int(gl::STENCIL_FAIL) as GLenum,
+                         _get_int(gl::STENCIL_PASS_DEPTH_FAIL) as GLenum,
+                         _get_int(gl::STENCIL_PASS_DEPTH_PASS) as GLenum),
+            cull_face:
+                CullFaceMode::from_gl(_get_int(gl::CULL_FACE_MODE) as GLenum)
+                    .ok(),
+            front_face:
+                WindingOrder::from_gl(_get_int(gl::FRONT_FACE) as GLenum).ok()
+        };
+        dbg_gl_sanity_check! {
+            _ => "Unknown error"
+        }
+
+        snapshot
+    }
+}
+
+impl fmt::Display for GLStateSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(writeln!(f, "Bindings:"));
+        try!(writeln!(f, "    array buffer: {}", self.array_buffer));
+        try!(writeln!(f, "    element array buffer: {}",
+                      self.element_array_buffer));
+        try!(writeln!(f, "    framebuffer: {}", self.framebuffer));
+        try!(writeln!(f, "    renderbuffer: {}", self.renderbuffer));
+        try!(writeln!(f, "    program: {}", self.program));
+        try!(writeln!(f, "    active texture unit: {}",
+                      self.active_texture_unit));
+        try!(writeln!(f, "    texture 2d: {}", self.texture_2d));
+        try!(writeln!(f, "    texture cube map: {}", self.texture_cube_map));
+        try!(writeln!(f, "Enabled: {:?}", self.enabled));
+        try!(writeln!(f, "Viewport: {:?}", self.viewport));
+        try!(writeln!(f, "Scissor box: {:?}", self.scissor_box));
+        try!(writeln!(f, "Clear color: {:?}", self.clear_color));
+        try!(writeln!(f, "Color write mask: {:?}", self.color_write_mask));
+        try!(writeln!(f, "Blend:"));
+        try!(writeln!(f, "    equation: {} {}",
+                      _enum_name(self.blend_equation.0),
+                      _enum_name(self.blend_equation.1)));
+        try!(writeln!(f, "    func: {} {} {} {}",
+                      _enum_name(self.blend_func.0),
+                      _enum_name(self.blend_func.1),
+                      _enum_name(self.blend_func.2),
+                      _enum_name(self.blend_func.3)));
+        try!(writeln!(f, "    color: {:?}", self.blend_color));
+        try!(writeln!(f, "Depth:"));
+        try!(writeln!(f, "    func: {}", _enum_name(self.depth_func)));
+        try!(writeln!(f, "    write mask: {}", self.depth_write_mask));
+        try!(writeln!(f, "    range: {:?}", self.depth_range));
+        try!(writeln!(f, "    clear value: {}", self.clear_depth));
+        try!(writeln!(f, "Stencil:"));
+        try!(writeln!(f, "    func: {} ref={} mask={:#x}",
+                      _enum_name(self.stencil_func),
+                      self.stencil_ref,
+                      self.stencil_value_mask));
+        try!(writeln!(f, "    write mask: {:#x}", self.stencil_write_mask));
+        try!(writeln!(f, "    op: {} {} {}",
+                      _enum_name(self.stencil_op.0),
+                      _enum_name(self.stencil_op.1),
+                      _enum_name(self.stencil_op.2)));
+        try!(writeln!(f, "Cull face: {:?}", self.cull_face));
+        write!(f, "Front face: {:?}", self.front_face)
+    }
+}
+
+unsafe fn _get_int(pname: GLenum) -> GLint {
+    let mut value: GLint = 0;
+    gl_fns::GetIntegerv(pname, &mut value);
+    value
+}
+
+unsafe fn _get_ints<A>(pname: GLenum) -> A
+    where A: Default + AsMut<[GLint]>
+{
+    let mut values = A::default();
+    gl_fns::GetIntegerv(pname, values.as_mut().as_mut_ptr());
+    values
+}
+
+unsafe fn _get_floats<A>(pname: GLenum) -> A
+    where A: Default + AsMut<[GLfloat]>
+{
+    let mut values = A::default();
+    gl_fns::GetFloatv(pname, values.as_mut().as_mut_ptr());
+    values
+}
+
+// Get the name of a blend, depth, or stencil enum value, for display
+fn _enum_name(value: GLenum) -> String {
+    let name = match value {
+        gl::ZERO => "ZERO",
+        gl::ONE => "ONE",
+        gl::SRC_COLOR => "SRC_COLOR",
+        gl::ONE_MINUS_SRC_COLOR => "ONE_MINUS_SRC_COLOR",
+        gl::DST_COLOR => "DST_COLOR",
+        gl::ONE_MINUS_DST_COLOR => "ONE_MINUS_DST_COLOR",
+        gl::SRC_ALPHA => "SRC_ALPHA",
+        gl::ONE_MINUS_SRC_ALPHA => "ONE_MINUS_SRC_ALPHA",
+        gl::DST_ALPHA => "DST_ALPHA",
+        gl::ONE_MINUS_DST_ALPHA => "ONE_MINUS_DST_ALPHA",
+        gl::CONSTANT_COLOR => "CONSTANT_COLOR",
+        gl::ONE_MINUS_CONSTANT_COLOR => "ONE_MINUS_CONSTANT_COLOR",
+        gl::CONSTANT_ALPHA => "CONSTANT_ALPHA",
+        gl::ONE_MINUS_CONSTANT_ALPHA => "ONE_MINUS_CONSTANT_ALPHA",
+        gl::SRC_ALPHA_SATURATE => "SRC_ALPHA_SATURATE",
+        gl::FUNC_ADD => "FUNC_ADD",
+        gl::FUNC_SUBTRACT => "FUNC_SUBTRACT",
+        gl::FUNC_REVERSE_SUBTRACT => "FUNC_REVERSE_SUBTRACT",
+        gl::MIN => "MIN",
+        gl::MAX => "MAX",
+        gl::NEVER => "NEVER",
+        gl::LESS => "LESS",
+        gl::EQUAL => "EQUAL",
+        gl::LEQUAL => "LEQUAL",
+        gl::GREATER => "GREATER",
+        gl::NOTEQUAL => "NOTEQUAL",
+        gl::GEQUAL => "GEQUAL",
+        gl::ALWAYS => "ALWAYS",
+        gl::KEEP => "KEEP",
+        gl::REPLACE => "REPLACE",
+        gl::INCR => "INCR",
+        gl::DECR => "DECR",
+        gl::INVERT => "INVERT",
+        gl::INCR_WRAP => "INCR_WRAP",
+        gl::DECR_WRAP => "DECR_WRAP",
+        _ => { return format!("{:#06x}", value); }
+    };
+    name.to_owned()
+}
+
+/// An extension trait that adds methods for receiving debug output from the
+/// OpenGL driver. Debug output requires OpenGL 4.3, OpenGL ES 3.2, or the
+/// `KHR_debug` extension (see [`Extensions::has_debug_output`]
+/// (../extensions/struct.Extensions.html#method.has_debug_output)).
+pub trait ContextDebugExt: ContextExt {
+    /// Set a function that will be called for each debug message generated
+    /// by the OpenGL driver. The arguments are the source, type, severity,
+    /// and ID of the message, followed by the message itself. Debug output
+    /// is much more detailed than the errors returned by [`Context::get_error`]
+    /// (../context/struct.ContextOf.html#method.get_error), and often
+    /// includes performance warnings as well.
+    ///
+    /// This function enables `GL_DEBUG_OUTPUT` and
+    /// `GL_DEBUG_OUTPUT_SYNCHRONOUS`, so the callback is always called on
+    /// the current thread, during the OpenGL call that generated the
+    /// message. The callback replaces any previous callback, and is kept
+    /// until it is replaced again or removed with
+    /// [`gl.clear_debug_message_callback`]
+    /// (trait.ContextDebugExt.html#method.clear_debug_message_callback).
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if debug output is not supported by the
+    /// current context.
+    ///
+    /// # Panics
+    /// This function will panic if it is called from within a debug message
+    /// callback.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// gl.debug_message_callback(|source, ty, severity, id, message| {
+    ///     println!("{:?} {:?} {:?} {}: {}", source, ty, severity, id, message);
+    /// }).unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glDebugMessageCallback`](http://docs.gl/es3/glDebugMessageCallback)
+    /// OpenGL docs
+    fn debug_message_callback<F>(&mut self, callback: F)
+        -> Result<(), GLError>
+        where F: FnMut(DebugSource, DebugType, DebugSeverity, u32, &str)
+                 + 'static
+    {
+        if !self.get_extensions().has_debug_output() {
+            return Err(GLError::Unsupported("debug output"));
+        }
+
+        DEBUG_CALLBACK.with(|current| {
+            *current.borrow_mut() = Some(Box::new(callback));
+        });
+
+        unsafe {
+            gl_fns::Enable(gl::DEBUG_OUTPUT);
+            gl_fns::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+            gl_fns::DebugMessageCallback(Some(_debug_callback), ptr::null());
+            dbg_gl_sanity_check! {
+                _ => "Unknown error"
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove the function set with [`gl.debug_message_callback`]
+    /// (trait.ContextDebugExt.html#method.debug_message_callback), and
+    /// disable `GL_DEBUG_OUTPUT`.
+    ///
+    /// # Panics
+    /// This function will panic if it is called from within a debug message
+    /// callback.
+    fn clear_debug_message_callback(&mut self) {
+        let had_callback = DEBUG_CALLBACK.with(|current| {
+            current.borrow_mut().take().is_some()
+        });
+
+        if had_callback {
+            unsafe {
+                gl_fns::DebugMessageCallback(None, ptr::null());
+                gl_fns::Disable(gl::DEBUG_OUTPUT);
+                dbg_gl_sanity_check! {
+                    _ => "Unknown error"
+                }
+            }
+        }
+    }
+
+    /// Start a named scope for collecting OpenGL errors. Any pending errors
+    /// are discarded when the scope is created, so that only errors from
+    /// OpenGL calls made while the returned [`ErrorScope`]
+    /// (struct.ErrorScope.html) is alive are reported. This is useful for
+    /// narrowing down which part of a program generated an error.
+    ///
+    /// # Note
+    /// When the `debug_assertions` configuration option is set, most
+    /// glitter functions already panic on an OpenGL error, so error scopes
+    /// are mostly useful in release builds, or around unsafe code that
+    /// makes raw OpenGL calls.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let scope = gl.error_scope("shadow pass");
+    /// // ... draw the shadow pass ...
+    /// if let Err(e) = scope.finish() {
+    ///     println!("{}", e);
+    /// }
+    /// # }
+    /// ```
+    fn error_scope<S>(&self, name: S) -> ErrorScope
+        where S: Into<String>
+    {
+        _drain_errors();
+
+        ErrorScope {
+            name: name.into(),
+            finished: false
+        }
+    }
+
+    /// Query the current OpenGL state from the driver, including bindings,
+    /// enabled capabilities, and blend, depth, and stencil settings. See
+    /// [`GLStateSnapshot`](struct.GLStateSnapshot.html) for more details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// println!("{}", gl.dump_state());
+    /// # }
+    /// ```
+    fn dump_state(&self) -> GLStateSnapshot {
+        unsafe { GLStateSnapshot::current() }
+    }
+
+    /// Enable or disable debug messages with the given source, type,
+    /// and severity. A value of `None` matches messages with any source,
+    /// type, or severity. Newer calls take precedence over older calls.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if debug output is not supported by the
+    /// current context.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// // Ignore everything but errors
+    /// gl.debug_message_control(None, None, None, false).unwrap();
+    /// gl.debug_message_control(None, Some(glitter::DEBUG_TYPE_ERROR), None, true)
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glDebugMessageControl`](http://docs.gl/es3/glDebugMessageControl)
+    /// OpenGL docs
+    fn debug_message_control(&mut self,
+                             source: Option<DebugSource>,
+                             gl_type: Option<DebugType>,
+                             severity: Option<DebugSeverity>,
+                             enabled: bool)
+        -> Result<(), GLError>
+    {
+        if !self.get_extensions().has_debug_output() {
+            return Err(GLError::Unsupported("debug output"));
+        }
+
+        let source = source.map_or(gl::DONT_CARE, |s| s.gl_enum());
+        let gl_type = gl_type.map_or(gl::DONT_CARE, |t| t.gl_enum());
+        let severity = severity.map_or(gl::DONT_CARE, |s| s.gl_enum());
+        unsafe {
+            gl_fns::DebugMessageControl(source,
+                                    gl_type,
+                                    severity,
+                                    0,
+                                    ptr::null(),
+                                    enabled as GLboolean);
+            dbg_gl_sanity_check! {
+                in "glDebugMessageControl"();
+                GLError::InvalidEnum => "`source`, `type`, or `severity` is not a valid debug enum",
+                _ => "Unknown error"
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Start a named group of OpenGL calls, which is shown in frame
+    /// captures (such as from RenderDoc or Xcode). Each call should be
+    /// matched by a call to [`gl.pop_debug_group`]
+    /// (trait.ContextDebugExt.html#method.pop_debug_group); prefer
+    /// [`gl.debug_group`](trait.ContextDebugExt.html#method.debug_group),
+    /// which pops the group automatically.
+    ///
+    /// Groups are annotated with `KHR_debug` if it's supported, falling back
+    /// to `EXT_debug_marker`. If neither is supported, this does nothing.
+    ///
+    /// # See also
+    /// [`glPushDebugGroup`](http://docs.gl/es3/glPushDebugGroup) OpenGL docs
+    fn push_debug_group<S>(&self, name: S)
+        where S: AsRef<str>
+    {
+        let backend = _debug_marker_backend(&self.get_extensions());
+        _push_debug_group(backend, name.as_ref());
+    }
+
+    /// End the group of OpenGL calls started by the last call to
+    /// [`gl.push_debug_group`]
+    /// (trait.ContextDebugExt.html#method.push_debug_group).
+    ///
+    /// # See also
+    /// [`glPopDebugGroup`](http://docs.gl/es3/glPopDebugGroup) OpenGL docs
+    fn pop_debug_group(&self) {
+        _pop_debug_group(_debug_marker_backend(&self.get_extensions()));
+    }
+
+    /// Start a named group of OpenGL calls, which is shown in frame
+    /// captures, and ends when the returned guard is dropped. See
+    /// [`DebugGroup`](struct.DebugGroup.html) for more details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// {
+    ///     let _group = gl.debug_group("Shadow pass");
+    ///     // ...
+    /// }
+    /// # }
+    /// ```
+    fn debug_group<S>(&self, name: S) -> DebugGroup
+        where S: AsRef<str>
+    {
+        let backend = _debug_marker_backend(&self.get_extensions());
+        _push_debug_group(backend, name.as_ref());
+        DebugGroup { backend: backend }
+    }
+
+    /// Insert a single named marker into the stream of OpenGL calls, which
+    /// is shown in frame captures. Uses the same backend as
+    /// [`gl.debug_group`](trait.ContextDebugExt.html#method.debug_group),
+    /// and does nothing if neither `KHR_debug` nor `EXT_debug_marker` is
+    /// supported.
+    ///
+    /// # See also
+    /// [`glDebugMessageInsert`](http://docs.gl/es3/glDebugMessageInsert)
+    /// OpenGL docs
+    fn insert_debug_marker<S>(&self, message: S)
+        where S: AsRef<str>
+    {
+        let message = message.as_ref();
+        let ptr = message.as_ptr() as *const GLchar;
+        let len = message.len() as GLsizei;
+        unsafe {
+            match _debug_marker_backend(&self.get_extensions()) {
+                DebugMarkerBackend::Khr => {
+                    gl_fns::DebugMessageInsert(gl::DEBUG_SOURCE_APPLICATION,
+                                           gl::DEBUG_TYPE_MARKER,
+                                           0,
+                                           gl::DEBUG_SEVERITY_NOTIFICATION,
+                                           len,
+                                           ptr);
+                    dbg_gl_sanity_check! {
+                        in "glDebugMessageInsert"();
+                        GLError::InvalidValue => "`length` is greater than `GL_MAX_DEBUG_MESSAGE_LENGTH`",
+                        _ => "Unknown error"
+                    }
+                },
+                DebugMarkerBackend::Ext => {
+                    gl_fns::InsertEventMarkerEXT(len, ptr);
+                },
+                DebugMarkerBackend::None => { }
+            }
+        }
+    }
+}
+
+impl<C: BaseContext> ContextDebugExt for C {
+
+}
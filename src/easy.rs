@@ -0,0 +1,245 @@
+//! A simpler, higher-level interface to glitter, for getting started
+//! quickly. The [`Gl`](struct.Gl.html) type owns a [`Context`]
+//! (../context/type.Context.html), and makes all of the bindings needed by
+//! each call internally, so that drawing is a single method call:
+//!
+//! ```no_run
+//! #[macro_use] extern crate glitter;
+//! use glitter::easy::{Gl, Uniforms};
+//!
+//! #[derive(Clone, Copy)]
+//! struct Vertex {
+//!     position: [f32; 2]
+//! }
+//!
+//! impl_vertex_data!(Vertex, position);
+//!
+//! # fn main() {
+//! let mut gl = Gl::new(unsafe { glitter::Context::current_context() });
+//!
+//! let mut program = gl.program(r##"
+//!     attribute vec2 position;
+//!     void main() { gl_Position = vec4(position, 0.0, 1.0); }
+//! "##, r##"
+//!     uniform mediump vec4 color;
+//!     void main() { gl_FragColor = color; }
+//! "##).unwrap();
+//!
+//! let vertices = [Vertex { position: [-1.0, -1.0] },
+//!                 Vertex { position: [1.0, -1.0] },
+//!                 Vertex { position: [0.0, 1.0] }];
+//! let mut triangle = gl.mesh(&program, &vertices, &[0u16, 1, 2]).unwrap();
+//!
+//! gl.clear(glitter::Color::rgba(0.0, 0.0, 0.0, 1.0));
+//! gl.draw(&mut triangle,
+//!         &mut program,
+//!         Uniforms::new().set("color", [1.0, 0.5, 0.0, 1.0]));
+//! # }
+//! ```
+//!
+//! Everything that `Gl` does can also be done with the core API, which is
+//! available with [`gl.context`](struct.Gl.html#method.context) when more
+//! control is needed.
+
+use context::{Context, ContextExt, ContextFramebufferExt,
+              ContextProgramExt, ContextProgramBuilderExt,
+              ContextTextureBuilderExt, ProgramContext, ProgramBinding,
+              TextureUnitBinding2d};
+use context::program_context;
+use program::{Program, ProgramUniform};
+use texture::Texture2d;
+use image_data::Image2d;
+use index_data::{IndexData, IndexDatum};
+use uniform_data::UniformDatum;
+use vertex_data::{VertexData, VertexBytes};
+use vertex_buffer::{Mesh, AttribBinder, AttribAddError,
+                    ContextVertexBufferExt, VertexBufferContext};
+use types::{Color, GLError, COLOR_BUFFER_BIT, DEPTH_BUFFER_BIT, TRIANGLES};
+use buffer::STATIC_DRAW;
+
+/// An OpenGL context with a conventional, single-owner interface. See the
+/// [module-level docs](index.html) for an example.
+pub struct Gl {
+    gl: Context
+}
+
+impl Gl {
+    /// Create a `Gl` from a context with all free bindings.
+    pub fn new(gl: Context) -> Self {
+        Gl { gl: gl }
+    }
+
+    /// Get the underlying context, to use the core API.
+    pub fn context(&mut self) -> &mut Context {
+        &mut self.gl
+    }
+
+    /// Convert the `Gl` back into the underlying context.
+    pub fn into_context(self) -> Context {
+        self.gl
+    }
+
+    /// Clear the color and depth buffers, using `color` as the clear color.
+    pub fn clear(&mut self, color: Color) {
+        self.gl.clear_color(color);
+        self.gl.clear(COLOR_BUFFER_BIT | DEPTH_BUFFER_BIT);
+    }
+
+    /// Compile a program from a vertex shader and a fragment shader.
+    ///
+    /// # Failures
+    /// An error will be returned if either shader failed to compile, or if
+    /// the program failed to link.
+    pub fn program(&mut self, vertex_source: &str, fragment_source: &str)
+        -> Result<Program, GLError>
+    {
+        self.gl.build_program(&[])
+            .vertex_shader(vertex_source)
+            .fragment_shader(fragment_source)
+            .try_unwrap()
+    }
+
+    /// Create a mesh with the given vertices and indices, which can be
+    /// drawn with `program`. Each of the vertex type's attributes is bound
+    /// to the program attribute with the same name.
+    ///
+    /// # Failures
+    /// An error will be returned if one of the vertex type's attributes
+    /// could not be found in `program`.
+    pub fn mesh<V, I>(&mut self,
+                      program: &Program,
+                      vertices: &[V],
+                      indices: &[I])
+        -> Result<Mesh<V, I>, AttribAddError>
+        where V: VertexData, [V]: VertexBytes,
+              I: IndexDatum, [I]: IndexData
+    {
+        let mut attribs = AttribBinder::new();
+        try!(attribs.add_program_attribs::<V, _>(&self.gl, program));
+
+        let mut mesh = self.gl.new_mesh();
+        mesh.bind_attrib_pointers(attribs);
+        {
+            let (mut gl_mesh, gl) = (&mut self.gl).bind_mesh(&mut mesh);
+            gl.buffer_mesh(&mut gl_mesh, vertices, indices, STATIC_DRAW);
+        }
+        Ok(mesh)
+    }
+
+    /// Create a 2D texture from an image, with the default filtering and
+    /// wrapping modes.
+    ///
+    /// # Failures
+    /// An error will be returned if the texture could not be created.
+    pub fn texture(&mut self, image: &Image2d) -> Result<Texture2d, GLError> {
+        (&mut self.gl).build_texture_2d()
+            .image_2d(image)
+            .try_unwrap()
+    }
+
+    /// Draw all of the triangles of a mesh with a program, after setting
+    /// the program's uniforms. Textures are bound to the texture units in
+    /// the order they were added to `uniforms`.
+    ///
+    /// Uniforms that the program doesn't have are ignored, since drivers
+    /// remove uniforms that a program doesn't use.
+    ///
+    /// # Panics
+    /// This function will panic if `uniforms` has more textures than the
+    /// context has texture units.
+    pub fn draw<V, I>(&mut self,
+                      mesh: &mut Mesh<V, I>,
+                      program: &mut Program,
+                      uniforms: Uniforms)
+        where V: VertexData, I: IndexDatum
+    {
+        let Uniforms { values, textures } = uniforms;
+        let max_units = self.gl.limits().max_combined_texture_image_units;
+        assert!(textures.len() <= max_units as usize,
+                "{} textures were given, but the context only has {} texture units",
+                textures.len(), max_units);
+
+        let value_locations: Vec<_> = values.iter().map(|&(name, _)| {
+            self.gl.get_uniform_location(program, name).ok()
+        }).collect();
+        let texture_locations: Vec<_> = textures.iter().map(|&(name, _)| {
+            self.gl.get_uniform_location(program, name).ok()
+        }).collect();
+
+        let (gl_mesh, gl) = (&mut self.gl).bind_mesh(mesh);
+        let (gl_program, gl) = gl.use_program(program);
+        let (tex_units, gl) = gl.swap_tex_units(());
+
+        for ((_, set), location) in values.iter().zip(value_locations) {
+            if let Some(location) = location {
+                set(&gl_program, location);
+            }
+        }
+
+        let textures = textures.into_iter().zip(texture_locations);
+        for (idx, ((_, texture), location)) in textures.enumerate() {
+            let gl_tex_unit = unsafe { tex_units.active_nth(idx as u32) };
+            let sampler = gl_tex_unit.sampler();
+            gl_tex_unit.bind_texture_2d(texture);
+            if let Some(location) = location {
+                gl.set_uniform(&gl_program, location, sampler);
+            }
+        }
+
+        gl_mesh.draw(TRIANGLES);
+    }
+}
+
+/// A set of uniform values and textures to set when drawing with
+/// [`gl.draw`](struct.Gl.html#method.draw).
+///
+/// # Example
+/// ```no_run
+/// # let mut texture: glitter::Texture2d = unsafe { ::std::mem::uninitialized() };
+/// use glitter::easy::Uniforms;
+///
+/// let uniforms = Uniforms::new()
+///     .set("scale", 2.0_f32)
+///     .set("color", [1.0, 0.0, 0.0, 1.0])
+///     .texture("sprite", &mut texture);
+/// ```
+pub struct Uniforms<'a> {
+    values: Vec<(&'a str, Box<Fn(&ProgramBinding, ProgramUniform) + 'a>)>,
+    textures: Vec<(&'a str, &'a mut Texture2d)>
+}
+
+impl<'a> Uniforms<'a> {
+    /// Create an empty set of uniforms.
+    pub fn new() -> Self {
+        Uniforms {
+            values: vec![],
+            textures: vec![]
+        }
+    }
+
+    /// Set the uniform named `name` to `value`.
+    pub fn set<T>(mut self, name: &'a str, value: T) -> Self
+        where T: UniformDatum + Copy + 'a
+    {
+        let set = move |gl_program: &ProgramBinding, uniform| {
+            program_context::_set_uniform(gl_program, uniform, value);
+        };
+        self.values.push((name, Box::new(set)));
+        self
+    }
+
+    /// Bind `texture` to a texture unit, and set the sampler uniform named
+    /// `name` to that texture unit.
+    pub fn texture(mut self, name: &'a str, texture: &'a mut Texture2d)
+        -> Self
+    {
+        self.textures.push((name, texture));
+        self
+    }
+}
+
+impl<'a> Default for Uniforms<'a> {
+    fn default() -> Self {
+        Uniforms::new()
+    }
+}
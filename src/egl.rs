@@ -0,0 +1,290 @@
+// The parts of EGL used to create windowless contexts (see the `headless`
+// and `offscreen` modules), and to query the current surface.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use types::GLError;
+
+pub type EGLBoolean = u32;
+pub type EGLint = i32;
+pub type EGLenum = u32;
+pub type EGLDisplay = *mut c_void;
+pub type EGLConfig = *mut c_void;
+pub type EGLContext = *mut c_void;
+pub type EGLSurface = *mut c_void;
+
+pub const EGL_FALSE: EGLBoolean = 0;
+pub const EGL_NONE: EGLint = 0x3038;
+pub const EGL_EXTENSIONS: EGLint = 0x3055;
+pub const EGL_SURFACE_TYPE: EGLint = 0x3033;
+pub const EGL_PBUFFER_BIT: EGLint = 0x0001;
+pub const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+pub const EGL_OPENGL_ES2_BIT: EGLint = 0x0004;
+pub const EGL_OPENGL_ES3_BIT: EGLint = 0x0040;
+pub const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+pub const EGL_CONTEXT_CLIENT_VERSION: EGLint = 0x3098;
+pub const EGL_WIDTH: EGLint = 0x3057;
+pub const EGL_HEIGHT: EGLint = 0x3056;
+pub const EGL_DRAW: EGLint = 0x3059;
+pub const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+
+#[link(name = "EGL")]
+extern "system" {
+    pub fn eglGetDisplay(display_id: *mut c_void) -> EGLDisplay;
+    pub fn eglInitialize(display: EGLDisplay,
+                         major: *mut EGLint,
+                         minor: *mut EGLint) -> EGLBoolean;
+    pub fn eglQueryString(display: EGLDisplay, name: EGLint) -> *const c_char;
+    pub fn eglBindAPI(api: EGLenum) -> EGLBoolean;
+    pub fn eglChooseConfig(display: EGLDisplay,
+                           attrib_list: *const EGLint,
+                           configs: *mut EGLConfig,
+                           config_size: EGLint,
+                           num_config: *mut EGLint) -> EGLBoolean;
+    pub fn eglCreateContext(display: EGLDisplay,
+                            config: EGLConfig,
+                            share_context: EGLContext,
+                            attrib_list: *const EGLint) -> EGLContext;
+    pub fn eglCreatePbufferSurface(display: EGLDisplay,
+                                   config: EGLConfig,
+                                   attrib_list: *const EGLint) -> EGLSurface;
+    pub fn eglMakeCurrent(display: EGLDisplay,
+                          draw: EGLSurface,
+                          read: EGLSurface,
+                          context: EGLContext) -> EGLBoolean;
+    pub fn eglDestroyContext(display: EGLDisplay,
+                             context: EGLContext) -> EGLBoolean;
+    pub fn eglDestroySurface(display: EGLDisplay,
+                             surface: EGLSurface) -> EGLBoolean;
+    pub fn eglGetCurrentDisplay() -> EGLDisplay;
+    pub fn eglGetCurrentSurface(readdraw: EGLint) -> EGLSurface;
+    pub fn eglQuerySurface(display: EGLDisplay,
+                           surface: EGLSurface,
+                           attribute: EGLint,
+                           value: *mut EGLint) -> EGLBoolean;
+    pub fn eglGetError() -> EGLint;
+    pub fn eglGetProcAddress(procname: *const c_char) -> *const c_void;
+}
+
+type GetPlatformDisplayFn = extern "system" fn(platform: EGLenum,
+                                               native_display: *mut c_void,
+                                               attrib_list: *const EGLint)
+                                               -> EGLDisplay;
+
+// A windowless OpenGL ES context, and its pbuffer (which is null for
+// surfaceless contexts). The context is destroyed when dropped.
+pub struct EglContext {
+    pub display: EGLDisplay,
+    pub context: EGLContext,
+    pub surface: EGLSurface
+}
+
+impl EglContext {
+    // Create an OpenGL ES 3 context if possible, or an OpenGL ES 2 context
+    // otherwise. `pbuffer_size` is the size of the pbuffer to draw to, or
+    // `None` to create a surfaceless context (which requires
+    // `EGL_KHR_surfaceless_context`).
+    pub unsafe fn new(pbuffer_size: Option<(u32, u32)>)
+        -> Result<EglContext, GLError>
+    {
+        let display = _get_display();
+        if display.is_null() {
+            return Err(_egl_error("Failed to get an EGL display"));
+        }
+
+        let (mut major, mut minor) = (0, 0);
+        if eglInitialize(display, &mut major, &mut minor) == EGL_FALSE {
+            return Err(_egl_error("Failed to initialize EGL"));
+        }
+        if eglBindAPI(EGL_OPENGL_ES_API) == EGL_FALSE {
+            return Err(_egl_error("Failed to bind the OpenGL ES API"));
+        }
+
+        let surfaceless = pbuffer_size.is_none();
+        for &(renderable_type, version) in &[(EGL_OPENGL_ES3_BIT, 3),
+                                             (EGL_OPENGL_ES2_BIT, 2)]
+        {
+            let config = match _choose_config(display,
+                                              renderable_type,
+                                              surfaceless) {
+                Some(config) => { config },
+                None => { continue; }
+            };
+
+            let context_attribs = [
+                EGL_CONTEXT_CLIENT_VERSION, version,
+                EGL_NONE
+            ];
+            let context = eglCreateContext(display,
+                                           config,
+                                           ptr::null_mut(),
+                                           context_attribs.as_ptr());
+            if context.is_null() {
+                continue;
+            }
+
+            let mut egl_context = EglContext {
+                display: display,
+                context: context,
+                surface: ptr::null_mut()
+            };
+
+            if let Some((width, height)) = pbuffer_size {
+                let surface_attribs = [
+                    EGL_WIDTH, width as EGLint,
+                    EGL_HEIGHT, height as EGLint,
+                    EGL_NONE
+                ];
+                let surface = eglCreatePbufferSurface(display,
+                                                      config,
+                                                      surface_attribs.as_ptr());
+                if surface.is_null() {
+                    return Err(_egl_error("Failed to create a pbuffer"));
+                }
+                egl_context.surface = surface;
+            }
+
+            return Ok(egl_context);
+        }
+
+        Err(_egl_error("Failed to create an OpenGL ES context"))
+    }
+
+    // Make the context current on the current thread
+    pub unsafe fn make_current(&self) -> Result<(), GLError> {
+        if eglMakeCurrent(self.display,
+                          self.surface,
+                          self.surface,
+                          self.context) == EGL_FALSE
+        {
+            Err(_egl_error("Failed to make the context current"))
+        }
+        else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for EglContext {
+    fn drop(&mut self) {
+        // NOTE: The display is left initialized, since EGL displays are
+        //       shared by the whole process
+        unsafe {
+            eglMakeCurrent(self.display,
+                           ptr::null_mut(),
+                           ptr::null_mut(),
+                           ptr::null_mut());
+            if !self.surface.is_null() {
+                eglDestroySurface(self.display, self.surface);
+            }
+            eglDestroyContext(self.display, self.context);
+        }
+    }
+}
+
+// Returns `true` if surfaceless contexts are supported
+pub unsafe fn _supports_surfaceless() -> bool {
+    let display = _get_display();
+    if display.is_null() {
+        return false;
+    }
+
+    let (mut major, mut minor) = (0, 0);
+    if eglInitialize(display, &mut major, &mut minor) == EGL_FALSE {
+        eglGetError();
+        return false;
+    }
+    _has_extension(display, "EGL_KHR_surfaceless_context")
+}
+
+// Get the surfaceless platform display if it's supported, or the default
+// display otherwise
+pub unsafe fn _get_display() -> EGLDisplay {
+    if _has_extension(ptr::null_mut(), "EGL_MESA_platform_surfaceless") {
+        let name = CString::new("eglGetPlatformDisplayEXT").unwrap();
+        let get_platform_display = eglGetProcAddress(name.as_ptr());
+        if !get_platform_display.is_null() {
+            let get_platform_display: GetPlatformDisplayFn =
+                ::std::mem::transmute(get_platform_display);
+            let display = get_platform_display(EGL_PLATFORM_SURFACELESS_MESA,
+                                               ptr::null_mut(),
+                                               ptr::null());
+            if !display.is_null() {
+                return display;
+            }
+        }
+    }
+
+    eglGetDisplay(ptr::null_mut())
+}
+
+// Returns `true` if the given extension is supported by the display (or, if
+// `display` is `EGL_NO_DISPLAY`, by the EGL client)
+pub unsafe fn _has_extension(display: EGLDisplay, name: &str) -> bool {
+    let extensions = eglQueryString(display, EGL_EXTENSIONS);
+    if extensions.is_null() {
+        // Querying client extensions generates an error if they're not
+        // supported, so clear it
+        eglGetError();
+        return false;
+    }
+
+    let extensions = CStr::from_ptr(extensions).to_string_lossy();
+    extensions.split_whitespace().any(|extension| extension == name)
+}
+
+pub unsafe fn _choose_config(display: EGLDisplay,
+                         renderable_type: EGLint,
+                         surfaceless: bool)
+    -> Option<EGLConfig>
+{
+    let surface_type = if surfaceless { 0 } else { EGL_PBUFFER_BIT };
+    let config_attribs = [
+        EGL_RENDERABLE_TYPE, renderable_type,
+        EGL_SURFACE_TYPE, surface_type,
+        EGL_NONE
+    ];
+
+    let mut config = ptr::null_mut();
+    let mut num_configs = 0;
+    let success = eglChooseConfig(display,
+                                  config_attribs.as_ptr(),
+                                  &mut config,
+                                  1,
+                                  &mut num_configs);
+    if success == EGL_FALSE || num_configs < 1 {
+        None
+    }
+    else {
+        Some(config)
+    }
+}
+
+// Get the size of the current EGL draw surface, or `None` if there is no
+// current EGL context, or if the context is surfaceless
+pub unsafe fn _current_surface_size() -> Option<(u32, u32)> {
+    let display = eglGetCurrentDisplay();
+    let surface = eglGetCurrentSurface(EGL_DRAW);
+    if display.is_null() || surface.is_null() {
+        return None;
+    }
+
+    let (mut width, mut height) = (0, 0);
+    let queried = eglQuerySurface(display, surface, EGL_WIDTH, &mut width)
+        != EGL_FALSE
+        && eglQuerySurface(display, surface, EGL_HEIGHT, &mut height)
+        != EGL_FALSE;
+    if queried {
+        Some((width as u32, height as u32))
+    }
+    else {
+        eglGetError();
+        None
+    }
+}
+
+pub fn _egl_error(msg: &str) -> GLError {
+    let code = unsafe { eglGetError() };
+    GLError::Message(format!("{} (EGL error {:#x})", msg, code))
+}
@@ -0,0 +1,531 @@
+//! Contains the [`Extensions`](struct.Extensions.html) type, which lists
+//! the OpenGL extensions supported by a context.
+
+use std::collections::HashSet;
+use std::collections::hash_set;
+use std::ffi::CStr;
+use gl;
+use gl_fns;
+use gl::types::*;
+use context::Context;
+use types::GlVersion;
+
+/// The set of OpenGL extensions supported by a context, along with the
+/// context's version. The extensions are queried once when getting the
+/// current context (with [`Context::current_context`]
+/// (../context/struct.ContextOf.html#method.current_context)), and can
+/// then be accessed with [`gl.extensions`]
+/// (../context/struct.ContextOf.html#method.extensions).
+///
+/// Besides checking for an extension by name with [`has`]
+/// (struct.Extensions.html#method.has), `Extensions` has a `has_*` method
+/// for each feature that glitter uses that may not always be available.
+/// These methods also take the context's version into account, so (for
+/// example) [`has_vertex_array_object`]
+/// (struct.Extensions.html#method.has_vertex_array_object) returns `true`
+/// for any OpenGL ES 3 context, even if it doesn't list
+/// `GL_OES_vertex_array_object`.
+///
+/// # Example
+/// ```no_run
+/// let gl = unsafe { glitter::Context::current_context() };
+/// if gl.extensions().has_texture_filter_anisotropic() {
+///     // Use anisotropic filtering
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Extensions {
+    version: Option<GlVersion>,
+    names: HashSet<String>
+}
+
+impl Extensions {
+    /// Query the version and extensions of the current OpenGL context.
+    /// For desktop OpenGL 3.0 and later and for OpenGL ES 3.0 and later,
+    /// the extensions are queried one at a time (using `glGetStringi`), since
+    /// the combined `GL_EXTENSIONS` string is unavailable with core profiles.
+    ///
+    /// # Safety
+    /// An OpenGL context must be current, and OpenGL function pointers must
+    /// be loaded (see [`Context::current_context`]
+    /// (../context/struct.ContextOf.html#method.current_context)).
+    pub unsafe fn current() -> Self {
+        let version = _get_string(gl_fns::GetString(gl::VERSION)).and_then(|version| {
+            GlVersion::parse(&version)
+        });
+
+        let indexed = match version {
+            Some(ref version) => { version.major >= 3 },
+            None => { false }
+        };
+        let names: Vec<String> = if indexed {
+            let mut count: GLint = 0;
+            gl_fns::GetIntegerv(gl::NUM_EXTENSIONS, &mut count as *mut GLint);
+            Context::get_error();
+
+            (0..count).filter_map(|i| {
+                _get_string(gl_fns::GetStringi(gl::EXTENSIONS, i as GLuint))
+            }).collect()
+        }
+        else {
+            let extensions = _get_string(gl_fns::GetString(gl::EXTENSIONS));
+            Context::get_error();
+
+            extensions.unwrap_or(String::new())
+                      .split_whitespace()
+                      .map(|name| name.to_owned())
+                      .collect()
+        };
+
+        Extensions::new(version, names)
+    }
+
+    /// Create a set of extensions from a context version and a list of
+    /// extension names. This is useful for testing code that checks for
+    /// extensions without an OpenGL context.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{Extensions, GlVersion};
+    ///
+    /// let es2 = GlVersion { major: 2, minor: 0, es: true };
+    /// let names = vec!["GL_OES_element_index_uint".to_owned()];
+    /// let extensions = Extensions::new(Some(es2), names);
+    /// assert!(extensions.has_element_index_uint());
+    /// assert!(!extensions.has_vertex_array_object());
+    /// ```
+    pub fn new<I>(version: Option<GlVersion>, names: I) -> Self
+        where I: IntoIterator<Item=String>
+    {
+        Extensions {
+            version: version,
+            names: names.into_iter().collect()
+        }
+    }
+
+    /// Get the version of the context, or `None` if the version string
+    /// could not be parsed.
+    pub fn version(&self) -> Option<GlVersion> {
+        self.version
+    }
+
+    /// Returns `true` if the extension with the given name (such as
+    /// `"GL_OES_element_index_uint"`) is supported.
+    pub fn has(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Iterate over the names of all of the supported extensions.
+    pub fn iter(&self) -> hash_set::Iter<String> {
+        self.names.iter()
+    }
+
+    /// Returns `true` if framebuffer objects are supported (desktop
+    /// OpenGL 3.0, OpenGL ES 2.0, `GL_ARB_framebuffer_object`, or
+    /// `GL_EXT_framebuffer_object`).
+    pub fn has_framebuffer_object(&self) -> bool {
+        self._core_since((3, 0), (2, 0))
+            || self.has("GL_ARB_framebuffer_object")
+            || self.has("GL_EXT_framebuffer_object")
+    }
+
+    /// Returns `true` if vertex array objects are supported (desktop
+    /// OpenGL 3.0, OpenGL ES 3.0, or `GL_OES_vertex_array_object`).
+    pub fn has_vertex_array_object(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_vertex_array_object")
+            || self.has("GL_OES_vertex_array_object")
+            || self.has("GL_APPLE_vertex_array_object")
+    }
+
+    /// Returns `true` if 32-bit (`u32`) indices are supported (desktop
+    /// OpenGL, OpenGL ES 3.0, or `GL_OES_element_index_uint`).
+    pub fn has_element_index_uint(&self) -> bool {
+        self._core_since((1, 0), (3, 0))
+            || self.has("GL_OES_element_index_uint")
+    }
+
+    /// Returns `true` if anisotropic texture filtering is supported
+    /// (desktop OpenGL 4.6 or `GL_EXT_texture_filter_anisotropic`).
+    pub fn has_texture_filter_anisotropic(&self) -> bool {
+        let core = match self.version {
+            Some(ref version) => { !version.es && version.is_at_least(4, 6) },
+            None => { false }
+        };
+        core
+            || self.has("GL_EXT_texture_filter_anisotropic")
+            || self.has("GL_ARB_texture_filter_anisotropic")
+    }
+
+    /// Returns `true` if debug output is supported (desktop OpenGL 4.3,
+    /// OpenGL ES 3.2, or `GL_KHR_debug`).
+    pub fn has_debug_output(&self) -> bool {
+        // NOTE: `GL_ARB_debug_output` isn't included, since it doesn't
+        //       support `GL_DEBUG_OUTPUT`
+        self._core_since((4, 3), (3, 2)) || self.has("GL_KHR_debug")
+    }
+
+    /// Returns `true` if the GPU's memory usage can be queried (with
+    /// `GL_NVX_gpu_memory_info` or `GL_ATI_meminfo`).
+    pub fn has_gpu_memory_info(&self) -> bool {
+        self.has("GL_NVX_gpu_memory_info") || self.has("GL_ATI_meminfo")
+    }
+
+    /// Returns `true` if context resets can be detected with
+    /// `glGetGraphicsResetStatus` (desktop OpenGL 4.5, OpenGL ES 3.2,
+    /// `GL_KHR_robustness`, `GL_ARB_robustness`, or `GL_EXT_robustness`).
+    pub fn has_robustness(&self) -> bool {
+        self._core_since((4, 5), (3, 2))
+            || self.has("GL_KHR_robustness")
+            || self.has("GL_ARB_robustness")
+            || self.has("GL_EXT_robustness")
+    }
+
+    /// Returns `true` if debug groups and debug markers can be inserted
+    /// with `glPushDebugGroup` and `glDebugMessageInsert` (desktop
+    /// OpenGL 4.3, OpenGL ES 3.2, or `GL_KHR_debug`).
+    pub fn has_debug_groups(&self) -> bool {
+        self._core_since((4, 3), (3, 2))
+            || self.has("GL_KHR_debug")
+    }
+
+    /// Returns `true` if debug markers can be inserted with
+    /// `GL_EXT_debug_marker`, which is commonly supported by OpenGL ES 2
+    /// drivers that don't support `GL_KHR_debug`.
+    pub fn has_debug_marker(&self) -> bool {
+        self.has("GL_EXT_debug_marker")
+    }
+
+    /// Returns `true` if instanced arrays (vertex attribute divisors) are
+    /// supported (desktop OpenGL 3.3, OpenGL ES 3.0, or
+    /// `GL_ARB_instanced_arrays`).
+    pub fn has_instanced_arrays(&self) -> bool {
+        self._core_since((3, 3), (3, 0))
+            || self.has("GL_ARB_instanced_arrays")
+    }
+
+    /// Returns `true` if integer vertex attributes are supported (desktop
+    /// OpenGL 3.0 or OpenGL ES 3.0).
+    pub fn has_integer_vertex_attribs(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_EXT_gpu_shader4")
+    }
+
+    /// Returns `true` if half-float vertex data is supported (desktop
+    /// OpenGL 3.0, OpenGL ES 3.0, or `GL_ARB_half_float_vertex`).
+    pub fn has_half_float_vertex(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_half_float_vertex")
+    }
+
+    /// Returns `true` if packed 2_10_10_10 vertex data is supported (desktop
+    /// OpenGL 3.3, OpenGL ES 3.0, or `GL_ARB_vertex_type_2_10_10_10_rev`).
+    pub fn has_vertex_type_2_10_10_10_rev(&self) -> bool {
+        self._core_since((3, 3), (3, 0))
+            || self.has("GL_ARB_vertex_type_2_10_10_10_rev")
+    }
+
+    /// Returns `true` if buffers can be mapped (desktop OpenGL 3.0,
+    /// OpenGL ES 3.0, or `GL_EXT_map_buffer_range`).
+    pub fn has_map_buffer_range(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_map_buffer_range")
+            || self.has("GL_EXT_map_buffer_range")
+    }
+
+    /// Returns `true` if pixels can be read into a buffer bound to
+    /// `GL_PIXEL_PACK_BUFFER` (desktop OpenGL 2.1, OpenGL ES 3.0,
+    /// `GL_ARB_pixel_buffer_object`, or `GL_NV_pixel_buffer_object`).
+    pub fn has_pixel_buffer_object(&self) -> bool {
+        self._core_since((2, 1), (3, 0))
+            || self.has("GL_ARB_pixel_buffer_object")
+            || self.has("GL_EXT_pixel_buffer_object")
+            || self.has("GL_NV_pixel_buffer_object")
+    }
+
+    /// Returns `true` if fence sync objects are supported (desktop OpenGL
+    /// 3.2, OpenGL ES 3.0, `GL_ARB_sync`, or `GL_APPLE_sync`).
+    pub fn has_sync(&self) -> bool {
+        self._core_since((3, 2), (3, 0))
+            || self.has("GL_ARB_sync")
+            || self.has("GL_APPLE_sync")
+    }
+
+    /// Returns `true` if geometry shaders (and the adjacency drawing modes,
+    /// such as `DrawingMode::LinesAdjacency`) are supported (desktop OpenGL
+    /// 3.2, OpenGL ES 3.2, `GL_ARB_geometry_shader4`, or
+    /// `GL_EXT_geometry_shader`).
+    pub fn has_geometry_shader(&self) -> bool {
+        self._core_since((3, 2), (3, 2))
+            || self.has("GL_ARB_geometry_shader4")
+            || self.has("GL_EXT_geometry_shader")
+            || self.has("GL_OES_geometry_shader")
+    }
+
+    /// Returns `true` if tessellation shaders (and `DrawingMode::Patches`)
+    /// are supported (desktop OpenGL 4.0, OpenGL ES 3.2,
+    /// `GL_ARB_tessellation_shader`, or `GL_EXT_tessellation_shader`).
+    pub fn has_tessellation_shader(&self) -> bool {
+        self._core_since((4, 0), (3, 2))
+            || self.has("GL_ARB_tessellation_shader")
+            || self.has("GL_EXT_tessellation_shader")
+            || self.has("GL_OES_tessellation_shader")
+    }
+
+    /// Returns `true` if data can be copied between buffers (desktop
+    /// OpenGL 3.1, OpenGL ES 3.0, or `GL_ARB_copy_buffer`).
+    pub fn has_copy_buffer(&self) -> bool {
+        self._core_since((3, 1), (3, 0))
+            || self.has("GL_ARB_copy_buffer")
+    }
+
+    /// Returns `true` if image data can be copied directly between textures
+    /// (desktop OpenGL 4.3, OpenGL ES 3.2, or `GL_EXT_copy_image`).
+    pub fn has_copy_image(&self) -> bool {
+        self._core_since((4, 3), (3, 2))
+            || self.has("GL_ARB_copy_image")
+            || self.has("GL_EXT_copy_image")
+            || self.has("GL_OES_copy_image")
+    }
+
+    /// Returns `true` if multisampled 2D textures are supported (desktop
+    /// OpenGL 3.2, OpenGL ES 3.1, or `GL_ARB_texture_multisample`). Note
+    /// that `glTexImage2DMultisample` is only available on desktop OpenGL,
+    /// and `glTexStorage2DMultisample` requires desktop OpenGL 4.3 (or
+    /// `GL_ARB_texture_storage_multisample`) or OpenGL ES 3.1.
+    pub fn has_texture_multisample(&self) -> bool {
+        self._core_since((3, 2), (3, 1))
+            || self.has("GL_ARB_texture_multisample")
+    }
+
+    /// Returns `true` if the contents of a framebuffer's attachments can be
+    /// invalidated (desktop OpenGL 4.3, OpenGL ES 3.0, or
+    /// `GL_EXT_discard_framebuffer`).
+    pub fn has_invalidate_framebuffer(&self) -> bool {
+        self._core_since((4, 3), (3, 0))
+            || self.has("GL_ARB_invalidate_subdata")
+            || self.has("GL_EXT_discard_framebuffer")
+    }
+
+    /// Returns `true` if 2D array textures are supported (desktop OpenGL
+    /// 3.0, OpenGL ES 3.0, or `GL_EXT_texture_array`).
+    pub fn has_texture_array(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_EXT_texture_array")
+    }
+
+    /// Returns `true` if 3D textures are supported (desktop OpenGL 1.2,
+    /// OpenGL ES 3.0, or `GL_OES_texture_3D`).
+    pub fn has_texture_3d(&self) -> bool {
+        self._core_since((1, 2), (3, 0))
+            || self.has("GL_OES_texture_3D")
+    }
+
+    /// Returns `true` if a single layer of a 2D array or 3D texture can be
+    /// attached to a framebuffer with `glFramebufferTextureLayer` (desktop
+    /// OpenGL 3.0, OpenGL ES 3.0, or `GL_EXT_texture_array`).
+    pub fn has_framebuffer_texture_layer(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_EXT_texture_array")
+    }
+
+    /// Returns `true` if every layer of a texture can be attached to
+    /// a framebuffer at once for layered rendering with
+    /// `glFramebufferTexture` (desktop OpenGL 3.2, OpenGL ES 3.2,
+    /// `GL_ARB_geometry_shader4`, `GL_EXT_geometry_shader`, or
+    /// `GL_OES_geometry_shader`). A geometry shader selects which layer
+    /// each primitive is drawn to with `gl_Layer`.
+    pub fn has_layered_framebuffer(&self) -> bool {
+        self._core_since((3, 2), (3, 2))
+            || self.has("GL_ARB_geometry_shader4")
+            || self.has("GL_EXT_geometry_shader")
+            || self.has("GL_OES_geometry_shader")
+    }
+
+    /// Returns `true` if a framebuffer's draw buffers can be selected with
+    /// `glDrawBuffers` (desktop OpenGL 2.0, OpenGL ES 3.0, or
+    /// `GL_EXT_draw_buffers`).
+    pub fn has_draw_buffers(&self) -> bool {
+        self._core_since((2, 0), (3, 0))
+            || self.has("GL_EXT_draw_buffers")
+    }
+
+    /// Returns `true` if a framebuffer's read buffer can be selected with
+    /// `glReadBuffer` (desktop OpenGL, OpenGL ES 3.0, or
+    /// `GL_NV_read_buffer`).
+    pub fn has_read_buffer(&self) -> bool {
+        self._core_since((1, 0), (3, 0))
+            || self.has("GL_NV_read_buffer")
+    }
+
+    /// Returns `true` if the parameters of a texture's mipmap levels (such
+    /// as their sizes and formats) can be queried with
+    /// `glGetTexLevelParameteriv` (desktop OpenGL or OpenGL ES 3.1).
+    pub fn has_tex_level_parameter(&self) -> bool {
+        self._core_since((1, 0), (3, 1))
+    }
+
+    /// Returns `true` if renderbuffers can be multisampled (desktop OpenGL
+    /// 3.0, OpenGL ES 3.0, `GL_EXT_multisampled_render_to_texture`, or
+    /// a vendor's framebuffer multisample extension).
+    pub fn has_renderbuffer_multisample(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_framebuffer_object")
+            || self.has("GL_EXT_framebuffer_multisample")
+            || self.has("GL_EXT_multisampled_render_to_texture")
+            || self.has("GL_ANGLE_framebuffer_multisample")
+            || self.has("GL_APPLE_framebuffer_multisample")
+    }
+
+    /// Returns `true` if renderbuffers can use the `RGB8` and `RGBA8`
+    /// formats (desktop OpenGL 3.0, OpenGL ES 3.0, or `GL_OES_rgb8_rgba8`).
+    pub fn has_rgba8_renderbuffer(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_framebuffer_object")
+            || self.has("GL_OES_rgb8_rgba8")
+    }
+
+    /// Returns `true` if renderbuffers can use the `DEPTH_COMPONENT24`
+    /// format (desktop OpenGL 3.0, OpenGL ES 3.0, or `GL_OES_depth24`).
+    pub fn has_depth24(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_framebuffer_object")
+            || self.has("GL_OES_depth24")
+    }
+
+    /// Returns `true` if renderbuffers can use the `DEPTH24_STENCIL8` format
+    /// (desktop OpenGL 3.0, OpenGL ES 3.0, or
+    /// `GL_OES_packed_depth_stencil`).
+    pub fn has_packed_depth_stencil(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_framebuffer_object")
+            || self.has("GL_EXT_packed_depth_stencil")
+            || self.has("GL_OES_packed_depth_stencil")
+    }
+
+    /// Returns `true` if renderbuffers can use the `DEPTH_COMPONENT32F` and
+    /// `DEPTH32F_STENCIL8` formats (desktop OpenGL 3.0, OpenGL ES 3.0, or
+    /// `GL_ARB_depth_buffer_float`).
+    pub fn has_depth_buffer_float(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_depth_buffer_float")
+    }
+
+    /// Returns `true` if one- and two-component (`R8` and `RG8`) textures
+    /// and renderbuffers are supported (desktop OpenGL 3.0, OpenGL ES 3.0,
+    /// or `GL_EXT_texture_rg`).
+    pub fn has_texture_rg(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_ARB_texture_rg")
+            || self.has("GL_EXT_texture_rg")
+    }
+
+    /// Returns `true` if sRGB textures and renderbuffers are supported
+    /// (desktop OpenGL 3.0, OpenGL ES 3.0, or `GL_EXT_sRGB`).
+    pub fn has_srgb(&self) -> bool {
+        self._core_since((3, 0), (3, 0))
+            || self.has("GL_EXT_sRGB")
+    }
+
+    /// Returns `true` if 16-bit floating-point formats can be rendered to
+    /// (desktop OpenGL 3.0, OpenGL ES 3.2, or
+    /// `GL_EXT_color_buffer_half_float`).
+    pub fn has_color_buffer_half_float(&self) -> bool {
+        self._core_since((3, 0), (3, 2))
+            || self.has("GL_EXT_color_buffer_half_float")
+            || self.has("GL_EXT_color_buffer_float")
+    }
+
+    /// Returns `true` if 32-bit floating-point formats (and
+    /// `R11F_G11F_B10F`) can be rendered to (desktop OpenGL 3.0, OpenGL
+    /// ES 3.2, or `GL_EXT_color_buffer_float`).
+    pub fn has_color_buffer_float(&self) -> bool {
+        self._core_since((3, 0), (3, 2))
+            || self.has("GL_EXT_color_buffer_float")
+    }
+
+    /// Returns `true` if depth textures are supported (desktop OpenGL,
+    /// OpenGL ES 3.0, or `GL_OES_depth_texture`).
+    pub fn has_depth_texture(&self) -> bool {
+        self._core_since((1, 4), (3, 0))
+            || self.has("GL_OES_depth_texture")
+            || self.has("GL_ANGLE_depth_texture")
+    }
+
+    /// Returns `true` if derivative functions (such as `dFdx`) can be used
+    /// in fragment shaders (desktop OpenGL, OpenGL ES 3.0, or
+    /// `GL_OES_standard_derivatives`).
+    pub fn has_standard_derivatives(&self) -> bool {
+        self._core_since((2, 0), (3, 0))
+            || self.has("GL_OES_standard_derivatives")
+    }
+
+    /// Returns `true` if a texture's level of detail can be clamped, and its
+    /// base and max mipmap levels can be set (desktop OpenGL 1.2 or
+    /// OpenGL ES 3.0).
+    pub fn has_texture_lod(&self) -> bool {
+        self._core_since((1, 2), (3, 0))
+    }
+
+    /// Returns `true` if a texture's level of detail can be biased with
+    /// `GL_TEXTURE_LOD_BIAS` (desktop OpenGL 1.4 or
+    /// `GL_EXT_texture_lod_bias`). OpenGL ES otherwise only supports a bias
+    /// passed to `texture2D` in a fragment shader.
+    pub fn has_texture_lod_bias(&self) -> bool {
+        let desktop = match self.version {
+            Some(ref version) if !version.es => {
+                version.is_at_least(1, 4)
+            },
+            _ => { false }
+        };
+        desktop || self.has("GL_EXT_texture_lod_bias")
+    }
+
+    /// Returns `true` if the length of the rows of uploaded image data can
+    /// be set with `GL_UNPACK_ROW_LENGTH` (desktop OpenGL, OpenGL ES 3.0, or
+    /// `GL_EXT_unpack_subimage`).
+    pub fn has_unpack_row_length(&self) -> bool {
+        self._core_since((1, 0), (3, 0))
+            || self.has("GL_EXT_unpack_subimage")
+    }
+
+    /// Returns `true` if external textures (`GL_TEXTURE_EXTERNAL_OES`) are
+    /// supported, which are used to sample EGL images such as camera
+    /// frames and decoded video (`GL_OES_EGL_image_external`).
+    pub fn has_egl_image_external(&self) -> bool {
+        self.has("GL_OES_EGL_image_external")
+    }
+
+    // Returns `true` if the context's version is at least `desktop` (for
+    // desktop OpenGL) or `es` (for OpenGL ES)
+    fn _core_since(&self, desktop: (u32, u32), es: (u32, u32)) -> bool {
+        match self.version {
+            Some(ref version) if version.es => {
+                version.is_at_least(es.0, es.1)
+            },
+            Some(ref version) => {
+                version.is_at_least(desktop.0, desktop.1)
+            },
+            None => { false }
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Extensions {
+    type Item = &'a String;
+    type IntoIter = hash_set::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+unsafe fn _get_string(string: *const GLubyte) -> Option<String> {
+    if string.is_null() {
+        None
+    }
+    else {
+        let string = CStr::from_ptr(string as *const _);
+        Some(string.to_string_lossy().into_owned())
+    }
+}
@@ -0,0 +1,138 @@
+// Counts the OpenGL calls made through glitter since the counters were last
+// reset, usually once per frame (see `ContextOf::frame_stats`). Counting is
+// enabled with the `frame-stats` feature; otherwise, all of the hooks are
+// no-ops.
+
+/// The number of OpenGL calls made through glitter since the counters were
+/// last reset with [`gl.reset_frame_stats`]
+/// (context/struct.ContextOf.html#method.reset_frame_stats). Returned by
+/// [`gl.frame_stats`](context/struct.ContextOf.html#method.frame_stats).
+///
+/// Calls are counted per thread, and calls made outside of glitter (such as
+/// within [`gl.with_raw`](context/struct.ContextOf.html#method.with_raw))
+/// aren't counted.
+#[cfg(feature = "frame-stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// The number of draw calls (such as `glDrawArrays` or
+    /// `glDrawElements`).
+    pub draw_calls: usize,
+
+    /// The number of calls that changed other OpenGL state, such as
+    /// enabling a capability, binding a buffer or program, or setting the
+    /// viewport.
+    pub state_changes: usize,
+
+    /// The number of calls that uploaded data to a buffer (such as
+    /// `glBufferData` or `glBufferSubData`).
+    pub buffer_uploads: usize,
+
+    /// The total number of bytes uploaded to buffers.
+    pub buffer_upload_bytes: usize,
+
+    /// The number of calls that bound a texture.
+    pub texture_binds: usize
+}
+
+#[cfg(feature = "frame-stats")]
+mod imp {
+    use std::cell::Cell;
+    use super::FrameStats;
+
+    thread_local! {
+        static STATS: Cell<FrameStats> = Cell::new(FrameStats::default())
+    }
+
+    fn _update<F>(f: F)
+        where F: FnOnce(&mut FrameStats)
+    {
+        STATS.with(|stats| {
+            let mut current = stats.get();
+            f(&mut current);
+            stats.set(current);
+        });
+    }
+
+    pub fn draw() {
+        _update(|stats| stats.draw_calls += 1);
+    }
+
+    pub fn state_change() {
+        _update(|stats| stats.state_changes += 1);
+    }
+
+    pub fn buffer_upload(bytes: usize) {
+        _update(|stats| {
+            stats.buffer_uploads += 1;
+            stats.buffer_upload_bytes += bytes;
+        });
+    }
+
+    pub fn texture_bind() {
+        _update(|stats| stats.texture_binds += 1);
+    }
+
+    pub fn frame_stats() -> FrameStats {
+        STATS.with(|stats| stats.get())
+    }
+
+    pub fn reset() {
+        STATS.with(|stats| stats.set(FrameStats::default()));
+    }
+}
+
+#[cfg(not(feature = "frame-stats"))]
+mod imp {
+    #[inline(always)]
+    pub fn draw() {
+
+    }
+
+    #[inline(always)]
+    pub fn state_change() {
+
+    }
+
+    #[inline(always)]
+    pub fn buffer_upload(_bytes: usize) {
+
+    }
+
+    #[inline(always)]
+    pub fn texture_bind() {
+
+    }
+}
+
+// Called after each draw call
+pub fn _draw() {
+    imp::draw();
+}
+
+// Called after each call that changes a binding (other than a texture
+// binding) or any other OpenGL state
+pub fn _state_change() {
+    imp::state_change();
+}
+
+// Called after `bytes` bytes are uploaded to a buffer
+pub fn _buffer_upload(bytes: usize) {
+    imp::buffer_upload(bytes);
+}
+
+// Called after a texture is bound
+pub fn _texture_bind() {
+    imp::texture_bind();
+}
+
+// Get the counters for the current thread
+#[cfg(feature = "frame-stats")]
+pub fn _frame_stats() -> FrameStats {
+    imp::frame_stats()
+}
+
+// Reset all of the counters for the current thread to 0
+#[cfg(feature = "frame-stats")]
+pub fn _reset() {
+    imp::reset();
+}
@@ -3,8 +3,22 @@
 
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
-use types::GLObject;
+use context::{BaseContext, ContextExt};
+use types::{GLObject, GLError, Viewport};
+use leaks::{self, ObjectKind};
+use bind_cache;
+#[cfg(any(feature = "headless", feature = "offscreen"))] use egl;
+
+// The `GL_*_BITS` queries were removed from core OpenGL (but not OpenGL ES),
+// so they aren't included in the `gl` crate
+const RED_BITS: GLenum = 0x0D52;
+const GREEN_BITS: GLenum = 0x0D53;
+const BLUE_BITS: GLenum = 0x0D54;
+const ALPHA_BITS: GLenum = 0x0D55;
+const DEPTH_BITS: GLenum = 0x0D56;
+const STENCIL_BITS: GLenum = 0x0D57;
 
 /// An OpenGL framebuffer object.
 ///
@@ -32,13 +46,18 @@ use types::GLObject;
 /// (../context/framebuffer_context/struct.FramebufferBinding.html) type.
 pub struct Framebuffer {
     gl_id: GLuint,
+
+    // Unlike most objects, framebuffers are never shared between contexts,
+    // so they must stay on the thread (and context) that created them
     _phantom: PhantomData<*mut ()>
 }
 
 impl Drop for Framebuffer {
     fn drop(&mut self) {
+        leaks::_untrack(ObjectKind::Framebuffer, self.gl_id);
+        bind_cache::_delete(ObjectKind::Framebuffer, self.gl_id);
         unsafe {
-            gl::DeleteFramebuffers(1, &self.gl_id as *const GLuint);
+            gl_fns::DeleteFramebuffers(1, &self.gl_id as *const GLuint);
         }
     }
 }
@@ -47,6 +66,7 @@ impl GLObject for Framebuffer {
     type Id = GLuint;
 
     unsafe fn from_raw(id: Self::Id) -> Self {
+        leaks::_track(ObjectKind::Framebuffer, id);
         Framebuffer {
             gl_id: id,
             _phantom: PhantomData
@@ -57,3 +77,329 @@ impl GLObject for Framebuffer {
         self.gl_id
     }
 }
+
+impl Framebuffer {
+    /// Take ownership of a framebuffer that was created outside of glitter (such
+    /// as by another library), after checking that `id` names a framebuffer
+    /// in the current context.
+    /// The framebuffer will be deleted when the returned object is dropped.
+    ///
+    /// Note that OpenGL doesn't create the framebuffer until the ID is first bound,
+    /// so an ID from `glGenFramebuffers` that was never bound is rejected.
+    ///
+    /// # Safety
+    /// The ID must not be owned by anything else (such as another glitter
+    /// object, or another library that will delete it). Otherwise, the
+    /// framebuffer will be deleted twice.
+    ///
+    /// # Failures
+    /// A [`GLError::InvalidObject`](../types/enum.GLError.html#variant.InvalidObject)
+    /// error will be returned if `id` is not a framebuffer.
+    ///
+    /// # See also
+    /// [`glIsFramebuffer`](http://docs.gl/es2/glIsFramebuffer) OpenGL docs
+    pub unsafe fn adopt<C: BaseContext>(id: GLuint, _gl: &C)
+        -> Result<Self, GLError>
+    {
+        let is_framebuffer = gl_fns::IsFramebuffer(id) == gl::TRUE;
+        if is_framebuffer {
+            Ok(Framebuffer::from_raw(id))
+        }
+        else {
+            Err(GLError::InvalidObject(ObjectKind::Framebuffer, id))
+        }
+    }
+}
+
+/// A handle to the default framebuffer, which is the framebuffer provided
+/// by the window system (usually the window itself). Unlike a
+/// [`Framebuffer`](struct.Framebuffer.html), the default framebuffer is
+/// never deleted by glitter, and it has no attachments that can be changed.
+///
+/// On most platforms, the default framebuffer has the ID 0, which is what
+/// [`DefaultFramebuffer::new`](struct.DefaultFramebuffer.html#method.new)
+/// uses. Some platforms (such as iOS) draw to the window using
+/// a framebuffer object instead, which can be wrapped with
+/// [`DefaultFramebuffer::from_raw`]
+/// (struct.DefaultFramebuffer.html#method.from_raw).
+///
+/// # See also
+/// [`gl.bind_default_framebuffer`](../context/framebuffer_context/trait.FramebufferContext.html#method.bind_default_framebuffer):
+/// Bind the default framebuffer to a context, such as after drawing to
+/// a framebuffer object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultFramebuffer {
+    gl_id: GLuint
+}
+
+impl DefaultFramebuffer {
+    /// Get the default framebuffer with the ID 0.
+    pub fn new() -> Self {
+        DefaultFramebuffer { gl_id: 0 }
+    }
+
+    /// Get a default framebuffer with a different ID, for platforms where
+    /// the window system provides a framebuffer object to draw to.
+    ///
+    /// # Safety
+    /// `id` must name the framebuffer that the window system presents. The
+    /// framebuffer must not be deleted while the handle is used.
+    pub unsafe fn from_raw(id: GLuint) -> Self {
+        DefaultFramebuffer { gl_id: id }
+    }
+
+    /// Get the ID of the default framebuffer.
+    pub fn id(&self) -> GLuint {
+        self.gl_id
+    }
+
+    /// Get the width and height of the default framebuffer, in pixels.
+    /// For a framebuffer object (see [`DefaultFramebuffer::from_raw`]
+    /// (struct.DefaultFramebuffer.html#method.from_raw)), this is the size
+    /// of the renderbuffer attached to `COLOR_ATTACHMENT0`. For the
+    /// framebuffer with the ID 0, OpenGL has no way to query the size, so
+    /// it is queried from EGL when the `headless` or `offscreen` feature is
+    /// enabled.
+    ///
+    /// Returns `None` if the size could not be determined, in which case
+    /// the size must be tracked by the windowing system instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let window = glitter::DefaultFramebuffer::new();
+    /// if let Some(viewport) = window.viewport(&gl) {
+    ///     gl.viewport(viewport);
+    /// }
+    /// ```
+    pub fn dimensions<C: BaseContext>(&self, _gl: &C) -> Option<(u32, u32)> {
+        unsafe {
+            if self.gl_id == 0 {
+                _window_dimensions()
+            }
+            else {
+                _with_framebuffer(self.gl_id, || _attachment_dimensions())
+            }
+        }
+    }
+
+    /// Get a viewport that covers the whole default framebuffer, such as
+    /// after the window was resized. Returns `None` if the size could not
+    /// be determined (see [`DefaultFramebuffer::dimensions`]
+    /// (struct.DefaultFramebuffer.html#method.dimensions)).
+    pub fn viewport<C: BaseContext>(&self, gl: &C) -> Option<Viewport> {
+        self.dimensions(gl).map(|(width, height)| {
+            Viewport::new(0, 0, width, height)
+        })
+    }
+
+    /// Query the size, sample count, and bit depths of the default
+    /// framebuffer.
+    pub fn info<C: BaseContext>(&self, gl: &C) -> DefaultFramebufferInfo {
+        let dimensions = self.dimensions(gl);
+
+        // NOTE: `GL_RED_BITS` (and friends) were removed from core desktop
+        //       OpenGL, so the attachments are queried instead
+        let query_attachments = match gl.get_extensions().version() {
+            Some(version) => { !version.es && version.is_at_least(3, 0) },
+            None => { false }
+        };
+
+        unsafe {
+            _with_framebuffer(self.gl_id, || {
+                let mut samples = 0;
+                gl_fns::GetIntegerv(gl::SAMPLES, &mut samples);
+
+                let bits = if query_attachments {
+                    _attachment_bits(self.gl_id == 0)
+                }
+                else {
+                    _integer_bits()
+                };
+                dbg_gl_sanity_check! {
+                    _ => "Unknown error while querying the default framebuffer"
+                }
+
+                DefaultFramebufferInfo {
+                    dimensions: dimensions,
+                    samples: samples as u32,
+                    red_bits: bits[0],
+                    green_bits: bits[1],
+                    blue_bits: bits[2],
+                    alpha_bits: bits[3],
+                    depth_bits: bits[4],
+                    stencil_bits: bits[5]
+                }
+            })
+        }
+    }
+}
+
+impl Default for DefaultFramebuffer {
+    fn default() -> Self {
+        DefaultFramebuffer::new()
+    }
+}
+
+/// The size and format of a default framebuffer, as returned by
+/// [`DefaultFramebuffer::info`](struct.DefaultFramebuffer.html#method.info).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultFramebufferInfo {
+    /// The width and height of the framebuffer in pixels, or `None` if they
+    /// could not be determined.
+    pub dimensions: Option<(u32, u32)>,
+
+    /// The number of samples per pixel, or 0 if the framebuffer is not
+    /// multisampled.
+    pub samples: u32,
+
+    /// The number of bits in the red channel of the color buffer.
+    pub red_bits: u32,
+
+    /// The number of bits in the green channel of the color buffer.
+    pub green_bits: u32,
+
+    /// The number of bits in the blue channel of the color buffer.
+    pub blue_bits: u32,
+
+    /// The number of bits in the alpha channel of the color buffer.
+    pub alpha_bits: u32,
+
+    /// The number of bits in the depth buffer, or 0 if there is no depth
+    /// buffer.
+    pub depth_bits: u32,
+
+    /// The number of bits in the stencil buffer, or 0 if there is no
+    /// stencil buffer.
+    pub stencil_bits: u32
+}
+
+// Temporarily bind the framebuffer with the given ID, call `f`, and then
+// restore the previous binding
+unsafe fn _with_framebuffer<F, T>(id: GLuint, f: F) -> T
+    where F: FnOnce() -> T
+{
+    let mut prev_id = 0;
+    gl_fns::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut prev_id);
+    let prev_id = prev_id as GLuint;
+
+    if prev_id != id {
+        gl_fns::BindFramebuffer(gl::FRAMEBUFFER, id);
+    }
+    let result = f();
+    if prev_id != id {
+        gl_fns::BindFramebuffer(gl::FRAMEBUFFER, prev_id);
+    }
+    result
+}
+
+#[cfg(any(feature = "headless", feature = "offscreen"))]
+unsafe fn _window_dimensions() -> Option<(u32, u32)> {
+    egl::_current_surface_size()
+}
+
+#[cfg(not(any(feature = "headless", feature = "offscreen")))]
+unsafe fn _window_dimensions() -> Option<(u32, u32)> {
+    None
+}
+
+// Get the size of the renderbuffer attached to `COLOR_ATTACHMENT0` of the
+// bound framebuffer object
+unsafe fn _attachment_dimensions() -> Option<(u32, u32)> {
+    let mut object_type = 0;
+    gl_fns::GetFramebufferAttachmentParameteriv(
+        gl::FRAMEBUFFER,
+        gl::COLOR_ATTACHMENT0,
+        gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE,
+        &mut object_type
+    );
+    if object_type as GLenum != gl::RENDERBUFFER {
+        return None;
+    }
+
+    let mut renderbuffer_id = 0;
+    gl_fns::GetFramebufferAttachmentParameteriv(
+        gl::FRAMEBUFFER,
+        gl::COLOR_ATTACHMENT0,
+        gl::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME,
+        &mut renderbuffer_id
+    );
+
+    let mut prev_id = 0;
+    gl_fns::GetIntegerv(gl::RENDERBUFFER_BINDING, &mut prev_id);
+    gl_fns::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer_id as GLuint);
+
+    let (mut width, mut height) = (0, 0);
+    gl_fns::GetRenderbufferParameteriv(gl::RENDERBUFFER,
+                                       gl::RENDERBUFFER_WIDTH,
+                                       &mut width);
+    gl_fns::GetRenderbufferParameteriv(gl::RENDERBUFFER,
+                                       gl::RENDERBUFFER_HEIGHT,
+                                       &mut height);
+
+    gl_fns::BindRenderbuffer(gl::RENDERBUFFER, prev_id as GLuint);
+    dbg_gl_sanity_check! {
+        _ => "Unknown error while querying the framebuffer's size"
+    }
+
+    Some((width as u32, height as u32))
+}
+
+// Get the red, green, blue, alpha, depth, and stencil bits of the bound
+// framebuffer, using the `GL_*_BITS` queries
+unsafe fn _integer_bits() -> [u32; 6] {
+    let pnames = [RED_BITS, GREEN_BITS, BLUE_BITS,
+                  ALPHA_BITS, DEPTH_BITS, STENCIL_BITS];
+    let mut bits = [0; 6];
+    for (bits, &pname) in bits.iter_mut().zip(pnames.iter()) {
+        let mut value = 0;
+        gl_fns::GetIntegerv(pname, &mut value);
+        *bits = value as u32;
+    }
+    bits
+}
+
+// Get the red, green, blue, alpha, depth, and stencil bits of the bound
+// framebuffer by querying its attachments. The window system's framebuffer
+// uses different attachment names than framebuffer objects.
+unsafe fn _attachment_bits(window: bool) -> [u32; 6] {
+    let (color, depth, stencil) = if window {
+        (gl::BACK_LEFT, gl::DEPTH, gl::STENCIL)
+    }
+    else {
+        (gl::COLOR_ATTACHMENT0, gl::DEPTH_ATTACHMENT, gl::STENCIL_ATTACHMENT)
+    };
+    let queries = [
+        (color, gl::FRAMEBUFFER_ATTACHMENT_RED_SIZE),
+        (color, gl::FRAMEBUFFER_ATTACHMENT_GREEN_SIZE),
+        (color, gl::FRAMEBUFFER_ATTACHMENT_BLUE_SIZE),
+        (color, gl::FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE),
+        (depth, gl::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE),
+        (stencil, gl::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE)
+    ];
+
+    let mut bits = [0; 6];
+    for (bits, &(attachment, pname)) in bits.iter_mut().zip(queries.iter()) {
+        // NOTE: Querying the size of a missing attachment is an error
+        let mut object_type = 0;
+        gl_fns::GetFramebufferAttachmentParameteriv(
+            gl::FRAMEBUFFER,
+            attachment,
+            gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE,
+            &mut object_type
+        );
+        if object_type as GLenum == gl::NONE {
+            continue;
+        }
+
+        let mut value = 0;
+        gl_fns::GetFramebufferAttachmentParameteriv(gl::FRAMEBUFFER,
+                                                    attachment,
+                                                    pname,
+                                                    &mut value);
+        *bits = value as u32;
+    }
+    bits
+}
@@ -0,0 +1,138 @@
+//! Contains [`FullscreenTriangle`](struct.FullscreenTriangle.html), a single
+//! triangle that covers the whole viewport, for drawing post-processing
+//! passes without setting up a vertex buffer for each pass.
+//!
+//! # Example
+//! ```no_run
+//! use glitter::prelude::*;
+//! use glitter::fullscreen::{FullscreenTriangle, FullscreenContext};
+//!
+//! let mut gl = unsafe { glitter::Context::current_context() };
+//! let mut program = gl.build_program(&[])
+//!     .vertex_shader(r##"
+//!         attribute vec2 position;
+//!         varying vec2 v_tex_coord;
+//!         void main() {
+//!             v_tex_coord = position * 0.5 + 0.5;
+//!             gl_Position = vec4(position, 0.0, 1.0);
+//!         }
+//!     "##)
+//!     .fragment_shader(r##"
+//!         varying mediump vec2 v_tex_coord;
+//!         uniform sampler2D scene;
+//!         void main() { gl_FragColor = texture2D(scene, v_tex_coord); }
+//!     "##)
+//!     .unwrap();
+//! let position = gl.get_attrib_location(&program, "position").unwrap();
+//!
+//! let mut fullscreen = FullscreenTriangle::new();
+//!
+//! let (gl_program, mut gl) = gl.use_program(&mut program);
+//! gl.draw_fullscreen(&mut fullscreen, &gl_program, position);
+//! ```
+
+use context::{ArrayBufferContext, ProgramBinding};
+use program::ProgramAttrib;
+use vertex_data::{VertexData, VertexDatum, VertexAttribute};
+use vertex_buffer::{VertexBuffer, AttribBinder, ContextVertexBufferExt,
+                    VertexBufferContext};
+use buffer::STATIC_DRAW;
+use types::TRIANGLES;
+use std::mem;
+
+/// The vertex type of a [`FullscreenTriangle`]
+/// (struct.FullscreenTriangle.html), which has a single `position`
+/// attribute in normalized device coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FullscreenVertex {
+    /// The position of the vertex, in normalized device coordinates.
+    pub position: [f32; 2]
+}
+
+unsafe impl VertexData for FullscreenVertex {
+    fn visit_attributes<F>(mut f: F)
+        where F: FnMut(VertexAttribute)
+    {
+        f(VertexAttribute {
+            ty: <[f32; 2] as VertexDatum>::attrib_type(),
+            name: "position".into(),
+            stride: mem::size_of::<FullscreenVertex>(),
+            offset: 0
+        });
+    }
+}
+
+// A triangle that covers the [-1, 1] square of normalized device
+// coordinates. The parts of the triangle outside of the square are clipped,
+// which avoids the seam along the diagonal of a two-triangle quad.
+const FULLSCREEN_VERTICES: [FullscreenVertex; 3] = [
+    FullscreenVertex { position: [-1.0, -1.0] },
+    FullscreenVertex { position: [3.0, -1.0] },
+    FullscreenVertex { position: [-1.0, 3.0] }
+];
+
+/// A triangle that covers the whole viewport. The vertex buffer is created
+/// the first time the triangle is drawn (see [`gl.draw_fullscreen`]
+/// (trait.FullscreenContext.html#method.draw_fullscreen)), so a
+/// `FullscreenTriangle` can be created before a context is available.
+pub struct FullscreenTriangle {
+    vbo: Option<VertexBuffer<FullscreenVertex>>
+}
+
+impl FullscreenTriangle {
+    /// Create a new `FullscreenTriangle`.
+    pub fn new() -> Self {
+        FullscreenTriangle { vbo: None }
+    }
+
+    /// Get the triangle's vertex buffer, or `None` if the triangle hasn't
+    /// been drawn yet.
+    pub fn vertex_buffer(&self) -> Option<&VertexBuffer<FullscreenVertex>> {
+        self.vbo.as_ref()
+    }
+}
+
+impl Default for FullscreenTriangle {
+    fn default() -> Self {
+        FullscreenTriangle::new()
+    }
+}
+
+/// An OpenGL context that can draw a [`FullscreenTriangle`]
+/// (struct.FullscreenTriangle.html). Any context with a free
+/// `GL_ARRAY_BUFFER` binding is a `FullscreenContext`.
+pub trait FullscreenContext: ArrayBufferContext + Sized {
+    /// Draw a triangle that covers the whole viewport with the current
+    /// program. `position` is the program's `vec2` position attribute,
+    /// which is given the corners of the triangle in normalized device
+    /// coordinates (so the viewport spans from -1 to 1). The triangle's
+    /// vertex buffer is bound to `GL_ARRAY_BUFFER` while drawing, and is
+    /// created and filled on the first draw.
+    fn draw_fullscreen(self,
+                       fullscreen: &mut FullscreenTriangle,
+                       _gl_program: &ProgramBinding,
+                       position: ProgramAttrib)
+    {
+        let is_new = fullscreen.vbo.is_none();
+        if is_new {
+            fullscreen.vbo = Some(self.new_vertex_buffer());
+        }
+        let vbo = fullscreen.vbo.as_mut().unwrap();
+
+        // NOTE: The attribute is rebound on every draw, since each program
+        //       can have its position attribute at a different location
+        let mut attribs = AttribBinder::new();
+        attribs.add("position", position).unwrap();
+        vbo.bind_attrib_pointers(attribs);
+
+        let (mut gl_vbo, gl) = self.bind_vertex_buffer(vbo);
+        if is_new {
+            gl.buffer_vertices(&mut gl_vbo, &FULLSCREEN_VERTICES, STATIC_DRAW);
+        }
+        gl.draw_arrays_vbo(&gl_vbo, TRIANGLES);
+    }
+}
+
+impl<C: ArrayBufferContext> FullscreenContext for C {
+
+}
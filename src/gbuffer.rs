@@ -0,0 +1,245 @@
+//! Contains [`GBuffer`](struct.GBuffer.html), a framebuffer with several
+//! color textures and a depth texture, for deferred rendering.
+//!
+//! # Example
+//! ```no_run
+//! use glitter::prelude::*;
+//! use glitter::{RGBA8, RGBA16F};
+//! use glitter::gbuffer::GBufferBuilder;
+//!
+//! let mut gl = unsafe { glitter::Context::current_context() };
+//!
+//! // Albedo, normals, and positions
+//! let mut gbuffer = GBufferBuilder::new(&mut gl, 1280, 720)
+//!     .color(RGBA8)
+//!     .color(RGBA16F)
+//!     .color(RGBA16F)
+//!     .unwrap();
+//!
+//! {
+//!     let (gl_fbo, gl) = gbuffer.begin_pass(&mut gl);
+//!     // Draw the scene, writing to `gl_FragData[0]` through
+//!     // `gl_FragData[2]`...
+//! }
+//!
+//! // ...then sample `gbuffer.colors()` and `gbuffer.depth()` in the
+//! // lighting pass
+//! ```
+
+use context::{ContextExt, ContextFramebufferExt,
+              ContextFramebufferBuilderExt, FramebufferContext,
+              FramebufferBinding, FramebufferAttachment, DEPTH_ATTACHMENT,
+              ContextTextureBuilderExt, SomeTextureUnitContext};
+use framebuffer::Framebuffer;
+use texture::{Texture2d, NEAREST, CLAMP_TO_EDGE};
+use image_data::{RenderbufferFormat, DEPTH_COMPONENT24};
+use types::{GLError, BuilderError, Viewport,
+            COLOR_BUFFER_BIT, DEPTH_BUFFER_BIT};
+
+// The number of color attachment points in `FramebufferAttachment`
+const MAX_COLORS: u32 = 8;
+
+/// Provides a safe interface for building a [`GBuffer`]
+/// (struct.GBuffer.html). Each call to [`color`]
+/// (struct.GBufferBuilder.html#method.color) adds a color texture, which
+/// is attached to the next color attachment point, and is written by the
+/// fragment shader output with the same index.
+pub struct GBufferBuilder<'a, C: 'a> {
+    gl: &'a mut C,
+    width: u32,
+    height: u32,
+    colors: Vec<RenderbufferFormat>,
+    depth: RenderbufferFormat
+}
+
+impl<'a, C: 'a> GBufferBuilder<'a, C>
+    where for<'b> &'b mut C: SomeTextureUnitContext + FramebufferContext
+{
+    /// Create a new G-buffer builder, for a G-buffer that is `width` by
+    /// `height` pixels. The G-buffer has a `DEPTH_COMPONENT24` depth
+    /// texture unless a different format is set with [`depth`]
+    /// (struct.GBufferBuilder.html#method.depth).
+    pub fn new(gl: &'a mut C, width: u32, height: u32) -> Self {
+        GBufferBuilder {
+            gl: gl,
+            width: width,
+            height: height,
+            colors: vec![],
+            depth: DEPTH_COMPONENT24
+        }
+    }
+
+    /// Add a color texture with the given format. See [`gl.tex_image_2d_sized`]
+    /// (../context/texture_context/trait.ContextTextureExt.html#method.tex_image_2d_sized)
+    /// for details about the supported formats.
+    pub fn color(mut self, format: RenderbufferFormat) -> Self {
+        self.colors.push(format);
+        self
+    }
+
+    /// Set the format of the depth texture, which must be
+    /// `DEPTH_COMPONENT16`, `DEPTH_COMPONENT24`, or `DEPTH_COMPONENT32F`.
+    pub fn depth(mut self, format: RenderbufferFormat) -> Self {
+        self.depth = format;
+        self
+    }
+
+    /// Create and return the G-buffer, or return an error. The textures
+    /// use `NEAREST` filtering and `CLAMP_TO_EDGE` wrapping.
+    ///
+    /// # Failures
+    /// An error will be returned if more than one color texture was added,
+    /// and the context doesn't support that many color attachments (see
+    /// [`ContextLimits::max_color_attachments`]
+    /// (../limits/struct.ContextLimits.html#structfield.max_color_attachments)
+    /// and [`ContextLimits::max_draw_buffers`]
+    /// (../limits/struct.ContextLimits.html#structfield.max_draw_buffers)).
+    /// An error will also be returned if one of the textures could not be
+    /// built (such as if its format isn't supported), or if the framebuffer
+    /// is not framebuffer-complete (some contexts can't render to every
+    /// combination of formats).
+    pub fn try_unwrap(self) -> Result<GBuffer, GLError> {
+        let gl = self.gl;
+        let count = self.colors.len() as u32;
+        if count > 1 {
+            let limits = (&mut *gl).get_limits();
+            if count > MAX_COLORS
+                || count > limits.max_color_attachments
+                || count > limits.max_draw_buffers
+            {
+                return Err(BuilderError::UnsupportedColorAttachments.into());
+            }
+        }
+
+        let mut colors = Vec::with_capacity(self.colors.len());
+        for &format in &self.colors {
+            let texture = try!((&mut *gl).build_texture_2d()
+                                          .empty_sized(format,
+                                                       self.width,
+                                                       self.height)
+                                          .min_filter(NEAREST)
+                                          .mag_filter(NEAREST)
+                                          .wrap_s(CLAMP_TO_EDGE)
+                                          .wrap_t(CLAMP_TO_EDGE)
+                                          .try_unwrap());
+            colors.push(texture);
+        }
+        let mut depth = try!((&mut *gl).build_texture_2d()
+                                       .depth(self.depth,
+                                              self.width,
+                                              self.height)
+                                       .min_filter(NEAREST)
+                                       .mag_filter(NEAREST)
+                                       .wrap_s(CLAMP_TO_EDGE)
+                                       .wrap_t(CLAMP_TO_EDGE)
+                                       .try_unwrap());
+
+        let framebuffer = {
+            let mut builder = (&mut *gl).build_framebuffer()
+                                        .texture_2d(DEPTH_ATTACHMENT,
+                                                    &mut depth,
+                                                    0);
+            for (index, texture) in colors.iter_mut().enumerate() {
+                let attachment = FramebufferAttachment::color(index as u32)
+                    .unwrap();
+                builder = builder.texture_2d(attachment, texture, 0);
+            }
+            try!(builder.try_unwrap())
+        };
+
+        Ok(GBuffer {
+            framebuffer: framebuffer,
+            colors: colors,
+            depth: depth,
+            width: self.width,
+            height: self.height
+        })
+    }
+
+    /// Create and return the G-buffer, or panic.
+    ///
+    /// # Panics
+    /// See the [`try_unwrap`](struct.GBufferBuilder.html#method.try_unwrap)
+    /// method docs for all of the possible failure cases when building
+    /// a G-buffer.
+    pub fn unwrap(self) -> GBuffer {
+        self.try_unwrap().unwrap()
+    }
+}
+
+/// A framebuffer with several color textures and a depth texture, as built
+/// by a [`GBufferBuilder`](struct.GBufferBuilder.html). Color texture `i`
+/// is attached to `COLOR_ATTACHMENTi`, and is written by fragment shader
+/// output `i`.
+pub struct GBuffer {
+    framebuffer: Framebuffer,
+    colors: Vec<Texture2d>,
+    depth: Texture2d,
+    width: u32,
+    height: u32
+}
+
+impl GBuffer {
+    /// Get the width and height of the G-buffer.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Get the viewport that covers the whole G-buffer, to use while
+    /// rendering to it.
+    pub fn viewport(&self) -> Viewport {
+        Viewport::new(0, 0, self.width, self.height)
+    }
+
+    /// Get the color textures, in the order they were added.
+    pub fn colors(&self) -> &[Texture2d] {
+        &self.colors
+    }
+
+    /// Get the color textures mutably, such as to bind them for drawing.
+    pub fn colors_mut(&mut self) -> &mut [Texture2d] {
+        &mut self.colors
+    }
+
+    /// Get the depth texture.
+    pub fn depth(&self) -> &Texture2d {
+        &self.depth
+    }
+
+    /// Get the depth texture mutably, such as to bind it for drawing.
+    pub fn depth_mut(&mut self) -> &mut Texture2d {
+        &mut self.depth
+    }
+
+    /// Get the framebuffer that renders to the G-buffer's textures.
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.framebuffer
+    }
+
+    /// Get the framebuffer that renders to the G-buffer's textures
+    /// mutably, such as to bind it.
+    pub fn framebuffer_mut(&mut self) -> &mut Framebuffer {
+        &mut self.framebuffer
+    }
+
+    /// Convert the G-buffer into its framebuffer, color textures, and depth
+    /// texture.
+    pub fn into_parts(self) -> (Framebuffer, Vec<Texture2d>, Texture2d) {
+        (self.framebuffer, self.colors, self.depth)
+    }
+
+    /// Start rendering to the G-buffer: bind its framebuffer, set the
+    /// viewport to cover the G-buffer, and clear its color and depth
+    /// buffers (using the current clear color). The viewport is left set
+    /// afterwards, so it should be reset before drawing to another
+    /// framebuffer.
+    pub fn begin_pass<C>(&mut self, gl: C) -> (FramebufferBinding, C::Rest)
+        where C: FramebufferContext
+    {
+        let viewport = self.viewport();
+        let (gl_fbo, gl) = gl.bind_framebuffer(&mut self.framebuffer);
+        gl.viewport(viewport);
+        gl.clear(COLOR_BUFFER_BIT | DEPTH_BUFFER_BIT);
+        (gl_fbo, gl)
+    }
+}
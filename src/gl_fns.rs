@@ -0,0 +1,720 @@
+// The OpenGL functions used by glitter. Rather than calling the `gl` crate
+// (whose function pointers are global), glitter calls the functions in this
+// module, which look up the function pointers owned by the context that is
+// active on this thread. Each context keeps the `GlFns` it was created with
+// (either its own, from `ContextOf::current_context_with`, or the ones
+// loaded by `ContextOf::load_with` at the time it was created), and makes
+// them active again with `ContextOf::activate`. With `debug_assertions`,
+// calling a function on a thread that never had an active context panics
+// before the call is made.
+//
+// This also includes extension functions that aren't in the `gl` crate's
+// bindings. Since a function pointer is only available if the driver
+// supports it, each function has an `is_loaded` function.
+//
+// NOTE: The `GL_ARB_shader_objects` functions (such as
+//       `glCreateProgramObjectARB`) are deliberately not used as fallbacks,
+//       since they take a `GLhandleARB`, which is a pointer rather than
+//       a `GLuint` on some platforms (such as macOS).
+
+#![allow(non_snake_case)]
+
+use std::cell::Cell;
+use std::fmt;
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use gl::types::*;
+use validate;
+
+/// A set of OpenGL function pointers, loaded with [`GlFns::load_with`]
+/// (struct.GlFns.html#method.load_with).
+///
+/// By default, glitter uses the function pointers loaded by
+/// [`Context::load_with`](context/struct.ContextOf.html#method.load_with),
+/// which are shared by every thread. A `GlFns` can be used to give
+/// a context its own function pointers instead (with
+/// [`Context::current_context_with`]
+/// (context/struct.ContextOf.html#method.current_context_with)), which is
+/// needed when a process uses multiple contexts from different OpenGL
+/// implementations (such as two windows on different GPUs).
+///
+/// A `GlFns` is a cheap handle that can be copied freely. The function
+/// pointers themselves are never freed, so a `GlFns` should be loaded once
+/// per OpenGL implementation, rather than once per context.
+#[derive(Clone, Copy)]
+pub struct GlFns {
+    table: &'static FnTable
+}
+
+impl fmt::Debug for GlFns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GlFns({:p})", self.table)
+    }
+}
+
+impl GlFns {
+    /// Load OpenGL function pointers using a function, which takes an
+    /// OpenGL function name, and returns the function pointer (or a null
+    /// pointer if the function isn't available).
+    ///
+    /// # Safety
+    /// `load_fn` must return function pointers that can be used as the
+    /// corresponding OpenGL functions.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # fn get_proc_address(_: &str) -> *const std::os::raw::c_void { unimplemented!() }
+    /// let fns = unsafe { glitter::GlFns::load_with(get_proc_address) };
+    /// let gl = unsafe { glitter::Context::current_context_with(fns) };
+    /// ```
+    pub unsafe fn load_with<L>(mut load_fn: L) -> GlFns
+        where L: FnMut(&str) -> *const GLvoid
+    {
+        let table = Box::new(FnTable::load_with(&mut load_fn));
+        GlFns { table: &*Box::into_raw(table) }
+    }
+}
+
+// The address of the `FnTable` loaded by `ContextOf::load_with`, or 0 if it
+// hasn't been called
+static DEFAULT_FNS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+thread_local! {
+    // The function pointers of the context that is active on this thread,
+    // or `None` if no context has been active on this thread
+    static CURRENT_FNS: Cell<Option<GlFns>> = Cell::new(None)
+}
+
+// Load the default function pointers, which are used by contexts that
+// don't have their own `GlFns`. The previous function pointers are leaked,
+// since existing contexts may still be using them.
+pub unsafe fn load_default<L>(load_fn: &mut L)
+    where L: FnMut(&str) -> *const GLvoid
+{
+    let table = Box::new(FnTable::load_with(load_fn));
+    DEFAULT_FNS.store(Box::into_raw(table) as usize, Ordering::SeqCst);
+}
+
+// Get the function pointers loaded by `ContextOf::load_with`, or `None` if
+// it hasn't been called yet
+pub fn default_fns() -> Option<GlFns> {
+    let table = DEFAULT_FNS.load(Ordering::SeqCst) as *const FnTable;
+    unsafe { table.as_ref() }.map(|table| GlFns { table: table })
+}
+
+// Set the function pointers of the context that is active on the current
+// thread
+pub fn set_current(fns: GlFns) {
+    CURRENT_FNS.with(|current| current.set(Some(fns)));
+}
+
+// Call a function with `fns` as the current thread's function pointers,
+// then switch back to the previous function pointers
+pub fn with<F, T>(fns: GlFns, f: F) -> T
+    where F: FnOnce() -> T
+{
+    let previous = CURRENT_FNS.with(|current| current.replace(Some(fns)));
+    let result = f();
+    CURRENT_FNS.with(|current| current.set(previous));
+    result
+}
+
+// Check if a context is active on the current thread. Threads without
+// a context are allowed while thread-locals are being destroyed, since
+// objects can be dropped then.
+fn _has_current() -> bool {
+    CURRENT_FNS.try_with(|current| current.get().is_some())
+        .unwrap_or(true)
+}
+
+fn _current_table() -> Option<&'static FnTable> {
+    // NOTE: `try_with` is used because objects can be dropped while
+    //       thread-locals are being destroyed
+    let current = CURRENT_FNS.try_with(|current| current.get())
+        .unwrap_or(None)
+        .or_else(default_fns);
+    current.map(|fns| fns.table)
+}
+
+// Load a function pointer, trying each fallback name if the function isn't
+// available under its main name
+fn _load<L>(load_fn: &mut L, name: &str, fallbacks: &[&str]) -> usize
+    where L: FnMut(&str) -> *const GLvoid
+{
+    let ptr = load_fn(name);
+    if !ptr.is_null() {
+        return ptr as usize;
+    }
+
+    for fallback in fallbacks {
+        let ptr = load_fn(fallback);
+        if !ptr.is_null() {
+            return ptr as usize;
+        }
+    }
+
+    0
+}
+
+macro_rules! gl_fns {
+    ($(
+        $(#[fallback = $fallback:expr])*
+        fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;
+    )+) => {
+        struct FnTable {
+            $($name: usize),+
+        }
+
+        impl FnTable {
+            fn load_with<L>(load_fn: &mut L) -> FnTable
+                where L: FnMut(&str) -> *const GLvoid
+            {
+                FnTable {
+                    $($name: _load(load_fn,
+                                   concat!("gl", stringify!($name)),
+                                   &[$($fallback),*])),+
+                }
+            }
+        }
+
+        $(
+            pub mod $name {
+                #[allow(dead_code)]
+                pub fn is_loaded() -> bool {
+                    super::_current_table()
+                        .map_or(false, |table| table.$name != 0)
+                }
+
+                #[allow(dead_code)]
+                pub fn is_loaded_in(fns: super::GlFns) -> bool {
+                    fns.table.$name != 0
+                }
+            }
+
+            #[inline]
+            pub unsafe fn $name($($arg: $ty),*) -> $ret {
+                if cfg!(debug_assertions) && !_has_current() {
+                    validate::no_context(concat!("gl", stringify!($name)));
+                }
+                let ptr = _current_table().map_or(0, |table| table.$name);
+                if ptr == 0 {
+                    panic!(concat!("gl", stringify!($name), " was not loaded"));
+                }
+                let f: extern "system" fn($($ty),*) -> $ret = mem::transmute(ptr);
+                f($($arg),*)
+            }
+        )+
+    }
+}
+
+gl_fns! {
+    #[fallback = "glActiveTextureARB"]
+    fn ActiveTexture(texture: GLenum) -> ();
+
+    fn AttachShader(program: GLuint, shader: GLuint) -> ();
+
+    fn BindAttribLocation(program: GLuint,
+                          index: GLuint,
+                          name: *const GLchar) -> ();
+
+    #[fallback = "glBindBufferARB"]
+    fn BindBuffer(target: GLenum, buffer: GLuint) -> ();
+
+    fn BindFramebuffer(target: GLenum, framebuffer: GLuint) -> ();
+
+    fn BindRenderbuffer(target: GLenum, renderbuffer: GLuint) -> ();
+
+    #[fallback = "glBindTextureEXT"]
+    fn BindTexture(target: GLenum, texture: GLuint) -> ();
+
+    fn BlendFunc(sfactor: GLenum, dfactor: GLenum) -> ();
+
+    #[fallback = "glBufferDataARB"]
+    fn BufferData(target: GLenum,
+                  size: GLsizeiptr,
+                  data: *const GLvoid,
+                  usage: GLenum) -> ();
+
+    #[fallback = "glBufferSubDataARB"]
+    fn BufferSubData(target: GLenum,
+                     offset: GLintptr,
+                     size: GLsizeiptr,
+                     data: *const GLvoid) -> ();
+
+    #[fallback = "glCheckFramebufferStatusEXT"]
+    fn CheckFramebufferStatus(target: GLenum) -> GLenum;
+
+    fn Clear(mask: GLbitfield) -> ();
+
+    fn ClearColor(red: GLfloat,
+                  green: GLfloat,
+                  blue: GLfloat,
+                  alpha: GLfloat) -> ();
+
+    #[fallback = "glClearDepthfOES"]
+    fn ClearDepthf(d: GLfloat) -> ();
+
+    fn ClearStencil(s: GLint) -> ();
+
+    #[fallback = "glClientWaitSyncAPPLE"]
+    fn ClientWaitSync(sync: GLsync,
+                      flags: GLbitfield,
+                      timeout: GLuint64) -> GLenum;
+
+    fn CompileShader(shader: GLuint) -> ();
+
+    #[fallback = "glCompressedTexImage2DARB"]
+    fn CompressedTexImage2D(target: GLenum,
+                            level: GLint,
+                            internal_format: GLenum,
+                            width: GLsizei,
+                            height: GLsizei,
+                            border: GLint,
+                            image_size: GLsizei,
+                            data: *const GLvoid) -> ();
+
+    #[fallback = "glCopyBufferSubDataNV"]
+    fn CopyBufferSubData(read_target: GLenum,
+                         write_target: GLenum,
+                         read_offset: GLintptr,
+                         write_offset: GLintptr,
+                         size: GLsizeiptr) -> ();
+
+    #[fallback = "glCopyImageSubDataEXT"]
+    #[fallback = "glCopyImageSubDataOES"]
+    fn CopyImageSubData(src_name: GLuint,
+                        src_target: GLenum,
+                        src_level: GLint,
+                        src_x: GLint,
+                        src_y: GLint,
+                        src_z: GLint,
+                        dst_name: GLuint,
+                        dst_target: GLenum,
+                        dst_level: GLint,
+                        dst_x: GLint,
+                        dst_y: GLint,
+                        dst_z: GLint,
+                        src_width: GLsizei,
+                        src_height: GLsizei,
+                        src_depth: GLsizei) -> ();
+
+    fn CopyTexSubImage2D(target: GLenum,
+                         level: GLint,
+                         xoffset: GLint,
+                         yoffset: GLint,
+                         x: GLint,
+                         y: GLint,
+                         width: GLsizei,
+                         height: GLsizei) -> ();
+
+    fn CreateProgram() -> GLuint;
+
+    fn CreateShader(gl_type: GLenum) -> GLuint;
+
+    fn CullFace(mode: GLenum) -> ();
+
+    #[fallback = "glDebugMessageCallbackKHR"]
+    fn DebugMessageCallback(callback: GLDEBUGPROC,
+                            user_param: *const GLvoid) -> ();
+
+    #[fallback = "glDebugMessageControlKHR"]
+    fn DebugMessageControl(source: GLenum,
+                           gl_type: GLenum,
+                           severity: GLenum,
+                           count: GLsizei,
+                           ids: *const GLuint,
+                           enabled: GLboolean) -> ();
+
+    #[fallback = "glDebugMessageInsertKHR"]
+    fn DebugMessageInsert(source: GLenum,
+                          gl_type: GLenum,
+                          id: GLuint,
+                          severity: GLenum,
+                          length: GLsizei,
+                          buf: *const GLchar) -> ();
+
+    #[fallback = "glDeleteBuffersARB"]
+    fn DeleteBuffers(n: GLsizei, buffers: *const GLuint) -> ();
+
+    #[fallback = "glDeleteFramebuffersEXT"]
+    fn DeleteFramebuffers(n: GLsizei, framebuffers: *const GLuint) -> ();
+
+    fn DeleteProgram(program: GLuint) -> ();
+
+    #[fallback = "glDeleteRenderbuffersEXT"]
+    fn DeleteRenderbuffers(n: GLsizei, renderbuffers: *const GLuint) -> ();
+
+    fn DeleteShader(shader: GLuint) -> ();
+
+    #[fallback = "glDeleteSyncAPPLE"]
+    fn DeleteSync(sync: GLsync) -> ();
+
+    fn DeleteTextures(n: GLsizei, textures: *const GLuint) -> ();
+
+    fn DepthFunc(func: GLenum) -> ();
+
+    fn Disable(cap: GLenum) -> ();
+
+    #[fallback = "glDrawArraysEXT"]
+    fn DrawArrays(mode: GLenum, first: GLint, count: GLsizei) -> ();
+
+    #[fallback = "glDrawBuffersEXT"]
+    fn DrawBuffers(n: GLsizei, bufs: *const GLenum) -> ();
+
+    fn DrawElements(mode: GLenum,
+                    count: GLsizei,
+                    gl_type: GLenum,
+                    indices: *const GLvoid) -> ();
+
+    fn EGLImageTargetTexture2DOES(target: GLenum,
+                                  image: GLeglImageOES) -> ();
+
+    fn Enable(cap: GLenum) -> ();
+
+    #[fallback = "glEnableVertexAttribArrayARB"]
+    fn EnableVertexAttribArray(index: GLuint) -> ();
+
+    #[fallback = "glFenceSyncAPPLE"]
+    fn FenceSync(condition: GLenum, flags: GLbitfield) -> GLsync;
+
+    #[fallback = "glFramebufferRenderbufferEXT"]
+    fn FramebufferRenderbuffer(target: GLenum,
+                               attachment: GLenum,
+                               renderbuffertarget: GLenum,
+                               renderbuffer: GLuint) -> ();
+
+    #[fallback = "glFramebufferTextureARB"]
+    #[fallback = "glFramebufferTextureEXT"]
+    #[fallback = "glFramebufferTextureOES"]
+    fn FramebufferTexture(target: GLenum,
+                          attachment: GLenum,
+                          texture: GLuint,
+                          level: GLint) -> ();
+
+    #[fallback = "glFramebufferTexture2DEXT"]
+    fn FramebufferTexture2D(target: GLenum,
+                            attachment: GLenum,
+                            textarget: GLenum,
+                            texture: GLuint,
+                            level: GLint) -> ();
+
+    #[fallback = "glFramebufferTextureLayerEXT"]
+    fn FramebufferTextureLayer(target: GLenum,
+                               attachment: GLenum,
+                               texture: GLuint,
+                               level: GLint,
+                               layer: GLint) -> ();
+
+    fn Finish() -> ();
+
+    fn Flush() -> ();
+
+    fn FrontFace(mode: GLenum) -> ();
+
+    #[fallback = "glGenBuffersARB"]
+    fn GenBuffers(n: GLsizei, buffers: *mut GLuint) -> ();
+
+    #[fallback = "glGenFramebuffersEXT"]
+    fn GenFramebuffers(n: GLsizei, framebuffers: *mut GLuint) -> ();
+
+    #[fallback = "glGenRenderbuffersEXT"]
+    fn GenRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint) -> ();
+
+    fn GenTextures(n: GLsizei, textures: *mut GLuint) -> ();
+
+    #[fallback = "glGenerateMipmapEXT"]
+    fn GenerateMipmap(target: GLenum) -> ();
+
+    fn GetAttribLocation(program: GLuint, name: *const GLchar) -> GLint;
+
+    fn GetBooleanv(pname: GLenum, data: *mut GLboolean) -> ();
+
+    fn GetError() -> GLenum;
+
+    fn GetFloatv(pname: GLenum, data: *mut GLfloat) -> ();
+
+    #[fallback = "glGetFramebufferAttachmentParameterivEXT"]
+    fn GetFramebufferAttachmentParameteriv(target: GLenum,
+                                           attachment: GLenum,
+                                           pname: GLenum,
+                                           params: *mut GLint) -> ();
+
+    #[fallback = "glGetGraphicsResetStatusEXT"]
+    #[fallback = "glGetGraphicsResetStatusKHR"]
+    fn GetGraphicsResetStatus() -> GLenum;
+
+    fn GetIntegerv(pname: GLenum, data: *mut GLint) -> ();
+
+    fn GetProgramInfoLog(program: GLuint,
+                         buf_size: GLsizei,
+                         length: *mut GLsizei,
+                         info_log: *mut GLchar) -> ();
+
+    fn GetProgramiv(program: GLuint, pname: GLenum, params: *mut GLint) -> ();
+
+    #[fallback = "glGetRenderbufferParameterivEXT"]
+    fn GetRenderbufferParameteriv(target: GLenum,
+                                  pname: GLenum,
+                                  params: *mut GLint) -> ();
+
+    fn GetShaderInfoLog(shader: GLuint,
+                        buf_size: GLsizei,
+                        length: *mut GLsizei,
+                        info_log: *mut GLchar) -> ();
+
+    fn GetShaderiv(shader: GLuint, pname: GLenum, params: *mut GLint) -> ();
+
+    fn GetString(name: GLenum) -> *const GLubyte;
+
+    fn GetStringi(name: GLenum, index: GLuint) -> *const GLubyte;
+
+    #[fallback = "glGetSyncivAPPLE"]
+    fn GetSynciv(sync: GLsync,
+                 pname: GLenum,
+                 buf_size: GLsizei,
+                 length: *mut GLsizei,
+                 values: *mut GLint) -> ();
+
+    fn GetTexLevelParameteriv(target: GLenum,
+                              level: GLint,
+                              pname: GLenum,
+                              params: *mut GLint) -> ();
+
+    fn GetUniformLocation(program: GLuint, name: *const GLchar) -> GLint;
+
+    fn GetUniformfv(program: GLuint,
+                    location: GLint,
+                    params: *mut GLfloat) -> ();
+
+    fn GetUniformiv(program: GLuint,
+                    location: GLint,
+                    params: *mut GLint) -> ();
+
+    fn Hint(target: GLenum, mode: GLenum) -> ();
+
+    fn InsertEventMarkerEXT(length: GLsizei, marker: *const GLchar) -> ();
+
+    #[fallback = "glDiscardFramebufferEXT"]
+    fn InvalidateFramebuffer(target: GLenum,
+                             numAttachments: GLsizei,
+                             attachments: *const GLenum) -> ();
+
+    #[fallback = "glIsBufferARB"]
+    fn IsBuffer(buffer: GLuint) -> GLboolean;
+
+    fn IsEnabled(cap: GLenum) -> GLboolean;
+
+    #[fallback = "glIsFramebufferEXT"]
+    fn IsFramebuffer(framebuffer: GLuint) -> GLboolean;
+
+    fn IsProgram(program: GLuint) -> GLboolean;
+
+    #[fallback = "glIsRenderbufferEXT"]
+    fn IsRenderbuffer(renderbuffer: GLuint) -> GLboolean;
+
+    fn IsShader(shader: GLuint) -> GLboolean;
+
+    fn IsTexture(texture: GLuint) -> GLboolean;
+
+    fn LineWidth(width: GLfloat) -> ();
+
+    fn LinkProgram(program: GLuint) -> ();
+
+    #[fallback = "glMapBufferRangeEXT"]
+    fn MapBufferRange(target: GLenum,
+                      offset: GLintptr,
+                      length: GLsizeiptr,
+                      access: GLbitfield) -> *mut GLvoid;
+
+    fn PixelStorei(pname: GLenum, param: GLint) -> ();
+
+    #[fallback = "glPopDebugGroupKHR"]
+    fn PopDebugGroup() -> ();
+
+    fn PopGroupMarkerEXT() -> ();
+
+    #[fallback = "glPushDebugGroupKHR"]
+    fn PushDebugGroup(source: GLenum,
+                      id: GLuint,
+                      length: GLsizei,
+                      message: *const GLchar) -> ();
+
+    fn PushGroupMarkerEXT(length: GLsizei, marker: *const GLchar) -> ();
+
+    #[fallback = "glReadBufferNV"]
+    fn ReadBuffer(src: GLenum) -> ();
+
+    fn ReadPixels(x: GLint,
+                  y: GLint,
+                  width: GLsizei,
+                  height: GLsizei,
+                  format: GLenum,
+                  gl_type: GLenum,
+                  pixels: *mut GLvoid) -> ();
+
+    #[fallback = "glRenderbufferStorageEXT"]
+    fn RenderbufferStorage(target: GLenum,
+                           internalformat: GLenum,
+                           width: GLsizei,
+                           height: GLsizei) -> ();
+
+    #[fallback = "glRenderbufferStorageMultisampleEXT"]
+    #[fallback = "glRenderbufferStorageMultisampleANGLE"]
+    #[fallback = "glRenderbufferStorageMultisampleAPPLE"]
+    fn RenderbufferStorageMultisample(target: GLenum,
+                                      samples: GLsizei,
+                                      internalformat: GLenum,
+                                      width: GLsizei,
+                                      height: GLsizei) -> ();
+
+    #[fallback = "glSampleCoverageARB"]
+    fn SampleCoverage(value: GLfloat, invert: GLboolean) -> ();
+
+    fn Scissor(x: GLint, y: GLint, width: GLsizei, height: GLsizei) -> ();
+
+    fn ShaderBinary(count: GLsizei,
+                    shaders: *const GLuint,
+                    binaryformat: GLenum,
+                    binary: *const GLvoid,
+                    length: GLsizei) -> ();
+
+    fn ShaderSource(shader: GLuint,
+                    count: GLsizei,
+                    string: *const *const GLchar,
+                    length: *const GLint) -> ();
+
+    fn TexImage2D(target: GLenum,
+                  level: GLint,
+                  internalformat: GLint,
+                  width: GLsizei,
+                  height: GLsizei,
+                  border: GLint,
+                  format: GLenum,
+                  gl_type: GLenum,
+                  pixels: *const GLvoid) -> ();
+
+    fn TexImage2DMultisample(target: GLenum,
+                             samples: GLsizei,
+                             internalformat: GLenum,
+                             width: GLsizei,
+                             height: GLsizei,
+                             fixedsamplelocations: GLboolean) -> ();
+
+    #[fallback = "glTexImage3DOES"]
+    fn TexImage3D(target: GLenum,
+                  level: GLint,
+                  internalformat: GLint,
+                  width: GLsizei,
+                  height: GLsizei,
+                  depth: GLsizei,
+                  border: GLint,
+                  format: GLenum,
+                  gl_type: GLenum,
+                  pixels: *const GLvoid) -> ();
+
+    fn TexParameterfv(target: GLenum,
+                      pname: GLenum,
+                      params: *const GLfloat) -> ();
+
+    fn TexParameteriv(target: GLenum,
+                      pname: GLenum,
+                      params: *const GLint) -> ();
+
+    fn TexStorage2DMultisample(target: GLenum,
+                               samples: GLsizei,
+                               internalformat: GLenum,
+                               width: GLsizei,
+                               height: GLsizei,
+                               fixedsamplelocations: GLboolean) -> ();
+
+    fn TexSubImage2D(target: GLenum,
+                     level: GLint,
+                     xoffset: GLint,
+                     yoffset: GLint,
+                     width: GLsizei,
+                     height: GLsizei,
+                     format: GLenum,
+                     gl_type: GLenum,
+                     pixels: *const GLvoid) -> ();
+
+    #[fallback = "glUniform1fvARB"]
+    fn Uniform1fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> ();
+
+    #[fallback = "glUniform1ivARB"]
+    fn Uniform1iv(location: GLint, count: GLsizei, value: *const GLint) -> ();
+
+    #[fallback = "glUniform2fvARB"]
+    fn Uniform2fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> ();
+
+    #[fallback = "glUniform2ivARB"]
+    fn Uniform2iv(location: GLint, count: GLsizei, value: *const GLint) -> ();
+
+    #[fallback = "glUniform3fvARB"]
+    fn Uniform3fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> ();
+
+    #[fallback = "glUniform3ivARB"]
+    fn Uniform3iv(location: GLint, count: GLsizei, value: *const GLint) -> ();
+
+    #[fallback = "glUniform4fvARB"]
+    fn Uniform4fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> ();
+
+    #[fallback = "glUniform4ivARB"]
+    fn Uniform4iv(location: GLint, count: GLsizei, value: *const GLint) -> ();
+
+    #[fallback = "glUniformMatrix2fvARB"]
+    fn UniformMatrix2fv(location: GLint,
+                        count: GLsizei,
+                        transpose: GLboolean,
+                        value: *const GLfloat) -> ();
+
+    #[fallback = "glUniformMatrix3fvARB"]
+    fn UniformMatrix3fv(location: GLint,
+                        count: GLsizei,
+                        transpose: GLboolean,
+                        value: *const GLfloat) -> ();
+
+    #[fallback = "glUniformMatrix4fvARB"]
+    fn UniformMatrix4fv(location: GLint,
+                        count: GLsizei,
+                        transpose: GLboolean,
+                        value: *const GLfloat) -> ();
+
+    #[fallback = "glUnmapBufferARB"]
+    #[fallback = "glUnmapBufferOES"]
+    fn UnmapBuffer(target: GLenum) -> GLboolean;
+
+    fn UseProgram(program: GLuint) -> ();
+
+    fn ValidateProgram(program: GLuint) -> ();
+
+    #[fallback = "glVertexAttribDivisorANGLE"]
+    #[fallback = "glVertexAttribDivisorARB"]
+    #[fallback = "glVertexAttribDivisorEXT"]
+    #[fallback = "glVertexAttribDivisorNV"]
+    fn VertexAttribDivisor(index: GLuint, divisor: GLuint) -> ();
+
+    #[fallback = "glVertexAttribIPointerEXT"]
+    fn VertexAttribIPointer(index: GLuint,
+                            size: GLint,
+                            gl_type: GLenum,
+                            stride: GLsizei,
+                            pointer: *const GLvoid) -> ();
+
+    #[fallback = "glVertexAttribPointerARB"]
+    fn VertexAttribPointer(index: GLuint,
+                           size: GLint,
+                           gl_type: GLenum,
+                           normalized: GLboolean,
+                           stride: GLsizei,
+                           pointer: *const GLvoid) -> ();
+
+    fn Viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) -> ();
+}
@@ -0,0 +1,7 @@
+use half;
+use types::DataType;
+use vertex_data::VertexPrimitive;
+
+unsafe impl VertexPrimitive for half::f16 {
+    fn data_type() -> DataType { DataType::HalfFloat }
+}
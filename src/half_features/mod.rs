@@ -0,0 +1 @@
+mod half_vertex_data;
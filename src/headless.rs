@@ -0,0 +1,74 @@
+//! Creates OpenGL ES contexts without a window, using EGL. This is mostly
+//! useful for integration tests, which can then run against a software
+//! OpenGL implementation (such as Mesa's llvmpipe) on machines without
+//! a GPU or a display. Enabled with the `headless` feature, which requires
+//! `libEGL`.
+//!
+//! # Example
+//! ```no_run
+//! extern crate glitter;
+//! use glitter::prelude::*;
+//!
+//! # fn main() {
+//! let (_headless, mut gl) = unsafe {
+//!     glitter::headless::HeadlessContext::new().unwrap()
+//! };
+//! let mut buffer = gl.gen_buffer();
+//! let (mut gl_buffer, _) = gl.bind_array_buffer(&mut buffer);
+//! # }
+//! ```
+
+use std::ffi::CString;
+use context::Context;
+use types::GLError;
+use egl::*;
+
+/// An OpenGL ES context created without a window. The context is made
+/// current on the thread that created it, and is destroyed when the
+/// `HeadlessContext` is dropped.
+pub struct HeadlessContext {
+    egl: EglContext
+}
+
+impl HeadlessContext {
+    /// Create a new OpenGL ES context, make it current, load the OpenGL
+    /// function pointers, and return it along with a ready-to-use
+    /// [`Context`](../context/type.Context.html). An OpenGL ES 3 context is
+    /// created if possible; otherwise, an OpenGL ES 2 context is created.
+    ///
+    /// When the `EGL_MESA_platform_surfaceless` and
+    /// `EGL_KHR_surfaceless_context` extensions are available, the context
+    /// has no default framebuffer, so a [`Framebuffer`]
+    /// (../framebuffer/struct.Framebuffer.html) must be bound before
+    /// drawing. Otherwise, the context draws to a 1x1 pbuffer.
+    ///
+    /// # Failures
+    /// An error will be returned if EGL could not be initialized, or if
+    /// a context could not be created.
+    ///
+    /// # Safety
+    /// The same rules apply as for [`Context::current_context`]
+    /// (../context/struct.ContextOf.html#method.current_context). The
+    /// returned `Context` must not be used after the `HeadlessContext`
+    /// is dropped.
+    pub unsafe fn new() -> Result<(HeadlessContext, Context), GLError> {
+        let pbuffer_size = if _supports_surfaceless() {
+            None
+        }
+        else {
+            Some((1, 1))
+        };
+        let headless = HeadlessContext {
+            egl: try!(EglContext::new(pbuffer_size))
+        };
+        try!(headless.egl.make_current());
+
+        Context::load_with(|name| {
+            let name = CString::new(name).unwrap();
+            eglGetProcAddress(name.as_ptr())
+        });
+        let gl = Context::current_context();
+
+        Ok((headless, gl))
+    }
+}
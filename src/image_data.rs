@@ -3,7 +3,10 @@
 use std::ops;
 use std::mem;
 use std::slice;
+use std::cmp;
 use gl;
+use gl::types::*;
+use extensions::Extensions;
 
 /// A trait for types that that contain 2D image data, which can
 /// be uploaded to a texture using a [`Texture2dBuilder`]
@@ -24,8 +27,188 @@ pub trait Image2d {
     /// [`texel_bytes`](trait.Image2d.html#tymethod.texel_bytes) method.
     fn format(&self) -> ImageFormat;
 
-    /// Get the raw texel data of the image data, as a `u8` slice.
+    /// Get the raw texel data of the image data, as a `u8` slice. The
+    /// slice starts with the first texel of the first row, and each row
+    /// starts [`row_pitch`](trait.Image2d.html#method.row_pitch) bytes
+    /// after the previous one.
     fn texel_bytes(&self) -> &[u8];
+
+    /// Get the number of bytes from the start of one row of texels to the
+    /// start of the next. By default, rows are tightly packed, so the row
+    /// pitch is the width times the [`bytes_per_texel`]
+    /// (struct.ImageFormat.html#method.bytes_per_texel) of the format.
+    fn row_pitch(&self) -> usize {
+        self.width() * self.format().bytes_per_texel()
+    }
+
+    /// Returns `true` if the rows of the image should be flipped vertically
+    /// when uploaded. OpenGL expects the first row of an image to be the
+    /// bottom row, while most image formats (and the `image` crate) store
+    /// the top row first, so a flipped image appears upright when drawn
+    /// with texture coordinates that start at the bottom-left. Defaults to
+    /// `false` (see [`Image2dView::flip_y`]
+    /// (struct.Image2dView.html#method.flip_y)).
+    fn is_flipped_y(&self) -> bool {
+        false
+    }
+
+    /// Get a view of a rectangular region of the image, starting at
+    /// texel (`x`, `y`), without copying any texel data. The rows are
+    /// counted in the order they're stored, and the view is flipped if the
+    /// image is. The view can be
+    /// uploaded like any other `Image2d`, such as with [`gl.tex_sub_image_2d`]
+    /// (../context/texture_context/trait.ContextTextureExt.html#method.tex_sub_image_2d)
+    /// to update part of a texture from part of an atlas.
+    ///
+    /// # Panics
+    /// This function will panic if the region is not within the image.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::Image2d;
+    ///
+    /// let atlas = glitter::Pixels::new(256, 256);
+    /// let sprite = atlas.sub_image(32, 64, 16, 16);
+    /// assert_eq!(sprite.width(), 16);
+    /// assert_eq!(sprite.row_pitch(), atlas.row_pitch());
+    /// ```
+    fn sub_image(&self, x: usize, y: usize, width: usize, height: usize)
+        -> Image2dView
+    {
+        assert!(x + width <= self.width() && y + height <= self.height(),
+                "The region at ({}, {}) with size {}x{} is not within the {}x{} image",
+                x, y, width, height, self.width(), self.height());
+
+        let format = self.format();
+        let start = y * self.row_pitch() + x * format.bytes_per_texel();
+        let len = _image_len(width, height, self.row_pitch(), format);
+        let view = Image2dView::new(&self.texel_bytes()[start..(start + len)],
+                                    width,
+                                    height,
+                                    self.row_pitch(),
+                                    format);
+        if self.is_flipped_y() {
+            view.flip_y()
+        }
+        else {
+            view
+        }
+    }
+}
+
+// Get the number of bytes needed to hold the texels of an image, where the
+// last row doesn't need any padding
+fn _image_len(width: usize, height: usize, row_pitch: usize, format: ImageFormat)
+    -> usize
+{
+    if width == 0 || height == 0 {
+        0
+    }
+    else {
+        (height - 1) * row_pitch + width * format.bytes_per_texel()
+    }
+}
+
+/// A borrowed 2D image, whose rows may be padded or may be part of a larger
+/// image. An `Image2dView` can be created from a slice of texel data with
+/// [`Image2dView::new`](struct.Image2dView.html#method.new), or from
+/// another image with [`sub_image`](trait.Image2d.html#method.sub_image).
+#[derive(Debug, Clone, Copy)]
+pub struct Image2dView<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    row_pitch: usize,
+    format: ImageFormat,
+    flipped_y: bool
+}
+
+impl<'a> Image2dView<'a> {
+    /// Create a view of texel data with the given size and format, where
+    /// each row starts `row_pitch` bytes after the previous one.
+    ///
+    /// # Panics
+    /// This function will panic if `row_pitch` is smaller than a row of
+    /// texels, or if `bytes` is too small to hold the image.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{Image2d, Image2dView, ImageFormat};
+    ///
+    /// // A 3x2 RGB image, where each row is padded to 12 bytes
+    /// let bytes = [0u8; 21];
+    /// let image = Image2dView::new(&bytes, 3, 2, 12, ImageFormat::rgb8());
+    /// assert_eq!(image.height(), 2);
+    /// ```
+    pub fn new(bytes: &'a [u8],
+               width: usize,
+               height: usize,
+               row_pitch: usize,
+               format: ImageFormat)
+        -> Self
+    {
+        assert!(row_pitch >= width * format.bytes_per_texel(),
+                "A row pitch of {} bytes is too small for {} texels with format {:?}",
+                row_pitch, width, format);
+        let len = _image_len(width, height, row_pitch, format);
+        assert!(bytes.len() >= len,
+                "Image data is {} bytes, but a {}x{} image with format {:?} and row pitch {} needs at least {} bytes",
+                bytes.len(), width, height, format, row_pitch, len);
+
+        Image2dView {
+            bytes: bytes,
+            width: width,
+            height: height,
+            row_pitch: row_pitch,
+            format: format,
+            flipped_y: false
+        }
+    }
+
+    /// Flip the rows of the image vertically when it's uploaded, such as
+    /// for image data that stores the top row first (see [`is_flipped_y`]
+    /// (trait.Image2d.html#method.is_flipped_y)). Calling `flip_y` on a
+    /// flipped view un-flips it.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{Image2d, Image2dView, ImageFormat};
+    ///
+    /// let bytes = [0u8; 16];
+    /// let image = Image2dView::new(&bytes, 2, 2, 8, ImageFormat::rgba8());
+    /// assert!(image.flip_y().is_flipped_y());
+    /// assert!(!image.flip_y().flip_y().is_flipped_y());
+    /// ```
+    pub fn flip_y(mut self) -> Self {
+        self.flipped_y = !self.flipped_y;
+        self
+    }
+}
+
+impl<'a> Image2d for Image2dView<'a> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    fn texel_bytes(&self) -> &[u8] {
+        self.bytes
+    }
+
+    fn row_pitch(&self) -> usize {
+        self.row_pitch
+    }
+
+    fn is_flipped_y(&self) -> bool {
+        self.flipped_y
+    }
 }
 
 /// A single OpenGL color value, with `u8` components laid out
@@ -201,6 +384,143 @@ impl Pixels {
             pixels: vec![Pixel::default(); width * height]
         }
     }
+
+    /// Create an image filled with a single color.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{Pixel, Pixels};
+    ///
+    /// let red = Pixels::solid(4, 4, Pixel::rgb(0xFF0000));
+    /// assert_eq!(red[3][3].r, 0xFF);
+    /// ```
+    pub fn solid(width: usize, height: usize, color: Pixel) -> Self {
+        Pixels {
+            width: width,
+            height: height,
+            pixels: vec![color; width * height]
+        }
+    }
+
+    /// Create a 1x1 opaque white image. This is useful as a placeholder for
+    /// a missing texture, since sampling it leaves a color unchanged when
+    /// multiplied.
+    pub fn white() -> Self {
+        Pixels::solid(1, 1, Pixel::rgb(0xFFFFFF))
+    }
+
+    /// Create an image filled with a "flat" normal (pointing straight out
+    /// of the surface), encoded as the color `(0.5, 0.5, 1.0)`. This is
+    /// useful as a placeholder for a missing normal map.
+    pub fn flat_normal_map(width: usize, height: usize) -> Self {
+        Pixels::solid(width, height, Pixel::rgb(0x8080FF))
+    }
+
+    /// Create a checkerboard image made of `cell_size` by `cell_size`
+    /// squares, alternating between `first` and `second`. The square in
+    /// the first row and column is `first`.
+    ///
+    /// # Panics
+    /// This function will panic if `cell_size` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{Pixel, Pixels};
+    ///
+    /// let black = Pixel::rgb(0x000000);
+    /// let magenta = Pixel::rgb(0xFF00FF);
+    /// let checkerboard = Pixels::checkerboard(8, 8, 2, black, magenta);
+    /// assert_eq!(checkerboard[0][1].g, 0x00);
+    /// assert_eq!(checkerboard[0][2].r, 0xFF);
+    /// assert_eq!(checkerboard[2][2].r, 0x00);
+    /// ```
+    pub fn checkerboard(width: usize,
+                        height: usize,
+                        cell_size: usize,
+                        first: Pixel,
+                        second: Pixel)
+        -> Self
+    {
+        assert!(cell_size > 0, "Checkerboard cells must not be empty");
+
+        let mut pixels = Pixels::solid(width, height, first);
+        for y in 0..height {
+            for x in 0..width {
+                if (x / cell_size + y / cell_size) % 2 == 1 {
+                    pixels[y][x] = second;
+                }
+            }
+        }
+        pixels
+    }
+
+    /// Create an image with a gradient that goes from `left` in the first
+    /// column to `right` in the last column.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{Pixel, Pixels};
+    ///
+    /// let black = Pixel::rgb(0x000000);
+    /// let white = Pixel::rgb(0xFFFFFF);
+    /// let gradient = Pixels::horizontal_gradient(3, 1, black, white);
+    /// assert_eq!(gradient[0][0].r, 0x00);
+    /// assert_eq!(gradient[0][1].r, 0x80);
+    /// assert_eq!(gradient[0][2].r, 0xFF);
+    /// ```
+    pub fn horizontal_gradient(width: usize,
+                               height: usize,
+                               left: Pixel,
+                               right: Pixel)
+        -> Self
+    {
+        let mut pixels = Pixels::new(width, height);
+        for x in 0..width {
+            let color = _lerp_pixel(left, right, x, width);
+            for y in 0..height {
+                pixels[y][x] = color;
+            }
+        }
+        pixels
+    }
+
+    /// Create an image with a gradient that goes from `first` in the first
+    /// row to `last` in the last row. Since OpenGL treats the first row as
+    /// the bottom of a texture, `first` is the color at the bottom, unless
+    /// the image is flipped when uploading.
+    pub fn vertical_gradient(width: usize,
+                             height: usize,
+                             first: Pixel,
+                             last: Pixel)
+        -> Self
+    {
+        let mut pixels = Pixels::new(width, height);
+        for y in 0..height {
+            let color = _lerp_pixel(first, last, y, height);
+            for pixel in pixels[y].iter_mut() {
+                *pixel = color;
+            }
+        }
+        pixels
+    }
+}
+
+// Get the color of the `i`th of `n` steps of a gradient from `from` to `to`
+// (rounded to the nearest value)
+fn _lerp_pixel(from: Pixel, to: Pixel, i: usize, n: usize) -> Pixel {
+    if n <= 1 {
+        return from;
+    }
+
+    let steps = (n - 1) as u32;
+    let i = i as u32;
+    let lerp = |from: u8, to: u8| {
+        ((from as u32 * (steps - i) + to as u32 * i + steps / 2) / steps) as u8
+    };
+    Pixel::r_g_b_a(lerp(from.r, to.r),
+                   lerp(from.g, to.g),
+                   lerp(from.b, to.b),
+                   lerp(from.a, to.a))
 }
 
 impl ops::Index<usize> for Pixels {
@@ -242,10 +562,427 @@ impl Image2d for Pixels {
     }
 }
 
+/// A type that represents a single texel, with a fixed [`ImageFormat`]
+/// (struct.ImageFormat.html). Since the format is tied to the type, an
+/// image made of `Texel`s (such as [`Texels`](struct.Texels.html)) always
+/// has a valid texel type and format pair, which is checked at compile time.
+///
+/// # Safety
+/// The type must have the same size and layout as a texel with the
+/// format returned by `image_format` (such as using `#[repr(C)]`).
+pub unsafe trait Texel: Copy + Default {
+    /// Get the image format of this texel type.
+    fn image_format() -> ImageFormat;
+}
+
+unsafe impl Texel for Pixel {
+    fn image_format() -> ImageFormat {
+        ImageFormat::rgba8()
+    }
+}
+
+/// A packed RGB texel, with 5-bit/6-bit/5-bit components (the
+/// [`rgb565`](struct.ImageFormat.html#method.rgb565) format). This is
+/// useful for opaque textures that don't need full color precision, since
+/// it uses half as much memory as [`Pixel`](struct.Pixel.html).
+///
+/// # Example
+/// ```
+/// use glitter::{Pixel, Rgb565};
+///
+/// let texel = Rgb565::from(Pixel::rgb(0xFF8000));
+/// assert_eq!(texel.0, 0b11111_100000_00000);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Rgb565(pub u16);
+
+/// A packed RGBA texel, with 4 bits per component (the
+/// [`rgba4`](struct.ImageFormat.html#method.rgba4) format).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Rgba4444(pub u16);
+
+/// A packed RGBA texel, with 5-bit color components and a 1-bit alpha
+/// component (the [`rgb5_a1`](struct.ImageFormat.html#method.rgb5_a1)
+/// format).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Rgba5551(pub u16);
+
+unsafe impl Texel for Rgb565 {
+    fn image_format() -> ImageFormat {
+        ImageFormat::rgb565()
+    }
+}
+
+unsafe impl Texel for Rgba4444 {
+    fn image_format() -> ImageFormat {
+        ImageFormat::rgba4()
+    }
+}
+
+unsafe impl Texel for Rgba5551 {
+    fn image_format() -> ImageFormat {
+        ImageFormat::rgb5_a1()
+    }
+}
+
+// NOTE: Converting a `Pixel` to a packed texel drops the low bits of
+//       each component
+
+impl From<Pixel> for Rgb565 {
+    fn from(p: Pixel) -> Rgb565 {
+        let (r, g, b) = (p.r as u16, p.g as u16, p.b as u16);
+        Rgb565((r >> 3) << 11 | (g >> 2) << 5 | (b >> 3))
+    }
+}
+
+impl From<Pixel> for Rgba4444 {
+    fn from(p: Pixel) -> Rgba4444 {
+        let (r, g, b, a) = (p.r as u16, p.g as u16, p.b as u16, p.a as u16);
+        Rgba4444((r >> 4) << 12 | (g >> 4) << 8 | (b >> 4) << 4 | (a >> 4))
+    }
+}
+
+impl From<Pixel> for Rgba5551 {
+    fn from(p: Pixel) -> Rgba5551 {
+        let (r, g, b, a) = (p.r as u16, p.g as u16, p.b as u16, p.a as u16);
+        Rgba5551((r >> 3) << 11 | (g >> 3) << 6 | (b >> 3) << 1 | (a >> 7))
+    }
+}
+
+/// A (heap-allocated) 2D image composed of a list of texels of a single
+/// [`Texel`](trait.Texel.html) type, which determines the image's format.
+///
+/// # Example
+/// ```
+/// use glitter::{Image2d, Pixel, Pixels, Rgb565, Texels};
+///
+/// let ui = Pixels::solid(64, 64, Pixel::rgb(0x336699));
+/// let ui_565: Texels<Rgb565> = Texels::from_pixels(&ui);
+/// assert_eq!(ui_565.texel_bytes().len(), 64 * 64 * 2);
+/// ```
+#[derive(Clone)]
+pub struct Texels<T: Texel> {
+    width: usize,
+    height: usize,
+    texels: Vec<T>
+}
+
+impl<T: Texel> Texels<T> {
+    /// Create a new image with the desired width and height. Texels are
+    /// initialized with `T::default()`.
+    pub fn new(width: usize, height: usize) -> Self {
+        Texels::from_texels(vec![T::default(); width * height], width, height)
+    }
+
+    /// Create an image from a list of texels, starting with the first row.
+    ///
+    /// # Panics
+    /// This function will panic if `texels` doesn't have exactly
+    /// `width * height` texels.
+    pub fn from_texels(texels: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(texels.len(), width * height,
+                   "Expected {} texels for a {}x{} image",
+                   width * height, width, height);
+        Texels {
+            width: width,
+            height: height,
+            texels: texels
+        }
+    }
+
+    /// Convert an RGBA image into this texel type.
+    pub fn from_pixels(pixels: &Pixels) -> Self
+        where T: From<Pixel>
+    {
+        Texels {
+            width: pixels.width,
+            height: pixels.height,
+            texels: pixels.pixels.iter().map(|&p| T::from(p)).collect()
+        }
+    }
+
+    /// Convert the image into its list of texels.
+    pub fn into_texels(self) -> Vec<T> {
+        self.texels
+    }
+}
+
+impl<T: Texel> ops::Index<usize> for Texels<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        debug_assert!(row < self.height);
+
+        &self.texels[(row*self.width)..((row+1)*self.width)]
+    }
+}
+
+impl<T: Texel> ops::IndexMut<usize> for Texels<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        debug_assert!(row < self.height);
+
+        &mut self.texels[(row*self.width)..((row+1)*self.width)]
+    }
+}
+
+impl<T: Texel> Image2d for Texels<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn format(&self) -> ImageFormat {
+        T::image_format()
+    }
+
+    fn texel_bytes(&self) -> &[u8] {
+        let len = self.texels.len() * mem::size_of::<T>();
+        unsafe {
+            slice::from_raw_parts(self.texels.as_ptr() as *const u8, len)
+        }
+    }
+}
+
+/// An owned 2D image with any [`ImageFormat`](struct.ImageFormat.html),
+/// stored as raw texel data with tightly-packed rows.
+///
+/// # Example
+/// ```
+/// use glitter::{Image2d, Image2dBuf, ImageFormat};
+///
+/// // A 2x2 luminance image
+/// let image = Image2dBuf::new(vec![0x00, 0x55, 0xAA, 0xFF],
+///                             2, 2,
+///                             ImageFormat::luminance8());
+/// assert_eq!(image.texel_bytes().len(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Image2dBuf {
+    bytes: Vec<u8>,
+    width: usize,
+    height: usize,
+    format: ImageFormat,
+    flipped_y: bool
+}
+
+impl Image2dBuf {
+    /// Create an image from raw texel data with the given size and format.
+    ///
+    /// # Panics
+    /// This function will panic if `bytes` is too small to hold the image.
+    pub fn new(bytes: Vec<u8>, width: usize, height: usize, format: ImageFormat)
+        -> Self
+    {
+        let len = width * height * format.bytes_per_texel();
+        assert!(bytes.len() >= len,
+                "Image data is {} bytes, but a {}x{} image with format {:?} needs at least {} bytes",
+                bytes.len(), width, height, format, len);
+
+        Image2dBuf {
+            bytes: bytes,
+            width: width,
+            height: height,
+            format: format,
+            flipped_y: false
+        }
+    }
+
+    /// Flip the rows of the image vertically when it's uploaded (see
+    /// [`Image2dView::flip_y`](struct.Image2dView.html#method.flip_y)).
+    pub fn flip_y(mut self) -> Self {
+        self.flipped_y = !self.flipped_y;
+        self
+    }
+
+    /// Convert the image back into its raw texel data.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl Image2d for Image2dBuf {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    fn texel_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn is_flipped_y(&self) -> bool {
+        self.flipped_y
+    }
+}
+
+/// Generate the mipmap levels of an image on the CPU, by repeatedly
+/// averaging each 2x2 block of texels (a box filter) until the image
+/// is 1x1. The returned images are levels 1 and up; level 0 is `img`
+/// itself. This can be used instead of [`gl.generate_mipmap`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.generate_mipmap)
+/// for consistent results across drivers, and the levels can be uploaded
+/// with [`Texture2dBuilder::image_2d_with_mips`]
+/// (../context/texture_context/struct.Texture2dBuilder.html#method.image_2d_with_mips).
+///
+/// The levels are flipped if `img` is flipped (see [`is_flipped_y`]
+/// (trait.Image2d.html#method.is_flipped_y)).
+///
+/// # Example
+/// ```
+/// use glitter::{Image2d, Pixels};
+///
+/// let img = Pixels::new(8, 4);
+/// let mips = glitter::mipmap_chain(&img);
+/// let sizes: Vec<_> = mips.iter().map(|m| (m.width(), m.height())).collect();
+/// assert_eq!(sizes, vec![(4, 2), (2, 1), (1, 1)]);
+/// ```
+pub fn mipmap_chain(img: &Image2d) -> Vec<Image2dBuf> {
+    let mut levels: Vec<Image2dBuf> = vec![];
+    if img.width() == 0 || img.height() == 0 {
+        return levels;
+    }
+
+    let (mut width, mut height) = (img.width(), img.height());
+    while width > 1 || height > 1 {
+        let level = match levels.last() {
+            Some(prev) => { _downsample(prev) },
+            None => { _downsample(img) }
+        };
+        width = level.width();
+        height = level.height();
+        levels.push(level);
+    }
+
+    if img.is_flipped_y() {
+        levels.into_iter().map(|level| level.flip_y()).collect()
+    }
+    else {
+        levels
+    }
+}
+
+// Shrink an image to half its size (rounded down, but at least 1) by
+// averaging each 2x2 block of texels. With an odd width or height, the
+// last column or row is only averaged with its neighbor.
+fn _downsample(src: &Image2d) -> Image2dBuf {
+    let format = src.format();
+    let bytes_per_texel = format.bytes_per_texel();
+    let (src_width, src_height) = (src.width(), src.height());
+    let row_pitch = src.row_pitch();
+    let bytes = src.texel_bytes();
+
+    let width = cmp::max(1, src_width / 2);
+    let height = cmp::max(1, src_height / 2);
+    let mut out = Vec::with_capacity(width * height * bytes_per_texel);
+    for y in 0..height {
+        let rows = [2 * y, cmp::min(2 * y + 1, src_height - 1)];
+        for x in 0..width {
+            let columns = [2 * x, cmp::min(2 * x + 1, src_width - 1)];
+
+            let mut sum = [0u32; 4];
+            for &row in &rows {
+                for &column in &columns {
+                    let start = row * row_pitch + column * bytes_per_texel;
+                    let texel = &bytes[start..(start + bytes_per_texel)];
+                    let components = _unpack_texel(format, texel);
+                    for (total, component) in sum.iter_mut().zip(&components) {
+                        *total += *component;
+                    }
+                }
+            }
+
+            let mut average = [0u32; 4];
+            for (average, total) in average.iter_mut().zip(&sum) {
+                *average = (*total + 2) / 4;
+            }
+            _pack_texel(format, average, &mut out);
+        }
+    }
+
+    Image2dBuf::new(out, width, height, format)
+}
+
+// Get the shift and bit count of each component of a packed texel type
+fn _packed_components(texel_type: TexelType) -> &'static [(u32, u32)] {
+    match texel_type {
+        TexelType::UnsignedByte => { &[] },
+        TexelType::UnsignedShort565 => { &[(11, 5), (5, 6), (0, 5)] },
+        TexelType::UnsignedShort4444 => {
+            &[(12, 4), (8, 4), (4, 4), (0, 4)]
+        },
+        TexelType::UnsignedShort5551 => {
+            &[(11, 5), (6, 5), (1, 5), (0, 1)]
+        }
+    }
+}
+
+// Split a texel into its components (in the order they're stored, and
+// without scaling packed components)
+fn _unpack_texel(format: ImageFormat, texel: &[u8]) -> [u32; 4] {
+    let mut components = [0u32; 4];
+    match format.texel_type {
+        TexelType::UnsignedByte => {
+            for (component, &byte) in components.iter_mut().zip(texel) {
+                *component = byte as u32;
+            }
+        },
+        texel_type => {
+            // NOTE: Packed texels are stored in native byte order
+            let packed: u16 = unsafe {
+                mem::transmute([texel[0], texel[1]])
+            };
+            let packed_components = _packed_components(texel_type);
+            for (component, &(shift, bits)) in components.iter_mut()
+                                                         .zip(packed_components)
+            {
+                *component = (packed as u32 >> shift) & ((1 << bits) - 1);
+            }
+        }
+    }
+    components
+}
+
+// Combine components (as returned by `_unpack_texel`) into a texel, and
+// add its bytes to `out`
+fn _pack_texel(format: ImageFormat, components: [u32; 4], out: &mut Vec<u8>) {
+    match format.texel_type {
+        TexelType::UnsignedByte => {
+            let count = format.texel_format.components();
+            for &component in &components[..count] {
+                out.push(component as u8);
+            }
+        },
+        texel_type => {
+            let mut packed = 0u16;
+            let packed_components = _packed_components(texel_type);
+            for (&component, &(shift, _)) in components.iter()
+                                                       .zip(packed_components)
+            {
+                packed |= (component << shift) as u16;
+            }
+            let bytes: [u8; 2] = unsafe { mem::transmute(packed) };
+            out.extend_from_slice(&bytes);
+        }
+    }
+}
+
 gl_enum! {
     /// The data types for a texel.
     pub gl_enum TexelType {
-        /// Each texel is 4 * 8 bits.
+        /// Each component of a texel is 8 bits.
         pub const UnsignedByte as UNSIGNED_BYTE_TEXEL =
             gl::UNSIGNED_BYTE,
 
@@ -269,16 +1006,55 @@ gl_enum! {
         /// A texel contains only an alpha component.
         pub const Alpha as ALPHA = gl::ALPHA,
 
+        /// A texel contains only a red component. Requires OpenGL ES 3 or
+        /// the `GL_EXT_texture_rg` extension.
+        pub const Red as RED = gl::RED,
+
+        /// A texel contains red and green components. Requires OpenGL ES 3
+        /// or the `GL_EXT_texture_rg` extension.
+        pub const RG as RG = gl::RG,
+
         /// A texel contains red, green, and blue components.
         pub const RGB as RGB = gl::RGB,
 
         /// A texel contains red, green, blue, and alpha components.
-        pub const RGBA as RGBA = gl::RGBA
+        pub const RGBA as RGBA = gl::RGBA,
+
+        // NOTE: The luminance formats aren't included in the `gl` crate,
+        //       since they were removed from the OpenGL core profile
+
+        /// A texel contains a luminance component, which is used for the
+        /// red, green, and blue components when sampled (with an alpha
+        /// of 1.0).
+        pub const Luminance as LUMINANCE = 0x1909,
+
+        /// A texel contains a luminance component, which is used for the
+        /// red, green, and blue components when sampled, and an
+        /// alpha component.
+        pub const LuminanceAlpha as LUMINANCE_ALPHA = 0x190A
+    }
+}
+
+impl TexelFormat {
+    /// Returns the number of components in each texel with this format.
+    pub fn components(&self) -> usize {
+        match *self {
+            TexelFormat::Alpha
+            | TexelFormat::Red
+            | TexelFormat::Luminance => 1,
+            TexelFormat::RG
+            | TexelFormat::LuminanceAlpha => 2,
+            TexelFormat::RGB => 3,
+            TexelFormat::RGBA => 4
+        }
     }
 }
 
 gl_enum! {
-    /// The various image formats of a renderbuffer.
+    /// The various image formats of a renderbuffer. Only the first five
+    /// formats are supported by every context; use [`is_supported`]
+    /// (enum.RenderbufferFormat.html#method.is_supported) to check for the
+    /// others.
     pub gl_enum RenderbufferFormat {
         /// The red, green, blue, and alpha channels are all stored with 4 bits.
         pub const RGBA4 as RGBA4 = gl::RGBA4,
@@ -295,7 +1071,159 @@ gl_enum! {
         pub const DepthComponent16 as DEPTH_COMPONENT16 = gl::DEPTH_COMPONENT16,
 
         /// The renderbuffer stores an 8-bit stencil component.
-        pub const StencilIndex8 as STENCIL_INDEX8 = gl::STENCIL_INDEX8
+        pub const StencilIndex8 as STENCIL_INDEX8 = gl::STENCIL_INDEX8,
+
+        /// The red, green, and blue channels are stored with 8 bits.
+        pub const RGB8 as RGB8 = gl::RGB8,
+
+        /// The red, green, blue, and alpha channels are all stored with
+        /// 8 bits.
+        pub const RGBA8 as RGBA8 = gl::RGBA8,
+
+        /// The red channel is stored with 8 bits.
+        pub const R8 as R8 = gl::R8,
+
+        /// The red and green channels are stored with 8 bits.
+        pub const RG8 as RG8 = gl::RG8,
+
+        /// The red, green, and blue channels are stored with 10 bits, and
+        /// the alpha channel is stored with 2 bits.
+        pub const RGB10A2 as RGB10_A2 = gl::RGB10_A2,
+
+        /// The red, green, and blue channels are stored with 8 bits in the
+        /// sRGB color space, and the alpha channel is stored with 8 bits.
+        pub const SRGB8Alpha8 as SRGB8_ALPHA8 = gl::SRGB8_ALPHA8,
+
+        /// The red channel is stored as a 16-bit float.
+        pub const R16F as R16F = gl::R16F,
+
+        /// The red and green channels are stored as 16-bit floats.
+        pub const RG16F as RG16F = gl::RG16F,
+
+        /// The red, green, blue, and alpha channels are all stored as
+        /// 16-bit floats.
+        pub const RGBA16F as RGBA16F = gl::RGBA16F,
+
+        /// The red channel is stored as a 32-bit float.
+        pub const R32F as R32F = gl::R32F,
+
+        /// The red and green channels are stored as 32-bit floats.
+        pub const RG32F as RG32F = gl::RG32F,
+
+        /// The red, green, blue, and alpha channels are all stored as
+        /// 32-bit floats.
+        pub const RGBA32F as RGBA32F = gl::RGBA32F,
+
+        /// The red and green channels are stored as 11-bit floats, and the
+        /// blue channel is stored as a 10-bit float.
+        pub const R11FG11FB10F as R11F_G11F_B10F = gl::R11F_G11F_B10F,
+
+        /// The renderbuffer stores a 24-bit depth component.
+        pub const DepthComponent24 as DEPTH_COMPONENT24 = gl::DEPTH_COMPONENT24,
+
+        /// The renderbuffer stores a 32-bit floating-point depth component.
+        pub const DepthComponent32F as DEPTH_COMPONENT32F =
+            gl::DEPTH_COMPONENT32F,
+
+        /// The renderbuffer stores a 24-bit depth component and an 8-bit
+        /// stencil component.
+        pub const Depth24Stencil8 as DEPTH24_STENCIL8 = gl::DEPTH24_STENCIL8,
+
+        /// The renderbuffer stores a 32-bit floating-point depth component
+        /// and an 8-bit stencil component.
+        pub const Depth32FStencil8 as DEPTH32F_STENCIL8 = gl::DEPTH32F_STENCIL8
+    }
+}
+
+impl RenderbufferFormat {
+    /// Returns the number of bytes used by each pixel of a renderbuffer
+    /// with this format. Implementations may pad some formats (such as
+    /// `RGB8` or `DEPTH_COMPONENT24`) to use more memory than this.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match *self {
+            RenderbufferFormat::StencilIndex8
+            | RenderbufferFormat::R8 => 1,
+            RenderbufferFormat::RGBA4
+            | RenderbufferFormat::RGB565
+            | RenderbufferFormat::RGB5A1
+            | RenderbufferFormat::DepthComponent16
+            | RenderbufferFormat::RG8
+            | RenderbufferFormat::R16F => 2,
+            RenderbufferFormat::RGB8
+            | RenderbufferFormat::DepthComponent24 => 3,
+            RenderbufferFormat::RGBA8
+            | RenderbufferFormat::RGB10A2
+            | RenderbufferFormat::SRGB8Alpha8
+            | RenderbufferFormat::RG16F
+            | RenderbufferFormat::R32F
+            | RenderbufferFormat::R11FG11FB10F
+            | RenderbufferFormat::DepthComponent32F
+            | RenderbufferFormat::Depth24Stencil8 => 4,
+            RenderbufferFormat::Depth32FStencil8 => 5,
+            RenderbufferFormat::RGBA16F
+            | RenderbufferFormat::RG32F => 8,
+            RenderbufferFormat::RGBA32F => 16
+        }
+    }
+
+    /// Returns `true` if a renderbuffer can be created with this format,
+    /// given a context's extensions.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let extensions = gl.extensions();
+    /// let depth_format = if glitter::DEPTH24_STENCIL8.is_supported(extensions) {
+    ///     glitter::DEPTH24_STENCIL8
+    /// }
+    /// else {
+    ///     glitter::DEPTH_COMPONENT16
+    /// };
+    /// ```
+    pub fn is_supported(&self, extensions: &Extensions) -> bool {
+        match *self {
+            RenderbufferFormat::RGBA4
+            | RenderbufferFormat::RGB565
+            | RenderbufferFormat::RGB5A1
+            | RenderbufferFormat::DepthComponent16
+            | RenderbufferFormat::StencilIndex8 => true,
+            RenderbufferFormat::RGB8
+            | RenderbufferFormat::RGBA8 => {
+                extensions.has_rgba8_renderbuffer()
+            },
+            RenderbufferFormat::R8
+            | RenderbufferFormat::RG8 => { extensions.has_texture_rg() },
+            RenderbufferFormat::RGB10A2 => {
+                // NOTE: `RGB10_A2` is renderable in both desktop OpenGL 3.0
+                //       and OpenGL ES 3.0
+                extensions.version()
+                    .map_or(false, |version| version.is_at_least(3, 0))
+            },
+            RenderbufferFormat::SRGB8Alpha8 => { extensions.has_srgb() },
+            RenderbufferFormat::R16F
+            | RenderbufferFormat::RG16F
+            | RenderbufferFormat::RGBA16F => {
+                extensions.has_color_buffer_half_float()
+            },
+            RenderbufferFormat::R32F
+            | RenderbufferFormat::RG32F
+            | RenderbufferFormat::RGBA32F
+            | RenderbufferFormat::R11FG11FB10F => {
+                extensions.has_color_buffer_float()
+            },
+            RenderbufferFormat::DepthComponent24 => {
+                extensions.has_depth24()
+            },
+            RenderbufferFormat::Depth24Stencil8 => {
+                extensions.has_packed_depth_stencil()
+            },
+            RenderbufferFormat::DepthComponent32F
+            | RenderbufferFormat::Depth32FStencil8 => {
+                extensions.has_depth_buffer_float()
+            }
+        }
     }
 }
 
@@ -305,11 +1233,42 @@ pub struct ImageFormat {
     /// The texel type of the image.
     pub texel_type: TexelType,
 
-        /// The texel format of the image.
+    /// The texel format of the image.
     pub texel_format: TexelFormat
 }
 
 impl ImageFormat {
+    /// Create an image format from a texel type and format, or return
+    /// `None` if the texel type can't be used with the texel format. The
+    /// packed 16-bit texel types can only be used with the format that has
+    /// the same number of components (`UNSIGNED_SHORT_5_6_5` with `RGB`,
+    /// and `UNSIGNED_SHORT_4_4_4_4` or `UNSIGNED_SHORT_5_5_5_1` with
+    /// `RGBA`).
+    /// An image of [`Texel`](trait.Texel.html)s (such as [`Texels`]
+    /// (struct.Texels.html)) has its format checked at compile time instead.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{ImageFormat, UNSIGNED_SHORT_5_6_5, RGB, RGBA};
+    ///
+    /// assert!(ImageFormat::new(UNSIGNED_SHORT_5_6_5, RGB).is_some());
+    /// assert!(ImageFormat::new(UNSIGNED_SHORT_5_6_5, RGBA).is_none());
+    /// ```
+    pub fn new(texel_type: TexelType, texel_format: TexelFormat)
+        -> Option<Self>
+    {
+        let format = ImageFormat {
+            texel_type: texel_type,
+            texel_format: texel_format
+        };
+        if format.is_valid() {
+            Some(format)
+        }
+        else {
+            None
+        }
+    }
+
     /// Returns the RGBA image format with 8 bits per component.
     pub fn rgba8() -> Self {
         ImageFormat {
@@ -317,4 +1276,549 @@ impl ImageFormat {
             texel_format: TexelFormat::RGBA
         }
     }
+
+    /// Returns the RGB image format with 8 bits per component.
+    pub fn rgb8() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedByte,
+            texel_format: TexelFormat::RGB
+        }
+    }
+
+    /// Returns the single-channel (red) image format with 8 bits per
+    /// texel. Requires OpenGL ES 3 or the `GL_EXT_texture_rg` extension.
+    pub fn r8() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedByte,
+            texel_format: TexelFormat::Red
+        }
+    }
+
+    /// Returns the dual-channel (red and green) image format with 8 bits
+    /// per component. Requires OpenGL ES 3 or the `GL_EXT_texture_rg`
+    /// extension.
+    pub fn rg8() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedByte,
+            texel_format: TexelFormat::RG
+        }
+    }
+
+    /// Returns the packed RGB image format with 5-bit/6-bit/5-bit
+    /// components.
+    pub fn rgb565() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedShort565,
+            texel_format: TexelFormat::RGB
+        }
+    }
+
+    /// Returns the packed RGBA image format with 4 bits per component.
+    pub fn rgba4() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedShort4444,
+            texel_format: TexelFormat::RGBA
+        }
+    }
+
+    /// Returns the packed RGBA image format with 5-bit color components
+    /// and a 1-bit alpha component.
+    pub fn rgb5_a1() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedShort5551,
+            texel_format: TexelFormat::RGBA
+        }
+    }
+
+    /// Returns the alpha-only image format with 8 bits per texel.
+    pub fn alpha8() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedByte,
+            texel_format: TexelFormat::Alpha
+        }
+    }
+
+    /// Returns the luminance image format with 8 bits per texel.
+    pub fn luminance8() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedByte,
+            texel_format: TexelFormat::Luminance
+        }
+    }
+
+    /// Returns the luminance-alpha image format with 8 bits per component.
+    pub fn luminance_alpha8() -> Self {
+        ImageFormat {
+            texel_type: TexelType::UnsignedByte,
+            texel_format: TexelFormat::LuminanceAlpha
+        }
+    }
+
+    /// Returns `true` if the texel type can be used with the texel format
+    /// (see [`ImageFormat::new`](struct.ImageFormat.html#method.new)).
+    pub fn is_valid(&self) -> bool {
+        match self.texel_type {
+            TexelType::UnsignedByte => true,
+            TexelType::UnsignedShort565 => {
+                self.texel_format == TexelFormat::RGB
+            },
+            TexelType::UnsignedShort4444
+            | TexelType::UnsignedShort5551 => {
+                self.texel_format == TexelFormat::RGBA
+            }
+        }
+    }
+
+    /// Returns the number of bytes used by each texel of an image with
+    /// this format (without any row padding).
+    pub fn bytes_per_texel(&self) -> usize {
+        match self.texel_type {
+            TexelType::UnsignedByte => self.texel_format.components(),
+            TexelType::UnsignedShort565
+            | TexelType::UnsignedShort4444
+            | TexelType::UnsignedShort5551 => 2
+        }
+    }
+}
+
+/// A compressed texture format that the OpenGL implementation supports,
+/// as returned by [`gl.compressed_formats`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.compressed_formats).
+/// Compressed formats are mostly vendor-specific, so the supported
+/// formats should be checked before choosing which version of a texture
+/// to load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompressedFormat {
+    /// The OpenGL enum value for the format.
+    pub gl_enum: GLenum
+}
+
+impl CompressedFormat {
+    /// Get the compressed image format with a known block size (which can
+    /// be uploaded with [`gl.compressed_tex_image_2d`]
+    /// (../context/texture_context/trait.ContextTextureExt.html#method.compressed_tex_image_2d))
+    /// that matches this format, if there is one.
+    pub fn image_format(&self) -> Option<CompressedImageFormat> {
+        CompressedImageFormat::from_gl(self.gl_enum).ok()
+    }
+
+    /// Get the family of compression schemes that this format belongs to,
+    /// or `None` if the format isn't a well-known format.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::CompressedFormatFamily;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let families: Vec<_> = gl.compressed_formats()
+    ///     .iter()
+    ///     .filter_map(|format| format.family())
+    ///     .collect();
+    ///
+    /// let suffix = if families.contains(&CompressedFormatFamily::Astc) {
+    ///     "astc"
+    /// }
+    /// else if families.contains(&CompressedFormatFamily::Etc2) {
+    ///     "etc2"
+    /// }
+    /// else if families.contains(&CompressedFormatFamily::S3tc) {
+    ///     "dxt"
+    /// }
+    /// else {
+    ///     "png"
+    /// };
+    /// ```
+    pub fn family(&self) -> Option<CompressedFormatFamily> {
+        match self.gl_enum {
+            0x8D64 => Some(CompressedFormatFamily::Etc1),
+            0x9270...0x9279 => Some(CompressedFormatFamily::Etc2),
+            0x83F0...0x83F3
+            | 0x8C4C...0x8C4F => Some(CompressedFormatFamily::S3tc),
+            0x93B0...0x93BD
+            | 0x93D0...0x93DD => Some(CompressedFormatFamily::Astc),
+            0x8C00...0x8C03
+            | 0x9137...0x9138 => Some(CompressedFormatFamily::Pvrtc),
+            0x8E8C...0x8E8F => Some(CompressedFormatFamily::Bptc),
+            0x8DBB...0x8DBE => Some(CompressedFormatFamily::Rgtc),
+            0x8C92 | 0x8C93 | 0x87EE => Some(CompressedFormatFamily::Atc),
+            _ => None
+        }
+    }
+}
+
+/// The families of compressed texture formats, which usually correspond
+/// to the GPU vendors and platforms that support them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompressedFormatFamily {
+    /// ETC1 (`GL_OES_compressed_ETC1_RGB8_texture`), supported by most
+    /// OpenGL ES 2 devices on Android.
+    Etc1,
+
+    /// ETC2 and EAC, which are required by OpenGL ES 3.0.
+    Etc2,
+
+    /// S3TC/DXT (`GL_EXT_texture_compression_s3tc`), supported by most
+    /// desktop GPUs.
+    S3tc,
+
+    /// ASTC (`GL_KHR_texture_compression_astc_ldr`), supported by most
+    /// recent mobile GPUs.
+    Astc,
+
+    /// PVRTC (`GL_IMG_texture_compression_pvrtc`), supported by PowerVR
+    /// GPUs (including older iOS devices).
+    Pvrtc,
+
+    /// BPTC/BC7 (`GL_ARB_texture_compression_bptc`), supported by recent
+    /// desktop GPUs.
+    Bptc,
+
+    /// RGTC/BC4 and BC5 (`GL_ARB_texture_compression_rgtc`), supported by
+    /// desktop GPUs.
+    Rgtc,
+
+    /// ATC (`GL_AMD_compressed_ATC_texture`), supported by older Adreno
+    /// GPUs.
+    Atc
+}
+
+gl_enum! {
+    /// The compressed image formats with a known block size, which can be
+    /// uploaded with [`gl.compressed_tex_image_2d`]
+    /// (../context/texture_context/trait.ContextTextureExt.html#method.compressed_tex_image_2d).
+    /// The ETC2/EAC formats are required by OpenGL ES 3.0 (and desktop
+    /// OpenGL 4.3), the ASTC formats require the
+    /// `GL_KHR_texture_compression_astc_ldr` extension, and the BC formats
+    /// require `GL_EXT_texture_compression_s3tc` (BC1 to BC3), desktop
+    /// OpenGL 3.0 (BC4 and BC5), or `GL_ARB_texture_compression_bptc` (BC6H
+    /// and BC7), which are supported by most desktop GPUs. Either way, check
+    /// [`gl.compressed_formats`]
+    /// (../context/texture_context/trait.ContextTextureExt.html#method.compressed_formats)
+    /// before using a format.
+    pub gl_enum CompressedImageFormat {
+        /// ETC2 compressed RGB data, with 8 bits per component.
+        pub const Rgb8Etc2 as COMPRESSED_RGB8_ETC2 =
+            gl::COMPRESSED_RGB8_ETC2,
+
+        /// ETC2 compressed sRGB data, with 8 bits per component.
+        pub const Srgb8Etc2 as COMPRESSED_SRGB8_ETC2 =
+            gl::COMPRESSED_SRGB8_ETC2,
+
+        /// ETC2 compressed RGB data, with a 1-bit alpha component.
+        pub const Rgb8PunchthroughAlpha1Etc2
+            as COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2 =
+            gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+
+        /// ETC2 compressed sRGB data, with a 1-bit alpha component.
+        pub const Srgb8PunchthroughAlpha1Etc2
+            as COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2 =
+            gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+
+        /// ETC2 compressed RGB data, with EAC compressed alpha.
+        pub const Rgba8Etc2Eac as COMPRESSED_RGBA8_ETC2_EAC =
+            gl::COMPRESSED_RGBA8_ETC2_EAC,
+
+        /// ETC2 compressed sRGB data, with EAC compressed alpha.
+        pub const Srgb8Alpha8Etc2Eac as COMPRESSED_SRGB8_ALPHA8_ETC2_EAC =
+            gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,
+
+        /// EAC compressed single-channel (red) data.
+        pub const R11Eac as COMPRESSED_R11_EAC = gl::COMPRESSED_R11_EAC,
+
+        /// EAC compressed signed single-channel (red) data.
+        pub const SignedR11Eac as COMPRESSED_SIGNED_R11_EAC =
+            gl::COMPRESSED_SIGNED_R11_EAC,
+
+        /// EAC compressed dual-channel (red and green) data.
+        pub const Rg11Eac as COMPRESSED_RG11_EAC = gl::COMPRESSED_RG11_EAC,
+
+        /// EAC compressed signed dual-channel (red and green) data.
+        pub const SignedRg11Eac as COMPRESSED_SIGNED_RG11_EAC =
+            gl::COMPRESSED_SIGNED_RG11_EAC,
+
+        // NOTE: The S3TC formats are from `GL_EXT_texture_compression_s3tc`
+        //       and `GL_EXT_texture_sRGB`, which aren't included in the `gl`
+        //       crate's bindings
+
+        /// BC1 (DXT1) compressed RGB data.
+        pub const RgbS3tcDxt1 as COMPRESSED_RGB_S3TC_DXT1 = 0x83F0,
+
+        /// BC1 (DXT1) compressed RGB data, with a 1-bit alpha component.
+        pub const RgbaS3tcDxt1 as COMPRESSED_RGBA_S3TC_DXT1 = 0x83F1,
+
+        /// BC2 (DXT3) compressed RGBA data, with explicit 4-bit alpha.
+        pub const RgbaS3tcDxt3 as COMPRESSED_RGBA_S3TC_DXT3 = 0x83F2,
+
+        /// BC3 (DXT5) compressed RGBA data, with interpolated alpha.
+        pub const RgbaS3tcDxt5 as COMPRESSED_RGBA_S3TC_DXT5 = 0x83F3,
+
+        /// BC1 (DXT1) compressed sRGB data.
+        pub const SrgbS3tcDxt1 as COMPRESSED_SRGB_S3TC_DXT1 = 0x8C4C,
+
+        /// BC1 (DXT1) compressed sRGB data, with a 1-bit alpha component.
+        pub const SrgbAlphaS3tcDxt1 as COMPRESSED_SRGB_ALPHA_S3TC_DXT1 =
+            0x8C4D,
+
+        /// BC2 (DXT3) compressed sRGB data, with explicit 4-bit alpha.
+        pub const SrgbAlphaS3tcDxt3 as COMPRESSED_SRGB_ALPHA_S3TC_DXT3 =
+            0x8C4E,
+
+        /// BC3 (DXT5) compressed sRGB data, with interpolated alpha.
+        pub const SrgbAlphaS3tcDxt5 as COMPRESSED_SRGB_ALPHA_S3TC_DXT5 =
+            0x8C4F,
+
+        /// BC4 (RGTC1) compressed single-channel (red) data.
+        pub const RedRgtc1 as COMPRESSED_RED_RGTC1 = gl::COMPRESSED_RED_RGTC1,
+
+        /// BC4 (RGTC1) compressed signed single-channel (red) data.
+        pub const SignedRedRgtc1 as COMPRESSED_SIGNED_RED_RGTC1 =
+            gl::COMPRESSED_SIGNED_RED_RGTC1,
+
+        /// BC5 (RGTC2) compressed dual-channel (red and green) data.
+        pub const RgRgtc2 as COMPRESSED_RG_RGTC2 = gl::COMPRESSED_RG_RGTC2,
+
+        /// BC5 (RGTC2) compressed signed dual-channel (red and green) data.
+        pub const SignedRgRgtc2 as COMPRESSED_SIGNED_RG_RGTC2 =
+            gl::COMPRESSED_SIGNED_RG_RGTC2,
+
+        /// BC6H compressed signed floating-point RGB data.
+        pub const RgbBptcSignedFloat as COMPRESSED_RGB_BPTC_SIGNED_FLOAT =
+            gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT,
+
+        /// BC6H compressed unsigned floating-point RGB data.
+        pub const RgbBptcUnsignedFloat as COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT =
+            gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+
+        /// BC7 compressed RGBA data.
+        pub const RgbaBptcUnorm as COMPRESSED_RGBA_BPTC_UNORM =
+            gl::COMPRESSED_RGBA_BPTC_UNORM,
+
+        /// BC7 compressed sRGB data (with linear alpha).
+        pub const SrgbAlphaBptcUnorm as COMPRESSED_SRGB_ALPHA_BPTC_UNORM =
+            gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+
+        // NOTE: The ASTC formats are from `GL_KHR_texture_compression_astc_ldr`,
+        //       which isn't included in the `gl` crate's bindings
+
+        /// ASTC compressed RGBA data, with 4x4 blocks.
+        pub const RgbaAstc4x4 as COMPRESSED_RGBA_ASTC_4X4 = 0x93B0,
+
+        /// ASTC compressed RGBA data, with 5x4 blocks.
+        pub const RgbaAstc5x4 as COMPRESSED_RGBA_ASTC_5X4 = 0x93B1,
+
+        /// ASTC compressed RGBA data, with 5x5 blocks.
+        pub const RgbaAstc5x5 as COMPRESSED_RGBA_ASTC_5X5 = 0x93B2,
+
+        /// ASTC compressed RGBA data, with 6x5 blocks.
+        pub const RgbaAstc6x5 as COMPRESSED_RGBA_ASTC_6X5 = 0x93B3,
+
+        /// ASTC compressed RGBA data, with 6x6 blocks.
+        pub const RgbaAstc6x6 as COMPRESSED_RGBA_ASTC_6X6 = 0x93B4,
+
+        /// ASTC compressed RGBA data, with 8x5 blocks.
+        pub const RgbaAstc8x5 as COMPRESSED_RGBA_ASTC_8X5 = 0x93B5,
+
+        /// ASTC compressed RGBA data, with 8x6 blocks.
+        pub const RgbaAstc8x6 as COMPRESSED_RGBA_ASTC_8X6 = 0x93B6,
+
+        /// ASTC compressed RGBA data, with 8x8 blocks.
+        pub const RgbaAstc8x8 as COMPRESSED_RGBA_ASTC_8X8 = 0x93B7,
+
+        /// ASTC compressed RGBA data, with 10x5 blocks.
+        pub const RgbaAstc10x5 as COMPRESSED_RGBA_ASTC_10X5 = 0x93B8,
+
+        /// ASTC compressed RGBA data, with 10x6 blocks.
+        pub const RgbaAstc10x6 as COMPRESSED_RGBA_ASTC_10X6 = 0x93B9,
+
+        /// ASTC compressed RGBA data, with 10x8 blocks.
+        pub const RgbaAstc10x8 as COMPRESSED_RGBA_ASTC_10X8 = 0x93BA,
+
+        /// ASTC compressed RGBA data, with 10x10 blocks.
+        pub const RgbaAstc10x10 as COMPRESSED_RGBA_ASTC_10X10 = 0x93BB,
+
+        /// ASTC compressed RGBA data, with 12x10 blocks.
+        pub const RgbaAstc12x10 as COMPRESSED_RGBA_ASTC_12X10 = 0x93BC,
+
+        /// ASTC compressed RGBA data, with 12x12 blocks.
+        pub const RgbaAstc12x12 as COMPRESSED_RGBA_ASTC_12X12 = 0x93BD,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 4x4
+        /// blocks.
+        pub const Srgb8Alpha8Astc4x4 as COMPRESSED_SRGB8_ALPHA8_ASTC_4X4 =
+            0x93D0,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 5x4
+        /// blocks.
+        pub const Srgb8Alpha8Astc5x4 as COMPRESSED_SRGB8_ALPHA8_ASTC_5X4 =
+            0x93D1,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 5x5
+        /// blocks.
+        pub const Srgb8Alpha8Astc5x5 as COMPRESSED_SRGB8_ALPHA8_ASTC_5X5 =
+            0x93D2,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 6x5
+        /// blocks.
+        pub const Srgb8Alpha8Astc6x5 as COMPRESSED_SRGB8_ALPHA8_ASTC_6X5 =
+            0x93D3,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 6x6
+        /// blocks.
+        pub const Srgb8Alpha8Astc6x6 as COMPRESSED_SRGB8_ALPHA8_ASTC_6X6 =
+            0x93D4,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 8x5
+        /// blocks.
+        pub const Srgb8Alpha8Astc8x5 as COMPRESSED_SRGB8_ALPHA8_ASTC_8X5 =
+            0x93D5,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 8x6
+        /// blocks.
+        pub const Srgb8Alpha8Astc8x6 as COMPRESSED_SRGB8_ALPHA8_ASTC_8X6 =
+            0x93D6,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 8x8
+        /// blocks.
+        pub const Srgb8Alpha8Astc8x8 as COMPRESSED_SRGB8_ALPHA8_ASTC_8X8 =
+            0x93D7,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 10x5
+        /// blocks.
+        pub const Srgb8Alpha8Astc10x5 as COMPRESSED_SRGB8_ALPHA8_ASTC_10X5 =
+            0x93D8,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 10x6
+        /// blocks.
+        pub const Srgb8Alpha8Astc10x6 as COMPRESSED_SRGB8_ALPHA8_ASTC_10X6 =
+            0x93D9,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 10x8
+        /// blocks.
+        pub const Srgb8Alpha8Astc10x8 as COMPRESSED_SRGB8_ALPHA8_ASTC_10X8 =
+            0x93DA,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 10x10
+        /// blocks.
+        pub const Srgb8Alpha8Astc10x10 as COMPRESSED_SRGB8_ALPHA8_ASTC_10X10 =
+            0x93DB,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 12x10
+        /// blocks.
+        pub const Srgb8Alpha8Astc12x10 as COMPRESSED_SRGB8_ALPHA8_ASTC_12X10 =
+            0x93DC,
+
+        /// ASTC compressed sRGB data (with linear alpha), with 12x12
+        /// blocks.
+        pub const Srgb8Alpha8Astc12x12 as COMPRESSED_SRGB8_ALPHA8_ASTC_12X12 =
+            0x93DD
+    }
+}
+
+impl CompressedImageFormat {
+    /// Returns the width and height (in texels) of each compressed block.
+    pub fn block_dims(&self) -> (u32, u32) {
+        match *self {
+            CompressedImageFormat::Rgb8Etc2
+            | CompressedImageFormat::Srgb8Etc2
+            | CompressedImageFormat::Rgb8PunchthroughAlpha1Etc2
+            | CompressedImageFormat::Srgb8PunchthroughAlpha1Etc2
+            | CompressedImageFormat::Rgba8Etc2Eac
+            | CompressedImageFormat::Srgb8Alpha8Etc2Eac
+            | CompressedImageFormat::R11Eac
+            | CompressedImageFormat::SignedR11Eac
+            | CompressedImageFormat::Rg11Eac
+            | CompressedImageFormat::SignedRg11Eac
+            | CompressedImageFormat::RgbS3tcDxt1
+            | CompressedImageFormat::RgbaS3tcDxt1
+            | CompressedImageFormat::RgbaS3tcDxt3
+            | CompressedImageFormat::RgbaS3tcDxt5
+            | CompressedImageFormat::SrgbS3tcDxt1
+            | CompressedImageFormat::SrgbAlphaS3tcDxt1
+            | CompressedImageFormat::SrgbAlphaS3tcDxt3
+            | CompressedImageFormat::SrgbAlphaS3tcDxt5
+            | CompressedImageFormat::RedRgtc1
+            | CompressedImageFormat::SignedRedRgtc1
+            | CompressedImageFormat::RgRgtc2
+            | CompressedImageFormat::SignedRgRgtc2
+            | CompressedImageFormat::RgbBptcSignedFloat
+            | CompressedImageFormat::RgbBptcUnsignedFloat
+            | CompressedImageFormat::RgbaBptcUnorm
+            | CompressedImageFormat::SrgbAlphaBptcUnorm => (4, 4),
+            CompressedImageFormat::RgbaAstc4x4
+            | CompressedImageFormat::Srgb8Alpha8Astc4x4 => (4, 4),
+            CompressedImageFormat::RgbaAstc5x4
+            | CompressedImageFormat::Srgb8Alpha8Astc5x4 => (5, 4),
+            CompressedImageFormat::RgbaAstc5x5
+            | CompressedImageFormat::Srgb8Alpha8Astc5x5 => (5, 5),
+            CompressedImageFormat::RgbaAstc6x5
+            | CompressedImageFormat::Srgb8Alpha8Astc6x5 => (6, 5),
+            CompressedImageFormat::RgbaAstc6x6
+            | CompressedImageFormat::Srgb8Alpha8Astc6x6 => (6, 6),
+            CompressedImageFormat::RgbaAstc8x5
+            | CompressedImageFormat::Srgb8Alpha8Astc8x5 => (8, 5),
+            CompressedImageFormat::RgbaAstc8x6
+            | CompressedImageFormat::Srgb8Alpha8Astc8x6 => (8, 6),
+            CompressedImageFormat::RgbaAstc8x8
+            | CompressedImageFormat::Srgb8Alpha8Astc8x8 => (8, 8),
+            CompressedImageFormat::RgbaAstc10x5
+            | CompressedImageFormat::Srgb8Alpha8Astc10x5 => (10, 5),
+            CompressedImageFormat::RgbaAstc10x6
+            | CompressedImageFormat::Srgb8Alpha8Astc10x6 => (10, 6),
+            CompressedImageFormat::RgbaAstc10x8
+            | CompressedImageFormat::Srgb8Alpha8Astc10x8 => (10, 8),
+            CompressedImageFormat::RgbaAstc10x10
+            | CompressedImageFormat::Srgb8Alpha8Astc10x10 => (10, 10),
+            CompressedImageFormat::RgbaAstc12x10
+            | CompressedImageFormat::Srgb8Alpha8Astc12x10 => (12, 10),
+            CompressedImageFormat::RgbaAstc12x12
+            | CompressedImageFormat::Srgb8Alpha8Astc12x12 => (12, 12)
+        }
+    }
+
+    /// Returns the number of bytes used by each compressed block.
+    pub fn bytes_per_block(&self) -> usize {
+        match *self {
+            CompressedImageFormat::Rgb8Etc2
+            | CompressedImageFormat::Srgb8Etc2
+            | CompressedImageFormat::Rgb8PunchthroughAlpha1Etc2
+            | CompressedImageFormat::Srgb8PunchthroughAlpha1Etc2
+            | CompressedImageFormat::R11Eac
+            | CompressedImageFormat::SignedR11Eac
+            | CompressedImageFormat::RgbS3tcDxt1
+            | CompressedImageFormat::RgbaS3tcDxt1
+            | CompressedImageFormat::SrgbS3tcDxt1
+            | CompressedImageFormat::SrgbAlphaS3tcDxt1
+            | CompressedImageFormat::RedRgtc1
+            | CompressedImageFormat::SignedRedRgtc1 => 8,
+            _ => 16
+        }
+    }
+
+    /// Returns the number of bytes of compressed data for an image with
+    /// the given dimensions. Partial blocks at the right and top edges of
+    /// the image take up a whole block.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::{COMPRESSED_RGB8_ETC2, COMPRESSED_RGBA_ASTC_6X6};
+    ///
+    /// // 16x16 texels is 4x4 blocks of 8 bytes each
+    /// assert_eq!(COMPRESSED_RGB8_ETC2.image_size(16, 16), 128);
+    ///
+    /// // 16x16 texels is 3x3 blocks of 16 bytes each
+    /// assert_eq!(COMPRESSED_RGBA_ASTC_6X6.image_size(16, 16), 144);
+    /// ```
+    pub fn image_size(&self, width: u32, height: u32) -> usize {
+        let (block_width, block_height) = self.block_dims();
+        let blocks_x = (width + block_width - 1) / block_width;
+        let blocks_y = (height + block_height - 1) / block_height;
+        blocks_x as usize * blocks_y as usize * self.bytes_per_block()
+    }
+}
+
+impl From<CompressedImageFormat> for CompressedFormat {
+    fn from(format: CompressedImageFormat) -> CompressedFormat {
+        CompressedFormat { gl_enum: format.gl_enum() }
+    }
 }
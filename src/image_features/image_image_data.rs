@@ -0,0 +1,167 @@
+use image;
+use image::{ImageBuffer, DynamicImage, Pixel};
+use image_data;
+use image_data::{Image2d, Image2dBuf, ImageFormat, TexelType, TexelFormat,
+                 Rgb565, Rgba4444, Rgba5551};
+
+macro_rules! impl_image_2d_for_image_buffer {
+    ($pixel:ty, $format:expr) => {
+        impl Image2d for ImageBuffer<$pixel, Vec<u8>> {
+            fn width(&self) -> usize {
+                ImageBuffer::width(self) as usize
+            }
+
+            fn height(&self) -> usize {
+                ImageBuffer::height(self) as usize
+            }
+
+            fn format(&self) -> ImageFormat {
+                $format
+            }
+
+            fn texel_bytes(&self) -> &[u8] {
+                &**self
+            }
+        }
+    }
+}
+
+impl_image_2d_for_image_buffer!(image::Luma<u8>, ImageFormat::luminance8());
+impl_image_2d_for_image_buffer!(image::LumaA<u8>,
+                                ImageFormat::luminance_alpha8());
+impl_image_2d_for_image_buffer!(image::Rgb<u8>, ImageFormat::rgb8());
+impl_image_2d_for_image_buffer!(image::Rgba<u8>, ImageFormat::rgba8());
+
+// Get the `Image2d` of the buffer that a `DynamicImage` wraps
+fn _dynamic_image_2d(img: &DynamicImage) -> &Image2d {
+    match *img {
+        DynamicImage::ImageLuma8(ref buffer) => { buffer },
+        DynamicImage::ImageLumaA8(ref buffer) => { buffer },
+        DynamicImage::ImageRgb8(ref buffer) => { buffer },
+        DynamicImage::ImageRgba8(ref buffer) => { buffer }
+    }
+}
+
+// NOTE: The image format is picked from the type of pixel in the image,
+//       and the texel data is used directly (without copying it)
+impl Image2d for DynamicImage {
+    fn width(&self) -> usize {
+        _dynamic_image_2d(self).width()
+    }
+
+    fn height(&self) -> usize {
+        _dynamic_image_2d(self).height()
+    }
+
+    fn format(&self) -> ImageFormat {
+        _dynamic_image_2d(self).format()
+    }
+
+    fn texel_bytes(&self) -> &[u8] {
+        _dynamic_image_2d(self).texel_bytes()
+    }
+}
+
+impl From<DynamicImage> for Image2dBuf {
+    fn from(img: DynamicImage) -> Image2dBuf {
+        let (width, height) = (img.width(), img.height());
+        let format = img.format();
+        let bytes = match img {
+            DynamicImage::ImageLuma8(buffer) => { buffer.into_raw() },
+            DynamicImage::ImageLumaA8(buffer) => { buffer.into_raw() },
+            DynamicImage::ImageRgb8(buffer) => { buffer.into_raw() },
+            DynamicImage::ImageRgba8(buffer) => { buffer.into_raw() }
+        };
+        Image2dBuf::new(bytes, width, height, format)
+    }
+}
+
+impl Image2dBuf {
+    /// Convert an image from the `image` crate to the given format, or
+    /// return `None` if the format is invalid (see [`ImageFormat::is_valid`]
+    /// (struct.ImageFormat.html#method.is_valid)). Components that the
+    /// format doesn't have are dropped (so [`r8`]
+    /// (struct.ImageFormat.html#method.r8) only keeps the red component),
+    /// and packed formats are rounded down to fewer bits per component.
+    ///
+    /// # Example
+    /// ```no_run
+    /// extern crate image;
+    /// # extern crate glitter;
+    /// use glitter::{Image2dBuf, ImageFormat};
+    ///
+    /// # fn main() {
+    /// let img = image::open("sprite.png").unwrap();
+    /// let sprite = Image2dBuf::from_image_as(&img, ImageFormat::rgba4())
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn from_image_as(img: &DynamicImage, format: ImageFormat)
+        -> Option<Image2dBuf>
+    {
+        if !format.is_valid() {
+            return None;
+        }
+
+        let (width, height) = (img.width(), img.height());
+        let bytes = match (format.texel_type, format.texel_format) {
+            (TexelType::UnsignedByte, TexelFormat::Luminance) => {
+                img.to_luma().into_raw()
+            },
+            (TexelType::UnsignedByte, TexelFormat::LuminanceAlpha) => {
+                img.to_luma_alpha().into_raw()
+            },
+            (TexelType::UnsignedByte, TexelFormat::RGB) => {
+                img.to_rgb().into_raw()
+            },
+            (TexelType::UnsignedByte, TexelFormat::RGBA) => {
+                img.to_rgba().into_raw()
+            },
+            _ => {
+                let rgba = img.to_rgba();
+                let mut bytes = Vec::with_capacity(width * height
+                                                   * format.bytes_per_texel());
+                for pixel in rgba.pixels() {
+                    _push_texel(&mut bytes, format, pixel.channels());
+                }
+                bytes
+            }
+        };
+        Some(Image2dBuf::new(bytes, width, height, format))
+    }
+}
+
+// Convert an RGBA pixel to a texel with the given format, and add its bytes
+// to `bytes`. Packed texels are stored in native byte order, which is the
+// order that OpenGL reads them in.
+fn _push_texel(bytes: &mut Vec<u8>, format: ImageFormat, rgba: &[u8]) {
+    let pixel = image_data::Pixel::r_g_b_a(rgba[0], rgba[1], rgba[2], rgba[3]);
+    let packed = match format.texel_type {
+        TexelType::UnsignedByte => {
+            match format.texel_format {
+                TexelFormat::Alpha => { bytes.push(pixel.a); },
+                TexelFormat::Red => { bytes.push(pixel.r); },
+                TexelFormat::RG => {
+                    bytes.push(pixel.r);
+                    bytes.push(pixel.g);
+                },
+                TexelFormat::RGB => {
+                    bytes.push(pixel.r);
+                    bytes.push(pixel.g);
+                    bytes.push(pixel.b);
+                },
+                TexelFormat::RGBA => { bytes.extend_from_slice(rgba); },
+                TexelFormat::Luminance | TexelFormat::LuminanceAlpha => {
+                    unreachable!();
+                }
+            }
+            return;
+        },
+        TexelType::UnsignedShort565 => { Rgb565::from(pixel).0 },
+        TexelType::UnsignedShort4444 => { Rgba4444::from(pixel).0 },
+        TexelType::UnsignedShort5551 => { Rgba5551::from(pixel).0 }
+    };
+
+    let packed: [u8; 2] = unsafe { ::std::mem::transmute(packed) };
+    bytes.extend_from_slice(&packed);
+}
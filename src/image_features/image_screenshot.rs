@@ -0,0 +1,71 @@
+use std::io;
+use std::path::Path;
+use image;
+use image::ImageBuffer;
+use context::ContextFramebufferExt;
+use image_data::Image2d;
+use types::Viewport;
+
+/// An extension trait for capturing screenshots as images from the `image`
+/// crate. Requires the `image` feature.
+pub trait ContextScreenshotExt: ContextFramebufferExt {
+    /// Read the pixels inside of `viewport` from the currently-bound read
+    /// framebuffer, and return them as an image. Unlike [`gl.read_pixels`]
+    /// (context/framebuffer_context/trait.ContextFramebufferExt.html#method.read_pixels),
+    /// the rows are flipped so that the top row is first, which is the
+    /// order the `image` crate (and most image formats) use.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let screenshot = gl.capture_screenshot(glitter::Viewport::new(0, 0,
+    ///                                                               640,
+    ///                                                               480));
+    /// assert_eq!(screenshot.dimensions(), (640, 480));
+    /// ```
+    fn capture_screenshot(&self, viewport: Viewport) -> image::RgbaImage {
+        let pixels = self.read_pixels(viewport);
+        let (width, height) = (pixels.width(), pixels.height());
+        let row_len = pixels.row_pitch();
+        let bytes = pixels.texel_bytes();
+
+        let mut flipped = Vec::with_capacity(row_len * height);
+        for row in (0..height).rev() {
+            let start = row * row_len;
+            flipped.extend_from_slice(&bytes[start..(start + row_len)]);
+        }
+
+        ImageBuffer::from_raw(width as u32, height as u32, flipped)
+            .expect("Screenshot image data is the wrong size")
+    }
+
+    /// Capture a screenshot (see [`gl.capture_screenshot`]
+    /// (trait.ContextScreenshotExt.html#method.capture_screenshot)), and
+    /// save it to a file. The image format is picked from the file's
+    /// extension, such as `.png`.
+    ///
+    /// # Failures
+    /// An error will be returned if the file could not be written, or if
+    /// the file's extension isn't a supported image format.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// gl.save_screenshot(glitter::Viewport::new(0, 0, 640, 480),
+    ///                    "screenshot.png")
+    ///   .unwrap();
+    /// ```
+    fn save_screenshot<P>(&self, viewport: Viewport, path: P) -> io::Result<()>
+        where P: AsRef<Path>
+    {
+        self.capture_screenshot(viewport).save(path)
+    }
+}
+
+impl<C: ContextFramebufferExt> ContextScreenshotExt for C {
+
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::error;
+use std::path::Path;
+use image;
+use context::{SomeTextureUnitContext, Texture2dBuilder};
+use image_data::Image2dBuf;
+use texture::Texture2d;
+use types::GLError;
+
+/// An error generated when building a texture from an image file with
+/// [`Texture2dBuilder::file`]
+/// (context/texture_context/struct.Texture2dBuilder.html#method.file).
+#[derive(Debug)]
+pub enum TextureFileError {
+    /// The image file could not be opened or decoded.
+    Image(image::ImageError),
+
+    /// The image was loaded, but the texture could not be built.
+    Texture(GLError)
+}
+
+impl From<image::ImageError> for TextureFileError {
+    fn from(e: image::ImageError) -> TextureFileError {
+        TextureFileError::Image(e)
+    }
+}
+
+impl From<GLError> for TextureFileError {
+    fn from(e: GLError) -> TextureFileError {
+        TextureFileError::Texture(e)
+    }
+}
+
+impl fmt::Display for TextureFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextureFileError::Image(ref e) => {
+                write!(f, "Error loading texture image: {}", e)
+            },
+            TextureFileError::Texture(ref e) => { write!(f, "{}", e) }
+        }
+    }
+}
+
+impl error::Error for TextureFileError {
+    fn description(&self) -> &str {
+        match *self {
+            TextureFileError::Image(_) => "A texture's image file could not be loaded",
+            TextureFileError::Texture(_) => "A texture could not be built from an image file"
+        }
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        match *self {
+            TextureFileError::Image(ref e) => { Some(e) },
+            TextureFileError::Texture(ref e) => { Some(e) }
+        }
+    }
+}
+
+impl<'a, C> Texture2dBuilder<'a, C>
+    where C: SomeTextureUnitContext
+{
+    /// Load an image file, and create and return a texture with its image
+    /// data and the specified options, or return an error. The image is
+    /// converted to the [`ImageFormat`](../../image_data/struct.ImageFormat.html)
+    /// that matches its pixels (such as [`rgba8`]
+    /// (../../image_data/struct.ImageFormat.html#method.rgba8) for an image
+    /// with an alpha channel), and replaces any image data set with
+    /// [`image_2d`](struct.Texture2dBuilder.html#method.image_2d). Options
+    /// like [`flip_y`](struct.Texture2dBuilder.html#method.flip_y) and
+    /// [`generate_mipmap`]
+    /// (struct.Texture2dBuilder.html#method.generate_mipmap) are applied
+    /// as usual. Requires the `image` feature.
+    ///
+    /// # Failures
+    /// A [`TextureFileError::Image`](../../enum.TextureFileError.html) error
+    /// will be returned if the file could not be opened or decoded, and
+    /// a `TextureFileError::Texture` error will be returned for any of
+    /// the errors that [`try_unwrap`]
+    /// (struct.Texture2dBuilder.html#method.try_unwrap) can return.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    /// use glitter::LINEAR_MIPMAP_LINEAR;
+    ///
+    /// let mut gl = unsafe { glitter::Context::current_context() };
+    /// let texture = gl.build_texture_2d()
+    ///     .flip_y()
+    ///     .generate_mipmap()
+    ///     .min_filter(LINEAR_MIPMAP_LINEAR)
+    ///     .file("assets/foo.png")
+    ///     .unwrap();
+    /// ```
+    pub fn file<P>(self, path: P) -> Result<Texture2d, TextureFileError>
+        where P: AsRef<Path>
+    {
+        let image = Image2dBuf::from(try!(image::open(path)));
+        let texture = try!(self.image_2d(&image).try_unwrap());
+        Ok(texture)
+    }
+}
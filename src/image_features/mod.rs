@@ -1 +1,7 @@
 mod image_conversions;
+mod image_image_data;
+mod image_screenshot;
+mod image_texture;
+
+pub use self::image_texture::TextureFileError;
+pub use self::image_screenshot::ContextScreenshotExt;
@@ -5,12 +5,19 @@ pub use std::mem;
 pub use std::slice;
 
 /// The set of possible [`IndexDatum`](trait.IndexDatum.html) types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IndexDatumType {
     /// Unsigned 8-bit index data.
     UnsignedByte,
 
     /// Unsigned 16-bit index data.
-    UnsignedShort
+    UnsignedShort,
+
+    /// Unsigned 32-bit index data. In OpenGL ES 2, this requires the
+    /// `GL_OES_element_index_uint` extension, which can be checked with
+    /// [`gl.supports_index_datum_type`]
+    /// (../context/buffer_context/trait.ContextBufferExt.html#method.supports_index_datum_type).
+    UnsignedInt
 }
 
 /// Indicates that a type can be coerced to a `u8` slice that can
@@ -50,6 +57,10 @@ unsafe impl IndexDatum for u16 {
     fn index_datum_type() -> IndexDatumType { IndexDatumType::UnsignedShort }
 }
 
+unsafe impl IndexDatum for u32 {
+    fn index_datum_type() -> IndexDatumType { IndexDatumType::UnsignedInt }
+}
+
 impl<T: IndexDatum> IndexData for [T] {
     fn index_datum_type() -> IndexDatumType {
         T::index_datum_type()
@@ -0,0 +1,158 @@
+// Tracks which OpenGL objects are still alive, to find objects that are
+// never dropped (see `ContextOf::report_leaks`). Tracking is enabled with
+// the `leak-tracking` feature; otherwise, `_track` and `_untrack` are
+// no-ops. Objects are tracked per thread, since objects can only be sent
+// between threads with a `Transfer` (which moves their tracking along with
+// them).
+
+use std::fmt;
+use gl::types::*;
+
+/// The types of OpenGL objects that are tracked for leaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ObjectKind {
+    /// A [`Buffer`](buffer/struct.Buffer.html).
+    Buffer,
+
+    /// A [`Texture`](texture/struct.Texture.html) of any type.
+    Texture,
+
+    /// A [`Framebuffer`](framebuffer/struct.Framebuffer.html).
+    Framebuffer,
+
+    /// A [`Renderbuffer`](renderbuffer/struct.Renderbuffer.html).
+    Renderbuffer,
+
+    /// A [`Program`](program/struct.Program.html).
+    Program,
+
+    /// A [`Shader`](shader/struct.Shader.html).
+    Shader
+}
+
+impl fmt::Display for ObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            ObjectKind::Buffer => "buffer",
+            ObjectKind::Texture => "texture",
+            ObjectKind::Framebuffer => "framebuffer",
+            ObjectKind::Renderbuffer => "renderbuffer",
+            ObjectKind::Program => "program",
+            ObjectKind::Shader => "shader"
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// An OpenGL object that is still alive. Returned by [`gl.report_leaks`]
+/// (context/struct.ContextOf.html#method.report_leaks).
+#[cfg(feature = "leak-tracking")]
+#[derive(Debug, Clone)]
+pub struct LeakedObject {
+    /// The type of the object.
+    pub kind: ObjectKind,
+
+    /// The object's OpenGL ID.
+    pub id: GLuint,
+
+    /// A backtrace of where the object was created, or `None` if debug
+    /// assertions are disabled.
+    pub backtrace: Option<String>
+}
+
+#[cfg(feature = "leak-tracking")]
+impl fmt::Display for LeakedObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Leaked {} {}", self.kind, self.id));
+        if let Some(ref backtrace) = self.backtrace {
+            try!(write!(f, ", created at:\n{}", backtrace));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "leak-tracking")]
+mod imp {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+    use backtrace::Backtrace;
+    use gl::types::*;
+    use super::{ObjectKind, LeakedObject};
+
+    thread_local! {
+        static LIVE_OBJECTS: RefCell<BTreeMap<(ObjectKind, GLuint),
+                                              Option<Backtrace>>> =
+            RefCell::new(BTreeMap::new())
+    }
+
+    pub fn track(kind: ObjectKind, id: GLuint) {
+        // Backtraces are resolved lazily, since most objects will
+        // (hopefully) never be reported
+        let backtrace = if cfg!(debug_assertions) {
+            Some(Backtrace::new_unresolved())
+        }
+        else {
+            None
+        };
+
+        // NOTE: `try_with` is used because objects can be dropped while
+        //       thread-locals are being destroyed
+        let _ = LIVE_OBJECTS.try_with(|live| {
+            live.borrow_mut().insert((kind, id), backtrace);
+        });
+    }
+
+    pub fn untrack(kind: ObjectKind, id: GLuint) {
+        let _ = LIVE_OBJECTS.try_with(|live| {
+            live.borrow_mut().remove(&(kind, id));
+        });
+    }
+
+    pub fn live_objects() -> Vec<LeakedObject> {
+        LIVE_OBJECTS.with(|live| {
+            live.borrow_mut().iter_mut().map(|(&(kind, id), backtrace)| {
+                LeakedObject {
+                    kind: kind,
+                    id: id,
+                    backtrace: backtrace.as_mut().map(|backtrace| {
+                        backtrace.resolve();
+                        format!("{:?}", backtrace)
+                    })
+                }
+            }).collect()
+        })
+    }
+}
+
+#[cfg(not(feature = "leak-tracking"))]
+mod imp {
+    use gl::types::*;
+    use super::ObjectKind;
+
+    #[inline(always)]
+    pub fn track(_kind: ObjectKind, _id: GLuint) {
+
+    }
+
+    #[inline(always)]
+    pub fn untrack(_kind: ObjectKind, _id: GLuint) {
+
+    }
+}
+
+// Called when an object is created (with `GLObject::from_raw`)
+pub fn _track(kind: ObjectKind, id: GLuint) {
+    imp::track(kind, id);
+}
+
+// Called when an object is dropped
+pub fn _untrack(kind: ObjectKind, id: GLuint) {
+    imp::untrack(kind, id);
+}
+
+// Get all of the tracked objects that are still alive on the current
+// thread, ordered by type and ID
+#[cfg(feature = "leak-tracking")]
+pub fn _live_objects() -> Vec<LeakedObject> {
+    imp::live_objects()
+}
@@ -47,11 +47,38 @@
 //! to vertex buffer objects when vertex array objects are unavailable.
 //!
 //! # Thread Safety
-//! Eventually, glitter should support proper thread safety using the [`Send`]
-//! (https://doc.rust-lang.org/std/marker/trait.Send.html) and [`Sync`]
-//! (https://doc.rust-lang.org/std/marker/trait.Sync.html) marker traits.
-//! For now, most types have been marked as `!Send` and `!Sync`, meaning that
-//! they cannot be sent or shared across threads.
+//! An OpenGL context can only be current on one thread at a time, so
+//! contexts, bindings, and objects are neither [`Send`]
+//! (https://doc.rust-lang.org/std/marker/trait.Send.html) nor [`Sync`]
+//! (https://doc.rust-lang.org/std/marker/trait.Sync.html). Dropping an
+//! object deletes it using whatever context is current on the dropping
+//! thread, so an object can't be moved to a thread without a context (or
+//! with a context that doesn't share objects).
+//!
+//! Objects that OpenGL shares between contexts ([`Buffer`]
+//! (buffer/struct.Buffer.html), [`Texture`](texture/struct.Texture.html),
+//! [`Renderbuffer`](renderbuffer/struct.Renderbuffer.html), [`Shader`]
+//! (shader/struct.Shader.html), and [`Program`](program/struct.Program.html),
+//! along with [`VertexBuffer`](vertex_buffer/struct.VertexBuffer.html) and
+//! [`IndexBuffer`](vertex_buffer/struct.IndexBuffer.html)) can instead be
+//! sent to another thread inside of a [`Transfer`]
+//! (transfer/struct.Transfer.html). This allows objects to be created on
+//! a loader thread, with a context that shares objects with the render
+//! thread's context, and then sent to the render thread. A transfer inserts
+//! a fence, so changes made to the object are visible once it's accepted,
+//! and it can only be accepted by a context in the same share group (see
+//! [`gl.join_share_group`](context/struct.ContextOf.html#method.join_share_group)).
+//! [`Framebuffer`](framebuffer/struct.Framebuffer.html)s are not shared
+//! between contexts, so they can't be transferred.
+//!
+//! Leak tracking and resource statistics are kept per thread. A transferred
+//! object is moved to the accepting thread's leak tracking and statistics.
+//!
+//! Likewise, the `bind-cache` feature (which skips binding objects that are
+//! already bound) remembers bindings per thread. If an object that is bound
+//! on one thread is deleted on another, call [`gl.with_raw`]
+//! (context/struct.ContextOf.html#method.with_raw) on the first thread
+//! before binding anything else, so that the cache is cleared.
 //!
 //! # The Future
 //! In its current form, glitter should be considered work-in-progress, and
@@ -63,8 +90,30 @@ extern crate gl;
 #[cfg(feature = "cgmath")] extern crate cgmath;
 #[cfg(feature = "image")] extern crate image;
 #[cfg(feature = "nalgebra")] extern crate nalgebra;
+#[cfg(feature = "mint")] extern crate mint;
+#[cfg(feature = "half")] extern crate half;
+#[cfg(feature = "leak-tracking")] extern crate backtrace;
+#[cfg(feature = "tracing")] extern crate tracing;
+
+// Re-export `#[derive(VertexData)]` when the `derive` feature is enabled.
+// Re-exporting a procedural macro requires Rust 1.30, so (unlike the rest
+// of glitter) the `derive` feature isn't tested against Rust 1.26
+#[cfg(feature = "derive")]
+#[allow(unused_imports)]
+#[macro_use]
+extern crate glitter_derive;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use glitter_derive::*;
 
 mod to_ref;
+mod validate;
+mod gl_fns;
+mod leaks;
+mod stats;
+mod frame_stats;
+mod bind_cache;
+#[cfg(any(feature = "headless", feature = "offscreen"))] mod egl;
 
 #[macro_use] mod macros;
 pub mod context;
@@ -80,10 +129,24 @@ pub mod vertex_buffer;
 pub mod index_data;
 pub mod uniform_data;
 pub mod types;
+pub mod limits;
+pub mod extensions;
+pub mod debug;
+pub mod easy;
+pub mod fullscreen;
+pub mod gbuffer;
+pub mod shadow_map;
+pub mod transfer;
+#[cfg(feature = "mock")] pub mod mock;
+#[cfg(feature = "headless")] pub mod headless;
+#[cfg(feature = "offscreen")] pub mod offscreen;
+#[cfg(feature = "atlas")] pub mod atlas;
 
 #[cfg(feature = "cgmath")] mod cgmath_features;
 #[cfg(feature = "image")] mod image_features;
 #[cfg(feature = "nalgebra")] mod nalgebra_features;
+#[cfg(feature = "mint")] mod mint_features;
+#[cfg(feature = "half")] mod half_features;
 
 pub use context::*;
 pub use buffer::*;
@@ -98,6 +161,16 @@ pub use vertex_buffer::*;
 pub use index_data::*;
 pub use uniform_data::*;
 pub use types::*;
+pub use limits::*;
+pub use extensions::*;
+pub use debug::*;
+pub use gl_fns::GlFns;
+pub use leaks::ObjectKind;
+#[cfg(feature = "leak-tracking")] pub use leaks::LeakedObject;
+#[cfg(feature = "resource-stats")] pub use stats::ResourceStats;
+#[cfg(feature = "frame-stats")] pub use frame_stats::FrameStats;
+#[cfg(feature = "image")] pub use image_features::{TextureFileError,
+                                                   ContextScreenshotExt};
 
 /// Re-exports essential extension traits. Everything exported in this module
 /// should be used anywhere that glitter is used.
@@ -118,13 +191,22 @@ pub mod prelude {
                       TextureBinding, ContextTextureBuilderExt,
                       TextureUnit, TextureUnitBinding, ATextureUnitBinding,
                       TextureUnitBinding2d, TextureUnitBindingCubeMap,
+                      TextureUnitBindingExternal,
+                      TextureUnitBinding2dMultisample,
+                      TextureUnitBinding2dArray, TextureUnitBinding3d,
                       TextureUnit0Context, TextureUnit1Context,
                       TextureUnit2Context, TextureUnit3Context,
                       TextureUnit4Context, TextureUnit5Context,
-                      TextureUnit6Context, TextureUnit7Context};
+                      TextureUnit6Context, TextureUnit7Context,
+                      TextureUnit8Context, TextureUnit9Context,
+                      TextureUnit10Context, TextureUnit11Context,
+                      TextureUnit12Context, TextureUnit13Context,
+                      TextureUnit14Context, TextureUnit15Context,
+                      SomeTextureUnitContext};
     pub use context::ext::*;
     pub use shader::ContextShaderBuilderExt;
     pub use vertex_buffer::{VertexBufferContext, IndexBufferContext,
                             ContextVertexBufferExt};
     pub use types::GLObject;
+    #[cfg(feature = "image")] pub use image_features::ContextScreenshotExt;
 }
@@ -0,0 +1,238 @@
+//! Contains the [`ContextLimits`](struct.ContextLimits.html) type, which
+//! describes the implementation-dependent limits of an OpenGL context, and
+//! the [`GpuMemoryInfo`](struct.GpuMemoryInfo.html) type, which describes
+//! the GPU's memory usage.
+
+use gl;
+use gl_fns;
+use gl::types::*;
+use context::Context;
+use extensions::Extensions;
+
+// `GL_NVX_gpu_memory_info` and `GL_ATI_meminfo` aren't included in the
+// `gl` crate
+const GPU_MEMORY_INFO_DEDICATED_VIDMEM_NVX: GLenum = 0x9047;
+const GPU_MEMORY_INFO_TOTAL_AVAILABLE_MEMORY_NVX: GLenum = 0x9048;
+const GPU_MEMORY_INFO_CURRENT_AVAILABLE_VIDMEM_NVX: GLenum = 0x9049;
+const GPU_MEMORY_INFO_EVICTION_COUNT_NVX: GLenum = 0x904A;
+const GPU_MEMORY_INFO_EVICTED_MEMORY_NVX: GLenum = 0x904B;
+const VBO_FREE_MEMORY_ATI: GLenum = 0x87FB;
+const TEXTURE_FREE_MEMORY_ATI: GLenum = 0x87FC;
+const RENDERBUFFER_FREE_MEMORY_ATI: GLenum = 0x87FD;
+
+/// The implementation-dependent limits of an OpenGL context, such as
+/// the maximum texture size. The limits are queried once when getting the
+/// current context (with [`Context::current_context`]
+/// (../context/struct.ContextOf.html#method.current_context)), and can
+/// then be accessed with [`gl.limits`]
+/// (../context/struct.ContextOf.html#method.limits).
+///
+/// # Note
+/// Some limits are not available in every version of OpenGL (for example,
+/// `max_varying_vectors` requires OpenGL ES 2 or OpenGL 4.1). Limits that
+/// could not be queried are set to 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextLimits {
+    /// The maximum width and height of a 2D texture.
+    pub max_texture_size: u32,
+
+    /// The maximum width and height of each face of a cube map texture.
+    pub max_cube_map_texture_size: u32,
+
+    /// The maximum width and height of a renderbuffer.
+    pub max_renderbuffer_size: u32,
+
+    /// The maximum number of samples of a multisampled renderbuffer. This is
+    /// 0 if multisampled renderbuffers aren't supported (see
+    /// [`has_renderbuffer_multisample`]
+    /// (../extensions/struct.Extensions.html#method.has_renderbuffer_multisample)).
+    pub max_samples: u32,
+
+    /// The maximum number of color attachments of a framebuffer. This is 0
+    /// if the context doesn't support multiple color attachments (such as
+    /// OpenGL ES 2 without `GL_EXT_draw_buffers`), in which case only
+    /// `COLOR_ATTACHMENT0` can be used.
+    pub max_color_attachments: u32,
+
+    /// The maximum number of fragment shader outputs that can be written to
+    /// color attachments at once (see [`gl.draw_buffers`]
+    /// (../context/framebuffer_context/trait.ContextFramebufferExt.html#method.draw_buffers)).
+    /// This is 0 if `glDrawBuffers` isn't supported.
+    pub max_draw_buffers: u32,
+
+    /// The maximum width and height of the viewport.
+    pub max_viewport_dims: (u32, u32),
+
+    /// The maximum number of texture units that can be used by
+    /// a fragment shader.
+    pub max_texture_image_units: u32,
+
+    /// The maximum number of texture units that can be used by
+    /// a vertex shader. This may be 0.
+    pub max_vertex_texture_image_units: u32,
+
+    /// The maximum number of texture units that can be used by all
+    /// shaders combined.
+    pub max_combined_texture_image_units: u32,
+
+    /// The maximum number of vertex attributes.
+    pub max_vertex_attribs: u32,
+
+    /// The maximum number of 4-component vectors that can be passed
+    /// from a vertex shader to a fragment shader.
+    pub max_varying_vectors: u32,
+
+    /// The maximum number of 4-component uniform vectors in
+    /// a vertex shader.
+    pub max_vertex_uniform_vectors: u32,
+
+    /// The maximum number of 4-component uniform vectors in
+    /// a fragment shader.
+    pub max_fragment_uniform_vectors: u32
+}
+
+impl ContextLimits {
+    /// Query the limits of the current OpenGL context.
+    ///
+    /// # Safety
+    /// An OpenGL context must be current, and OpenGL function pointers must
+    /// be loaded (see [`Context::current_context`]
+    /// (../context/struct.ContextOf.html#method.current_context)).
+    pub unsafe fn current() -> Self {
+        let mut max_viewport_dims: [GLint; 2] = [0, 0];
+        gl_fns::GetIntegerv(gl::MAX_VIEWPORT_DIMS, max_viewport_dims.as_mut_ptr());
+        Context::get_error();
+
+        ContextLimits {
+            max_texture_size: _get_limit(gl::MAX_TEXTURE_SIZE),
+            max_cube_map_texture_size:
+                _get_limit(gl::MAX_CUBE_MAP_TEXTURE_SIZE),
+            max_renderbuffer_size: _get_limit(gl::MAX_RENDERBUFFER_SIZE),
+            max_samples: _get_limit(gl::MAX_SAMPLES),
+            max_color_attachments: _get_limit(gl::MAX_COLOR_ATTACHMENTS),
+            max_draw_buffers: _get_limit(gl::MAX_DRAW_BUFFERS),
+            max_viewport_dims: (max_viewport_dims[0] as u32,
+                                max_viewport_dims[1] as u32),
+            max_texture_image_units: _get_limit(gl::MAX_TEXTURE_IMAGE_UNITS),
+            max_vertex_texture_image_units:
+                _get_limit(gl::MAX_VERTEX_TEXTURE_IMAGE_UNITS),
+            max_combined_texture_image_units:
+                _get_limit(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS),
+            max_vertex_attribs: _get_limit(gl::MAX_VERTEX_ATTRIBS),
+            max_varying_vectors: _get_limit(gl::MAX_VARYING_VECTORS),
+            max_vertex_uniform_vectors:
+                _get_limit(gl::MAX_VERTEX_UNIFORM_VECTORS),
+            max_fragment_uniform_vectors:
+                _get_limit(gl::MAX_FRAGMENT_UNIFORM_VECTORS)
+        }
+    }
+}
+
+// Query a single integer limit, returning 0 if the limit is not supported
+// by the current context (the error is cleared rather than reported, since
+// an unsupported limit is expected on some versions of OpenGL)
+unsafe fn _get_limit(pname: GLenum) -> u32 {
+    let mut value: GLint = 0;
+    gl_fns::GetIntegerv(pname, &mut value as *mut GLint);
+    match Context::get_error() {
+        Some(_) => 0,
+        None => value as u32
+    }
+}
+
+/// The GPU's memory usage, as reported by the driver with
+/// `GL_NVX_gpu_memory_info` (on NVIDIA GPUs) or `GL_ATI_meminfo` (on AMD
+/// GPUs). Returned by [`gl.gpu_memory_info`]
+/// (../context/trait.ContextExt.html#method.gpu_memory_info).
+///
+/// All sizes are in kilobytes. The two extensions report different
+/// information, so fields that aren't reported by the current driver are
+/// set to `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpuMemoryInfo {
+    /// The amount of memory that is currently available for new
+    /// resources. With `GL_ATI_meminfo`, this is the memory available for
+    /// textures.
+    pub available_kb: u32,
+
+    /// The total amount of dedicated video memory (`GL_NVX_gpu_memory_info`
+    /// only).
+    pub dedicated_kb: Option<u32>,
+
+    /// The total amount of memory that can be used by the GPU, including
+    /// dedicated memory (`GL_NVX_gpu_memory_info` only).
+    pub total_available_kb: Option<u32>,
+
+    /// The number of times that resources have been evicted from video
+    /// memory to make room for other resources (`GL_NVX_gpu_memory_info`
+    /// only).
+    pub eviction_count: Option<u32>,
+
+    /// The total amount of memory that has been evicted
+    /// (`GL_NVX_gpu_memory_info` only).
+    pub evicted_kb: Option<u32>,
+
+    /// The amount of memory available for buffers (`GL_ATI_meminfo` only).
+    pub buffer_available_kb: Option<u32>,
+
+    /// The amount of memory available for renderbuffers (`GL_ATI_meminfo`
+    /// only).
+    pub renderbuffer_available_kb: Option<u32>
+}
+
+impl GpuMemoryInfo {
+    /// Query the GPU's current memory usage, or return `None` if the
+    /// current context doesn't support `GL_NVX_gpu_memory_info` or
+    /// `GL_ATI_meminfo`.
+    ///
+    /// # Safety
+    /// An OpenGL context must be current, and OpenGL function pointers must
+    /// be loaded (see [`Context::current_context`]
+    /// (../context/struct.ContextOf.html#method.current_context)).
+    pub unsafe fn current(extensions: &Extensions) -> Option<Self> {
+        if extensions.has("GL_NVX_gpu_memory_info") {
+            Some(GpuMemoryInfo {
+                available_kb:
+                    _get_limit(GPU_MEMORY_INFO_CURRENT_AVAILABLE_VIDMEM_NVX),
+                dedicated_kb:
+                    Some(_get_limit(GPU_MEMORY_INFO_DEDICATED_VIDMEM_NVX)),
+                total_available_kb:
+                    Some(_get_limit(GPU_MEMORY_INFO_TOTAL_AVAILABLE_MEMORY_NVX)),
+                eviction_count:
+                    Some(_get_limit(GPU_MEMORY_INFO_EVICTION_COUNT_NVX)),
+                evicted_kb:
+                    Some(_get_limit(GPU_MEMORY_INFO_EVICTED_MEMORY_NVX)),
+                buffer_available_kb: None,
+                renderbuffer_available_kb: None
+            })
+        }
+        else if extensions.has("GL_ATI_meminfo") {
+            Some(GpuMemoryInfo {
+                available_kb: _get_free_memory_ati(TEXTURE_FREE_MEMORY_ATI),
+                dedicated_kb: None,
+                total_available_kb: None,
+                eviction_count: None,
+                evicted_kb: None,
+                buffer_available_kb:
+                    Some(_get_free_memory_ati(VBO_FREE_MEMORY_ATI)),
+                renderbuffer_available_kb:
+                    Some(_get_free_memory_ati(RENDERBUFFER_FREE_MEMORY_ATI))
+            })
+        }
+        else {
+            None
+        }
+    }
+}
+
+// Query the total free memory in a `GL_ATI_meminfo` pool. The query returns
+// 4 values (the total free memory, the largest free block, and the same for
+// auxiliary memory), but only the first is used.
+unsafe fn _get_free_memory_ati(pname: GLenum) -> u32 {
+    let mut values: [GLint; 4] = [0; 4];
+    gl_fns::GetIntegerv(pname, values.as_mut_ptr());
+    match Context::get_error() {
+        Some(_) => 0,
+        None => values[0] as u32
+    }
+}
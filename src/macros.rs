@@ -1,11 +1,51 @@
-// Used to specify checks that shouldn't fail (but might in unsafe)
+// Used to specify checks that shouldn't fail (but might in unsafe). The
+// checks can start with `in "glFunctionName"(arg = value, ...);`, so that
+// the panic message includes the OpenGL call that failed (along with any
+// important argument values). The panic message always includes the file
+// and line of the check. Errors from a lost context are ignored.
 macro_rules! dbg_gl_error {
+    (
+        in $func:tt($($arg:ident = $value:expr),*);
+        $($pat:pat => $msg:expr),*
+    ) => {
+        if cfg!(debug_assertions) {
+            let err = $crate::Context::get_error();
+            let call_args = || {
+                let args: Vec<String> = vec![
+                    $(format!("{} = {:?}", stringify!($arg), $value)),*
+                ];
+                args.join(", ")
+            };
+            match err {
+                // Every call fails after the context is lost, which isn't
+                // a bug (see `ContextExt::graphics_reset_status`)
+                Some($crate::GLError::ContextLost) => { }
+                $(Some($pat) => {
+                    panic!("OpenGL error {:?} in {}({}) at {}:{} - {}",
+                           err,
+                           $func,
+                           call_args(),
+                           file!(),
+                           line!(),
+                           $msg)
+                }),*
+                None => { }
+            }
+        }
+    };
     ($($pat:pat => $msg:expr),*) => {
         if cfg!(debug_assertions) {
             let err = $crate::Context::get_error();
             match err {
+                // Every call fails after the context is lost, which isn't
+                // a bug (see `ContextExt::graphics_reset_status`)
+                Some($crate::GLError::ContextLost) => { }
                 $(Some($pat) => {
-                    panic!("OpenGL error {:?} - {}", err, $msg)
+                    panic!("OpenGL error {:?} at {}:{} - {}",
+                           err,
+                           file!(),
+                           line!(),
+                           $msg)
                 }),*
                 None => { }
             }
@@ -15,11 +55,41 @@ macro_rules! dbg_gl_error {
 
 // Used to specify checks that should *never* be able to fail (even in unsafe!)
 macro_rules! dbg_gl_sanity_check {
+    (
+        in $func:tt($($arg:ident = $value:expr),*);
+        $($pat:pat => $msg:expr),*
+    ) => {
+        dbg_gl_error! {
+            in $func($($arg = $value),*);
+            $($pat => concat!("Sanity check failed: ", $msg)),*
+        }
+    };
     ($($pat:pat => $msg:expr),*) => {
         dbg_gl_error! { $($pat => concat!("Sanity check failed: ", $msg)),* }
     }
 }
 
+// Enter a `tracing` span for an expensive OpenGL operation (with the
+// `tracing` feature), which is exited when the returned guard is dropped.
+// The fields use the same syntax as `tracing::trace_span!`. Without the
+// `tracing` feature, this evaluates to `()`, and the fields are
+// never evaluated.
+#[cfg(feature = "tracing")]
+macro_rules! gl_span {
+    ($name:expr) => {
+        ::tracing::trace_span!($name).entered()
+    };
+    ($name:expr, $($fields:tt)*) => {
+        ::tracing::trace_span!($name, $($fields)*).entered()
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! gl_span {
+    ($name:expr) => { () };
+    ($name:expr, $($fields:tt)*) => { () }
+}
+
 // Create a Rust enum that maps to an OpenGL enum.
 macro_rules! gl_enum {
     (
@@ -0,0 +1,54 @@
+use mint;
+use uniform_data::{UniformDatumType, UniformDatum, UniformPrimitive};
+
+unsafe impl<T: UniformPrimitive> UniformDatum for mint::Vector2<T> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Vec2(T::uniform_primitive_type())
+    }
+}
+
+unsafe impl<T: UniformPrimitive> UniformDatum for mint::Vector3<T> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Vec3(T::uniform_primitive_type())
+    }
+}
+
+unsafe impl<T: UniformPrimitive> UniformDatum for mint::Vector4<T> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Vec4(T::uniform_primitive_type())
+    }
+}
+
+unsafe impl<T: UniformPrimitive> UniformDatum for mint::Point2<T> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Vec2(T::uniform_primitive_type())
+    }
+}
+
+unsafe impl<T: UniformPrimitive> UniformDatum for mint::Point3<T> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Vec3(T::uniform_primitive_type())
+    }
+}
+
+// NOTE: Only column-major matrices are supported, since that's the layout
+//       OpenGL expects (a `RowMatrix` can be converted into a
+//       `ColumnMatrix` with `From`)
+
+unsafe impl UniformDatum for mint::ColumnMatrix2<f32> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Matrix2x2
+    }
+}
+
+unsafe impl UniformDatum for mint::ColumnMatrix3<f32> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Matrix3x3
+    }
+}
+
+unsafe impl UniformDatum for mint::ColumnMatrix4<f32> {
+    fn uniform_datum_type() -> UniformDatumType {
+        UniformDatumType::Matrix4x4
+    }
+}
@@ -0,0 +1,57 @@
+use mint;
+use vertex_data::{VertexAttributeType, VertexDatum, VertexPrimitive};
+
+unsafe impl<T: VertexPrimitive> VertexDatum for mint::Vector2<T> {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: T::data_type(),
+            components: 2,
+            normalize: false,
+            integer: false
+        }
+    }
+}
+
+unsafe impl<T: VertexPrimitive> VertexDatum for mint::Vector3<T> {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: T::data_type(),
+            components: 3,
+            normalize: false,
+            integer: false
+        }
+    }
+}
+
+unsafe impl<T: VertexPrimitive> VertexDatum for mint::Vector4<T> {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: T::data_type(),
+            components: 4,
+            normalize: false,
+            integer: false
+        }
+    }
+}
+
+unsafe impl<T: VertexPrimitive> VertexDatum for mint::Point2<T> {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: T::data_type(),
+            components: 2,
+            normalize: false,
+            integer: false
+        }
+    }
+}
+
+unsafe impl<T: VertexPrimitive> VertexDatum for mint::Point3<T> {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: T::data_type(),
+            components: 3,
+            normalize: false,
+            integer: false
+        }
+    }
+}
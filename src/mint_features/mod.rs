@@ -0,0 +1,2 @@
+mod mint_uniform_data;
+mod mint_vertex_data;
@@ -0,0 +1,860 @@
+//! A mock OpenGL implementation, for testing code that uses glitter without
+//! a GPU or a window system. Enabled with the `mock` feature.
+//!
+//! After calling [`mock::load`](fn.load.html), each OpenGL function that
+//! glitter uses is replaced with a stub that records the call (see
+//! [`MockCall`](struct.MockCall.html)) and returns a canned value. The
+//! canned values can be changed with functions like [`set_integer`]
+//! (fn.set_integer.html) and [`push_error`](fn.push_error.html). By
+//! default, the mock reports itself as an OpenGL ES 3.0 context with no
+//! extensions, object IDs are handed out sequentially, and shaders,
+//! programs, and framebuffers are always reported as successfully compiled,
+//! linked, or complete. Binding buffers, framebuffers, renderbuffers, and
+//! programs updates the corresponding `glGetIntegerv` value (such as
+//! `GL_ARRAY_BUFFER_BINDING`), so the mock can be used along with the
+//! `validate-bindings` feature.
+//!
+//! The recorded calls and canned values are stored per thread, so tests
+//! that run in parallel don't see each other's calls. Note that calls to
+//! `glGetError` are never recorded, since glitter makes them after most
+//! calls when `debug_assertions` is set.
+//!
+//! # Example
+//! ```
+//! extern crate glitter;
+//! use glitter::prelude::*;
+//!
+//! # fn main() {
+//! unsafe { glitter::mock::load(); }
+//! let mut gl = unsafe { glitter::Context::current_context() };
+//! glitter::mock::take_calls();
+//!
+//! gl.clear_color(glitter::Color::rgba(1.0, 0.0, 0.0, 1.0));
+//!
+//! let calls = glitter::mock::take_calls();
+//! assert_eq!(calls.len(), 1);
+//! assert_eq!(calls[0].name, "glClearColor");
+//! assert_eq!(calls[0].args, vec!["1.0", "0.0", "0.0", "1.0"]);
+//! # }
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::ptr;
+use gl;
+use gl::types::*;
+use context::Context;
+
+/// A single OpenGL call recorded by the mock implementation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// The name of the OpenGL function (such as `"glBindBuffer"`).
+    pub name: &'static str,
+
+    /// The arguments of the call, formatted with `Debug`. Pointers are
+    /// formatted as addresses.
+    pub args: Vec<String>
+}
+
+struct MockState {
+    calls: Vec<MockCall>,
+    last_id: GLuint,
+    enabled: HashSet<GLenum>,
+    errors: VecDeque<GLenum>,
+    integers: HashMap<GLenum, Vec<GLint>>,
+    floats: HashMap<GLenum, Vec<GLfloat>>,
+    strings: HashMap<GLenum, CString>,
+    extensions: Vec<CString>,
+    locations: HashMap<Vec<u8>, GLint>,
+    mapped: Option<Vec<u8>>
+}
+
+impl MockState {
+    fn new() -> Self {
+        let mut state = MockState {
+            calls: vec![],
+            last_id: 0,
+            enabled: HashSet::new(),
+            errors: VecDeque::new(),
+            integers: HashMap::new(),
+            floats: HashMap::new(),
+            strings: HashMap::new(),
+            extensions: vec![],
+            locations: HashMap::new(),
+            mapped: None
+        };
+
+        state.set_string(gl::VENDOR, "glitter");
+        state.set_string(gl::RENDERER, "glitter mock");
+        state.set_string(gl::VERSION, "OpenGL ES 3.0 (glitter mock)");
+        state.set_string(gl::SHADING_LANGUAGE_VERSION,
+                         "OpenGL ES GLSL ES 3.00");
+        state.set_string(gl::EXTENSIONS, "");
+        state.integers.insert(gl::NUM_EXTENSIONS, vec![0]);
+        state.integers.insert(gl::ACTIVE_TEXTURE, vec![gl::TEXTURE0 as GLint]);
+        state.integers.insert(gl::MAX_TEXTURE_SIZE, vec![4096]);
+        state.integers.insert(gl::MAX_CUBE_MAP_TEXTURE_SIZE, vec![4096]);
+        state.integers.insert(gl::MAX_RENDERBUFFER_SIZE, vec![4096]);
+        state.integers.insert(gl::MAX_VIEWPORT_DIMS, vec![4096, 4096]);
+        state.integers.insert(gl::MAX_TEXTURE_IMAGE_UNITS, vec![16]);
+        state.integers.insert(gl::MAX_VERTEX_TEXTURE_IMAGE_UNITS, vec![16]);
+        state.integers.insert(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS, vec![32]);
+        state.integers.insert(gl::MAX_VERTEX_ATTRIBS, vec![16]);
+        state.integers.insert(gl::MAX_VARYING_VECTORS, vec![15]);
+        state.integers.insert(gl::MAX_VERTEX_UNIFORM_VECTORS, vec![256]);
+        state.integers.insert(gl::MAX_FRAGMENT_UNIFORM_VECTORS, vec![224]);
+        state.floats.insert(gl::ALIASED_LINE_WIDTH_RANGE, vec![1.0, 1.0]);
+
+        state
+    }
+
+    fn set_string(&mut self, name: GLenum, value: &str) {
+        let value = CString::new(value).expect("String contains a nul byte");
+        self.strings.insert(name, value);
+    }
+
+    fn next_id(&mut self) -> GLuint {
+        self.last_id += 1;
+        self.last_id
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<MockState> = RefCell::new(MockState::new())
+}
+
+fn _with_state<F, T>(f: F) -> T
+    where F: FnOnce(&mut MockState) -> T
+{
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+fn _record(name: &'static str, args: Vec<String>) {
+    // `glGetError` is called after most functions with `debug_assertions`,
+    // so recording it would make the recorded calls differ between debug
+    // and release builds
+    if name == "glGetError" {
+        return;
+    }
+
+    _with_state(|state| {
+        state.calls.push(MockCall { name: name, args: args });
+    });
+}
+
+unsafe fn _gen(n: GLsizei, ids: *mut GLuint) {
+    _with_state(|state| {
+        for i in 0..n {
+            *ids.offset(i as isize) = state.next_id();
+        }
+    });
+}
+
+// Check if an ID was handed out. Every kind of object shares one sequence of
+// IDs, so an ID is reported as an object of any kind.
+fn _is_object(id: GLuint) -> GLboolean {
+    _with_state(|state| {
+        (id != 0 && id <= state.last_id) as GLboolean
+    })
+}
+
+// Get the location for an attribute or uniform name. Each distinct name
+// gets a new location, regardless of the program.
+unsafe fn _location(name: *const GLchar) -> GLint {
+    let name = CStr::from_ptr(name).to_bytes().to_vec();
+    _with_state(|state| {
+        let next = state.locations.len() as GLint;
+        *state.locations.entry(name).or_insert(next)
+    })
+}
+
+// Update the object reported by `glGetIntegerv` for a binding target
+fn _set_binding(pname: GLenum, id: GLuint) {
+    _with_state(|state| {
+        state.integers.insert(pname, vec![id as GLint]);
+    });
+}
+
+// Get a shader or program parameter, reporting that every shader compiled
+// and every program linked and validated successfully
+fn _object_param(pname: GLenum) -> GLint {
+    match pname {
+        gl::COMPILE_STATUS | gl::LINK_STATUS | gl::VALIDATE_STATUS => {
+            gl::TRUE as GLint
+        },
+        _ => { 0 }
+    }
+}
+
+macro_rules! mock_gl {
+    ($(
+        fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block
+    )+) => {
+        $(
+            extern "system" fn $name($($arg: $ty),*) -> $ret {
+                _record(concat!("gl", stringify!($name)),
+                        vec![$(format!("{:?}", $arg)),*]);
+                $body
+            }
+        )+
+
+        fn _lookup(name: &str) -> *const GLvoid {
+            $(
+                if name == concat!("gl", stringify!($name)) {
+                    return $name as *const () as *const GLvoid;
+                }
+            )+
+            ptr::null()
+        }
+    }
+}
+
+mock_gl! {
+    fn ActiveTexture(texture: GLenum) -> () { }
+
+    fn AttachShader(program: GLuint, shader: GLuint) -> () { }
+
+    fn BindAttribLocation(program: GLuint,
+                          index: GLuint,
+                          name: *const GLchar) -> () { }
+
+    fn BindBuffer(target: GLenum, buffer: GLuint) -> () {
+        match target {
+            gl::ARRAY_BUFFER => {
+                _set_binding(gl::ARRAY_BUFFER_BINDING, buffer);
+            },
+            gl::ELEMENT_ARRAY_BUFFER => {
+                _set_binding(gl::ELEMENT_ARRAY_BUFFER_BINDING, buffer);
+            },
+            _ => { }
+        }
+    }
+
+    fn BindFramebuffer(target: GLenum, framebuffer: GLuint) -> () {
+        _set_binding(gl::FRAMEBUFFER_BINDING, framebuffer);
+    }
+
+    fn BindRenderbuffer(target: GLenum, renderbuffer: GLuint) -> () {
+        _set_binding(gl::RENDERBUFFER_BINDING, renderbuffer);
+    }
+
+    fn BindTexture(target: GLenum, texture: GLuint) -> () { }
+
+    fn BlendFunc(sfactor: GLenum, dfactor: GLenum) -> () { }
+
+    fn BufferData(target: GLenum,
+                  size: GLsizeiptr,
+                  data: *const GLvoid,
+                  usage: GLenum) -> () { }
+
+    fn BufferSubData(target: GLenum,
+                     offset: GLintptr,
+                     size: GLsizeiptr,
+                     data: *const GLvoid) -> () { }
+
+    fn CheckFramebufferStatus(target: GLenum) -> GLenum {
+        gl::FRAMEBUFFER_COMPLETE
+    }
+
+    fn Clear(mask: GLbitfield) -> () { }
+
+    fn ClearColor(red: GLfloat,
+                  green: GLfloat,
+                  blue: GLfloat,
+                  alpha: GLfloat) -> () { }
+
+    fn ClearDepthf(d: GLfloat) -> () { }
+
+    fn ClearStencil(s: GLint) -> () { }
+
+    fn ClientWaitSync(sync: GLsync,
+                      flags: GLbitfield,
+                      timeout: GLuint64) -> GLenum {
+        // Fences are always signaled immediately
+        gl::ALREADY_SIGNALED
+    }
+
+    fn CompileShader(shader: GLuint) -> () { }
+
+    fn CompressedTexImage2D(target: GLenum,
+                            level: GLint,
+                            internal_format: GLenum,
+                            width: GLsizei,
+                            height: GLsizei,
+                            border: GLint,
+                            image_size: GLsizei,
+                            data: *const GLvoid) -> () { }
+
+    fn CopyBufferSubData(read_target: GLenum,
+                         write_target: GLenum,
+                         read_offset: GLintptr,
+                         write_offset: GLintptr,
+                         size: GLsizeiptr) -> () { }
+
+    fn CopyImageSubData(src_name: GLuint,
+                        src_target: GLenum,
+                        src_level: GLint,
+                        src_x: GLint,
+                        src_y: GLint,
+                        src_z: GLint,
+                        dst_name: GLuint,
+                        dst_target: GLenum,
+                        dst_level: GLint,
+                        dst_x: GLint,
+                        dst_y: GLint,
+                        dst_z: GLint,
+                        src_width: GLsizei,
+                        src_height: GLsizei,
+                        src_depth: GLsizei) -> () { }
+
+    fn CopyTexSubImage2D(target: GLenum,
+                         level: GLint,
+                         xoffset: GLint,
+                         yoffset: GLint,
+                         x: GLint,
+                         y: GLint,
+                         width: GLsizei,
+                         height: GLsizei) -> () { }
+
+    fn CreateProgram() -> GLuint { _with_state(|state| state.next_id()) }
+
+    fn CreateShader(gl_type: GLenum) -> GLuint {
+        _with_state(|state| state.next_id())
+    }
+
+    fn CullFace(mode: GLenum) -> () { }
+
+    fn DebugMessageCallback(callback: GLDEBUGPROC,
+                            user_param: *const GLvoid) -> () { }
+
+    fn DebugMessageControl(source: GLenum,
+                           gl_type: GLenum,
+                           severity: GLenum,
+                           count: GLsizei,
+                           ids: *const GLuint,
+                           enabled: GLboolean) -> () { }
+
+    fn DebugMessageInsert(source: GLenum,
+                          gl_type: GLenum,
+                          id: GLuint,
+                          severity: GLenum,
+                          length: GLsizei,
+                          buf: *const GLchar) -> () { }
+
+    fn DeleteBuffers(n: GLsizei, buffers: *const GLuint) -> () { }
+
+    fn DeleteFramebuffers(n: GLsizei, framebuffers: *const GLuint) -> () { }
+
+    fn DeleteProgram(program: GLuint) -> () { }
+
+    fn DeleteRenderbuffers(n: GLsizei, renderbuffers: *const GLuint) -> () { }
+
+    fn DeleteShader(shader: GLuint) -> () { }
+
+    fn DeleteSync(sync: GLsync) -> () { }
+
+    fn DeleteTextures(n: GLsizei, textures: *const GLuint) -> () { }
+
+    fn DepthFunc(func: GLenum) -> () { }
+
+    fn Disable(cap: GLenum) -> () {
+        _with_state(|state| { state.enabled.remove(&cap); })
+    }
+
+    fn DrawArrays(mode: GLenum, first: GLint, count: GLsizei) -> () { }
+
+    fn DrawBuffers(n: GLsizei, bufs: *const GLenum) -> () { }
+
+    fn DrawElements(mode: GLenum,
+                    count: GLsizei,
+                    gl_type: GLenum,
+                    indices: *const GLvoid) -> () { }
+
+    fn EGLImageTargetTexture2DOES(target: GLenum,
+                                  image: GLeglImageOES) -> () { }
+
+    fn Enable(cap: GLenum) -> () {
+        _with_state(|state| { state.enabled.insert(cap); })
+    }
+
+    fn EnableVertexAttribArray(index: GLuint) -> () { }
+
+    fn FenceSync(condition: GLenum, flags: GLbitfield) -> GLsync {
+        // Any non-null pointer is a valid sync object
+        1 as GLsync
+    }
+
+    fn FramebufferRenderbuffer(target: GLenum,
+                               attachment: GLenum,
+                               renderbuffertarget: GLenum,
+                               renderbuffer: GLuint) -> () { }
+
+    fn FramebufferTexture(target: GLenum,
+                          attachment: GLenum,
+                          texture: GLuint,
+                          level: GLint) -> () { }
+
+    fn FramebufferTexture2D(target: GLenum,
+                            attachment: GLenum,
+                            textarget: GLenum,
+                            texture: GLuint,
+                            level: GLint) -> () { }
+
+    fn FramebufferTextureLayer(target: GLenum,
+                               attachment: GLenum,
+                               texture: GLuint,
+                               level: GLint,
+                               layer: GLint) -> () { }
+
+    fn Finish() -> () { }
+
+    fn Flush() -> () { }
+
+    fn FrontFace(mode: GLenum) -> () { }
+
+    fn GenBuffers(n: GLsizei, buffers: *mut GLuint) -> () {
+        unsafe { _gen(n, buffers) }
+    }
+
+    fn GenFramebuffers(n: GLsizei, framebuffers: *mut GLuint) -> () {
+        unsafe { _gen(n, framebuffers) }
+    }
+
+    fn GenRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint) -> () {
+        unsafe { _gen(n, renderbuffers) }
+    }
+
+    fn GenTextures(n: GLsizei, textures: *mut GLuint) -> () {
+        unsafe { _gen(n, textures) }
+    }
+
+    fn GenerateMipmap(target: GLenum) -> () { }
+
+    fn GetAttribLocation(program: GLuint, name: *const GLchar) -> GLint {
+        unsafe { _location(name) }
+    }
+
+    fn GetBooleanv(pname: GLenum, data: *mut GLboolean) -> () {
+        _with_state(|state| {
+            if let Some(values) = state.integers.get(&pname) {
+                for (i, value) in values.iter().enumerate() {
+                    let value = (*value != 0) as GLboolean;
+                    unsafe { *data.offset(i as isize) = value; }
+                }
+            }
+        })
+    }
+
+    fn GetError() -> GLenum {
+        _with_state(|state| {
+            state.errors.pop_front().unwrap_or(gl::NO_ERROR)
+        })
+    }
+
+    fn GetFloatv(pname: GLenum, data: *mut GLfloat) -> () {
+        _with_state(|state| {
+            if let Some(values) = state.floats.get(&pname) {
+                for (i, value) in values.iter().enumerate() {
+                    unsafe { *data.offset(i as isize) = *value; }
+                }
+            }
+        })
+    }
+
+    fn GetFramebufferAttachmentParameteriv(target: GLenum,
+                                           attachment: GLenum,
+                                           pname: GLenum,
+                                           params: *mut GLint) -> () { }
+
+    fn GetGraphicsResetStatus() -> GLenum { gl::NO_ERROR }
+
+    fn GetIntegerv(pname: GLenum, data: *mut GLint) -> () {
+        _with_state(|state| {
+            if let Some(values) = state.integers.get(&pname) {
+                for (i, value) in values.iter().enumerate() {
+                    unsafe { *data.offset(i as isize) = *value; }
+                }
+            }
+        })
+    }
+
+    fn GetProgramInfoLog(program: GLuint,
+                         buf_size: GLsizei,
+                         length: *mut GLsizei,
+                         info_log: *mut GLchar) -> () { }
+
+    fn GetProgramiv(program: GLuint,
+                    pname: GLenum,
+                    params: *mut GLint) -> () {
+        unsafe { *params = _object_param(pname); }
+    }
+
+    fn GetRenderbufferParameteriv(target: GLenum,
+                                  pname: GLenum,
+                                  params: *mut GLint) -> () { }
+
+    fn GetShaderInfoLog(shader: GLuint,
+                        buf_size: GLsizei,
+                        length: *mut GLsizei,
+                        info_log: *mut GLchar) -> () { }
+
+    fn GetShaderiv(shader: GLuint,
+                   pname: GLenum,
+                   params: *mut GLint) -> () {
+        unsafe { *params = _object_param(pname); }
+    }
+
+    fn GetString(name: GLenum) -> *const GLubyte {
+        _with_state(|state| {
+            match state.strings.get(&name) {
+                Some(string) => { string.as_ptr() as *const GLubyte },
+                None => { ptr::null() }
+            }
+        })
+    }
+
+    fn GetStringi(name: GLenum, index: GLuint) -> *const GLubyte {
+        _with_state(|state| {
+            if name != gl::EXTENSIONS {
+                return ptr::null();
+            }
+            match state.extensions.get(index as usize) {
+                Some(string) => { string.as_ptr() as *const GLubyte },
+                None => { ptr::null() }
+            }
+        })
+    }
+
+    fn GetSynciv(sync: GLsync,
+                 pname: GLenum,
+                 buf_size: GLsizei,
+                 length: *mut GLsizei,
+                 values: *mut GLint) -> () {
+        // Fences are always signaled immediately
+        if pname == gl::SYNC_STATUS {
+            unsafe { *values = gl::SIGNALED as GLint; }
+        }
+    }
+
+    fn GetTexLevelParameteriv(target: GLenum,
+                              level: GLint,
+                              pname: GLenum,
+                              params: *mut GLint) -> () { }
+
+    fn GetUniformLocation(program: GLuint, name: *const GLchar) -> GLint {
+        unsafe { _location(name) }
+    }
+
+    fn GetUniformfv(program: GLuint,
+                    location: GLint,
+                    params: *mut GLfloat) -> () { }
+
+    fn GetUniformiv(program: GLuint,
+                    location: GLint,
+                    params: *mut GLint) -> () { }
+
+    fn Hint(target: GLenum, mode: GLenum) -> () { }
+
+    fn InsertEventMarkerEXT(length: GLsizei, marker: *const GLchar) -> () { }
+
+    fn InvalidateFramebuffer(target: GLenum,
+                             numAttachments: GLsizei,
+                             attachments: *const GLenum) -> () { }
+
+    fn IsBuffer(buffer: GLuint) -> GLboolean { _is_object(buffer) }
+
+    fn IsEnabled(cap: GLenum) -> GLboolean {
+        _with_state(|state| {
+            state.enabled.contains(&cap) as GLboolean
+        })
+    }
+
+    fn IsFramebuffer(framebuffer: GLuint) -> GLboolean {
+        _is_object(framebuffer)
+    }
+
+    fn IsProgram(program: GLuint) -> GLboolean { _is_object(program) }
+
+    fn IsRenderbuffer(renderbuffer: GLuint) -> GLboolean {
+        _is_object(renderbuffer)
+    }
+
+    fn IsShader(shader: GLuint) -> GLboolean { _is_object(shader) }
+
+    fn IsTexture(texture: GLuint) -> GLboolean { _is_object(texture) }
+
+    fn LineWidth(width: GLfloat) -> () { }
+
+    fn LinkProgram(program: GLuint) -> () { }
+
+    fn MapBufferRange(target: GLenum,
+                      offset: GLintptr,
+                      length: GLsizeiptr,
+                      access: GLbitfield) -> *mut GLvoid {
+        _with_state(|state| {
+            // Keep the mapped memory alive until the buffer is unmapped
+            state.mapped = Some(vec![0; length as usize]);
+            state.mapped.as_mut().unwrap().as_mut_ptr() as *mut GLvoid
+        })
+    }
+
+    fn PixelStorei(pname: GLenum, param: GLint) -> () { }
+
+    fn PopDebugGroup() -> () { }
+
+    fn PopGroupMarkerEXT() -> () { }
+
+    fn PushDebugGroup(source: GLenum,
+                      id: GLuint,
+                      length: GLsizei,
+                      message: *const GLchar) -> () { }
+
+    fn PushGroupMarkerEXT(length: GLsizei, marker: *const GLchar) -> () { }
+
+    fn ReadBuffer(src: GLenum) -> () { }
+
+    fn ReadPixels(x: GLint,
+                  y: GLint,
+                  width: GLsizei,
+                  height: GLsizei,
+                  format: GLenum,
+                  gl_type: GLenum,
+                  pixels: *mut GLvoid) -> () { }
+
+    fn RenderbufferStorage(target: GLenum,
+                           internalformat: GLenum,
+                           width: GLsizei,
+                           height: GLsizei) -> () { }
+
+    fn RenderbufferStorageMultisample(target: GLenum,
+                                      samples: GLsizei,
+                                      internalformat: GLenum,
+                                      width: GLsizei,
+                                      height: GLsizei) -> () { }
+
+    fn SampleCoverage(value: GLfloat, invert: GLboolean) -> () { }
+
+    fn Scissor(x: GLint, y: GLint, width: GLsizei, height: GLsizei) -> () { }
+
+    fn ShaderBinary(count: GLsizei,
+                    shaders: *const GLuint,
+                    binaryformat: GLenum,
+                    binary: *const GLvoid,
+                    length: GLsizei) -> () { }
+
+    fn ShaderSource(shader: GLuint,
+                    count: GLsizei,
+                    string: *const *const GLchar,
+                    length: *const GLint) -> () { }
+
+    fn TexImage2D(target: GLenum,
+                  level: GLint,
+                  internalformat: GLint,
+                  width: GLsizei,
+                  height: GLsizei,
+                  border: GLint,
+                  format: GLenum,
+                  gl_type: GLenum,
+                  pixels: *const GLvoid) -> () { }
+
+    fn TexImage2DMultisample(target: GLenum,
+                             samples: GLsizei,
+                             internalformat: GLenum,
+                             width: GLsizei,
+                             height: GLsizei,
+                             fixedsamplelocations: GLboolean) -> () { }
+
+    fn TexImage3D(target: GLenum,
+                  level: GLint,
+                  internalformat: GLint,
+                  width: GLsizei,
+                  height: GLsizei,
+                  depth: GLsizei,
+                  border: GLint,
+                  format: GLenum,
+                  gl_type: GLenum,
+                  pixels: *const GLvoid) -> () { }
+
+    fn TexParameterfv(target: GLenum,
+                      pname: GLenum,
+                      params: *const GLfloat) -> () { }
+
+    fn TexParameteriv(target: GLenum,
+                      pname: GLenum,
+                      params: *const GLint) -> () { }
+
+    fn TexStorage2DMultisample(target: GLenum,
+                               samples: GLsizei,
+                               internalformat: GLenum,
+                               width: GLsizei,
+                               height: GLsizei,
+                               fixedsamplelocations: GLboolean) -> () { }
+
+    fn TexSubImage2D(target: GLenum,
+                     level: GLint,
+                     xoffset: GLint,
+                     yoffset: GLint,
+                     width: GLsizei,
+                     height: GLsizei,
+                     format: GLenum,
+                     gl_type: GLenum,
+                     pixels: *const GLvoid) -> () { }
+
+    fn Uniform1fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> () { }
+
+    fn Uniform1iv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLint) -> () { }
+
+    fn Uniform2fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> () { }
+
+    fn Uniform2iv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLint) -> () { }
+
+    fn Uniform3fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> () { }
+
+    fn Uniform3iv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLint) -> () { }
+
+    fn Uniform4fv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLfloat) -> () { }
+
+    fn Uniform4iv(location: GLint,
+                  count: GLsizei,
+                  value: *const GLint) -> () { }
+
+    fn UniformMatrix2fv(location: GLint,
+                        count: GLsizei,
+                        transpose: GLboolean,
+                        value: *const GLfloat) -> () { }
+
+    fn UniformMatrix3fv(location: GLint,
+                        count: GLsizei,
+                        transpose: GLboolean,
+                        value: *const GLfloat) -> () { }
+
+    fn UniformMatrix4fv(location: GLint,
+                        count: GLsizei,
+                        transpose: GLboolean,
+                        value: *const GLfloat) -> () { }
+
+    fn UnmapBuffer(target: GLenum) -> GLboolean {
+        _with_state(|state| {
+            state.mapped.take().is_some() as GLboolean
+        })
+    }
+
+    fn UseProgram(program: GLuint) -> () {
+        _set_binding(gl::CURRENT_PROGRAM, program);
+    }
+
+    fn ValidateProgram(program: GLuint) -> () { }
+
+    fn VertexAttribDivisor(index: GLuint, divisor: GLuint) -> () { }
+
+    fn VertexAttribIPointer(index: GLuint,
+                            size: GLint,
+                            gl_type: GLenum,
+                            stride: GLsizei,
+                            pointer: *const GLvoid) -> () { }
+
+    fn VertexAttribPointer(index: GLuint,
+                           size: GLint,
+                           gl_type: GLenum,
+                           normalized: GLboolean,
+                           stride: GLsizei,
+                           pointer: *const GLvoid) -> () { }
+
+    fn Viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) -> () { }
+}
+
+/// Load the mock OpenGL implementation, replacing any previously-loaded
+/// OpenGL function pointers. OpenGL functions that glitter doesn't use are
+/// left unloaded, and will panic if called.
+///
+/// # Safety
+/// OpenGL function pointers are global, so this function must not be called
+/// while another thread is using a real OpenGL context.
+pub unsafe fn load() {
+    Context::load_with(_lookup);
+}
+
+/// Reset the mock implementation for the current thread, clearing all
+/// recorded calls and restoring the default canned values.
+pub fn reset() {
+    _with_state(|state| { *state = MockState::new(); });
+}
+
+/// Get all of the calls recorded on the current thread.
+pub fn calls() -> Vec<MockCall> {
+    _with_state(|state| state.calls.clone())
+}
+
+/// Get and clear all of the calls recorded on the current thread.
+pub fn take_calls() -> Vec<MockCall> {
+    _with_state(|state| mem::replace(&mut state.calls, vec![]))
+}
+
+/// Count the recorded calls to the OpenGL function with the given name
+/// (such as `"glDrawArrays"`).
+pub fn call_count(name: &str) -> usize {
+    _with_state(|state| {
+        state.calls.iter().filter(|call| call.name == name).count()
+    })
+}
+
+/// Set the values returned by `glGetIntegerv` (and `glGetBooleanv`) for
+/// `pname`.
+pub fn set_integer(pname: GLenum, values: &[GLint]) {
+    _with_state(|state| { state.integers.insert(pname, values.to_vec()); });
+}
+
+/// Set the values returned by `glGetFloatv` for `pname`.
+pub fn set_float(pname: GLenum, values: &[GLfloat]) {
+    _with_state(|state| { state.floats.insert(pname, values.to_vec()); });
+}
+
+/// Set the string returned by `glGetString` for `name` (such as
+/// `GL_VERSION`).
+///
+/// # Panics
+/// This function will panic if `value` contains a nul byte.
+pub fn set_string(name: GLenum, value: &str) {
+    _with_state(|state| state.set_string(name, value));
+}
+
+/// Set the list of supported extensions, which are returned by both
+/// `glGetString(GL_EXTENSIONS)` and `glGetStringi`. Note that extensions
+/// are only queried when calling [`Context::current_context`]
+/// (../context/struct.ContextOf.html#method.current_context).
+///
+/// # Panics
+/// This function will panic if an extension name contains a nul byte.
+pub fn set_extensions(names: &[&str]) {
+    _with_state(|state| {
+        state.set_string(gl::EXTENSIONS, &names.join(" "));
+        state.extensions = names.iter().map(|name| {
+            CString::new(*name).expect("Extension name contains a nul byte")
+        }).collect();
+        state.integers.insert(gl::NUM_EXTENSIONS, vec![names.len() as GLint]);
+    });
+}
+
+/// Queue an error (such as `GL_INVALID_OPERATION`) to be returned by the
+/// next call to `glGetError`.
+pub fn push_error(error: GLenum) {
+    _with_state(|state| { state.errors.push_back(error); });
+}
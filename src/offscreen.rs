@@ -0,0 +1,153 @@
+//! Creates OpenGL ES contexts for offscreen rendering, using EGL. Unlike
+//! the [`headless`](../headless/index.html) module (which is meant for
+//! tests), the surface can be chosen explicitly, and each context uses its
+//! own [`GlFns`](../struct.GlFns.html), so offscreen contexts can be used
+//! on multiple threads at once (such as in a server that renders
+//! thumbnails). Enabled with the `offscreen` feature, which requires
+//! `libEGL`.
+//!
+//! # Example
+//! ```no_run
+//! extern crate glitter;
+//! use glitter::prelude::*;
+//! use glitter::offscreen::{OffscreenContext, OffscreenSurface};
+//!
+//! # fn main() {
+//! let surface = OffscreenSurface::Pbuffer { width: 256, height: 256 };
+//! let (_offscreen, mut gl) = unsafe {
+//!     OffscreenContext::new(surface).unwrap()
+//! };
+//! gl.clear(glitter::COLOR_BUFFER_BIT);
+//! # }
+//! ```
+
+use std::ffi::CString;
+use std::sync::{Once, ONCE_INIT};
+use context::{Context, ContextOf};
+use gl_fns::GlFns;
+use types::GLError;
+use egl::*;
+
+/// The surface that an [`OffscreenContext`](struct.OffscreenContext.html)
+/// draws to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffscreenSurface {
+    /// No surface, so the context has no default framebuffer, and
+    /// a [`Framebuffer`](../framebuffer/struct.Framebuffer.html) must be
+    /// bound before drawing. This requires the `EGL_KHR_surfaceless_context`
+    /// extension (see [`supports_surfaceless`](fn.supports_surfaceless.html)).
+    Surfaceless,
+
+    /// A pbuffer with the given size, which is used as the default
+    /// framebuffer.
+    Pbuffer {
+        /// The width of the pbuffer, in pixels.
+        width: u32,
+
+        /// The height of the pbuffer, in pixels.
+        height: u32
+    }
+}
+
+/// An OpenGL ES context for offscreen rendering. The context is destroyed
+/// when the `OffscreenContext` is dropped.
+pub struct OffscreenContext {
+    egl: EglContext,
+    surface: OffscreenSurface
+}
+
+impl OffscreenContext {
+    /// Create a new OpenGL ES context that draws to `surface`, make it
+    /// current, and return it along with a ready-to-use
+    /// [`Context`](../context/type.Context.html). An OpenGL ES 3 context is
+    /// created if possible; otherwise, an OpenGL ES 2 context is created.
+    ///
+    /// The returned `Context` uses its own function pointers (see
+    /// [`Context::current_context_with`]
+    /// (../context/struct.ContextOf.html#method.current_context_with)), so
+    /// [`Context::load_with`](../context/struct.ContextOf.html#method.load_with)
+    /// doesn't need to be called.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if `surface` is `Surfaceless` but surfaceless
+    /// contexts aren't supported. Other errors will be returned if EGL could
+    /// not be initialized, or if a context could not be created.
+    ///
+    /// # Safety
+    /// The same rules apply as for [`Context::current_context`]
+    /// (../context/struct.ContextOf.html#method.current_context). The
+    /// returned `Context` must not be used after the `OffscreenContext`
+    /// is dropped.
+    pub unsafe fn new(surface: OffscreenSurface)
+        -> Result<(OffscreenContext, Context), GLError>
+    {
+        let pbuffer_size = match surface {
+            OffscreenSurface::Surfaceless => {
+                if !_supports_surfaceless() {
+                    return Err(GLError::Unsupported("surfaceless contexts"));
+                }
+                None
+            },
+            OffscreenSurface::Pbuffer { width, height } => {
+                Some((width, height))
+            }
+        };
+
+        let offscreen = OffscreenContext {
+            egl: try!(EglContext::new(pbuffer_size)),
+            surface: surface
+        };
+        try!(offscreen.egl.make_current());
+
+        let gl = Context::current_context_with(_egl_fns());
+        Ok((offscreen, gl))
+    }
+
+    /// Get the surface that the context draws to.
+    pub fn surface(&self) -> OffscreenSurface {
+        self.surface
+    }
+
+    /// Make the context current on the current thread again, and make
+    /// glitter use `gl` (which must be the `Context` returned along with
+    /// this `OffscreenContext`). This is needed when switching between
+    /// multiple offscreen contexts on one thread.
+    ///
+    /// # Failures
+    /// An error will be returned if the context could not be made current.
+    ///
+    /// # Safety
+    /// The context must not be current on any other thread.
+    pub unsafe fn make_current<B, F, P, R, T>(&self,
+                                              gl: &ContextOf<B, F, P, R, T>)
+        -> Result<(), GLError>
+    {
+        try!(self.egl.make_current());
+        gl.activate();
+        Ok(())
+    }
+}
+
+/// Returns `true` if [`OffscreenSurface::Surfaceless`]
+/// (enum.OffscreenSurface.html#variant.Surfaceless) contexts are supported.
+pub fn supports_surfaceless() -> bool {
+    unsafe { _supports_surfaceless() }
+}
+
+// The function pointers for all offscreen contexts. EGL returns the same
+// function pointers for every context, so they're only loaded once.
+static mut EGL_FNS: Option<GlFns> = None;
+static EGL_FNS_INIT: Once = ONCE_INIT;
+
+fn _egl_fns() -> GlFns {
+    unsafe {
+        EGL_FNS_INIT.call_once(|| {
+            EGL_FNS = Some(GlFns::load_with(|name| {
+                let name = CString::new(name).unwrap();
+                eglGetProcAddress(name.as_ptr())
+            }));
+        });
+        EGL_FNS.unwrap()
+    }
+}
@@ -2,8 +2,12 @@
 
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
-use types::GLObject;
+use context::BaseContext;
+use types::{GLObject, GLError};
+use leaks::{self, ObjectKind};
+use bind_cache;
 
 /// An OpenGL program object.
 ///
@@ -31,8 +35,10 @@ pub struct Program {
 
 impl Drop for Program {
     fn drop(&mut self) {
+        leaks::_untrack(ObjectKind::Program, self.gl_id);
+        bind_cache::_delete(ObjectKind::Program, self.gl_id);
         unsafe {
-            gl::DeleteProgram(self.gl_id);
+            gl_fns::DeleteProgram(self.gl_id);
         }
     }
 }
@@ -41,6 +47,7 @@ impl GLObject for Program {
     type Id = GLuint;
 
     unsafe fn from_raw(id: Self::Id) -> Self {
+        leaks::_track(ObjectKind::Program, id);
         Program {
             gl_id: id,
             _phantom: PhantomData
@@ -52,6 +59,36 @@ impl GLObject for Program {
     }
 }
 
+impl Program {
+    /// Take ownership of a program that was created outside of glitter (such
+    /// as by another library), after checking that `id` names a program
+    /// in the current context.
+    /// The program will be deleted when the returned object is dropped.
+    ///
+    /// # Safety
+    /// The ID must not be owned by anything else (such as another glitter
+    /// object, or another library that will delete it). Otherwise, the
+    /// program will be deleted twice.
+    ///
+    /// # Failures
+    /// A [`GLError::InvalidObject`](../types/enum.GLError.html#variant.InvalidObject)
+    /// error will be returned if `id` is not a program.
+    ///
+    /// # See also
+    /// [`glIsProgram`](http://docs.gl/es2/glIsProgram) OpenGL docs
+    pub unsafe fn adopt<C: BaseContext>(id: GLuint, _gl: &C)
+        -> Result<Self, GLError>
+    {
+        let is_program = gl_fns::IsProgram(id) == gl::TRUE;
+        if is_program {
+            Ok(Program::from_raw(id))
+        }
+        else {
+            Err(GLError::InvalidObject(ObjectKind::Program, id))
+        }
+    }
+}
+
 
 /// An OpenGL generic vertex attribute.
 ///
@@ -3,8 +3,13 @@
 
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
-use types::GLObject;
+use context::BaseContext;
+use types::{GLObject, GLError};
+use leaks::{self, ObjectKind};
+use bind_cache;
+use stats;
 
 /// An OpenGL renderbuffer object.
 ///
@@ -36,8 +41,11 @@ pub struct Renderbuffer {
 
 impl Drop for Renderbuffer {
     fn drop(&mut self) {
+        leaks::_untrack(ObjectKind::Renderbuffer, self.gl_id);
+        bind_cache::_delete(ObjectKind::Renderbuffer, self.gl_id);
+        stats::_untrack(ObjectKind::Renderbuffer, self.gl_id);
         unsafe {
-            gl::DeleteRenderbuffers(1, &self.gl_id as *const GLuint);
+            gl_fns::DeleteRenderbuffers(1, &self.gl_id as *const GLuint);
         }
     }
 }
@@ -46,6 +54,8 @@ impl GLObject for Renderbuffer {
     type Id = GLuint;
 
     unsafe fn from_raw(id: Self::Id) -> Self {
+        leaks::_track(ObjectKind::Renderbuffer, id);
+        stats::_track(ObjectKind::Renderbuffer, id);
         Renderbuffer {
             gl_id: id,
             _phantom: PhantomData
@@ -57,6 +67,39 @@ impl GLObject for Renderbuffer {
     }
 }
 
+impl Renderbuffer {
+    /// Take ownership of a renderbuffer that was created outside of glitter (such
+    /// as by another library), after checking that `id` names a renderbuffer
+    /// in the current context.
+    /// The renderbuffer will be deleted when the returned object is dropped.
+    ///
+    /// Note that OpenGL doesn't create the renderbuffer until the ID is first bound,
+    /// so an ID from `glGenRenderbuffers` that was never bound is rejected.
+    ///
+    /// # Safety
+    /// The ID must not be owned by anything else (such as another glitter
+    /// object, or another library that will delete it). Otherwise, the
+    /// renderbuffer will be deleted twice.
+    ///
+    /// # Failures
+    /// A [`GLError::InvalidObject`](../types/enum.GLError.html#variant.InvalidObject)
+    /// error will be returned if `id` is not a renderbuffer.
+    ///
+    /// # See also
+    /// [`glIsRenderbuffer`](http://docs.gl/es2/glIsRenderbuffer) OpenGL docs
+    pub unsafe fn adopt<C: BaseContext>(id: GLuint, _gl: &C)
+        -> Result<Self, GLError>
+    {
+        let is_renderbuffer = gl_fns::IsRenderbuffer(id) == gl::TRUE;
+        if is_renderbuffer {
+            Ok(Renderbuffer::from_raw(id))
+        }
+        else {
+            Err(GLError::InvalidObject(ObjectKind::Renderbuffer, id))
+        }
+    }
+}
+
 
 
 gl_enum! {
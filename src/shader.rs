@@ -1,11 +1,18 @@
 //! Exposes the OpenGL [`Shader`](struct.Shader.html) object and related types.
 
+use std::borrow::Cow;
+use std::error;
+use std::ffi::CStr;
+use std::fmt;
 use std::marker::PhantomData;
 use std::ptr;
 use gl;
+use gl_fns;
 use gl::types::*;
-use context::{AContext, BaseContext};
+use context::{Context, AContext, BaseContext};
 use types::{GLObject, GLError};
+use leaks::{self, ObjectKind};
+use validate;
 
 /// An OpenGL shader object.
 ///
@@ -34,8 +41,9 @@ pub struct Shader {
 
 impl Drop for Shader {
     fn drop(&mut self) {
+        leaks::_untrack(ObjectKind::Shader, self.gl_id);
         unsafe {
-            gl::DeleteShader(self.gl_id);
+            gl_fns::DeleteShader(self.gl_id);
         }
     }
 }
@@ -44,6 +52,7 @@ impl GLObject for Shader {
     type Id = GLuint;
 
     unsafe fn from_raw(id: Self::Id) -> Self {
+        leaks::_track(ObjectKind::Shader, id);
         Shader {
             gl_id: id,
             _phantom: PhantomData
@@ -55,14 +64,45 @@ impl GLObject for Shader {
     }
 }
 
+impl Shader {
+    /// Take ownership of a shader that was created outside of glitter (such
+    /// as by another library), after checking that `id` names a shader
+    /// in the current context.
+    /// The shader will be deleted when the returned object is dropped.
+    ///
+    /// # Safety
+    /// The ID must not be owned by anything else (such as another glitter
+    /// object, or another library that will delete it). Otherwise, the
+    /// shader will be deleted twice.
+    ///
+    /// # Failures
+    /// A [`GLError::InvalidObject`](../types/enum.GLError.html#variant.InvalidObject)
+    /// error will be returned if `id` is not a shader.
+    ///
+    /// # See also
+    /// [`glIsShader`](http://docs.gl/es2/glIsShader) OpenGL docs
+    pub unsafe fn adopt<C: BaseContext>(id: GLuint, _gl: &C)
+        -> Result<Self, GLError>
+    {
+        let is_shader = gl_fns::IsShader(id) == gl::TRUE;
+        if is_shader {
+            Ok(Shader::from_raw(id))
+        }
+        else {
+            Err(GLError::InvalidObject(ObjectKind::Shader, id))
+        }
+    }
+}
+
 
 
 unsafe fn _get_shader_iv(shader: &Shader,
                          pname: GLenum,
                          params: *mut GLint)
 {
-    gl::GetShaderiv(shader.id(), pname, params);
+    gl_fns::GetShaderiv(shader.id(), pname, params);
     dbg_gl_sanity_check! {
+        in "glGetShaderiv"();
         GLError::InvalidEnum => "`pname` is not an accepted value",
         GLError::InvalidValue => "`shader` is not a value generated by OpenGL",
         GLError::InvalidOperation => "`shader` is not a shader object, or `pname` is GL_COMPILE_STATUS, GL_INFO_LOG_LENGTH or GL_SHADER_SOURCE_LENGTH but a shader compiler is not supported",
@@ -77,31 +117,60 @@ pub struct ShaderBuilder<'a, C: 'a>
 {
     gl: &'a C,
     ty: ShaderType,
-    source: &'a str
+    source: ShaderSource<'a>
 }
 
 impl<'a, C: 'a> ShaderBuilder<'a, C>
     where C: AContext
 {
-    fn new(gl: &'a C, ty: ShaderType, source: &'a str)
+    fn new(gl: &'a C, ty: ShaderType, source: ShaderSource<'a>)
         -> Self
     {
         ShaderBuilder { gl: gl, ty: ty, source: source }
     }
 
+    /// Add a `#define` to the shader's source. See [`ShaderSource::define`]
+    /// (struct.ShaderSource.html#method.define) for more details.
+    pub fn define<V>(mut self, name: &str, value: V) -> Self
+        where V: fmt::Display
+    {
+        self.source = self.source.define(name, value);
+        self
+    }
+
+    /// Make a named source available to `#include` directives in the
+    /// shader's source. See [`ShaderSource::include`]
+    /// (struct.ShaderSource.html#method.include) for more details.
+    pub fn include(mut self, name: &'a str, source: &'a str) -> Self {
+        self.source = self.source.include(name, source);
+        self
+    }
+
+    /// Prepend a `#version` and precision preamble matching the current
+    /// context's GLSL version, if the source does not already start with
+    /// a `#version` directive. See [`GlslVersion::preamble`]
+    /// (struct.GlslVersion.html#method.preamble) for more details.
+    pub fn auto_version(mut self) -> Self {
+        if let Some(version) = self.gl.get_glsl_version() {
+            self.source = self.source.version(version);
+        }
+        self
+    }
+
     /// Try to compile a shader with the provided options, or `Err` if
-    /// a new shader object could not be created or if there was an error
-    /// compiling the provided source.
+    /// a new shader object could not be created, if the provided source
+    /// could not be preprocessed, or if there was an error compiling
+    /// the provided source.
     pub fn try_unwrap(self) -> Result<Shader, GLError> {
+        let source = try!(self.source.expand());
         unsafe {
             let mut shader = try! {
                 self.gl.create_shader(self.ty).or_else(|_| {
-                    let msg = "Error creating OpenGL shader";
-                    Err(GLError::Message(msg.to_owned()))
+                    Err(GLError::ObjectCreationFailed("shader"))
                 })
             };
 
-            self.gl.shader_source(&mut shader, self.source);
+            self.gl.shader_source(&mut shader, &source);
             try!(self.gl.compile_shader(&mut shader));
             Ok(shader)
         }
@@ -115,6 +184,321 @@ impl<'a, C: 'a> ShaderBuilder<'a, C>
     }
 }
 
+/// A shader's source code, along with a set of `#define`s to inject and
+/// named sources that can be pulled in with `#include`. A `ShaderSource`
+/// can be passed anywhere a shader's source is expected when building
+/// a shader, such as [`gl.build_shader`]
+/// (trait.ContextShaderBuilderExt.html#tymethod.build_shader).
+///
+/// When the source is expanded, each line of the form `#include "name"`
+/// (or `#include <name>`) is replaced with the source registered under that
+/// name, recursively. Each named source is only pasted the first time it is
+/// included, like with `#pragma once`, so a source that's included by more
+/// than one other source won't be defined twice. Then, each `#define` is
+/// inserted right after the `#version` line (or at the very beginning, if
+/// there is no `#version` line). If a [`version`]
+/// (struct.ShaderSource.html#method.version) was given and the source has
+/// no `#version` line, the version's preamble is prepended first.
+///
+/// # Note
+/// Line numbers in compilation errors refer to the expanded source, which
+/// can be retrieved with [`expand`](struct.ShaderSource.html#method.expand).
+///
+/// # Example
+/// ```
+/// let lighting = r##"
+///     uniform vec3 light_positions[MAX_LIGHTS];
+/// "##;
+///
+/// let source = glitter::ShaderSource::new("#version 100\n#include \"lighting.glsl\"\n")
+///     .define("MAX_LIGHTS", 8)
+///     .include("lighting.glsl", lighting);
+///
+/// let expanded = source.expand().unwrap();
+/// let mut lines = expanded.lines();
+/// assert_eq!(lines.next(), Some("#version 100"));
+/// assert_eq!(lines.next(), Some("#define MAX_LIGHTS 8"));
+/// assert!(expanded.contains("uniform vec3 light_positions[MAX_LIGHTS];"));
+/// ```
+#[derive(Clone)]
+pub struct ShaderSource<'a> {
+    source: &'a str,
+    defines: Vec<(String, String)>,
+    includes: Vec<(&'a str, &'a str)>,
+    preamble: Option<String>
+}
+
+impl<'a> ShaderSource<'a> {
+    /// Create a new shader source, with no defines or includes.
+    pub fn new(source: &'a str) -> Self {
+        ShaderSource {
+            source: source,
+            defines: Vec::new(),
+            includes: Vec::new(),
+            preamble: None
+        }
+    }
+
+    /// Prepend the [`preamble`](struct.GlslVersion.html#method.preamble)
+    /// for `version` when expanding the source, unless the source already
+    /// has a `#version` line. This allows the same shader body to be
+    /// compiled for different OpenGL and OpenGL ES versions.
+    pub fn version(mut self, version: GlslVersion) -> Self {
+        self.preamble = Some(version.preamble());
+        self
+    }
+
+    /// Inject `#define name value` into the source after the `#version`
+    /// line.
+    pub fn define<V>(mut self, name: &str, value: V) -> Self
+        where V: fmt::Display
+    {
+        self.defines.push((name.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Register a named source, which will replace any `#include` directive
+    /// that refers to `name`. If the same name is registered more than once,
+    /// the last source wins.
+    pub fn include(mut self, name: &'a str, source: &'a str) -> Self {
+        self.includes.retain(|&(n, _)| n != name);
+        self.includes.push((name, source));
+        self
+    }
+
+    /// Expand all `#include` directives and inject all `#define`s,
+    /// returning the resulting source.
+    ///
+    /// # Failures
+    /// An error will be returned if an `#include` directive refers to
+    /// a name that was not registered with [`include`]
+    /// (struct.ShaderSource.html#method.include), or if a source
+    /// (directly or indirectly) includes itself.
+    ///
+    /// # Example
+    /// ```
+    /// // Both `a.glsl` and `b.glsl` include `common.glsl`
+    /// let source = glitter::ShaderSource::new("#include \"a.glsl\"\n#include \"b.glsl\"\n")
+    ///     .include("a.glsl", "#include \"common.glsl\"\nfloat a;\n")
+    ///     .include("b.glsl", "#include \"common.glsl\"\nfloat b;\n")
+    ///     .include("common.glsl", "float common;\n");
+    ///
+    /// let expanded = source.expand().unwrap();
+    /// assert_eq!(expanded, "float common;\nfloat a;\nfloat b;\n");
+    /// ```
+    pub fn expand(&self) -> Result<Cow<'a, str>, GLError> {
+        if self.defines.is_empty()
+            && self.includes.is_empty()
+            && self.preamble.is_none()
+        {
+            return Ok(Cow::Borrowed(self.source));
+        }
+
+        let mut body = String::with_capacity(self.source.len());
+        let mut stack = Vec::new();
+        let mut expanded = Vec::new();
+        try!(self._expand_includes(self.source,
+                                   &mut stack,
+                                   &mut expanded,
+                                   &mut body));
+
+        let mut defines = String::new();
+        for &(ref name, ref value) in &self.defines {
+            defines.push_str(&format!("#define {} {}\n", name, value));
+        }
+
+        // Split the source right after the `#version` line (if any), so the
+        // defines end up after it
+        let mut offset = 0;
+        let mut split = 0;
+        for line in body.split('\n') {
+            offset += line.len() + 1;
+            if line.trim().starts_with("#version") {
+                split = offset;
+                break;
+            }
+        }
+
+        let (head, tail) = if split == 0 {
+            let preamble = self.preamble.as_ref().map_or("", |p| &p[..]);
+            (preamble, &body[..])
+        }
+        else if split > body.len() {
+            (&body[..], "")
+        }
+        else {
+            body.split_at(split)
+        };
+
+        let mut expanded = String::with_capacity(body.len() + defines.len() + 1);
+        expanded.push_str(head);
+        if !head.is_empty() && !head.ends_with('\n') {
+            expanded.push('\n');
+        }
+        expanded.push_str(&defines);
+        expanded.push_str(tail);
+
+        Ok(Cow::Owned(expanded))
+    }
+
+    // Paste the included sources into `source`. `stack` holds the names of
+    // the sources currently being expanded (to catch recursive includes),
+    // and `expanded` holds the names of all sources pasted so far
+    fn _expand_includes(&self,
+                        source: &str,
+                        stack: &mut Vec<&'a str>,
+                        expanded: &mut Vec<&'a str>,
+                        out: &mut String)
+        -> Result<(), GLError>
+    {
+        for line in source.lines() {
+            let name = match _include_name(line) {
+                Some(name) => { name },
+                None => {
+                    out.push_str(line);
+                    out.push('\n');
+                    continue;
+                }
+            };
+
+            let (include_name, include_source) = {
+                let found = self.includes.iter().find(|&&(n, _)| n == name);
+                match found {
+                    Some(&include) => { include },
+                    None => {
+                        let name = name.to_owned();
+                        return Err(ShaderIncludeError::UnknownInclude(name).into());
+                    }
+                }
+            };
+
+            if stack.contains(&include_name) {
+                let name = name.to_owned();
+                return Err(ShaderIncludeError::RecursiveInclude(name).into());
+            }
+            if expanded.contains(&include_name) {
+                continue;
+            }
+
+            stack.push(include_name);
+            expanded.push(include_name);
+            try!(self._expand_includes(include_source, stack, expanded, out));
+            stack.pop();
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a str> for ShaderSource<'a> {
+    fn from(source: &'a str) -> Self {
+        ShaderSource::new(source)
+    }
+}
+
+// Get the name from an `#include "name"` or `#include <name>` directive
+fn _include_name(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if !line.starts_with('#') {
+        return None;
+    }
+
+    let directive = line[1..].trim();
+    if !directive.starts_with("include") {
+        return None;
+    }
+
+    let arg = directive["include".len()..].trim();
+    let close = if arg.starts_with('"') {
+        '"'
+    }
+    else if arg.starts_with('<') {
+        '>'
+    }
+    else {
+        return None;
+    };
+
+    let arg = &arg[1..];
+    arg.find(close).map(|end| &arg[..end])
+}
+
+/// A GLSL version, as reported by the OpenGL implementation.
+///
+/// # Example
+/// ```
+/// use glitter::GlslVersion;
+///
+/// let es2 = GlslVersion::parse("OpenGL ES GLSL ES 1.00").unwrap();
+/// assert_eq!(es2, GlslVersion { version: 100, es: true });
+/// assert_eq!(es2.preamble(), "#version 100\nprecision mediump float;\n");
+///
+/// let gl33 = GlslVersion::parse("3.30 NVIDIA via Cg compiler").unwrap();
+/// assert_eq!(gl33, GlslVersion { version: 330, es: false });
+/// assert_eq!(gl33.preamble(), "#version 330 core\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlslVersion {
+    /// The version number, as used in a `#version` directive (such as `100`
+    /// for GLSL ES 1.00, or `330` for GLSL 3.30).
+    pub version: u32,
+
+    /// Whether this is a GLSL ES version.
+    pub es: bool
+}
+
+impl GlslVersion {
+    /// Parse a GLSL version from a `GL_SHADING_LANGUAGE_VERSION` string,
+    /// or return `None` if no version number could be found.
+    pub fn parse(s: &str) -> Option<Self> {
+        let es = s.contains("OpenGL ES");
+        let start = match s.find(|c: char| c.is_digit(10)) {
+            Some(start) => { start },
+            None => { return None; }
+        };
+        let (major, rest) = match _split_number(&s[start..]) {
+            Some(major) => { major },
+            None => { return None; }
+        };
+        if !rest.starts_with('.') {
+            return None;
+        }
+        let minor_digits = rest[1..].chars()
+                                    .take_while(|c| c.is_digit(10))
+                                    .count();
+        let minor = match minor_digits {
+            0 => { return None; },
+            1 => { rest[1..2].parse::<u32>().unwrap() * 10 },
+            _ => { rest[1..3].parse::<u32>().unwrap() }
+        };
+
+        Some(GlslVersion { version: major * 100 + minor, es: es })
+    }
+
+    /// Get the preamble to prepend to a shader for this version. For
+    /// GLSL ES, this is the `#version` directive followed by a default
+    /// `precision mediump float;` declaration (which fragment shaders
+    /// require). For desktop GLSL 1.50 and later, this is a `#version`
+    /// directive using the `core` profile.
+    pub fn preamble(&self) -> String {
+        if self.es {
+            let version = if self.version >= 300 {
+                format!("{} es", self.version)
+            }
+            else {
+                format!("{}", self.version)
+            };
+            format!("#version {}\nprecision mediump float;\n", version)
+        }
+        else if self.version >= 150 {
+            format!("#version {} core\n", self.version)
+        }
+        else {
+            format!("#version {}\n", self.version)
+        }
+    }
+}
+
 /// An extension trait for [`ContextOf`](../context/struct.ContextOf.html) that
 /// adds functions to build shaders using the [`ShaderBuilder`]
 /// (struct.ShaderBuilder.html) interface.
@@ -140,8 +524,13 @@ pub trait ContextShaderBuilderExt: AContext + Sized {
     /// let shader = gl.build_shader(glitter::VERTEX_SHADER, vertex_source).unwrap();
     /// # }
     /// ```
-    fn build_shader<'a>(&'a self, ty: ShaderType, source: &'a str)
-        -> ShaderBuilder<'a, Self>;
+    ///
+    /// The source can either be a plain `&str`, or a [`ShaderSource`]
+    /// (struct.ShaderSource.html) with `#define`s and `#include`s to expand
+    /// before compiling.
+    fn build_shader<'a, S>(&'a self, ty: ShaderType, source: S)
+        -> ShaderBuilder<'a, Self>
+        where S: Into<ShaderSource<'a>>;
 
     /// Build a new fragment shader with the provided shader source.
     ///
@@ -161,8 +550,9 @@ pub trait ContextShaderBuilderExt: AContext + Sized {
     /// let shader = gl.build_fragment_shader(fragment_source).unwrap();
     /// # }
     /// ```
-    fn build_fragment_shader<'a>(&'a self, source: &'a str)
+    fn build_fragment_shader<'a, S>(&'a self, source: S)
         -> ShaderBuilder<'a, Self>
+        where S: Into<ShaderSource<'a>>
     {
         self.build_shader(ShaderType::FragmentShader, source)
     }
@@ -188,18 +578,20 @@ pub trait ContextShaderBuilderExt: AContext + Sized {
     /// let shader = gl.build_vertex_shader(vertex_source).unwrap();
     /// # }
     /// ```
-    fn build_vertex_shader<'a>(&'a self, source: &'a str)
+    fn build_vertex_shader<'a, S>(&'a self, source: S)
         -> ShaderBuilder<'a, Self>
+        where S: Into<ShaderSource<'a>>
     {
         self.build_shader(ShaderType::VertexShader, source)
     }
 }
 
 impl<C: AContext> ContextShaderBuilderExt for C {
-    fn build_shader<'a>(&'a self, ty: ShaderType, source: &'a str)
+    fn build_shader<'a, S>(&'a self, ty: ShaderType, source: S)
         -> ShaderBuilder<'a, C>
+        where S: Into<ShaderSource<'a>>
     {
-        ShaderBuilder::new(self, ty, source)
+        ShaderBuilder::new(self, ty, source.into())
     }
 }
 
@@ -252,8 +644,9 @@ pub trait ContextShaderExt: BaseContext {
     unsafe fn create_shader(&self, shader_type: ShaderType)
         -> Result<Shader, ()>
     {
-        let id = gl::CreateShader(shader_type.gl_enum());
+        let id = gl_fns::CreateShader(shader_type.gl_enum());
         dbg_gl_sanity_check! {
+            in "glCreateShader"();
             GLError::InvalidEnum => "`shaderType` is not an accepted value",
             _ => "Unknown error"
         }
@@ -286,10 +679,11 @@ pub trait ContextShaderExt: BaseContext {
             let source_ptr = source.as_ptr() as *const GLchar;
             let source_len = source.len() as GLint;
 
-            gl::ShaderSource(shader.id(), 1,
+            gl_fns::ShaderSource(shader.id(), 1,
                              &source_ptr as *const *const GLchar,
                              &source_len as *const GLint);
             dbg_gl_error! {
+                in "glShaderSource"();
                 GLError::InvalidOperation => "`shader` is not a shader object or shader compiler is not supported",
                 GLError::InvalidValue => "`shader` is not a value generated by OpenGL or `count` < 0",
                 _ => "Unknown error"
@@ -308,9 +702,11 @@ pub trait ContextShaderExt: BaseContext {
     /// shader compilation is unavailable with the current OpenGL context.
     ///
     /// # Failures
-    /// If a compilation error occurs, an `Err` value will be returned
-    /// with the compilation error messages (as determined by
-    /// [`gl.get_shader_info_log`](trait.ContextShaderExt.html#method.get_shader_info_log)).
+    /// If a compilation error occurs, a `GLError::CompileError` value will
+    /// be returned with the compilation error messages (as determined by
+    /// [`gl.get_shader_info_log`](trait.ContextShaderExt.html#method.get_shader_info_log)),
+    /// along with the diagnostics parsed from them. See the [`InfoLog`]
+    /// (struct.InfoLog.html) docs for more details.
     ///
     /// # See also
     /// [`gl.create_shader`](trait.ContextShaderExt.html#method.create_shader):
@@ -326,8 +722,9 @@ pub trait ContextShaderExt: BaseContext {
     /// [`glCompileShader`](http://docs.gl/es2/glCompileShader) OpenGL docs
     fn compile_shader(&self, shader: &mut Shader) -> Result<(), GLError> {
         let success = unsafe {
-            gl::CompileShader(shader.id());
+            gl_fns::CompileShader(shader.id());
             dbg_gl_error! {
+                in "glCompileShader"();
                 GLError::InvalidOperation => "`shader` is not a shader object or shader compiler is not supported",
                 GLError::InvalidValue => "`shader` is not a value generated by OpenGL",
                 _ => "Unknown error"
@@ -349,7 +746,7 @@ pub trait ContextShaderExt: BaseContext {
                 Some(s) => { s },
                 None => { String::from("[Unknown shader error]") }
             };
-            Err(GLError::Message(msg))
+            Err(GLError::CompileError(InfoLog::parse(msg)))
         }
     }
 
@@ -394,11 +791,12 @@ pub trait ContextShaderExt: BaseContext {
             if info_length > 0 {
                 let mut bytes = Vec::<u8>::with_capacity(info_length as usize);
 
-                gl::GetShaderInfoLog(shader.id(),
+                gl_fns::GetShaderInfoLog(shader.id(),
                                      info_length,
                                      ptr::null_mut(),
                                      bytes.as_mut_ptr() as *mut GLchar);
                 dbg_gl_sanity_check! {
+                    in "glGetShaderInfoLog"();
                     GLError::InvalidValue => "`shader` is not a value generated by OpenGL, or `maxLength` < 0",
                     GLError::InvalidOperation => "`shader` is not a shader object",
                     _ => "Unknown error"
@@ -413,12 +811,234 @@ pub trait ContextShaderExt: BaseContext {
             }
         }
     }
+
+    /// Get the list of binary formats that the OpenGL implementation
+    /// accepts for [`gl.shader_binary`]
+    /// (trait.ContextShaderExt.html#method.shader_binary). The list may
+    /// be empty, in which case shaders can only be loaded from source.
+    ///
+    /// # See also
+    /// [`glGet`](http://docs.gl/es2/glGet) OpenGL docs (see
+    /// `GL_SHADER_BINARY_FORMATS`)
+    fn get_shader_binary_formats(&self) -> Vec<ShaderBinaryFormat> {
+        unsafe {
+            let mut count: GLint = 0;
+            gl_fns::GetIntegerv(gl::NUM_SHADER_BINARY_FORMATS, &mut count);
+            dbg_gl_sanity_check! {
+                in "glGetIntegerv"();
+                GLError::InvalidEnum => "`pname` is not an accepted value",
+                _ => "Unknown error"
+            }
+
+            if count <= 0 {
+                return vec![];
+            }
+
+            let mut formats: Vec<GLint> = vec![0; count as usize];
+            gl_fns::GetIntegerv(gl::SHADER_BINARY_FORMATS, formats.as_mut_ptr());
+            dbg_gl_sanity_check! {
+                in "glGetIntegerv"();
+                GLError::InvalidEnum => "`pname` is not an accepted value",
+                _ => "Unknown error"
+            }
+
+            formats.into_iter().map(|format| {
+                ShaderBinaryFormat { gl_enum: format as GLenum }
+            }).collect()
+        }
+    }
+
+    /// Load a precompiled shader binary into one or more shader objects,
+    /// for use in place of [`gl.shader_source`]
+    /// (trait.ContextShaderExt.html#method.shader_source) and
+    /// [`gl.compile_shader`](trait.ContextShaderExt.html#method.compile_shader).
+    /// Each shader should be created with the type the binary was compiled
+    /// for. Some vendor formats can hold both a vertex and a fragment shader
+    /// in a single binary, in which case both shaders should be passed.
+    ///
+    /// # Failures
+    /// An error will be returned if `format` is not supported by the
+    /// OpenGL implementation, or if `binary` could not be loaded with the
+    /// given format.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// # let binary: Vec<u8> = vec![];
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let format = gl.get_shader_binary_formats()[0];
+    /// let mut shaders = unsafe {
+    ///     vec![gl.create_shader(glitter::VERTEX_SHADER).unwrap()]
+    /// };
+    /// gl.shader_binary(&mut shaders, format, &binary).unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # See also
+    /// [`glShaderBinary`](http://docs.gl/es2/glShaderBinary) OpenGL docs
+    fn shader_binary(&self,
+                     shaders: &mut [Shader],
+                     format: ShaderBinaryFormat,
+                     binary: &[u8])
+        -> Result<(), GLError>
+    {
+        let ids: Vec<GLuint> = shaders.iter().map(|s| s.id()).collect();
+        unsafe {
+            // NOTE: Errors generated before this call are cleared, so they
+            //       aren't mistaken for an error from loading the binary
+            validate::clear_errors();
+
+            gl_fns::ShaderBinary(ids.len() as GLsizei,
+                             ids.as_ptr(),
+                             format.gl_enum,
+                             binary.as_ptr() as *const _,
+                             binary.len() as GLsizei);
+            match Context::get_error() {
+                Some(GLError::InvalidEnum) => {
+                    Err(ShaderBinaryError::UnsupportedFormat(format).into())
+                },
+                Some(GLError::InvalidValue) => {
+                    Err(ShaderBinaryError::InvalidBinary.into())
+                },
+                Some(GLError::InvalidOperation) => {
+                    Err(ShaderBinaryError::InvalidShaders.into())
+                },
+                Some(err) => { Err(err) },
+                None => { Ok(()) }
+            }
+        }
+    }
+
+    /// Get the GLSL version supported by the current context, or `None`
+    /// if the version string could not be parsed.
+    ///
+    /// # See also
+    /// [`glGetString`](http://docs.gl/es2/glGetString) OpenGL docs
+    fn get_glsl_version(&self) -> Option<GlslVersion> {
+        unsafe {
+            let version = gl_fns::GetString(gl::SHADING_LANGUAGE_VERSION);
+            dbg_gl_sanity_check! {
+                in "glGetString"();
+                GLError::InvalidEnum => "`name` is not an accepted value",
+                _ => "Unknown error"
+            }
+
+            if version.is_null() {
+                return None;
+            }
+
+            let version = CStr::from_ptr(version as *const _);
+            version.to_str().ok().and_then(GlslVersion::parse)
+        }
+    }
 }
 
 impl<C: BaseContext> ContextShaderExt for C {
 
 }
 
+/// A format for precompiled shader binaries, which can be loaded with
+/// [`gl.shader_binary`](trait.ContextShaderExt.html#method.shader_binary).
+/// Binary formats are vendor-specific; the formats supported by the current
+/// context can be retrieved with [`gl.get_shader_binary_formats`]
+/// (trait.ContextShaderExt.html#method.get_shader_binary_formats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShaderBinaryFormat {
+    /// The OpenGL enum value for the format.
+    pub gl_enum: GLenum
+}
+
+/// The errors that can occur while preprocessing a shader's source (see
+/// [`ShaderSource::expand`](struct.ShaderSource.html#method.expand)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShaderIncludeError {
+    /// The source includes a name that was not provided. The name is
+    /// attached.
+    UnknownInclude(String),
+
+    /// An include includes itself (directly or indirectly). The name of the
+    /// include is attached.
+    RecursiveInclude(String)
+}
+
+impl fmt::Display for ShaderIncludeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShaderIncludeError::UnknownInclude(ref name) => {
+                write!(f, "Unknown shader include: {:?}", name)
+            },
+            ShaderIncludeError::RecursiveInclude(ref name) => {
+                write!(f, "Recursive shader include: {:?}", name)
+            }
+        }
+    }
+}
+
+impl error::Error for ShaderIncludeError {
+    fn description(&self) -> &str {
+        match *self {
+            ShaderIncludeError::UnknownInclude(_) => "A shader included a name that was not provided",
+            ShaderIncludeError::RecursiveInclude(_) => "A shader include included itself"
+        }
+    }
+}
+
+impl From<ShaderIncludeError> for GLError {
+    fn from(e: ShaderIncludeError) -> GLError {
+        GLError::ShaderIncludeError(e)
+    }
+}
+
+/// The errors that can occur while loading a shader binary with
+/// [`gl.shader_binary`](trait.ContextShaderExt.html#method.shader_binary).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderBinaryError {
+    /// The binary format is not supported by the current context.
+    UnsupportedFormat(ShaderBinaryFormat),
+
+    /// The binary is not valid for the given format.
+    InvalidBinary,
+
+    /// More than one shader of the same type was provided, or one of the
+    /// shaders was not a shader object.
+    InvalidShaders
+}
+
+impl fmt::Display for ShaderBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShaderBinaryError::UnsupportedFormat(format) => {
+                write!(f, "Unsupported shader binary format: {:?}", format)
+            },
+            ShaderBinaryError::InvalidBinary => {
+                write!(f, "Invalid shader binary for the given format")
+            },
+            ShaderBinaryError::InvalidShaders => {
+                write!(f, "More than one shader object of the same type was provided, or a shader was not a shader object")
+            }
+        }
+    }
+}
+
+impl error::Error for ShaderBinaryError {
+    fn description(&self) -> &str {
+        match *self {
+            ShaderBinaryError::UnsupportedFormat(_) => "The shader binary format is not supported",
+            ShaderBinaryError::InvalidBinary => "The shader binary is not valid for its format",
+            ShaderBinaryError::InvalidShaders => "The shaders for a shader binary were not valid"
+        }
+    }
+}
+
+impl From<ShaderBinaryError> for GLError {
+    fn from(e: ShaderBinaryError) -> GLError {
+        GLError::ShaderBinaryError(e)
+    }
+}
+
 gl_enum! {
     /// The possible types of shader objects.
     pub gl_enum ShaderType {
@@ -430,3 +1050,273 @@ gl_enum! {
         pub const FragmentShader as FRAGMENT_SHADER = gl::FRAGMENT_SHADER
     }
 }
+
+
+
+/// The severity of a single [`ShaderDiagnostic`](struct.ShaderDiagnostic.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    /// A problem that prevented the shader from being compiled or linked.
+    Error,
+
+    /// A potential problem that did not prevent compilation or linking.
+    Warning,
+
+    /// Any other informational message.
+    Info
+}
+
+/// A single message from a shader or program info log, such as a compilation
+/// error, along with the location in the source that it refers to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShaderDiagnostic {
+    /// The source string number that the diagnostic refers to (the first
+    /// number in a location such as `0:12`), if the driver reported one.
+    pub file_id: Option<u32>,
+
+    /// The line number that the diagnostic refers to, if the driver
+    /// reported one.
+    pub line: Option<u32>,
+
+    /// The column number that the diagnostic refers to, if the driver
+    /// reported one.
+    pub column: Option<u32>,
+
+    /// The severity of the diagnostic. Messages without an explicit
+    /// severity are treated as errors.
+    pub severity: DiagnosticSeverity,
+
+    /// The message text, with the location and severity removed.
+    pub message: String
+}
+
+/// The info log of a shader or program object, as returned by the OpenGL
+/// driver, along with the diagnostics that could be parsed from it.
+///
+/// The exact format of an info log is left up to each driver. `InfoLog`
+/// understands the most common formats, such as:
+///
+/// - Mesa: `0:12(5): error: ...`
+/// - NVIDIA: `0(12) : error C0000: ...`
+/// - AMD: `ERROR: 0:12: error(#132) ...`
+/// - ANGLE (and other glslang-based compilers): `ERROR: 0:12: ...`
+///
+/// Lines that can't be parsed as a new diagnostic are treated as a
+/// continuation of the previous diagnostic's message.
+///
+/// # Example
+/// ```
+/// let log = glitter::InfoLog::parse(String::from(
+///     "0:3(10): error: `foo' undeclared\n\
+///      0(7) : warning C7022: unrecognized profile specifier\n\
+///      ERROR: 0:9: 'bar' : undeclared identifier"
+/// ));
+///
+/// assert_eq!(log.diagnostics.len(), 3);
+///
+/// assert_eq!(log.diagnostics[0].line, Some(3));
+/// assert_eq!(log.diagnostics[0].column, Some(10));
+/// assert_eq!(log.diagnostics[0].severity, glitter::DiagnosticSeverity::Error);
+/// assert_eq!(log.diagnostics[0].message, "`foo' undeclared");
+///
+/// assert_eq!(log.diagnostics[1].line, Some(7));
+/// assert_eq!(log.diagnostics[1].severity, glitter::DiagnosticSeverity::Warning);
+/// assert_eq!(log.diagnostics[1].message, "unrecognized profile specifier");
+///
+/// assert_eq!(log.diagnostics[2].file_id, Some(0));
+/// assert_eq!(log.diagnostics[2].line, Some(9));
+/// assert_eq!(log.diagnostics[2].message, "'bar' : undeclared identifier");
+/// ```
+#[derive(Debug, Clone)]
+pub struct InfoLog {
+    /// The raw info log, exactly as returned by the driver.
+    pub raw: String,
+
+    /// The diagnostics parsed from the info log.
+    pub diagnostics: Vec<ShaderDiagnostic>
+}
+
+impl InfoLog {
+    /// Parse a raw info log into a list of diagnostics.
+    pub fn parse(raw: String) -> Self {
+        let mut diagnostics = Vec::<ShaderDiagnostic>::new();
+
+        for line in raw.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            match _parse_diagnostic(line) {
+                Some(diagnostic) => {
+                    diagnostics.push(diagnostic);
+                },
+                None => {
+                    if let Some(last) = diagnostics.last_mut() {
+                        last.message.push('\n');
+                        last.message.push_str(line);
+                    }
+                }
+            }
+        }
+
+        InfoLog {
+            raw: raw,
+            diagnostics: diagnostics
+        }
+    }
+}
+
+fn _skip_whitespace(s: &str) -> &str {
+    match s.find(|c: char| !c.is_whitespace()) {
+        Some(idx) => { &s[idx..] },
+        None => { "" }
+    }
+}
+
+fn _split_number(s: &str) -> Option<(u32, &str)> {
+    let end = s.find(|c: char| !c.is_digit(10)).unwrap_or(s.len());
+    if end == 0 {
+        return None;
+    }
+
+    s[..end].parse().ok().map(|n| (n, &s[end..]))
+}
+
+// Parse a location such as `0:12:`, `0:12(5):` or `0(12) :`, returning
+// the source string number, the line, the column and the remaining text
+fn _parse_location(s: &str) -> Option<(u32, u32, Option<u32>, &str)> {
+    let (file_id, rest) = match _split_number(s) {
+        Some(x) => { x },
+        None => { return None; }
+    };
+
+    let (line, column, rest) = if rest.starts_with(':') {
+        let (line, rest) = match _split_number(&rest[1..]) {
+            Some(x) => { x },
+            None => { return None; }
+        };
+
+        if rest.starts_with('(') {
+            let (column, rest) = match _split_number(&rest[1..]) {
+                Some(x) => { x },
+                None => { return None; }
+            };
+            if !rest.starts_with(')') {
+                return None;
+            }
+            (line, Some(column), &rest[1..])
+        }
+        else {
+            (line, None, rest)
+        }
+    }
+    else if rest.starts_with('(') {
+        let (line, rest) = match _split_number(&rest[1..]) {
+            Some(x) => { x },
+            None => { return None; }
+        };
+        if !rest.starts_with(')') {
+            return None;
+        }
+        (line, None, &rest[1..])
+    }
+    else {
+        return None;
+    };
+
+    let rest = _skip_whitespace(rest);
+    if rest.starts_with(':') {
+        Some((file_id, line, column, &rest[1..]))
+    }
+    else {
+        None
+    }
+}
+
+// Parse a severity such as `error:`, `ERROR:`, `error C0000:`
+// or `error(#132)`, returning the severity and the remaining text
+fn _parse_severity(s: &str) -> Option<(DiagnosticSeverity, &str)> {
+    let words = [("error", DiagnosticSeverity::Error),
+                 ("ERROR", DiagnosticSeverity::Error),
+                 ("warning", DiagnosticSeverity::Warning),
+                 ("WARNING", DiagnosticSeverity::Warning),
+                 ("info", DiagnosticSeverity::Info),
+                 ("INFO", DiagnosticSeverity::Info),
+                 ("note", DiagnosticSeverity::Info),
+                 ("NOTE", DiagnosticSeverity::Info)];
+
+    for &(word, severity) in words.iter() {
+        if !s.starts_with(word) {
+            continue;
+        }
+
+        let rest = &s[word.len()..];
+        if rest.starts_with(':') {
+            return Some((severity, &rest[1..]));
+        }
+        else if rest.starts_with('(') {
+            if let Some(close) = rest.find(')') {
+                let rest = _skip_whitespace(&rest[(close + 1)..]);
+                if rest.starts_with(':') {
+                    return Some((severity, &rest[1..]));
+                }
+                return Some((severity, rest));
+            }
+        }
+        else if rest.starts_with(' ') {
+            let rest = _skip_whitespace(rest);
+            if let Some(colon) = rest.find(':') {
+                let code = &rest[..colon];
+                if !code.is_empty() && !code.contains(char::is_whitespace) {
+                    return Some((severity, &rest[(colon + 1)..]));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn _parse_diagnostic(line: &str) -> Option<ShaderDiagnostic> {
+    let (mut severity, rest) = match _parse_severity(line) {
+        Some((severity, rest)) => { (Some(severity), rest) },
+        None => { (None, line) }
+    };
+
+    let rest = _skip_whitespace(rest);
+    let (location, rest) = match _parse_location(rest) {
+        Some((file_id, line, column, rest)) => {
+            (Some((file_id, line, column)), rest)
+        },
+        None => { (None, rest) }
+    };
+
+    let rest = _skip_whitespace(rest);
+    let rest = match _parse_severity(rest) {
+        Some((s, rest)) => {
+            severity = Some(s);
+            rest
+        },
+        None => { rest }
+    };
+
+    if severity.is_none() && location.is_none() {
+        return None;
+    }
+
+    let (file_id, line, column) = match location {
+        Some((file_id, line, column)) => {
+            (Some(file_id), Some(line), column)
+        },
+        None => { (None, None, None) }
+    };
+
+    Some(ShaderDiagnostic {
+        file_id: file_id,
+        line: line,
+        column: column,
+        severity: severity.unwrap_or(DiagnosticSeverity::Error),
+        message: rest.trim().to_owned()
+    })
+}
@@ -0,0 +1,150 @@
+//! Contains [`ShadowMap`](struct.ShadowMap.html), a depth texture and
+//! a framebuffer for rendering it, for shadow mapping.
+//!
+//! # Example
+//! ```no_run
+//! use glitter::prelude::*;
+//! use glitter::shadow_map::ShadowMap;
+//!
+//! let mut gl = unsafe { glitter::Context::current_context() };
+//! let mut shadow_map = ShadowMap::new(&mut gl, 1024, 1024).unwrap();
+//!
+//! {
+//!     let (gl_fbo, gl) = shadow_map.begin_pass(&mut gl);
+//!     // Draw the scene from the light's point of view...
+//! }
+//!
+//! // ...then sample `shadow_map.texture_mut()` when drawing the scene
+//! ```
+
+use context::{ContextExt, ContextFramebufferExt,
+              ContextFramebufferBuilderExt, FramebufferContext,
+              FramebufferBinding, DEPTH_ATTACHMENT, ContextTextureBuilderExt,
+              SomeTextureUnitContext};
+use framebuffer::Framebuffer;
+use texture::{Texture2d, NEAREST, CLAMP_TO_EDGE};
+use image_data::{RenderbufferFormat, DEPTH_COMPONENT16};
+use types::{GLError, Viewport, DEPTH_BUFFER_BIT};
+
+/// A depth texture, along with a framebuffer that renders to it (with no
+/// color attachment). This handles several platform-specific details of
+/// depth-only framebuffers:
+///
+/// - The framebuffer's draw and read buffers are set to `GL_NONE` where
+///   required (see [`gl.disable_color_buffers`]
+///   (../context/framebuffer_context/trait.ContextFramebufferExt.html#method.disable_color_buffers)).
+/// - OpenGL ES 2 only supports unsized depth texture formats (with the
+///   `GL_OES_depth_texture` extension), so the format only selects the
+///   depth precision.
+/// - The texture uses `NEAREST` filtering, since OpenGL ES 3 treats depth
+///   textures with linear filtering (and no comparison mode) as incomplete,
+///   and `CLAMP_TO_EDGE` wrapping, so that any shadow map size can be used
+///   on OpenGL ES 2.
+pub struct ShadowMap {
+    texture: Texture2d,
+    framebuffer: Framebuffer,
+    width: u32,
+    height: u32
+}
+
+impl ShadowMap {
+    /// Create a shadow map with a 16-bit depth texture, which every context
+    /// that supports depth textures can render to.
+    ///
+    /// # Failures
+    /// An error will be returned if the context doesn't support depth
+    /// textures (see [`Extensions::has_depth_texture`]
+    /// (../extensions/struct.Extensions.html#method.has_depth_texture)),
+    /// if `width` or `height` is 0, or if the framebuffer is not
+    /// framebuffer-complete.
+    pub fn new<C>(gl: &mut C, width: u32, height: u32) -> Result<Self, GLError>
+        where for<'a> &'a mut C: SomeTextureUnitContext + FramebufferContext
+    {
+        ShadowMap::with_format(gl, DEPTH_COMPONENT16, width, height)
+    }
+
+    /// Create a shadow map with a depth texture with the given format,
+    /// which must be `DEPTH_COMPONENT16`, `DEPTH_COMPONENT24`, or
+    /// `DEPTH_COMPONENT32F`.
+    ///
+    /// # Failures
+    /// An error will be returned if the format isn't a depth format, or if
+    /// the context doesn't support depth textures with the format. See
+    /// [`new`](struct.ShadowMap.html#method.new) for the other failure
+    /// cases.
+    pub fn with_format<C>(gl: &mut C,
+                          format: RenderbufferFormat,
+                          width: u32,
+                          height: u32)
+        -> Result<Self, GLError>
+        where for<'a> &'a mut C: SomeTextureUnitContext + FramebufferContext
+    {
+        let mut texture = try!(gl.build_texture_2d()
+                                 .depth(format, width, height)
+                                 .min_filter(NEAREST)
+                                 .mag_filter(NEAREST)
+                                 .wrap_s(CLAMP_TO_EDGE)
+                                 .wrap_t(CLAMP_TO_EDGE)
+                                 .try_unwrap());
+        let framebuffer = try!(gl.build_framebuffer()
+                                 .texture_2d(DEPTH_ATTACHMENT, &mut texture, 0)
+                                 .try_unwrap());
+        Ok(ShadowMap {
+            texture: texture,
+            framebuffer: framebuffer,
+            width: width,
+            height: height
+        })
+    }
+
+    /// Get the width and height of the shadow map.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Get the viewport that covers the whole shadow map, to use while
+    /// rendering to it.
+    pub fn viewport(&self) -> Viewport {
+        Viewport::new(0, 0, self.width, self.height)
+    }
+
+    /// Get the depth texture.
+    pub fn texture(&self) -> &Texture2d {
+        &self.texture
+    }
+
+    /// Get the depth texture mutably, such as to bind it for drawing.
+    pub fn texture_mut(&mut self) -> &mut Texture2d {
+        &mut self.texture
+    }
+
+    /// Get the framebuffer that renders to the depth texture.
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.framebuffer
+    }
+
+    /// Get the framebuffer that renders to the depth texture mutably, such
+    /// as to bind it.
+    pub fn framebuffer_mut(&mut self) -> &mut Framebuffer {
+        &mut self.framebuffer
+    }
+
+    /// Convert the shadow map into its depth texture and framebuffer.
+    pub fn into_parts(self) -> (Texture2d, Framebuffer) {
+        (self.texture, self.framebuffer)
+    }
+
+    /// Start rendering to the shadow map: bind its framebuffer, set the
+    /// viewport to cover the shadow map, and clear its depth buffer. The
+    /// viewport is left set afterwards, so it should be reset before
+    /// drawing to another framebuffer.
+    pub fn begin_pass<C>(&mut self, gl: C) -> (FramebufferBinding, C::Rest)
+        where C: FramebufferContext
+    {
+        let viewport = self.viewport();
+        let (gl_fbo, gl) = gl.bind_framebuffer(&mut self.framebuffer);
+        gl.viewport(viewport);
+        gl.clear(DEPTH_BUFFER_BIT);
+        (gl_fbo, gl)
+    }
+}
@@ -0,0 +1,368 @@
+// Tracks the number of live buffers, textures, and renderbuffers, along
+// with an estimate of how much memory each one uses (see
+// `ContextOf::resource_stats`). Tracking is enabled with the
+// `resource-stats` feature; otherwise, all of the hooks are no-ops.
+//
+// Uploads are made through bindings, which don't know the ID of the bound
+// object, so this module shadows the `GL_ARRAY_BUFFER`,
+// `GL_ELEMENT_ARRAY_BUFFER`, `GL_PIXEL_PACK_BUFFER`, `GL_RENDERBUFFER`, and
+// per-texture-unit texture bindings made through glitter.
+
+use gl::types::*;
+use leaks::ObjectKind;
+
+/// The number of live buffers, textures, and renderbuffers, and an estimate
+/// of the memory that they use. Returned by [`gl.resource_stats`]
+/// (context/struct.ContextOf.html#method.resource_stats).
+///
+/// The memory estimates are based on the sizes and formats of the data
+/// that was uploaded through glitter, so they don't account for any
+/// padding, alignment, or compression that the driver uses.
+#[cfg(feature = "resource-stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceStats {
+    /// The number of live buffers.
+    pub buffers: usize,
+
+    /// The estimated number of bytes used by all live buffers.
+    pub buffer_bytes: usize,
+
+    /// The number of live textures.
+    pub textures: usize,
+
+    /// The estimated number of bytes used by all live textures
+    /// (including mipmaps).
+    pub texture_bytes: usize,
+
+    /// The number of live renderbuffers.
+    pub renderbuffers: usize,
+
+    /// The estimated number of bytes used by all live renderbuffers.
+    pub renderbuffer_bytes: usize
+}
+
+#[cfg(feature = "resource-stats")]
+impl ResourceStats {
+    /// Returns the estimated number of bytes used by all live buffers,
+    /// textures, and renderbuffers.
+    pub fn total_bytes(&self) -> usize {
+        self.buffer_bytes + self.texture_bytes + self.renderbuffer_bytes
+    }
+}
+
+#[cfg(feature = "resource-stats")]
+mod imp {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use gl;
+    use gl::types::*;
+    use leaks::ObjectKind;
+    use super::ResourceStats;
+
+    // The key used for the single image of a buffer or renderbuffer
+    const WHOLE_OBJECT: (GLenum, u32) = (0, 0);
+
+    struct StatsState {
+        // The estimated size of each image of each live object, keyed by
+        // image target and mipmap level
+        objects: HashMap<(ObjectKind, GLuint), HashMap<(GLenum, u32), usize>>,
+
+        // The object bound to each target, keyed by binding target and
+        // texture unit (which is always 0 for non-texture targets)
+        bound: HashMap<(GLenum, u32), GLuint>,
+
+        active_texture: u32
+    }
+
+    // Get the type of object that can be bound to a binding target
+    fn _target_kind(target: GLenum) -> Option<ObjectKind> {
+        match target {
+            gl::ARRAY_BUFFER | gl::ELEMENT_ARRAY_BUFFER
+            | gl::PIXEL_PACK_BUFFER => {
+                Some(ObjectKind::Buffer)
+            },
+            // NOTE: 0x8D65 is `GL_TEXTURE_EXTERNAL_OES`
+            gl::TEXTURE_2D | gl::TEXTURE_CUBE_MAP | 0x8D65
+            | gl::TEXTURE_2D_MULTISAMPLE | gl::TEXTURE_2D_ARRAY
+            | gl::TEXTURE_3D => {
+                Some(ObjectKind::Texture)
+            },
+            gl::RENDERBUFFER => { Some(ObjectKind::Renderbuffer) },
+            _ => { None }
+        }
+    }
+
+    impl StatsState {
+        fn binding_key(&self, target: GLenum) -> (GLenum, u32) {
+            match _target_kind(target) {
+                Some(ObjectKind::Texture) => { (target, self.active_texture) },
+                _ => { (target, 0) }
+            }
+        }
+
+        fn bound_object(&self, target: GLenum)
+            -> Option<(ObjectKind, GLuint)>
+        {
+            let kind = match _target_kind(target) {
+                Some(kind) => { kind },
+                None => { return None; }
+            };
+            let key = self.binding_key(target);
+            self.bound.get(&key).map(|&id| (kind, id))
+        }
+    }
+
+    thread_local! {
+        static STATE: RefCell<StatsState> = RefCell::new(StatsState {
+            objects: HashMap::new(),
+            bound: HashMap::new(),
+            active_texture: 0
+        })
+    }
+
+    fn _with_state<F>(f: F)
+        where F: FnOnce(&mut StatsState)
+    {
+        // NOTE: `try_with` is used because objects can be dropped while
+        //       thread-locals are being destroyed
+        let _ = STATE.try_with(|state| f(&mut state.borrow_mut()));
+    }
+
+    pub fn track(kind: ObjectKind, id: GLuint) {
+        _with_state(|state| {
+            state.objects.insert((kind, id), HashMap::new());
+        });
+    }
+
+    pub fn untrack(kind: ObjectKind, id: GLuint) {
+        _with_state(|state| {
+            state.objects.remove(&(kind, id));
+
+            // Deleting an object unbinds it from the current context
+            state.bound.retain(|&(target, _), &mut bound_id| {
+                bound_id != id || _target_kind(target) != Some(kind)
+            });
+        });
+    }
+
+    // The sizes of an object's images, which are moved to another thread
+    // along with the object
+    pub type TrackedSizes = Option<HashMap<(GLenum, u32), usize>>;
+
+    pub fn take(kind: ObjectKind, id: GLuint) -> TrackedSizes {
+        let mut sizes = None;
+        _with_state(|state| {
+            sizes = state.objects.remove(&(kind, id));
+            state.bound.retain(|&(target, _), &mut bound_id| {
+                bound_id != id || _target_kind(target) != Some(kind)
+            });
+        });
+        sizes
+    }
+
+    pub fn restore(kind: ObjectKind, id: GLuint, sizes: TrackedSizes) {
+        if let Some(sizes) = sizes {
+            _with_state(|state| {
+                state.objects.insert((kind, id), sizes);
+            });
+        }
+    }
+
+    pub fn bind(target: GLenum, id: GLuint) {
+        _with_state(|state| {
+            if _target_kind(target).is_some() {
+                let key = state.binding_key(target);
+                state.bound.insert(key, id);
+            }
+        });
+    }
+
+    pub fn active_texture(idx: u32) {
+        _with_state(|state| {
+            state.active_texture = idx;
+        });
+    }
+
+    pub fn set_size(target: GLenum,
+                    image: Option<(GLenum, u32)>,
+                    bytes: usize)
+    {
+        _with_state(|state| {
+            if let Some(object) = state.bound_object(target) {
+                if let Some(sizes) = state.objects.get_mut(&object) {
+                    let image = image.unwrap_or(WHOLE_OBJECT);
+                    sizes.insert(image, bytes);
+                }
+            }
+        });
+    }
+
+    pub fn forget_bindings(active_texture: u32) {
+        _with_state(|state| {
+            state.bound.clear();
+            state.active_texture = active_texture;
+        });
+    }
+
+    pub fn generate_mipmap(target: GLenum) {
+        _with_state(|state| {
+            if let Some(object) = state.bound_object(target) {
+                if let Some(sizes) = state.objects.get_mut(&object) {
+                    // Each mipmap level is a quarter of the size of the
+                    // previous level, so a full chain of mipmaps uses about
+                    // a third of the size of the base level
+                    let base: Vec<_> = sizes.iter()
+                        .filter(|&(&(_, level), _)| level == 0)
+                        .map(|(&(image, _), &bytes)| (image, bytes))
+                        .collect();
+                    sizes.retain(|&(_, level), _| level == 0);
+                    for (image, bytes) in base {
+                        sizes.insert((image, 1), bytes / 3);
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn resource_stats() -> ResourceStats {
+        STATE.with(|state| {
+            let state = state.borrow();
+            let mut stats = ResourceStats::default();
+            for (&(kind, _), sizes) in &state.objects {
+                let bytes: usize = sizes.values().sum();
+                match kind {
+                    ObjectKind::Buffer => {
+                        stats.buffers += 1;
+                        stats.buffer_bytes += bytes;
+                    },
+                    ObjectKind::Texture => {
+                        stats.textures += 1;
+                        stats.texture_bytes += bytes;
+                    },
+                    ObjectKind::Renderbuffer => {
+                        stats.renderbuffers += 1;
+                        stats.renderbuffer_bytes += bytes;
+                    },
+                    _ => { }
+                }
+            }
+            stats
+        })
+    }
+}
+
+#[cfg(not(feature = "resource-stats"))]
+mod imp {
+    use gl::types::*;
+    use leaks::ObjectKind;
+
+    #[inline(always)]
+    pub fn track(_kind: ObjectKind, _id: GLuint) {
+
+    }
+
+    #[inline(always)]
+    pub fn untrack(_kind: ObjectKind, _id: GLuint) {
+
+    }
+
+    pub type TrackedSizes = ();
+
+    #[inline(always)]
+    pub fn take(_kind: ObjectKind, _id: GLuint) -> TrackedSizes {
+
+    }
+
+    #[inline(always)]
+    pub fn restore(_kind: ObjectKind, _id: GLuint, _sizes: TrackedSizes) {
+
+    }
+
+    #[inline(always)]
+    pub fn bind(_target: GLenum, _id: GLuint) {
+
+    }
+
+    #[inline(always)]
+    pub fn active_texture(_idx: u32) {
+
+    }
+
+    #[inline(always)]
+    pub fn set_size(_target: GLenum,
+                    _image: Option<(GLenum, u32)>,
+                    _bytes: usize)
+    {
+
+    }
+
+    #[inline(always)]
+    pub fn forget_bindings(_active_texture: u32) {
+
+    }
+
+    #[inline(always)]
+    pub fn generate_mipmap(_target: GLenum) {
+
+    }
+}
+
+// Called when a buffer, texture, or renderbuffer is created (with
+// `GLObject::from_raw`)
+pub fn _track(kind: ObjectKind, id: GLuint) {
+    imp::track(kind, id);
+}
+
+// Called when a buffer, texture, or renderbuffer is dropped
+pub fn _untrack(kind: ObjectKind, id: GLuint) {
+    imp::untrack(kind, id);
+}
+
+pub use self::imp::TrackedSizes;
+
+// Called when an object is sent to another thread (see `Transfer`). The
+// object's sizes are returned, so they can be passed to `_restore` on the
+// thread that accepts it.
+pub fn _take(kind: ObjectKind, id: GLuint) -> TrackedSizes {
+    imp::take(kind, id)
+}
+
+// Called when an object that was sent from another thread is accepted
+pub fn _restore(kind: ObjectKind, id: GLuint, sizes: TrackedSizes) {
+    imp::restore(kind, id, sizes);
+}
+
+// Called when an object is bound to `target` through glitter
+pub fn _bind(target: GLenum, id: GLuint) {
+    imp::bind(target, id);
+}
+
+// Called when the active texture unit is changed
+pub fn _active_texture(idx: u32) {
+    imp::active_texture(idx);
+}
+
+// Called when the bindings may have been changed outside of glitter (see
+// `ContextOf::with_raw`). Sizes aren't tracked again until each target is
+// bound through glitter.
+pub fn _forget_bindings(active_texture: u32) {
+    imp::forget_bindings(active_texture);
+}
+
+// Called when storage is allocated for the object bound to `target`. For
+// textures, `image` is the image target and mipmap level that was
+// allocated; for buffers and renderbuffers, it's `None`.
+pub fn _set_size(target: GLenum, image: Option<(GLenum, u32)>, bytes: usize) {
+    imp::set_size(target, image, bytes);
+}
+
+// Called when mipmaps are generated for the texture bound to `target`
+pub fn _generate_mipmap(target: GLenum) {
+    imp::generate_mipmap(target);
+}
+
+// Get the stats for all of the live objects on the current thread
+#[cfg(feature = "resource-stats")]
+pub fn _resource_stats() -> ResourceStats {
+    imp::resource_stats()
+}
@@ -3,8 +3,14 @@
 
 use std::marker::PhantomData;
 use gl;
+use gl_fns;
 use gl::types::*;
-use types::GLObject;
+use context::{Context, BaseContext};
+use types::{GLObject, GLError, Rect};
+use leaks::{self, ObjectKind};
+use bind_cache;
+use stats;
+use validate;
 
 /// A type of OpenGL texture.
 ///
@@ -19,8 +25,12 @@ use types::GLObject;
 ///
 /// In glitter, the `Texture` type has a generic type parameter, which is used
 /// to represent, at the type level, what type of image data a specific
-/// texture contains. For simplicity, the [`Texture2d`](type.Texture2d.html)
-/// and [`TextureCubeMap`](type.TextureCubeMap.html) type aliases are provided.
+/// texture contains. For simplicity, the [`Texture2d`](type.Texture2d.html),
+/// [`TextureCubeMap`](type.TextureCubeMap.html), [`TextureExternal`]
+/// (type.TextureExternal.html), [`Texture2dMultisample`]
+/// (type.Texture2dMultisample.html), [`Texture2dArray`]
+/// (type.Texture2dArray.html), and [`Texture3d`](type.Texture3d.html) type
+/// aliases are provided.
 ///
 /// All textures will be automatically deleted after going out of scope.
 ///
@@ -62,10 +72,88 @@ pub type Texture2d = Texture<Tx2d>;
 /// of textures.
 pub type TextureCubeMap = Texture<TxCubeMap>;
 
+/// An OpenGL texture that samples an external image, such as a camera
+/// frame or a decoded video frame (like the output of a `SurfaceTexture`
+/// on Android). External textures require the `GL_OES_EGL_image_external`
+/// extension (see [`Extensions::has_egl_image_external`]
+/// (../extensions/struct.Extensions.html#method.has_egl_image_external)),
+/// and are sampled with a `samplerExternalOES` uniform.
+///
+/// The image data of an external texture is owned by whatever produced it,
+/// so it can't be uploaded with `glTexImage2D`. Instead, an EGL image is
+/// attached with [`gl.egl_image_target_texture`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.egl_image_target_texture),
+/// or a texture created by another library is taken over with
+/// [`TextureExternal::adopt`](struct.Texture.html#method.adopt).
+///
+/// See the documentation for [`Texture`](struct.Texture.html) for
+/// more details about textures in glitter, and [`TextureBindingTarget`]
+/// (enum.TextureBindingTarget) for details about the different types
+/// of textures.
+pub type TextureExternal = Texture<TxExternal>;
+
+/// An OpenGL texture with a 2-dimensional, multisampled image. Multisampled
+/// textures can't be filtered or mipmapped; instead, they are attached to
+/// a framebuffer to render with multisample anti-aliasing, and each sample
+/// is read with `texelFetch` on a `sampler2DMS` uniform. This allows for
+/// custom resolve passes (such as tonemapping each sample before averaging).
+///
+/// Multisampled textures require desktop OpenGL 3.2 or OpenGL ES 3.1 (see
+/// [`Extensions::has_texture_multisample`]
+/// (../extensions/struct.Extensions.html#method.has_texture_multisample)),
+/// and their storage is allocated with [`gl.tex_storage_2d_multisample`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.tex_storage_2d_multisample)
+/// or [`gl.tex_image_2d_multisample`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.tex_image_2d_multisample).
+///
+/// See the documentation for [`Texture`](struct.Texture.html) for
+/// more details about textures in glitter, and [`TextureBindingTarget`]
+/// (enum.TextureBindingTarget) for details about the different types
+/// of textures.
+pub type Texture2dMultisample = Texture<Tx2dMultisample>;
+
+/// An OpenGL texture made up of an array of 2-dimensional images (called
+/// layers), which all have the same size and format. Each layer is sampled
+/// separately (such as the cascades of a cascaded shadow map), and can be
+/// attached to a framebuffer on its own (see [`gl.framebuffer_texture_layer`]
+/// (../context/framebuffer_context/trait.ContextFramebufferExt.html#method.framebuffer_texture_layer)).
+///
+/// 2D array textures require desktop OpenGL 3.0 or OpenGL ES 3.0 (see
+/// [`Extensions::has_texture_array`]
+/// (../extensions/struct.Extensions.html#method.has_texture_array)), and
+/// their storage is allocated with [`gl.tex_image_3d_sized`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.tex_image_3d_sized).
+///
+/// See the documentation for [`Texture`](struct.Texture.html) for
+/// more details about textures in glitter, and [`TextureBindingTarget`]
+/// (enum.TextureBindingTarget) for details about the different types
+/// of textures.
+pub type Texture2dArray = Texture<Tx2dArray>;
+
+/// An OpenGL texture with 3-dimensional image data. Each 2D slice (or
+/// layer) of the texture can be attached to a framebuffer on its own (see
+/// [`gl.framebuffer_texture_layer`]
+/// (../context/framebuffer_context/trait.ContextFramebufferExt.html#method.framebuffer_texture_layer)).
+///
+/// 3D textures require desktop OpenGL 1.2, OpenGL ES 3.0, or
+/// `GL_OES_texture_3D` (see [`Extensions::has_texture_3d`]
+/// (../extensions/struct.Extensions.html#method.has_texture_3d)), and their
+/// storage is allocated with [`gl.tex_image_3d_sized`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.tex_image_3d_sized).
+///
+/// See the documentation for [`Texture`](struct.Texture.html) for
+/// more details about textures in glitter, and [`TextureBindingTarget`]
+/// (enum.TextureBindingTarget) for details about the different types
+/// of textures.
+pub type Texture3d = Texture<Tx3d>;
+
 impl<T: TextureType> Drop for Texture<T> {
     fn drop(&mut self) {
+        leaks::_untrack(ObjectKind::Texture, self.gl_id);
+        bind_cache::_delete(ObjectKind::Texture, self.gl_id);
+        stats::_untrack(ObjectKind::Texture, self.gl_id);
         unsafe {
-            gl::DeleteTextures(1, &self.gl_id as *const GLuint);
+            gl_fns::DeleteTextures(1, &self.gl_id as *const GLuint);
         }
     }
 }
@@ -74,6 +162,8 @@ impl<T: TextureType> GLObject for Texture<T> {
     type Id = GLuint;
 
     unsafe fn from_raw(id: Self::Id) -> Self {
+        leaks::_track(ObjectKind::Texture, id);
+        stats::_track(ObjectKind::Texture, id);
         Texture {
             gl_id: id,
             phantom: PhantomData
@@ -85,6 +175,84 @@ impl<T: TextureType> GLObject for Texture<T> {
     }
 }
 
+impl<T: TextureType> Texture<T> {
+    /// Take ownership of a texture that was created outside of glitter
+    /// (such as by a video decoder), after checking that `id` names
+    /// a texture of type `T` in the current context.
+    /// The texture will be deleted when the returned object is dropped.
+    ///
+    /// Note that OpenGL doesn't create the texture (or set its type) until
+    /// the ID is first bound, so an ID from `glGenTextures` that was never
+    /// bound is rejected.
+    ///
+    /// # Safety
+    /// The ID must not be owned by anything else (such as another glitter
+    /// object, or another library that will delete it). Otherwise, the
+    /// texture will be deleted twice.
+    ///
+    /// # Failures
+    /// A [`GLError::InvalidObject`](../types/enum.GLError.html#variant.InvalidObject)
+    /// error will be returned if `id` is not a texture, or if it is
+    /// a texture of a different type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # let gl = unsafe { glitter::Context::current_context() };
+    /// # let id = 1;
+    /// let texture = unsafe { glitter::Texture2d::adopt(id, &gl).unwrap() };
+    /// ```
+    ///
+    /// # See also
+    /// [`glIsTexture`](http://docs.gl/es2/glIsTexture) OpenGL docs
+    pub unsafe fn adopt<C: BaseContext>(id: GLuint, _gl: &C)
+        -> Result<Self, GLError>
+    {
+        let target = T::target();
+        let binding_pname = match target {
+            TextureBindingTarget::Texture2d => gl::TEXTURE_BINDING_2D,
+            TextureBindingTarget::TextureCubeMap => {
+                gl::TEXTURE_BINDING_CUBE_MAP
+            },
+            TextureBindingTarget::TextureExternal => {
+                GL_TEXTURE_BINDING_EXTERNAL_OES
+            },
+            TextureBindingTarget::Texture2dMultisample => {
+                gl::TEXTURE_BINDING_2D_MULTISAMPLE
+            },
+            TextureBindingTarget::Texture2dArray => {
+                gl::TEXTURE_BINDING_2D_ARRAY
+            },
+            TextureBindingTarget::Texture3d => gl::TEXTURE_BINDING_3D
+        };
+
+        // NOTE: Errors generated before this call are cleared, so they
+        //       aren't mistaken for an error from binding the texture
+        validate::clear_errors();
+
+        let is_texture = if gl_fns::IsTexture(id) == gl::TRUE {
+            // There's no portable way to query a texture's type, but
+            // binding a texture to the wrong target fails, so the
+            // texture is bound (and the old binding is restored)
+            let mut previous: GLint = 0;
+            gl_fns::GetIntegerv(binding_pname, &mut previous as *mut GLint);
+            gl_fns::BindTexture(target.gl_enum(), id);
+            let error = Context::get_error();
+            gl_fns::BindTexture(target.gl_enum(), previous as GLuint);
+            error.is_none()
+        }
+        else {
+            false
+        };
+
+        if is_texture {
+            Ok(Texture::from_raw(id))
+        }
+        else {
+            Err(GLError::InvalidObject(ObjectKind::Texture, id))
+        }
+    }
+}
+
 
 
 /// A trait implemented for types that are used to represent all of the
@@ -186,6 +354,151 @@ impl TextureType for TxCubeMap {
     }
 }
 
+/// The [`TextureType`](trait.TextureType.html) for external textures.
+pub struct TxExternal;
+
+/// The possible image targets for `GL_TEXTURE_EXTERNAL_OES` (only one
+/// variant, which is only used to attach an EGL image).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxExternalImageTarget {
+    /// The only possible target for an external texture.
+    TextureExternal = GL_TEXTURE_EXTERNAL_OES as isize
+}
+
+impl ImageTargetType for TxExternalImageTarget {
+    fn gl_enum(&self) -> GLenum {
+        *self as GLenum
+    }
+}
+
+impl TextureType for TxExternal {
+    type ImageTargetType = TxExternalImageTarget;
+
+    fn target() -> TextureBindingTarget {
+        TextureBindingTarget::TextureExternal
+    }
+}
+
+/// The [`TextureType`](trait.TextureType.html) for multisampled 2D textures.
+pub struct Tx2dMultisample;
+
+/// The possible image targets for `GL_TEXTURE_2D_MULTISAMPLE` (only one
+/// variant, which is used to attach the texture to a framebuffer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tx2dMultisampleImageTarget {
+    /// The only possible target for a multisampled 2D texture.
+    Texture2dMultisample = gl::TEXTURE_2D_MULTISAMPLE as isize
+}
+
+impl ImageTargetType for Tx2dMultisampleImageTarget {
+    fn gl_enum(&self) -> GLenum {
+        *self as GLenum
+    }
+}
+
+impl TextureType for Tx2dMultisample {
+    type ImageTargetType = Tx2dMultisampleImageTarget;
+
+    fn target() -> TextureBindingTarget {
+        TextureBindingTarget::Texture2dMultisample
+    }
+}
+
+/// The [`TextureType`](trait.TextureType.html) for 2D array textures.
+pub struct Tx2dArray;
+
+/// The possible image targets for `GL_TEXTURE_2D_ARRAY` (only one variant,
+/// since all of the layers are set at once).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tx2dArrayImageTarget {
+    /// The only possible target for a 2D array texture.
+    Texture2dArray = gl::TEXTURE_2D_ARRAY as isize
+}
+
+impl ImageTargetType for Tx2dArrayImageTarget {
+    fn gl_enum(&self) -> GLenum {
+        *self as GLenum
+    }
+}
+
+impl TextureType for Tx2dArray {
+    type ImageTargetType = Tx2dArrayImageTarget;
+
+    fn target() -> TextureBindingTarget {
+        TextureBindingTarget::Texture2dArray
+    }
+}
+
+/// The [`TextureType`](trait.TextureType.html) for 3D textures.
+pub struct Tx3d;
+
+/// The possible image targets for `GL_TEXTURE_3D` (only one variant,
+/// since this *is* the 3D texture).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tx3dImageTarget {
+    /// The only possible target for a 3D texture.
+    Texture3d = gl::TEXTURE_3D as isize
+}
+
+impl ImageTargetType for Tx3dImageTarget {
+    fn gl_enum(&self) -> GLenum {
+        *self as GLenum
+    }
+}
+
+impl TextureType for Tx3d {
+    type ImageTargetType = Tx3dImageTarget;
+
+    fn target() -> TextureBindingTarget {
+        TextureBindingTarget::Texture3d
+    }
+}
+
+/// A [`TextureType`](trait.TextureType.html) whose images are stacks of
+/// 2D layers ([`Tx2dArray`](struct.Tx2dArray.html) and [`Tx3d`]
+/// (struct.Tx3d.html)). A single layer of these textures can be attached to
+/// a framebuffer with [`gl.framebuffer_texture_layer`]
+/// (../context/framebuffer_context/trait.ContextFramebufferExt.html#method.framebuffer_texture_layer).
+pub trait ArrayTextureType: TextureType {
+
+}
+
+impl ArrayTextureType for Tx2dArray {
+
+}
+
+impl ArrayTextureType for Tx3d {
+
+}
+
+/// A [`TextureType`](trait.TextureType.html) with several 2D images that
+/// can all be attached to a framebuffer at once, for layered rendering
+/// (where a geometry shader selects the layer to draw to with `gl_Layer`).
+/// Along with the [`ArrayTextureType`](trait.ArrayTextureType.html)s,
+/// this includes [`TxCubeMap`](struct.TxCubeMap.html), where each face is
+/// a layer. See [`gl.framebuffer_texture`]
+/// (../context/framebuffer_context/trait.ContextFramebufferExt.html#method.framebuffer_texture).
+pub trait LayeredTextureType: TextureType {
+
+}
+
+impl LayeredTextureType for TxCubeMap {
+
+}
+
+impl LayeredTextureType for Tx2dArray {
+
+}
+
+impl LayeredTextureType for Tx3d {
+
+}
+
+// NOTE: These constants are from `GL_OES_EGL_image_external`, which isn't
+//       included in the `gl` crate's bindings
+const GL_TEXTURE_EXTERNAL_OES: GLenum = 0x8D65;
+const GL_TEXTURE_BINDING_EXTERNAL_OES: GLenum = 0x8D67;
+
 
 
 /// Represents all of the possible types of OpenGL textures.
@@ -197,7 +510,27 @@ pub enum TextureBindingTarget {
     /// A cubemap texture, which is a texture made up of six 2-dimensional
     /// images, each of which represent a face of a cube. This type of texture
     /// is especially useful for skyboxes.
-    TextureCubeMap = gl::TEXTURE_CUBE_MAP as isize
+    TextureCubeMap = gl::TEXTURE_CUBE_MAP as isize,
+
+    /// An external texture, which samples an image that is owned outside
+    /// of OpenGL (such as a camera or video decoder surface). Requires the
+    /// `GL_OES_EGL_image_external` extension.
+    TextureExternal = GL_TEXTURE_EXTERNAL_OES as isize,
+
+    /// A multisampled 2-dimensional texture, which stores several samples
+    /// per texel, and is used as a framebuffer attachment for multisample
+    /// anti-aliasing. Requires desktop OpenGL 3.2 or OpenGL ES 3.1.
+    Texture2dMultisample = gl::TEXTURE_2D_MULTISAMPLE as isize,
+
+    /// An array of 2-dimensional textures with the same size and format,
+    /// where each layer is sampled separately. Requires desktop OpenGL 3.0
+    /// or OpenGL ES 3.0.
+    Texture2dArray = gl::TEXTURE_2D_ARRAY as isize,
+
+    /// A 3-dimensional texture, which can be thought of as a 3D grid of
+    /// colors. Requires desktop OpenGL 1.2, OpenGL ES 3.0, or
+    /// `GL_OES_texture_3D`.
+    Texture3d = gl::TEXTURE_3D as isize
 }
 
 impl TextureBindingTarget {
@@ -257,6 +590,26 @@ impl ImageTargetType for VariantTexture2d {
 pub const TEXTURE_CUBE_MAP : TextureBindingTarget =
     TextureBindingTarget::TextureCubeMap;
 
+/// This constant is designed to be used in glitter wherever the constant
+/// `GL_TEXTURE_EXTERNAL_OES` is used in plain OpenGL code.
+pub const TEXTURE_EXTERNAL_OES : TextureBindingTarget =
+    TextureBindingTarget::TextureExternal;
+
+/// This constant is designed to be used in glitter wherever the constant
+/// `GL_TEXTURE_2D_MULTISAMPLE` is used in plain OpenGL code.
+pub const TEXTURE_2D_MULTISAMPLE : TextureBindingTarget =
+    TextureBindingTarget::Texture2dMultisample;
+
+/// This constant is designed to be used in glitter wherever the constant
+/// `GL_TEXTURE_2D_ARRAY` is used in plain OpenGL code.
+pub const TEXTURE_2D_ARRAY : TextureBindingTarget =
+    TextureBindingTarget::Texture2dArray;
+
+/// This constant is designed to be used in glitter wherever the constant
+/// `GL_TEXTURE_3D` is used in plain OpenGL code.
+pub const TEXTURE_3D : TextureBindingTarget =
+    TextureBindingTarget::Texture3d;
+
 
 /// This constant is designed to be used in glitter wherever the constant
 /// `GL_TEXTURE_2D` is used in plain OpenGL code.
@@ -418,3 +771,38 @@ gl_enum! {
         pub const Repeat as REPEAT = gl::REPEAT
     }
 }
+
+/// A region of image data to copy from one texture to another with
+/// [`gl.copy_texture`]
+/// (../context/texture_context/trait.ContextTextureExt.html#method.copy_texture).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureCopyRegion {
+    /// The mipmap level of the source texture to copy from.
+    pub src_level: u32,
+
+    /// The region of the source texture to copy, in texels.
+    pub src_rect: Rect,
+
+    /// The mipmap level of the destination texture to copy to.
+    pub dst_level: u32,
+
+    /// The X coordinate of the destination texture to copy the region to.
+    pub dst_x: u32,
+
+    /// The Y coordinate of the destination texture to copy the region to.
+    pub dst_y: u32
+}
+
+impl TextureCopyRegion {
+    /// Create a region that copies `src_rect` from level 0 of the source
+    /// texture to (`dst_x`, `dst_y`) in level 0 of the destination texture.
+    pub fn new(src_rect: Rect, dst_x: u32, dst_y: u32) -> Self {
+        TextureCopyRegion {
+            src_level: 0,
+            src_rect: src_rect,
+            dst_level: 0,
+            dst_x: dst_x,
+            dst_y: dst_y
+        }
+    }
+}
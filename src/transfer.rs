@@ -0,0 +1,252 @@
+//! Contains [`Transfer`](struct.Transfer.html), for sending OpenGL objects
+//! to a context on another thread.
+//!
+//! OpenGL objects aren't `Send`, since dropping an object deletes it using
+//! whatever context is current on the dropping thread. Contexts can be
+//! created so that they share objects with each other (a "share group"),
+//! though, which lets objects be created on a loader thread and used on
+//! the render thread. A `Transfer` wraps an object while it moves between
+//! threads, and can only be unwrapped by a context in the same share group
+//! as the context that created it.
+//!
+//! # Example
+//! ```no_run
+//! use std::sync::mpsc;
+//! use std::thread;
+//! use glitter::prelude::*;
+//! use glitter::transfer::Transfer;
+//!
+//! let gl = unsafe { glitter::Context::current_context() };
+//! let share_group = gl.share_group();
+//! let (tx, rx) = mpsc::channel();
+//!
+//! thread::spawn(move || {
+//!     // ... create a context that shares objects with the render thread's
+//!     //     context, and make it current ...
+//!     let gl = unsafe { glitter::Context::current_context() };
+//!     unsafe { gl.join_share_group(share_group); }
+//!
+//!     let buffer = unsafe { gl.gen_buffer() };
+//!     // ... upload data to the buffer ...
+//!     tx.send(Transfer::new(&gl, buffer)).unwrap();
+//! });
+//!
+//! let buffer = rx.recv().unwrap().accept(&gl).ok().unwrap();
+//! ```
+
+use std::mem;
+use std::u64;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use gl;
+use gl_fns;
+use gl::types::*;
+use context::ContextOf;
+use buffer::Buffer;
+use texture::{Texture, TextureType};
+use renderbuffer::Renderbuffer;
+use shader::Shader;
+use program::Program;
+use vertex_data::VertexData;
+use index_data::IndexDatum;
+use vertex_buffer::{VertexBuffer, IndexBuffer};
+use types::GLObject;
+use leaks::{self, ObjectKind};
+use bind_cache;
+use stats::{self, TrackedSizes};
+
+// The ID to give to the next share group
+static NEXT_SHARE_GROUP: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// Identifies a group of contexts that share objects with each other.
+/// Each context gets its own share group when it's created; a context
+/// that was created to share objects with another context can join
+/// that context's share group with [`gl.join_share_group`]
+/// (../context/struct.ContextOf.html#method.join_share_group).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShareGroup {
+    id: usize
+}
+
+impl ShareGroup {
+    // Create a new share group, which no other context is a part of
+    #[doc(hidden)]
+    pub fn _new() -> Self {
+        ShareGroup { id: NEXT_SHARE_GROUP.fetch_add(1, Ordering::SeqCst) }
+    }
+}
+
+/// An OpenGL object that is shared between the contexts of a share group,
+/// so it can be sent to another thread with a [`Transfer`]
+/// (struct.Transfer.html).
+///
+/// # Safety
+/// Every OpenGL object owned by the type must be returned by `_objects`,
+/// and every one must be of a type that OpenGL shares between contexts
+/// (so framebuffers and vertex array objects can't be included).
+pub unsafe trait ShareableObject {
+    #[doc(hidden)]
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)>;
+}
+
+unsafe impl ShareableObject for Buffer {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        vec![(ObjectKind::Buffer, self.id())]
+    }
+}
+
+unsafe impl<T: TextureType> ShareableObject for Texture<T> {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        vec![(ObjectKind::Texture, self.id())]
+    }
+}
+
+unsafe impl ShareableObject for Renderbuffer {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        vec![(ObjectKind::Renderbuffer, self.id())]
+    }
+}
+
+unsafe impl ShareableObject for Shader {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        vec![(ObjectKind::Shader, self.id())]
+    }
+}
+
+unsafe impl ShareableObject for Program {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        vec![(ObjectKind::Program, self.id())]
+    }
+}
+
+unsafe impl<T: VertexData> ShareableObject for VertexBuffer<T> {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        self.buffer()._objects()
+    }
+}
+
+unsafe impl<T: IndexDatum> ShareableObject for IndexBuffer<T> {
+    fn _objects(&self) -> Vec<(ObjectKind, GLuint)> {
+        self.buffer()._objects()
+    }
+}
+
+/// An OpenGL object that is being sent to a context on another thread.
+/// Unlike the object itself, a `Transfer` is `Send`.
+///
+/// Creating a transfer inserts a fence after the commands that have been
+/// issued so far, and accepting the transfer waits for the fence, so any
+/// changes made to the object (such as uploading data) are visible to
+/// the accepting context. When the context doesn't support fences (see
+/// [`Extensions::has_sync`]
+/// (../extensions/struct.Extensions.html#method.has_sync)), creating
+/// a transfer waits for all previous commands to finish instead.
+///
+/// Leak tracking and resource statistics (which are kept per thread) are
+/// moved to the accepting thread along with the object.
+///
+/// If a transfer is dropped without being accepted, the object (and the
+/// fence) are leaked, since there may not be a context that can delete
+/// them on the current thread.
+pub struct Transfer<T: ShareableObject> {
+    object: Option<T>,
+    share_group: ShareGroup,
+    fence: Option<GLsync>,
+    stats: Vec<TrackedSizes>
+}
+
+// A transfer can only be unwrapped by a context that shares the object, and
+// the object can't be used (or dropped) while it's in transit
+unsafe impl<T: ShareableObject> Send for Transfer<T> {}
+
+impl<T: ShareableObject> Transfer<T> {
+    /// Start sending `object` to another thread. `gl` must be the context
+    /// that is current on this thread.
+    pub fn new<B, F, P, R, U>(gl: &ContextOf<B, F, P, R, U>, object: T)
+        -> Self
+    {
+        let objects = object._objects();
+        let stats = objects.iter().map(|&(kind, id)| {
+            // The object is no longer owned by this thread
+            leaks::_untrack(kind, id);
+            bind_cache::_delete(kind, id);
+            stats::_take(kind, id)
+        }).collect();
+
+        let fence = unsafe {
+            if gl.extensions().has_sync() {
+                let fence = gl_fns::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE,
+                                              0);
+                // NOTE: The fence must be flushed, or waiting for it in
+                //       another context may never finish
+                gl_fns::Flush();
+                dbg_gl_sanity_check! {
+                    _ => "Unknown error while creating a fence"
+                }
+                Some(fence)
+            }
+            else {
+                gl_fns::Finish();
+                None
+            }
+        };
+
+        Transfer {
+            object: Some(object),
+            share_group: gl.share_group(),
+            fence: fence,
+            stats: stats
+        }
+    }
+
+    /// Get the share group of the context that created the transfer.
+    pub fn share_group(&self) -> ShareGroup {
+        self.share_group
+    }
+
+    /// Receive the object, waiting until the commands issued before the
+    /// transfer was created have finished. `gl` must be the context that
+    /// is current on this thread.
+    ///
+    /// # Failures
+    /// The transfer is returned back if `gl` isn't in the same share group
+    /// as the context that created it (see [`gl.share_group`]
+    /// (../context/struct.ContextOf.html#method.share_group)).
+    pub fn accept<B, F, P, R, U>(mut self, gl: &ContextOf<B, F, P, R, U>)
+        -> Result<T, Self>
+    {
+        if gl.share_group() != self.share_group {
+            return Err(self);
+        }
+
+        if let Some(fence) = self.fence.take() {
+            unsafe {
+                loop {
+                    let status = gl_fns::ClientWaitSync(fence, 0, u64::MAX);
+                    if status != gl::TIMEOUT_EXPIRED {
+                        break;
+                    }
+                }
+                gl_fns::DeleteSync(fence);
+                dbg_gl_sanity_check! {
+                    _ => "Unknown error while waiting for a fence"
+                }
+            }
+        }
+
+        let object = self.object.take().unwrap();
+        let objects = object._objects();
+        for (&(kind, id), sizes) in objects.iter().zip(self.stats.drain(..)) {
+            leaks::_track(kind, id);
+            stats::_restore(kind, id, sizes);
+        }
+        Ok(object)
+    }
+}
+
+impl<T: ShareableObject> Drop for Transfer<T> {
+    fn drop(&mut self) {
+        if let Some(object) = self.object.take() {
+            mem::forget(object);
+        }
+    }
+}
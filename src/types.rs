@@ -1,11 +1,20 @@
 //! Contains miscellaneous general-purpose OpenGL types.
 
+use std::cmp;
 use std::mem;
 use std::fmt;
 use std::error;
 use gl;
+use gl::types::{GLenum, GLuint};
+use shader::{InfoLog, ShaderIncludeError, ShaderBinaryError};
+use context::FramebufferAttachment;
+use texture::{TxCubeMapImageTarget, TextureBindingTarget};
+use image_data::RenderbufferFormat;
+use leaks::ObjectKind;
+use extensions::Extensions;
 
 /// A color, with floating-point RGBA components.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Color {
     /// The color's red component.
     pub r: f32,
@@ -28,7 +37,7 @@ impl Color {
 }
 
 /// An OpenGL viewport, with an origin and size, with integer components.
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Viewport {
     /// The X coordinate of the viewport's origin
     pub x: u32,
@@ -56,6 +65,124 @@ impl Viewport {
     }
 }
 
+/// A rectangle in window coordinates, with an origin (the lower-left
+/// corner) and size, with integer components. Used to specify the scissor
+/// box with [`gl.scissor`](../context/trait.ContextExt.html#method.scissor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    /// The X coordinate of the rectangle's lower-left corner
+    pub x: u32,
+
+    /// The Y coordinate of the rectangle's lower-left corner
+    pub y: u32,
+
+    /// The rectangle's width
+    pub width: u32,
+
+    /// The rectangle's height
+    pub height: u32
+}
+
+impl Rect {
+    /// Create a new rectangle, with the given X and Y coordinates as the
+    /// lower-left corner and the given width and height as the size.
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Rect {
+            x: x,
+            y: y,
+            width: width,
+            height: height
+        }
+    }
+
+    /// Return the area that is inside of both `self` and `other`, or `None`
+    /// if the rectangles don't overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use glitter::Rect;
+    ///
+    /// let a = Rect::new(0, 0, 10, 10);
+    /// let b = Rect::new(5, 8, 10, 10);
+    /// assert_eq!(a.intersection(&b), Some(Rect::new(5, 8, 5, 2)));
+    /// assert_eq!(a.intersection(&Rect::new(10, 0, 5, 5)), None);
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        // NOTE: The right and top edges are computed as `u64`s, since they
+        //       can overflow a `u32`
+        let x = cmp::max(self.x, other.x);
+        let y = cmp::max(self.y, other.y);
+        let right = cmp::min(self.x as u64 + self.width as u64,
+                             other.x as u64 + other.width as u64);
+        let top = cmp::min(self.y as u64 + self.height as u64,
+                           other.y as u64 + other.height as u64);
+        if right <= x as u64 || top <= y as u64 {
+            return None;
+        }
+
+        Some(Rect::new(x, y, (right - x as u64) as u32, (top - y as u64) as u32))
+    }
+}
+
+/// An alias for [`Rect`](struct.Rect.html), for use with [`gl.scissor`]
+/// (../context/trait.ContextExt.html#method.scissor).
+pub type ScissorBox = Rect;
+
+/// An OpenGL version, as reported by the OpenGL implementation.
+///
+/// # Example
+/// ```
+/// use glitter::GlVersion;
+///
+/// let es3 = GlVersion::parse("OpenGL ES 3.0 Mesa 17.0.0").unwrap();
+/// assert_eq!(es3, GlVersion { major: 3, minor: 0, es: true });
+///
+/// let gl45 = GlVersion::parse("4.5.0 NVIDIA 375.39").unwrap();
+/// assert_eq!(gl45, GlVersion { major: 4, minor: 5, es: false });
+/// assert!(gl45.is_at_least(3, 3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlVersion {
+    /// The major version number.
+    pub major: u32,
+
+    /// The minor version number.
+    pub minor: u32,
+
+    /// Whether this is an OpenGL ES version.
+    pub es: bool
+}
+
+impl GlVersion {
+    /// Parse an OpenGL version from a `GL_VERSION` string, or return `None`
+    /// if no version number could be found. Desktop OpenGL versions start
+    /// with `<major>.<minor>`, and OpenGL ES versions start with
+    /// `OpenGL ES <major>.<minor>` (or `OpenGL ES-CM` for OpenGL ES 1).
+    pub fn parse(s: &str) -> Option<Self> {
+        let es = s.starts_with("OpenGL ES");
+        let start = match s.find(|c: char| c.is_digit(10)) {
+            Some(start) => { start },
+            None => { return None; }
+        };
+
+        let mut parts = s[start..].split(|c: char| !c.is_digit(10));
+        let major = parts.next().and_then(|major| major.parse().ok());
+        let minor = parts.next().and_then(|minor| minor.parse().ok());
+        match (major, minor) {
+            (Some(major), Some(minor)) => {
+                Some(GlVersion { major: major, minor: minor, es: es })
+            },
+            _ => { None }
+        }
+    }
+
+    /// Returns `true` if this version is the same as or newer than
+    /// `major.minor`.
+    pub fn is_at_least(&self, major: u32, minor: u32) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+}
+
 /// An OpenGL object.
 pub trait GLObject {
     /// The type of the object's internal ID.
@@ -146,13 +273,14 @@ gl_enum! {
             gl::SAMPLE_ALPHA_TO_COVERAGE,
 
         // TODO: Is this correct?
-        // TODO: Link to glSampleCoverage
         /// When multisampling, use the preset sample coverage value
-        /// as the alpha value.
+        /// as the alpha value (see [`gl.sample_coverage`]
+        /// (../context/trait.ContextExt.html#method.sample_coverage)).
         pub const SampleCoverage as SAMPLE_COVERAGE =
             gl::SAMPLE_COVERAGE,
 
-        /// Only draw fragments within the scissor rectangle.
+        /// Only draw fragments within the scissor box (see [`gl.scissor`]
+        /// (../context/trait.ContextExt.html#method.scissor)).
         pub const ScisscorTest as SCISSCOR_TEST =
             gl::SCISSOR_TEST,
 
@@ -164,7 +292,190 @@ gl_enum! {
     }
 }
 
+/// Only draw fragments within the scissor box. This is the same as
+/// [`SCISSCOR_TEST`](enum.Capability.html#variant.ScisscorTest), but
+/// spelled correctly.
+pub const SCISSOR_TEST: Capability = Capability::ScisscorTest;
+
+gl_enum! {
+    /// The implementation-specific behaviors that can be controlled with
+    /// [`gl.hint`](../context/trait.ContextExt.html#method.hint).
+    pub gl_enum HintTarget {
+        /// The quality of mipmaps generated with [`gl.generate_mipmap`]
+        /// (../context/texture_context/trait.ContextTextureExt.html#method.generate_mipmap).
+        /// Note that this hint is not available in core desktop OpenGL
+        /// profiles.
+        // NOTE: `GL_GENERATE_MIPMAP_HINT` isn't part of the core profile,
+        //       so it is missing from the `gl` crate
+        pub const GenerateMipmapHint as GENERATE_MIPMAP_HINT = 0x8192,
+
+        /// The accuracy of derivative functions (such as `dFdx`) in
+        /// fragment shaders. In OpenGL ES 2, this requires the
+        /// `OES_standard_derivatives` extension.
+        pub const FragmentShaderDerivativeHint as FRAGMENT_SHADER_DERIVATIVE_HINT =
+            gl::FRAGMENT_SHADER_DERIVATIVE_HINT
+    }
+}
+
+gl_enum! {
+    /// The behaviors that can be requested with [`gl.hint`]
+    /// (../context/trait.ContextExt.html#method.hint).
+    pub gl_enum HintMode {
+        /// Prefer the most efficient option.
+        pub const Fastest as FASTEST = gl::FASTEST,
+
+        /// Prefer the most correct or highest-quality option.
+        pub const Nicest as NICEST = gl::NICEST,
+
+        /// No preference (the default).
+        pub const DontCare as DONT_CARE = gl::DONT_CARE
+    }
+}
+
+gl_enum! {
+    /// The polygons that are culled when [`CULL_FACE`]
+    /// (enum.Capability.html#variant.CullFace) is enabled. Which polygons
+    /// are front-facing is determined by the [`WindingOrder`]
+    /// (enum.WindingOrder.html).
+    pub gl_enum CullFaceMode {
+        /// Cull front-facing polygons.
+        pub const Front as FRONT = gl::FRONT,
+
+        /// Cull back-facing polygons (the default).
+        pub const Back as BACK = gl::BACK,
+
+        /// Cull both front-facing and back-facing polygons. Points and
+        /// lines are still drawn.
+        pub const FrontAndBack as FRONT_AND_BACK = gl::FRONT_AND_BACK
+    }
+}
+
+gl_enum! {
+    /// The winding order of front-facing polygons, in window coordinates.
+    pub gl_enum WindingOrder {
+        /// Polygons with clockwise winding are front-facing.
+        pub const Clockwise as CW = gl::CW,
+
+        /// Polygons with counter-clockwise winding are front-facing
+        /// (the default).
+        pub const CounterClockwise as CCW = gl::CCW
+    }
+}
+
+
+gl_enum! {
+    /// The factors that source and destination colors are multiplied by
+    /// when blending, set with [`gl.blend_func`]
+    /// (../context/trait.ContextExt.html#method.blend_func).
+    pub gl_enum BlendFactor {
+        /// Multiply each component by 0.
+        pub const Zero as ZERO = gl::ZERO,
+
+        /// Multiply each component by 1.
+        pub const One as ONE = gl::ONE,
+
+        /// Multiply by the source color.
+        pub const SrcColor as SRC_COLOR = gl::SRC_COLOR,
+
+        /// Multiply by 1 minus the source color.
+        pub const OneMinusSrcColor as ONE_MINUS_SRC_COLOR =
+            gl::ONE_MINUS_SRC_COLOR,
+
+        /// Multiply by the destination color.
+        pub const DstColor as DST_COLOR = gl::DST_COLOR,
+
+        /// Multiply by 1 minus the destination color.
+        pub const OneMinusDstColor as ONE_MINUS_DST_COLOR =
+            gl::ONE_MINUS_DST_COLOR,
+
+        /// Multiply by the source alpha.
+        pub const SrcAlpha as SRC_ALPHA = gl::SRC_ALPHA,
+
+        /// Multiply by 1 minus the source alpha.
+        pub const OneMinusSrcAlpha as ONE_MINUS_SRC_ALPHA =
+            gl::ONE_MINUS_SRC_ALPHA,
+
+        /// Multiply by the destination alpha.
+        pub const DstAlpha as DST_ALPHA = gl::DST_ALPHA,
+
+        /// Multiply by 1 minus the destination alpha.
+        pub const OneMinusDstAlpha as ONE_MINUS_DST_ALPHA =
+            gl::ONE_MINUS_DST_ALPHA,
+
+        /// Multiply by the minimum of the source alpha and 1 minus the
+        /// destination alpha. Only valid as a source factor.
+        pub const SrcAlphaSaturate as SRC_ALPHA_SATURATE =
+            gl::SRC_ALPHA_SATURATE
+    }
+}
+
+gl_enum! {
+    /// The comparisons used for the depth test, set with [`gl.depth_func`]
+    /// (../context/trait.ContextExt.html#method.depth_func). A fragment
+    /// passes the test if the comparison between its depth and the stored
+    /// depth is true.
+    pub gl_enum DepthFunc {
+        /// The test never passes.
+        pub const Never as NEVER = gl::NEVER,
+
+        /// Pass if the fragment's depth is less than the stored depth
+        /// (the default).
+        pub const Less as LESS = gl::LESS,
+
+        /// Pass if the depths are equal.
+        pub const Equal as EQUAL = gl::EQUAL,
+
+        /// Pass if the fragment's depth is less than or equal to the
+        /// stored depth.
+        pub const LessOrEqual as LEQUAL = gl::LEQUAL,
+
+        /// Pass if the fragment's depth is greater than the stored depth.
+        pub const Greater as GREATER = gl::GREATER,
+
+        /// Pass if the depths are not equal.
+        pub const NotEqual as NOTEQUAL = gl::NOTEQUAL,
+
+        /// Pass if the fragment's depth is greater than or equal to the
+        /// stored depth.
+        pub const GreaterOrEqual as GEQUAL = gl::GEQUAL,
+
+        /// The test always passes.
+        pub const Always as ALWAYS = gl::ALWAYS
+    }
+}
+
+gl_enum! {
+    /// The pixel storage parameters that can be set with
+    /// [`gl.pixel_store`](../context/trait.ContextExt.html#method.pixel_store).
+    pub gl_enum PixelStoreParam {
+        /// The row alignment of pixel data read back from OpenGL.
+        pub const PackAlignment as PACK_ALIGNMENT = gl::PACK_ALIGNMENT,
+
+        /// The row alignment of pixel data sent to OpenGL (such as with
+        /// [`gl.tex_image_2d`]
+        /// (../context/texture_context/trait.ContextTextureExt.html#method.tex_image_2d)).
+        pub const UnpackAlignment as UNPACK_ALIGNMENT = gl::UNPACK_ALIGNMENT
+    }
+}
+
+gl_enum! {
+    /// The ways that a context can be reset after it is lost, as reported
+    /// by [`gl.graphics_reset_status`]
+    /// (../context/trait.ContextExt.html#method.graphics_reset_status).
+    pub gl_enum GraphicsResetStatus {
+        /// The reset was caused by the current context.
+        pub const GuiltyContextReset as GUILTY_CONTEXT_RESET =
+            gl::GUILTY_CONTEXT_RESET,
 
+        /// The reset was caused by another context.
+        pub const InnocentContextReset as INNOCENT_CONTEXT_RESET =
+            gl::INNOCENT_CONTEXT_RESET,
+
+        /// The cause of the reset is unknown.
+        pub const UnknownContextReset as UNKNOWN_CONTEXT_RESET =
+            gl::UNKNOWN_CONTEXT_RESET
+    }
+}
 
 /// The various possible OpenGL errors.
 #[derive(Debug)]
@@ -190,13 +501,76 @@ pub enum GLError {
     /// enough memory to satisfy a request.
     OutOfMemory,
 
+    /// Indicates that the context was lost, usually because the GPU was
+    /// reset (see [`gl.graphics_reset_status`]
+    /// (../context/trait.ContextExt.html#method.graphics_reset_status)).
+    /// Once a context is lost, every OpenGL call will fail, and all
+    /// OpenGL objects are invalid.
+    ContextLost,
+
     /// Indicates a framebuffer-related error.
     FramebufferError(GLFramebufferError),
 
-    /// Indicates an error with a message attached (such as
-    /// a message from an info log, or an error message
-    /// originating from glitter).
-    Message(String)
+    /// Indicates that a framebuffer built with a [`FramebufferBuilder`]
+    /// (../context/framebuffer_context/struct.FramebufferBuilder.html) is
+    /// not framebuffer-complete. Details about the framebuffer's
+    /// attachments are attached.
+    FramebufferIncomplete(FramebufferIncompleteError),
+
+    /// Indicates that a shader failed to compile. The shader's info log
+    /// is attached.
+    CompileError(InfoLog),
+
+    /// Indicates that a program failed to link. The program's info log
+    /// is attached.
+    LinkError(InfoLog),
+
+    /// Indicates that a program failed validation. The program's info log
+    /// is attached.
+    ValidationError(InfoLog),
+
+    /// Indicates that a shader's source could not be preprocessed.
+    ShaderIncludeError(ShaderIncludeError),
+
+    /// Indicates that a shader binary could not be loaded.
+    ShaderBinaryError(ShaderBinaryError),
+
+    /// Indicates that one of glitter's builders was not given enough
+    /// information to build an object.
+    BuilderError(BuilderError),
+
+    /// Indicates that OpenGL failed to create a new object. The kind of
+    /// object (such as `"shader"` or `"program"`) is attached.
+    ObjectCreationFailed(&'static str),
+
+    /// Indicates that an OpenGL ID passed to a checked constructor (such as
+    /// [`Texture::adopt`](../texture/struct.Texture.html#method.adopt))
+    /// does not name an object of the expected type. The expected type and
+    /// the ID are attached.
+    InvalidObject(ObjectKind, GLuint),
+
+    /// Indicates that a name (such as an attribute name) contains a nul
+    /// byte, so it can't be passed to OpenGL. The name is attached.
+    InvalidName(String),
+
+    /// Indicates that a buffer could not be mapped into memory.
+    BufferMapFailed,
+
+    /// Indicates that the current context doesn't meet glitter's minimum
+    /// requirements (see [`Context::load_and_query`]
+    /// (../context/struct.ContextOf.html#method.load_and_query)).
+    ContextError(ContextError),
+
+    /// Indicates an error with a message attached, for errors that don't
+    /// have a more specific variant (such as errors from creating
+    /// a context).
+    Message(String),
+
+    /// Indicates that a feature is not supported by the current context's
+    /// version or extensions (see [`Extensions`]
+    /// (../extensions/struct.Extensions.html)). The name of the missing
+    /// feature is attached.
+    Unsupported(&'static str)
 }
 
 impl fmt::Display for GLError {
@@ -217,11 +591,53 @@ impl fmt::Display for GLError {
             GLError::OutOfMemory => {
                 write!(f, "Out of memory")
             },
+            GLError::ContextLost => {
+                write!(f, "Context lost")
+            },
             GLError::FramebufferError(ref e) => {
                 write!(f, "{:?}", e)
             },
+            GLError::FramebufferIncomplete(ref e) => {
+                write!(f, "{}", e)
+            },
+            GLError::CompileError(ref log) => {
+                write!(f, "Shader compilation failed: {}", log.raw)
+            },
+            GLError::LinkError(ref log) => {
+                write!(f, "Program linking failed: {}", log.raw)
+            },
+            GLError::ValidationError(ref log) => {
+                write!(f, "Program validation failed: {}", log.raw)
+            },
+            GLError::ShaderIncludeError(ref e) => {
+                write!(f, "{}", e)
+            },
+            GLError::ShaderBinaryError(ref e) => {
+                write!(f, "{}", e)
+            },
+            GLError::BuilderError(ref e) => {
+                write!(f, "{}", e)
+            },
+            GLError::ObjectCreationFailed(object) => {
+                write!(f, "Failed to create OpenGL {}", object)
+            },
+            GLError::InvalidObject(kind, id) => {
+                write!(f, "{} is not a valid {} ID", id, kind)
+            },
+            GLError::InvalidName(ref name) => {
+                write!(f, "Name contains a nul byte: {:?}", name)
+            },
+            GLError::BufferMapFailed => {
+                write!(f, "Failed to map buffer")
+            },
+            GLError::ContextError(ref e) => {
+                write!(f, "{}", e)
+            },
             GLError::Message(ref s) => {
                 write!(f, "{}", s)
+            },
+            GLError::Unsupported(feature) => {
+                write!(f, "Unsupported by the current context: {}", feature)
             }
         }
     }
@@ -235,34 +651,79 @@ impl error::Error for GLError {
             GLError::InvalidOperation => "The specified OpenGL operation is not allowed in the current state.",
             GLError::InvalidFramebufferOperation => "OpenGL command tried to read or write to an incomplete framebuffer.",
             GLError::OutOfMemory => "There is not enough memory left to execute the specified OpenGL command.",
+            GLError::ContextLost => "The OpenGL context was lost.",
             GLError::FramebufferError(ref e) => {
                 error::Error::description(e)
             },
-            GLError::Message(ref s) => &s
+            GLError::FramebufferIncomplete(ref e) => {
+                error::Error::description(e)
+            },
+            GLError::CompileError(_) => "A shader failed to compile.",
+            GLError::LinkError(_) => "A program failed to link.",
+            GLError::ValidationError(_) => "A program failed validation.",
+            GLError::ShaderIncludeError(ref e) => {
+                error::Error::description(e)
+            },
+            GLError::ShaderBinaryError(ref e) => {
+                error::Error::description(e)
+            },
+            GLError::BuilderError(ref e) => {
+                error::Error::description(e)
+            },
+            GLError::ObjectCreationFailed(_) => "OpenGL failed to create a new object.",
+            GLError::InvalidObject(_, _) => "An OpenGL ID does not name an object of the expected type.",
+            GLError::InvalidName(_) => "A name passed to OpenGL contains a nul byte.",
+            GLError::BufferMapFailed => "A buffer could not be mapped into memory.",
+            GLError::ContextError(ref e) => {
+                error::Error::description(e)
+            },
+            GLError::Message(ref s) => &s,
+            GLError::Unsupported(_) => "The requested feature is not supported by the current OpenGL context."
         }
     }
 
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             GLError::FramebufferError(ref e) => { Some(e) },
+            GLError::FramebufferIncomplete(ref e) => { Some(e) },
+            GLError::ShaderIncludeError(ref e) => { Some(e) },
+            GLError::ShaderBinaryError(ref e) => { Some(e) },
+            GLError::BuilderError(ref e) => { Some(e) },
+            GLError::ContextError(ref e) => { Some(e) },
             _ => { None }
         }
     }
 }
 
 /// The possible framebuffer-incomplete errors.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GLFramebufferError {
     /// Not all framebuffer attachments are [attachment-complete]
     /// (https://www.opengl.org/wiki/Framebuffer_Object#Attachment_Completeness).
     IncompleteAttachment,
 
-    /// Not all attachments have the same dimensions.
+    /// Not all attachments have the same dimensions. This is only reported
+    /// by OpenGL ES 2 contexts.
     IncompleteDimensions,
 
     /// The framebuffer has no attachments.
     IncompleteMissingAttachment,
 
+    /// Not all attachments have the same number of samples.
+    IncompleteMultisample,
+
+    /// One of the framebuffer's draw buffers is an attachment point with
+    /// no image attached.
+    IncompleteDrawBuffer,
+
+    /// The framebuffer's read buffer is an attachment point with no image
+    /// attached.
+    IncompleteReadBuffer,
+
+    /// Some attachments are layered and others aren't, or the layered
+    /// attachments are different kinds of textures.
+    IncompleteLayerTargets,
+
     /// The combination of attachment formats is unsupported by the current
     /// OpenGL implementation.
     Unsupported
@@ -280,6 +741,18 @@ impl fmt::Display for GLFramebufferError {
             GLFramebufferError::IncompleteMissingAttachment => {
                 write!(f, "Missing attachments")
             },
+            GLFramebufferError::IncompleteMultisample => {
+                write!(f, "Incomplete multisample")
+            },
+            GLFramebufferError::IncompleteDrawBuffer => {
+                write!(f, "Incomplete draw buffer")
+            },
+            GLFramebufferError::IncompleteReadBuffer => {
+                write!(f, "Incomplete read buffer")
+            },
+            GLFramebufferError::IncompleteLayerTargets => {
+                write!(f, "Incomplete layer targets")
+            },
             GLFramebufferError::Unsupported => {
                 write!(f, "Unsupported")
             }
@@ -293,6 +766,10 @@ impl error::Error for GLFramebufferError {
             GLFramebufferError::IncompleteAttachment => "One or more framebuffer attachments are not complete",
             GLFramebufferError::IncompleteDimensions => "Not all images attached to the framebuffer have the same width and height",
             GLFramebufferError::IncompleteMissingAttachment => "The framebuffer has no images attached",
+            GLFramebufferError::IncompleteMultisample => "Not all images attached to the framebuffer have the same number of samples",
+            GLFramebufferError::IncompleteDrawBuffer => "A draw buffer of the framebuffer has no image attached",
+            GLFramebufferError::IncompleteReadBuffer => "The read buffer of the framebuffer has no image attached",
+            GLFramebufferError::IncompleteLayerTargets => "Not all images attached to the framebuffer are layered textures of the same kind",
             GLFramebufferError::Unsupported => "The framebuffer contains an unsupported combination of attachments",
         }
     }
@@ -304,6 +781,379 @@ impl From<GLFramebufferError> for GLError {
     }
 }
 
+/// The error returned when a framebuffer built with a [`FramebufferBuilder`]
+/// (../context/framebuffer_context/struct.FramebufferBuilder.html) is not
+/// framebuffer-complete. Along with the status returned by
+/// `glCheckFramebufferStatus`, this describes each of the framebuffer's
+/// attachments, since the reason a framebuffer is unsupported usually
+/// depends on the formats of its attachments (and on the device).
+#[derive(Debug, Clone)]
+pub struct FramebufferIncompleteError {
+    /// The framebuffer-completeness error, or `None` if the status
+    /// isn't one that glitter recognizes.
+    pub error: Option<GLFramebufferError>,
+
+    /// The raw status returned by `glCheckFramebufferStatus`.
+    pub status: GLenum,
+
+    /// The images that were attached to the framebuffer, ordered by
+    /// attachment point.
+    pub attachments: Vec<FramebufferAttachmentInfo>
+}
+
+impl fmt::Display for FramebufferIncompleteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.error {
+            Some(error) => {
+                try!(write!(f, "Framebuffer incomplete: {}", error));
+            },
+            None => {
+                try!(write!(f, "Framebuffer incomplete"));
+            }
+        }
+        try!(write!(f, " (status 0x{:04X})", self.status));
+        if self.attachments.is_empty() {
+            try!(write!(f, " with no attachments"));
+        }
+        for attachment in &self.attachments {
+            try!(write!(f, "; {}", attachment));
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for FramebufferIncompleteError {
+    fn description(&self) -> &str {
+        match self.error {
+            Some(ref error) => { error::Error::description(error) },
+            None => "The framebuffer is not framebuffer-complete"
+        }
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        match self.error {
+            Some(ref error) => { Some(error) },
+            None => { None }
+        }
+    }
+}
+
+impl From<FramebufferIncompleteError> for GLError {
+    fn from(e: FramebufferIncompleteError) -> GLError {
+        GLError::FramebufferIncomplete(e)
+    }
+}
+
+/// The kind of image attached to a framebuffer attachment point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramebufferImageKind {
+    /// A mipmap level of a 2D texture.
+    Texture2d,
+
+    /// A mipmap level of one face of a cubemap texture.
+    TextureCubeFace(TxCubeMapImageTarget),
+
+    /// A multisampled 2D texture.
+    Texture2dMultisample,
+
+    /// A renderbuffer.
+    Renderbuffer,
+
+    /// A single layer of a 2D array or 3D texture (with the given texture
+    /// target and layer).
+    TextureLayer(TextureBindingTarget, u32),
+
+    /// Every layer of a cubemap, 2D array, or 3D texture (with the given
+    /// texture target), for layered rendering.
+    TextureLayered(TextureBindingTarget)
+}
+
+/// Describes an image attached to a framebuffer, as part of
+/// a [`FramebufferIncompleteError`](struct.FramebufferIncompleteError.html).
+///
+/// The format, dimensions, and sample count are queried from OpenGL, and are
+/// `None` when the context can't query them. Renderbuffers can always be
+/// queried, but textures can only be queried on desktop OpenGL and
+/// OpenGL ES 3.1 (see [`Extensions::has_tex_level_parameter`]
+/// (../extensions/struct.Extensions.html#method.has_tex_level_parameter)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FramebufferAttachmentInfo {
+    /// The attachment point the image was attached to.
+    pub attachment: FramebufferAttachment,
+
+    /// The kind of image that was attached.
+    pub kind: FramebufferImageKind,
+
+    /// The ID of the attached texture or renderbuffer.
+    pub id: GLuint,
+
+    /// The attached mipmap level (which is always 0 for multisampled
+    /// textures and renderbuffers).
+    pub level: i32,
+
+    /// The internal format of the image, as a raw OpenGL enum (which may be
+    /// an unsized format, such as `GL_RGBA`).
+    pub internal_format: Option<GLenum>,
+
+    /// The width and height of the image, in pixels.
+    pub dimensions: Option<(u32, u32)>,
+
+    /// The number of samples of the image (which is 0 for images that
+    /// aren't multisampled).
+    pub samples: Option<u32>
+}
+
+impl fmt::Display for FramebufferAttachmentInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{:?}: ", self.attachment));
+        match self.kind {
+            FramebufferImageKind::Texture2d => {
+                try!(write!(f, "2D texture {} (level {})", self.id, self.level));
+            },
+            FramebufferImageKind::TextureCubeFace(face) => {
+                try!(write!(f, "cubemap texture {} ({:?}, level {})",
+                            self.id, face, self.level));
+            },
+            FramebufferImageKind::Texture2dMultisample => {
+                try!(write!(f, "multisample 2D texture {}", self.id));
+            },
+            FramebufferImageKind::Renderbuffer => {
+                try!(write!(f, "renderbuffer {}", self.id));
+            },
+            FramebufferImageKind::TextureLayer(target, layer) => {
+                try!(write!(f, "{:?} texture {} (layer {}, level {})",
+                            target, self.id, layer, self.level));
+            },
+            FramebufferImageKind::TextureLayered(target) => {
+                try!(write!(f, "layered {:?} texture {} (level {})",
+                            target, self.id, self.level));
+            }
+        }
+        if let Some(format) = self.internal_format {
+            match RenderbufferFormat::from_gl(format) {
+                Ok(sized) => {
+                    try!(write!(f, ", format {:?} (0x{:04X})", sized, format));
+                },
+                Err(()) => {
+                    try!(write!(f, ", format 0x{:04X}", format));
+                }
+            }
+        }
+        if let Some((width, height)) = self.dimensions {
+            try!(write!(f, ", {}x{}", width, height));
+        }
+        if let Some(samples) = self.samples {
+            try!(write!(f, ", {} samples", samples));
+        }
+        Ok(())
+    }
+}
+
+/// The errors that can occur when one of glitter's builders is not given
+/// enough information to build an object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderError {
+    /// A renderbuffer was built without a format or dimensions.
+    MissingRenderbufferStorage,
+
+    /// A renderbuffer was built with more samples than the context
+    /// supports (including any samples, if multisampled renderbuffers
+    /// aren't supported).
+    UnsupportedRenderbufferSamples,
+
+    /// A renderbuffer was built with a format that the context doesn't
+    /// support (see [`RenderbufferFormat::is_supported`]
+    /// (../image_data/enum.RenderbufferFormat.html#method.is_supported)).
+    UnsupportedRenderbufferFormat,
+
+    /// A texture was built without an image or a format.
+    MissingTextureImage,
+
+    /// A texture was built with a width or height of 0.
+    InvalidTextureDimensions,
+
+    /// A texture uses a mipmap filter, but does not have a mipmap.
+    MissingMipmap,
+
+    /// A texture was built with a mipmap level that isn't half the size
+    /// of the previous level.
+    InvalidMipmapDimensions,
+
+    /// A texture was built with a base mipmap level above its max level,
+    /// a base level that has no image, or a min level of detail above its
+    /// max level of detail.
+    InvalidMipmapLevels,
+
+    /// A texture was built with a parameter that the context doesn't
+    /// support (such as a level of detail clamp on OpenGL ES 2).
+    UnsupportedTextureParameter,
+
+    /// A texture was built with a sized format that the context doesn't
+    /// support for textures (see [`Extensions::has_depth_texture`]
+    /// (../extensions/struct.Extensions.html#method.has_depth_texture) for
+    /// depth formats), or a depth texture was built with a format that isn't
+    /// a depth format.
+    UnsupportedTextureFormat,
+
+    /// A framebuffer was built with more color attachments than the context
+    /// supports (see [`ContextLimits::max_color_attachments`]
+    /// (../limits/struct.ContextLimits.html#structfield.max_color_attachments)
+    /// and [`ContextLimits::max_draw_buffers`]
+    /// (../limits/struct.ContextLimits.html#structfield.max_draw_buffers)).
+    UnsupportedColorAttachments,
+
+    /// A context was built with a pixel store alignment other than
+    /// 1, 2, 4, or 8.
+    InvalidPixelAlignment,
+
+    /// A context was built with a viewport larger than the maximum
+    /// viewport dimensions.
+    InvalidViewport
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuilderError::MissingRenderbufferStorage => {
+                write!(f, "Error building renderbuffer: no format or dimensions provided")
+            },
+            BuilderError::UnsupportedRenderbufferSamples => {
+                write!(f, "Error building renderbuffer: the number of samples is not supported by the context")
+            },
+            BuilderError::UnsupportedRenderbufferFormat => {
+                write!(f, "Error building renderbuffer: the format is not supported by the context")
+            },
+            BuilderError::MissingTextureImage => {
+                write!(f, "Error building texture: neither an image nor a format were provided")
+            },
+            BuilderError::InvalidTextureDimensions => {
+                write!(f, "Error building texture: texture must have positive dimensions")
+            },
+            BuilderError::MissingMipmap => {
+                write!(f, "Error building texture: texture uses a mipmap filter but does not have a mipmap")
+            },
+            BuilderError::InvalidMipmapDimensions => {
+                write!(f, "Error building texture: each mipmap level must be half the size of the previous level")
+            },
+            BuilderError::InvalidMipmapLevels => {
+                write!(f, "Error building texture: the base level and min level of detail must not exceed the max level and max level of detail")
+            },
+            BuilderError::UnsupportedTextureParameter => {
+                write!(f, "Error building texture: a texture parameter is not supported by the context")
+            },
+            BuilderError::UnsupportedTextureFormat => {
+                write!(f, "Error building texture: the format is not supported by the context")
+            },
+            BuilderError::UnsupportedColorAttachments => {
+                write!(f, "Error building framebuffer: the number of color attachments is not supported by the context")
+            },
+            BuilderError::InvalidPixelAlignment => {
+                write!(f, "Error building context: pixel alignment must be 1, 2, 4, or 8")
+            },
+            BuilderError::InvalidViewport => {
+                write!(f, "Error building context: viewport exceeds the maximum viewport dimensions")
+            }
+        }
+    }
+}
+
+impl error::Error for BuilderError {
+    fn description(&self) -> &str {
+        match *self {
+            BuilderError::MissingRenderbufferStorage => "No format or dimensions were provided for a renderbuffer",
+            BuilderError::UnsupportedRenderbufferSamples => "A renderbuffer was given more samples than the context supports",
+            BuilderError::UnsupportedRenderbufferFormat => "A renderbuffer was given a format that the context doesn't support",
+            BuilderError::MissingTextureImage => "Neither an image nor a format were provided for a texture",
+            BuilderError::InvalidTextureDimensions => "A texture was given a width or height of 0",
+            BuilderError::MissingMipmap => "A texture uses a mipmap filter but does not have a mipmap",
+            BuilderError::InvalidMipmapDimensions => "A texture was given a mipmap level that isn't half the size of the previous level",
+            BuilderError::InvalidMipmapLevels => "A texture was given a base level or min level of detail above its max level or max level of detail",
+            BuilderError::UnsupportedTextureParameter => "A texture was given a parameter that the context doesn't support",
+            BuilderError::UnsupportedTextureFormat => "A texture was given a format that the context doesn't support",
+            BuilderError::UnsupportedColorAttachments => "A framebuffer was given more color attachments than the context supports",
+            BuilderError::InvalidPixelAlignment => "A context was given a pixel alignment other than 1, 2, 4, or 8",
+            BuilderError::InvalidViewport => "A context was given a viewport larger than the maximum viewport dimensions"
+        }
+    }
+}
+
+impl From<BuilderError> for GLError {
+    fn from(e: BuilderError) -> GLError {
+        GLError::BuilderError(e)
+    }
+}
+
+/// The reasons that the current context can't be used with glitter, which
+/// are checked by [`Context::load_and_query`]
+/// (../context/struct.ContextOf.html#method.load_and_query).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextError {
+    /// OpenGL functions that glitter requires could not be loaded. The
+    /// names of the missing functions are attached.
+    MissingFunctions(Vec<&'static str>),
+
+    /// The OpenGL version could not be queried, usually because no context
+    /// is current.
+    NoVersion,
+
+    /// The OpenGL version string could not be parsed. The version string
+    /// is attached.
+    InvalidVersion(String),
+
+    /// The context's OpenGL version is older than OpenGL ES 2.0 or
+    /// OpenGL 2.0. The version is attached.
+    UnsupportedVersion(GlVersion),
+
+    /// The context doesn't support framebuffer objects (see
+    /// [`Extensions::has_framebuffer_object`]
+    /// (../extensions/struct.Extensions.html#method.has_framebuffer_object)).
+    MissingFramebufferObject
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ContextError::MissingFunctions(ref names) => {
+                write!(f, "Required OpenGL functions could not be loaded: {}",
+                       names.join(", "))
+            },
+            ContextError::NoVersion => {
+                write!(f, "Could not get the OpenGL version (is a context current?)")
+            },
+            ContextError::InvalidVersion(ref version) => {
+                write!(f, "Could not parse the OpenGL version: {:?}", version)
+            },
+            ContextError::UnsupportedVersion(version) => {
+                write!(f, "OpenGL {}{}.{} is not supported (OpenGL ES 2.0 or OpenGL 2.0 is required)",
+                       if version.es { "ES " } else { "" },
+                       version.major,
+                       version.minor)
+            },
+            ContextError::MissingFramebufferObject => {
+                write!(f, "Framebuffer objects are not supported")
+            }
+        }
+    }
+}
+
+impl error::Error for ContextError {
+    fn description(&self) -> &str {
+        match *self {
+            ContextError::MissingFunctions(_) => "Required OpenGL functions could not be loaded",
+            ContextError::NoVersion => "The OpenGL version could not be queried",
+            ContextError::InvalidVersion(_) => "The OpenGL version could not be parsed",
+            ContextError::UnsupportedVersion(_) => "The OpenGL version is not supported",
+            ContextError::MissingFramebufferObject => "Framebuffer objects are not supported"
+        }
+    }
+}
+
+impl From<ContextError> for GLError {
+    fn from(e: ContextError) -> GLError {
+        GLError::ContextError(e)
+    }
+}
+
 
 
 gl_enum! {
@@ -339,7 +1189,36 @@ gl_enum! {
         pub const TriangleFan as TRIANGLE_FAN = gl::TRIANGLE_FAN,
 
         /// Draw each group of three vertices as a triangle.
-        pub const Triangles as TRIANGLES = gl::TRIANGLES
+        pub const Triangles as TRIANGLES = gl::TRIANGLES,
+
+        /// Draw each group of four vertices as a line segment between the
+        /// middle two vertices, with the outer two vertices available as
+        /// adjacency information in a geometry shader. Requires OpenGL 3.2
+        /// or OpenGL ES 3.2.
+        pub const LinesAdjacency as LINES_ADJACENCY = gl::LINES_ADJACENCY,
+
+        /// Draw a connected line segment like `LineStrip`, where the first
+        /// and last vertices are only available as adjacency information in
+        /// a geometry shader. Requires OpenGL 3.2 or OpenGL ES 3.2.
+        pub const LineStripAdjacency as LINE_STRIP_ADJACENCY = gl::LINE_STRIP_ADJACENCY,
+
+        /// Draw each group of six vertices as a triangle (using the even
+        /// vertices), with the odd vertices available as adjacency
+        /// information in a geometry shader. Requires OpenGL 3.2 or OpenGL
+        /// ES 3.2.
+        pub const TrianglesAdjacency as TRIANGLES_ADJACENCY = gl::TRIANGLES_ADJACENCY,
+
+        /// Draw a strip of triangles like `TriangleStrip` (using the even
+        /// vertices), with the odd vertices available as adjacency
+        /// information in a geometry shader. Requires OpenGL 3.2 or OpenGL
+        /// ES 3.2.
+        pub const TriangleStripAdjacency as TRIANGLE_STRIP_ADJACENCY = gl::TRIANGLE_STRIP_ADJACENCY,
+
+        /// Treat the vertices as a series of patches to be processed by
+        /// the tessellation shaders, where the number of vertices per patch
+        /// is set with `glPatchParameteri`. Requires OpenGL 4.0 or OpenGL ES
+        /// 3.2.
+        pub const Patches as PATCHES = gl::PATCHES
     }
 }
 
@@ -358,10 +1237,105 @@ gl_enum! {
         /// An unsigned 16-bit short.
         pub const UnsignedShort as UNSIGNED_SHORT = gl::UNSIGNED_SHORT,
 
+        /// A signed 32-bit integer.
+        pub const Int as INT = gl::INT,
+
+        /// An unsigned 32-bit integer.
+        pub const UnsignedInt as UNSIGNED_INT = gl::UNSIGNED_INT,
+
         /// A signed 32-bit, fixed-point number in 16.16 form.
         pub const Fixed as FIXED = gl::FIXED,
 
         /// A 32-bit, IEEE floating-point number.
-        pub const Float as FLOAT = gl::FLOAT
+        pub const Float as FLOAT = gl::FLOAT,
+
+        /// A 16-bit, IEEE half-precision floating-point number. Note that
+        /// OpenGL ES 2 only supports half-float vertex data through the
+        /// `OES_vertex_half_float` extension, which uses a different enum
+        /// value.
+        pub const HalfFloat as HALF_FLOAT = gl::HALF_FLOAT,
+
+        /// Four signed components packed into a single 32-bit integer, with
+        /// 10 bits each for the first three components, and 2 bits for the
+        /// fourth component (from least to most significant bits).
+        pub const Int2_10_10_10Rev as INT_2_10_10_10_REV = gl::INT_2_10_10_10_REV,
+
+        /// Four unsigned components packed into a single 32-bit integer, with
+        /// 10 bits each for the first three components, and 2 bits for the
+        /// fourth component (from least to most significant bits).
+        pub const UnsignedInt2_10_10_10Rev as UNSIGNED_INT_2_10_10_10_REV = gl::UNSIGNED_INT_2_10_10_10_REV
+    }
+}
+
+impl DrawingMode {
+    /// Check that primitives can be drawn with this mode by a context with
+    /// the given extensions. The adjacency modes require geometry shaders
+    /// (see [`Extensions::has_geometry_shader`]
+    /// (../extensions/struct.Extensions.html#method.has_geometry_shader)),
+    /// and `DrawingMode::Patches` requires tessellation shaders (see
+    /// [`Extensions::has_tessellation_shader`]
+    /// (../extensions/struct.Extensions.html#method.has_tessellation_shader));
+    /// all other modes are always supported.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](enum.GLError.html#variant.Unsupported)
+    /// error naming the missing feature will be returned if the mode isn't
+    /// supported.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use glitter::prelude::*;
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mode = match glitter::PATCHES.check_supported(gl.extensions()) {
+    ///     Ok(()) => glitter::PATCHES,
+    ///     Err(_) => glitter::TRIANGLES
+    /// };
+    /// ```
+    pub fn check_supported(&self, extensions: &Extensions)
+        -> Result<(), GLError>
+    {
+        match *self {
+            DrawingMode::LinesAdjacency
+            | DrawingMode::LineStripAdjacency
+            | DrawingMode::TrianglesAdjacency
+            | DrawingMode::TriangleStripAdjacency => {
+                if !extensions.has_geometry_shader() {
+                    return Err(GLError::Unsupported("geometry shaders"));
+                }
+            },
+            DrawingMode::Patches => {
+                if !extensions.has_tessellation_shader() {
+                    return Err(GLError::Unsupported("tessellation shaders"));
+                }
+            },
+            _ => { }
+        }
+        Ok(())
+    }
+}
+
+impl DataType {
+    /// Returns `true` if this is an integral (non-packed) data type, which
+    /// can be used for integer vertex attributes (see
+    /// [`gl.vertex_attrib_i_pointer`]
+    /// (../context/buffer_context/trait.ContextBufferExt.html#method.vertex_attrib_i_pointer)).
+    pub fn is_integral(&self) -> bool {
+        match *self {
+            DataType::Byte | DataType::UnsignedByte |
+            DataType::Short | DataType::UnsignedShort |
+            DataType::Int | DataType::UnsignedInt => true,
+            _ => false
+        }
+    }
+
+    /// Returns `true` if this is a packed data type, which holds all four
+    /// components of a vertex attribute in a single value.
+    pub fn is_packed(&self) -> bool {
+        match *self {
+            DataType::Int2_10_10_10Rev |
+            DataType::UnsignedInt2_10_10_10Rev => true,
+            _ => false
+        }
     }
 }
@@ -125,19 +125,19 @@ unsafe impl<T: UniformPrimitive> UniformDatum for [T; 1] {
 
 unsafe impl<T: UniformPrimitive> UniformDatum for [T; 2] {
     fn uniform_datum_type() -> UniformDatumType {
-        UniformDatumType::Vec1(T::uniform_primitive_type())
+        UniformDatumType::Vec2(T::uniform_primitive_type())
     }
 }
 
 unsafe impl<T: UniformPrimitive> UniformDatum for [T; 3] {
     fn uniform_datum_type() -> UniformDatumType {
-        UniformDatumType::Vec1(T::uniform_primitive_type())
+        UniformDatumType::Vec3(T::uniform_primitive_type())
     }
 }
 
 unsafe impl<T> UniformDatum for [T; 4] where T: UniformPrimitive {
     fn uniform_datum_type() -> UniformDatumType {
-        UniformDatumType::Vec1(T::uniform_primitive_type())
+        UniformDatumType::Vec4(T::uniform_primitive_type())
     }
 }
 
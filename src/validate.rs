@@ -0,0 +1,110 @@
+// Runtime checks for mistakes that glitter's types can't catch.
+//
+// Binding checks: glitter's typestate tracks which objects are bound, but
+// it can't see bindings changed by other code (or by another `Context`).
+// With the `validate-bindings` feature, each binding holds
+// a `BindingCheck`, which remembers the ID of the bound object and panics
+// if the driver disagrees. Without the feature, `BindingCheck` is
+// zero-sized and all checks are no-ops.
+//
+// Thread checks: an OpenGL context can only be used on the thread it was
+// made current on. Each context records the thread that created it, and
+// (with `debug_assertions`) each OpenGL call first checks that a context
+// is active on the calling thread (see `gl_fns`), rather than letting the
+// driver silently ignore the call (or crash).
+
+use std::thread::{self, ThreadId};
+use context::Context;
+
+// Panic if the context that was created on `owner` is used on another
+// thread
+pub fn check_owner_thread(owner: ThreadId) {
+    let current = thread::current();
+    if current.id() != owner {
+        panic!("A context created on thread {:?} was used on thread '{}' ({:?}). OpenGL contexts can only be used on the thread that created them",
+               owner, current.name().unwrap_or("<unnamed>"), current.id());
+    }
+}
+
+// Panic because no context is active on the current thread, where `func`
+// is the name of the OpenGL function that was about to be called
+pub fn no_context(func: &str) -> ! {
+    let current = thread::current();
+    panic!("{} was called on thread '{}' ({:?}), but no context is active on this thread. OpenGL contexts can only be used on the thread that created them (see `Context::current_context`)",
+           func, current.name().unwrap_or("<unnamed>"), current.id());
+}
+
+// Clear any pending OpenGL errors, such as before a call whose errors are
+// checked, or after calls made outside of glitter
+pub fn clear_errors() {
+    // NOTE: The number of errors is limited, since some drivers report the
+    //       same error indefinitely
+    for _ in 0..64 {
+        if Context::get_error().is_none() {
+            break;
+        }
+    }
+}
+
+#[cfg(feature = "validate-bindings")]
+mod imp {
+    use gl_fns;
+    use gl::types::*;
+
+    pub struct BindingCheck {
+        gl_id: GLuint
+    }
+
+    impl BindingCheck {
+        pub fn new(gl_id: GLuint) -> Self {
+            BindingCheck { gl_id: gl_id }
+        }
+
+        pub fn current(pname: GLenum) -> Self {
+            BindingCheck { gl_id: _get_binding(pname) }
+        }
+
+        pub fn check(&self, pname: GLenum, target: &str) {
+            let actual = _get_binding(pname);
+            if actual != self.gl_id {
+                panic!("Binding mismatch for {}: expected object {} to be bound, but the driver reports object {}",
+                       target, self.gl_id, actual);
+            }
+        }
+    }
+
+    fn _get_binding(pname: GLenum) -> GLuint {
+        let mut value: GLint = 0;
+        unsafe {
+            gl_fns::GetIntegerv(pname, &mut value as *mut GLint);
+        }
+        value as GLuint
+    }
+}
+
+#[cfg(not(feature = "validate-bindings"))]
+mod imp {
+    use gl::types::*;
+
+    pub struct BindingCheck;
+
+    impl BindingCheck {
+        #[inline(always)]
+        pub fn new(_gl_id: GLuint) -> Self {
+            BindingCheck
+        }
+
+        #[inline(always)]
+        pub fn current(_pname: GLenum) -> Self {
+            BindingCheck
+        }
+
+        #[inline(always)]
+        pub fn check(&self, _pname: GLenum, _target: &str) {
+
+        }
+    }
+}
+
+pub use self::imp::BindingCheck;
+
@@ -1,16 +1,31 @@
 //! Contains a higher-level abstraction for creating vertex and index
 //! buffer.
 
+use std::cmp;
+use std::error;
+use std::fmt;
+use std::mem;
+use std::ptr;
+use std::ops::Range;
+use std::{u8, u16};
 use std::marker::PhantomData;
 use std::collections::{HashMap, HashSet};
 use context::{ContextOf, AContext, ContextBufferExt,
               ArrayBufferBinding, ArrayBufferContext,
               ElementArrayBufferBinding, ElementArrayBufferContext};
-use program::ProgramAttrib;
-use vertex_data::{VertexData, VertexBytes, VertexAttribute};
-use index_data::{IndexData, IndexDatum};
-use buffer::Buffer;
-use types::DrawingMode;
+use context::buffer_context;
+use program::{Program, ProgramAttrib};
+use vertex_data::{VertexData, VertexLayout, VertexBytes, VertexAttribute};
+use index_data::{IndexData, IndexDatum, IndexDatumType};
+use buffer::{Buffer, BufferDataUsage, MAP_WRITE_BIT, MAP_INVALIDATE_RANGE_BIT};
+use types::{DataType, DrawingMode, GLObject, GLError};
+use extensions::Extensions;
+use stats;
+use frame_stats;
+use bind_cache;
+use gl;
+use gl_fns;
+use gl::types::*;
 
 /// An error generated when trying add an attribute to an [`AttribBinder`]
 /// (struct.AttribBinder.html) using the [`AttribBinder::add`]
@@ -19,7 +34,13 @@ use types::DrawingMode;
 pub enum AttribAddError {
     /// An attribute was added that already exists in the [`AttribBinder`]
     /// (struct.AttribBinder.html).
-    DuplicateAttrib(String)
+    DuplicateAttrib(String),
+
+    /// A vertex attribute's shader attribute could not be found in the
+    /// program (see [`AttribBinder::add_program_attribs`]
+    /// (struct.AttribBinder.html#method.add_program_attribs)). The
+    /// value is the name of the shader attribute that was looked up.
+    UnknownProgramAttrib(String)
 }
 
 /// An error generated when trying to iterate over the lists of attributes
@@ -40,17 +61,100 @@ pub struct AttribError {
 /// operating on vertex attributes. Consider using the direct lower-level
 /// glitter API's if heap allocations become a performance bottleneck.
 pub struct AttribBinder {
-    attribs: HashMap<String, ProgramAttrib>
+    attribs: HashMap<String, ProgramAttrib>,
+    divisors: HashMap<String, u32>,
+    shader_names: HashMap<String, String>
 }
 
 impl AttribBinder {
     /// Create a new, empty `AttribBinder`.
     pub fn new() -> Self {
         AttribBinder {
-            attribs: HashMap::new()
+            attribs: HashMap::new(),
+            divisors: HashMap::new(),
+            shader_names: HashMap::new()
         }
     }
 
+    /// Map the vertex attribute `name` (usually the name of a field in a
+    /// `VertexData` struct) to a differently-named shader attribute, such as
+    /// mapping `position` to `a_position`. The mapping is used when looking up
+    /// attributes with [`add_program_attribs`]
+    /// (struct.AttribBinder.html#method.add_program_attribs), so neither the
+    /// struct nor the GLSL source need to change to match the other's naming
+    /// convention.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// #[derive(Clone, Copy)]
+    /// struct Vertex {
+    ///     position: [f32; 3],
+    ///     color: [f32; 3]
+    /// }
+    ///
+    /// impl_vertex_data!(Vertex, position, color);
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// # let program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+    /// // The shader uses `a_position` and `a_color`
+    /// let mut binder = glitter::AttribBinder::new();
+    /// binder.rename("position", "a_position");
+    /// binder.rename("color", "a_color");
+    /// binder.add_program_attribs::<Vertex, _>(&gl, &program).unwrap();
+    /// # }
+    /// ```
+    pub fn rename(&mut self, name: &str, shader_name: &str) {
+        self.shader_names.insert(name.into(), shader_name.into());
+    }
+
+    /// Get the name of the shader attribute that the vertex attribute `name`
+    /// maps to. This is `name` itself unless a different name was set
+    /// with [`rename`](struct.AttribBinder.html#method.rename).
+    pub fn shader_name<'a>(&'a self, name: &'a str) -> &'a str {
+        match self.shader_names.get(name) {
+            Some(shader_name) => { shader_name },
+            None => { name }
+        }
+    }
+
+    /// Add every attribute of the `VertexData` type `V`, by looking up
+    /// each attribute's location in `program` (using [`gl.get_attrib_location`]
+    /// (../context/program_context/trait.ContextProgramExt.html#method.get_attrib_location)).
+    /// Each attribute is looked up by its shader name (see [`rename`]
+    /// (struct.AttribBinder.html#method.rename)).
+    ///
+    /// # Failures
+    /// An error will be returned if an attribute is already present, or if
+    /// an attribute could not be found in the program. Attributes that were
+    /// added before the error occurred are kept.
+    pub fn add_program_attribs<V, C>(&mut self, gl: &C, program: &Program)
+        -> Result<(), AttribAddError>
+        where V: VertexData, C: AContext
+    {
+        let mut names = Vec::<String>::new();
+        V::visit_attributes(|attrib| names.push(attrib.name));
+
+        for name in &names {
+            let attrib = {
+                let shader_name = self.shader_name(name);
+                match gl.get_attrib_location(program, shader_name) {
+                    Ok(attrib) => { attrib },
+                    Err(_) => {
+                        let shader_name = shader_name.into();
+                        return Err(AttribAddError::UnknownProgramAttrib(shader_name));
+                    }
+                }
+            };
+            try!(self.add(name, attrib));
+        }
+
+        Ok(())
+    }
+
     /// Add an attribute to the `AttribBinder`.
     ///
     /// # Failures
@@ -62,26 +166,59 @@ impl AttribBinder {
     pub fn add(&mut self, name: &str, attrib: ProgramAttrib)
         -> Result<(), AttribAddError>
     {
-        match self.attribs.insert(name.into(), attrib) {
-            None => Ok(()),
-            Some(_) => Err(AttribAddError::DuplicateAttrib(name.into()))
+        self.add_instanced(name, attrib, 0)
+    }
+
+    /// Add a per-instance attribute to the `AttribBinder`. When drawing
+    /// instanced primitives, the attribute will advance once every
+    /// `divisor` instances, rather than once per vertex. A `divisor` of
+    /// 0 is the same as using [`add`](struct.AttribBinder.html#method.add).
+    ///
+    /// # Failures
+    /// `add_instanced` will return an error if the attribute being added is
+    /// already present.
+    ///
+    /// # Note
+    /// Each call to `add_instanced` can potentially cause a heap allocation.
+    pub fn add_instanced(&mut self,
+                         name: &str,
+                         attrib: ProgramAttrib,
+                         divisor: u32)
+        -> Result<(), AttribAddError>
+    {
+        if self.attribs.contains_key(name) {
+            return Err(AttribAddError::DuplicateAttrib(name.into()));
+        }
+
+        self.attribs.insert(name.into(), attrib);
+        if divisor != 0 {
+            self.divisors.insert(name.into(), divisor);
         }
+        Ok(())
     }
 
     fn for_each<T, F>(&self, mut f: F) -> Result<(), AttribError>
         where T: VertexData, F: FnMut(VertexAttribute, ProgramAttrib)
+    {
+        self.for_each_slot::<(T,), _>(|_, vertex_attrib, program_attrib| {
+            f(vertex_attrib, program_attrib);
+        })
+    }
+
+    fn for_each_slot<L, F>(&self, mut f: F) -> Result<(), AttribError>
+        where L: VertexLayout, F: FnMut(usize, VertexAttribute, ProgramAttrib)
     {
         // TODO: Avoid heap allocations
-        // TODO: Avoid redundant calls to T::visit_attributes
+        // TODO: Avoid redundant calls to L::visit_slot_attributes
         let mut attribs =
-            HashMap::<String, (VertexAttribute, ProgramAttrib)>::new();
+            HashMap::<String, (usize, VertexAttribute, ProgramAttrib)>::new();
         let mut missing = Vec::<String>::new();
 
-        T::visit_attributes(|vertex_attrib| {
+        L::visit_slot_attributes(|slot, vertex_attrib| {
             match self.attribs.get(&vertex_attrib.name) {
                 Some(program_attrib) => {
-                    let pair = (vertex_attrib.clone(), *program_attrib);
-                    attribs.insert(vertex_attrib.name, pair);
+                    let triple = (slot, vertex_attrib.clone(), *program_attrib);
+                    attribs.insert(vertex_attrib.name, triple);
                 },
                 None => {
                     missing.push(vertex_attrib.name);
@@ -96,8 +233,8 @@ impl AttribBinder {
         };
 
         if missing.is_empty() && unknown.is_empty() {
-            for (_, (vertex_attrib, program_attrib)) in attribs.into_iter() {
-                f(vertex_attrib, program_attrib);
+            for (_, (slot, vertex_attrib, program_attrib)) in attribs.into_iter() {
+                f(slot, vertex_attrib, program_attrib);
             }
             Ok(())
         }
@@ -135,26 +272,151 @@ impl AttribBinder {
 
     /// Set up each vertex attribute with the appropriate attribute options
     /// (using [`glVertexAttribPointer`]
-    /// (http://docs.gl/es2/glVertexAttribPointer)). The `VertexData` type
+    /// (http://docs.gl/es2/glVertexAttribPointer), or
+    /// [`glVertexAttribIPointer`](http://docs.gl/es3/glVertexAttribIPointer)
+    /// for integer attributes). If instanced arrays are supported, the
+    /// divisor of each attribute is also set, using 0 for attributes that
+    /// weren't added with a divisor (using
+    /// [`glVertexAttribDivisor`](http://docs.gl/es3/glVertexAttribDivisor)). The `VertexData` type
     /// parameter is used to get the attribute options for each attribute.
     ///
     /// # Failures
     /// An error will be returned if the set of vertex attributes contained
     /// by the `VertexData` types does not exactly match the list of attributes
-    /// contained by the `AttribBinder.`
+    /// contained by the `AttribBinder.`, or if an attribute uses a feature
+    /// that is not supported by the current context.
     ///
     /// # Note
     /// Currently, calling `bind` will perform a heap allocation. Consider
     /// using [`gl.enable_vertex_attrib_array`]
     /// (../context/trait.ContextExt.html#method.enable_vertex_attrib_array)
     /// if this becomes a performance concern.
-    pub fn bind<V, C>(&self, gl: &C) -> Result<(), AttribError>
+    pub fn bind<V, C>(&self, gl: &C) -> Result<(), VertexBindError>
         where V: VertexData, C: AContext
     {
-        self.for_each::<V, _>(|vertex_attrib, program_attrib| {
-            unsafe {
-                // TODO: Refactor!
-                // (Make vertex_attrib_pointer take vertex_attrib)
+        let mut result = Ok(());
+        try!(self.for_each::<V, _>(|vertex_attrib, program_attrib| {
+            if result.is_ok() {
+                result = self.attrib_pointer(gl, &vertex_attrib, program_attrib);
+            }
+        }));
+        result
+    }
+
+    /// Enable all of the vertex attributes contained within the `AttribBinder`,
+    /// using a multi-buffer [`VertexLayout`]
+    /// (../vertex_data/trait.VertexLayout.html) to verify the list of
+    /// attributes. See [`enable`](struct.AttribBinder.html#method.enable)
+    /// for more details.
+    ///
+    /// # Failures
+    /// An error will be returned if the set of vertex attributes contained
+    /// by all of the slots of the `VertexLayout` does not exactly match the
+    /// list of attributes contained by the `AttribBinder`.
+    pub fn enable_layout<L, C>(&self, gl: &mut C) -> Result<(), AttribError>
+        where L: VertexLayout, C: AContext
+    {
+        self.for_each_slot::<L, _>(|_, _, program_attrib| {
+            gl.enable_vertex_attrib_array(program_attrib);
+        })
+    }
+
+    /// Set up each vertex attribute for a multi-buffer [`VertexLayout`]
+    /// (../vertex_data/trait.VertexLayout.html). `buffers` should contain
+    /// the OpenGL id of the buffer for each slot of the layout; each buffer
+    /// is bound to `GL_ARRAY_BUFFER` in turn while its attributes are set
+    /// up, and the buffer for slot 0 is left bound afterwards. See
+    /// [`bind`](struct.AttribBinder.html#method.bind) for more details.
+    ///
+    /// # Failures
+    /// An error will be returned if the set of vertex attributes contained
+    /// by all of the slots of the `VertexLayout` does not exactly match the
+    /// list of attributes contained by the `AttribBinder`, or if an
+    /// attribute uses a feature that is not supported by the current context.
+    ///
+    /// # Panics
+    /// This function will panic if the number of buffers does not match
+    /// the number of slots in the layout.
+    ///
+    /// # Safety
+    /// Each buffer id must be a valid buffer object, and `gl` must have
+    /// a free `GL_ARRAY_BUFFER` binding, which will be overwritten.
+    pub unsafe fn bind_layout<L, C>(&self, gl: &C, buffers: &[GLuint])
+        -> Result<(), VertexBindError>
+        where L: VertexLayout, C: AContext
+    {
+        assert_eq!(buffers.len(), L::slot_count());
+
+        let mut result = Ok(());
+        let slots = self.for_each_slot::<L, _>(|slot, vertex_attrib, program_attrib| {
+            if result.is_ok() {
+                _bind_array_buffer_id(buffers[slot]);
+                result = self.attrib_pointer(gl, &vertex_attrib, program_attrib);
+            }
+        });
+        if let Some(&first) = buffers.first() {
+            _bind_array_buffer_id(first);
+        }
+        try!(slots);
+        result
+    }
+
+    // Check that the current context supports all of the features used by
+    // the attributes of a layout, so that unsupported attributes are caught
+    // before they generate errors while drawing
+    fn check_support<L>(&self, extensions: &Extensions)
+        -> Result<(), VertexBindError>
+        where L: VertexLayout
+    {
+        if !self.divisors.is_empty() && !extensions.has_instanced_arrays() {
+            return Err(VertexBindError::Unsupported("instanced arrays"));
+        }
+
+        let mut unsupported = None;
+        L::visit_slot_attributes(|_, attrib| {
+            let ty = &attrib.ty;
+            if ty.integer && !extensions.has_integer_vertex_attribs() {
+                unsupported = Some("integer vertex attributes");
+            }
+            else if ty.data == DataType::HalfFloat
+                && !extensions.has_half_float_vertex()
+            {
+                unsupported = Some("half-float vertex attributes");
+            }
+            else if ty.data.is_packed()
+                && !extensions.has_vertex_type_2_10_10_10_rev()
+            {
+                unsupported = Some("packed 2_10_10_10 vertex attributes");
+            }
+        });
+
+        match unsupported {
+            Some(feature) => { Err(VertexBindError::Unsupported(feature)) },
+            None => { Ok(()) }
+        }
+    }
+
+    // Set the attribute pointer (and divisor, if needed) for a single
+    // attribute, reading from the buffer currently bound to `GL_ARRAY_BUFFER`
+    fn attrib_pointer<C>(&self,
+                         gl: &C,
+                         vertex_attrib: &VertexAttribute,
+                         program_attrib: ProgramAttrib)
+        -> Result<(), VertexBindError>
+        where C: AContext
+    {
+        unsafe {
+            // TODO: Refactor!
+            // (Make vertex_attrib_pointer take vertex_attrib)
+            if vertex_attrib.ty.integer {
+                try!(gl.vertex_attrib_i_pointer(program_attrib,
+                                                vertex_attrib.ty.components,
+                                                vertex_attrib.ty.data,
+                                                vertex_attrib.stride,
+                                                vertex_attrib.offset)
+                     .map_err(_unsupported));
+            }
+            else {
                 gl.vertex_attrib_pointer(program_attrib,
                                          vertex_attrib.ty.components,
                                          vertex_attrib.ty.data,
@@ -162,10 +424,67 @@ impl AttribBinder {
                                          vertex_attrib.stride,
                                          vertex_attrib.offset);
             }
-        })
+
+            // Per-vertex attributes still need their divisor reset to 0,
+            // since another binder may have left a divisor on the same
+            // attribute index. `glVertexAttribDivisor` is unavailable
+            // without instanced arrays, so a divisor of 0 is left alone then.
+            let divisor = self.divisors.get(&vertex_attrib.name)
+                                       .cloned()
+                                       .unwrap_or(0);
+            if divisor != 0 || gl._extensions().has_instanced_arrays() {
+                try!(gl.vertex_attrib_divisor(program_attrib, divisor)
+                       .map_err(_unsupported));
+            }
+        }
+        Ok(())
     }
 }
 
+// Returns `true` if the attributes of `T` are the same as the attributes of
+// slot `slot` of the layout `L`, so the data of `T` can be read as that slot
+fn _slot_attributes_match<L, T>(slot: usize) -> bool
+    where L: VertexLayout, T: VertexData
+{
+    let mut slot_attribs = vec![];
+    L::visit_slot_attributes(|attrib_slot, attrib| {
+        if attrib_slot == slot {
+            slot_attribs.push(attrib);
+        }
+    });
+
+    let mut attribs = vec![];
+    T::visit_attributes(|attrib| attribs.push(attrib));
+
+    slot_attribs == attribs
+}
+
+// Convert an error from setting up an attribute pointer. The only errors
+// those functions return are for unsupported features
+fn _unsupported(gl_error: GLError) -> VertexBindError {
+    match gl_error {
+        GLError::Unsupported(feature) => VertexBindError::Unsupported(feature),
+        e => { panic!("Unexpected error binding vertex attributes: {}", e); }
+    }
+}
+
+// Bind a buffer to `GL_ARRAY_BUFFER` by id, without going through the
+// typestate binding API. Used to switch between the buffers of a
+// multi-buffer vertex layout while it is bound.
+unsafe fn _bind_array_buffer_id(id: GLuint) {
+    if !bind_cache::_bind(gl::ARRAY_BUFFER, id) {
+        return;
+    }
+
+    gl_fns::BindBuffer(gl::ARRAY_BUFFER, id);
+    frame_stats::_state_change();
+    stats::_bind(gl::ARRAY_BUFFER, id);
+    dbg_gl_sanity_check! {
+        in "glBindBuffer"();
+        GLError::InvalidEnum => "`target` is not an allowed value",
+        _ => "Unknown error"
+    }
+}
 
 
 /// An error generated when binding vertex attributes.
@@ -175,7 +494,12 @@ pub enum VertexBindError {
     BindingError(AttribError),
 
     /// No attribute bindings were provided.
-    NoAttributeBindings
+    NoAttributeBindings,
+
+    /// An attribute uses a feature that is not supported by the current
+    /// context (such as an integer attribute with OpenGL ES 2). The name of
+    /// the missing feature is attached.
+    Unsupported(&'static str)
 }
 
 impl From<AttribError> for VertexBindError {
@@ -184,6 +508,60 @@ impl From<AttribError> for VertexBindError {
     }
 }
 
+impl fmt::Display for AttribError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Vertex attributes did not match (missing: {:?}, unknown: {:?})",
+               self.missing_attribs,
+               self.unknown_attribs)
+    }
+}
+
+impl error::Error for AttribError {
+    fn description(&self) -> &str {
+        "The vertex data attributes did not match the attribute bindings"
+    }
+}
+
+impl fmt::Display for VertexBindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VertexBindError::BindingError(ref e) => { write!(f, "{}", e) },
+            VertexBindError::NoAttributeBindings => {
+                write!(f, "No attribute bindings provided for vertex buffer")
+            },
+            VertexBindError::Unsupported(feature) => {
+                write!(f, "Vertex attributes require an unsupported feature: {}",
+                       feature)
+            }
+        }
+    }
+}
+
+impl error::Error for VertexBindError {
+    fn description(&self) -> &str {
+        match *self {
+            VertexBindError::BindingError(ref e) => {
+                error::Error::description(e)
+            },
+            VertexBindError::NoAttributeBindings => {
+                "No attribute bindings were provided"
+            },
+            VertexBindError::Unsupported(_) => {
+                "The vertex attributes require an unsupported feature"
+            }
+        }
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        match *self {
+            VertexBindError::BindingError(ref e) => { Some(e) },
+            VertexBindError::NoAttributeBindings => { None },
+            VertexBindError::Unsupported(_) => { None }
+        }
+    }
+}
+
 /// A buffer that contains vertex data. In addition to storing a buffer,
 /// a `VertexBuffer` stores an [`AttribBinder`](struct.AttribBinder.html)
 /// and a count of the amount of `VertexData` that has been buffered.
@@ -191,6 +569,7 @@ pub struct VertexBuffer<T: VertexData> {
     attrib_binder: Option<AttribBinder>,
     buffer: Buffer,
     count: usize,
+    capacity: usize,
     phantom: PhantomData<*const T>
 }
 
@@ -201,6 +580,21 @@ impl<V: VertexData> VertexBuffer<V> {
         self.attrib_binder = Some(binder);
     }
 
+    /// Get the number of vertices that have been buffered.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no vertices have been buffered.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get the number of vertices the buffer has storage for.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
     /// Get a reference to underlying OpenGL buffer.
     pub fn buffer(&self) -> &Buffer {
         &self.buffer
@@ -218,35 +612,769 @@ impl<V: VertexData> VertexBuffer<V> {
 pub struct VertexBufferBinding<'a, T: VertexData + 'a> {
     gl_buffer: ArrayBufferBinding<'a>,
     count: &'a mut usize,
+    capacity: &'a mut usize,
     _phantom: PhantomData<*const VertexBuffer<T>>
 }
 
-/// An extension trait that adds vertex buffer and index buffer-related methods
-/// to OpenGL contexts.
-pub trait ContextVertexBufferExt: AContext {
-    /// Create a new, empty vertex buffer.
-    fn new_vertex_buffer<V: VertexData>(&self) -> VertexBuffer<V> {
-        VertexBuffer {
+/// A vertex buffer for vertex data that changes every frame, such as
+/// immediate-mode UI or debug geometry. Vertices are appended to the buffer
+/// with [`gl.stream_vertices`]
+/// (trait.ContextVertexBufferExt.html#method.stream_vertices), which
+/// returns the range of vertices that were written.
+///
+/// Appended vertices are written one after another, like a ring buffer.
+/// When there is no room left for the next append, the buffer is "orphaned"
+/// (its storage is re-specified with [`gl.reserve_vertices`]
+/// (trait.ContextVertexBufferExt.html#method.reserve_vertices)), and writing
+/// starts over at the beginning. Orphaning lets OpenGL hand out new storage
+/// instead of waiting for pending draw calls to finish reading from the old
+/// storage, so appending never stalls the CPU.
+///
+/// # Note
+/// A range returned from `gl.stream_vertices` is only valid until the
+/// buffer is orphaned, so each range should be drawn before appending more
+/// vertices.
+pub struct StreamingVertexBuffer<T: VertexData> {
+    vbo: VertexBuffer<T>,
+    capacity: usize,
+    cursor: usize
+}
+
+impl<T: VertexData> StreamingVertexBuffer<T> {
+    /// Set the `AttribBinder` that will contain all of the vertex attributes
+    /// used when rendering.
+    pub fn bind_attrib_pointers(&mut self, binder: AttribBinder) {
+        self.vbo.bind_attrib_pointers(binder);
+    }
+
+    /// Get the number of vertices that can be appended before the buffer
+    /// is orphaned.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Represents a [`StreamingVertexBuffer`](struct.StreamingVertexBuffer.html)
+/// that has been bound to a context.
+pub struct StreamingVertexBufferBinding<'a, T: VertexData + 'a> {
+    gl_vbo: VertexBufferBinding<'a, T>,
+    capacity: usize,
+    cursor: &'a mut usize
+}
+
+impl<'a, T: VertexData + 'a> StreamingVertexBufferBinding<'a, T> {
+    /// Get the underlying vertex buffer binding, which can be used to draw
+    /// the ranges returned by [`gl.stream_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.stream_vertices).
+    pub fn vertex_buffer(&self) -> &VertexBufferBinding<'a, T> {
+        &self.gl_vbo
+    }
+}
+
+/// A set of vertex buffers that together hold the vertices for
+/// a multi-buffer [`VertexLayout`](../vertex_data/trait.VertexLayout.html),
+/// with one buffer for each slot of the layout. Each slot's vertices are
+/// sent separately (using [`gl.buffer_slot_vertices`]
+/// (trait.ContextVertexBufferExt.html#method.buffer_slot_vertices)), so
+/// data that changes often (such as skinning data) can be updated without
+/// resending data that doesn't.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use] extern crate glitter;
+/// use glitter::prelude::*;
+///
+/// # fn main() {
+/// #[derive(Clone, Copy)]
+/// struct Position {
+///     position: [f32; 3]
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// struct Skinning {
+///     weights: [f32; 4]
+/// }
+///
+/// impl_vertex_data!(Position, position);
+/// impl_vertex_data!(Skinning, weights);
+///
+/// let gl = unsafe { glitter::Context::current_context() };
+/// # let program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+/// let mut mvbo = gl.new_multi_vertex_buffer::<(Position, Skinning)>();
+/// mvbo.bind_attrib_pointers(attrib_pointers! {
+///     position => gl.get_attrib_location(&program, "position").unwrap(),
+///     weights => gl.get_attrib_location(&program, "weights").unwrap()
+/// });
+///
+/// let positions = [Position { position: [0.0, 0.0, 0.0] }; 3];
+/// let skinning = [Skinning { weights: [1.0, 0.0, 0.0, 0.0] }; 3];
+///
+/// let (mut gl_mvbo, gl) = gl.bind_multi_vertex_buffer(&mut mvbo);
+/// gl.buffer_slot_vertices(&mut gl_mvbo, 0, &positions, glitter::STATIC_DRAW);
+/// gl.buffer_slot_vertices(&mut gl_mvbo, 1, &skinning, glitter::DYNAMIC_DRAW);
+/// gl.draw_arrays_multi_vbo(&gl_mvbo, glitter::TRIANGLES);
+/// # }
+/// ```
+pub struct MultiVertexBuffer<L: VertexLayout> {
+    attrib_binder: Option<AttribBinder>,
+    buffers: Vec<Buffer>,
+    counts: Vec<usize>,
+    phantom: PhantomData<*const L>
+}
+
+impl<L: VertexLayout> MultiVertexBuffer<L> {
+    /// Set the `AttribBinder` that will contain all of the vertex attributes
+    /// used when rendering, across all slots.
+    pub fn bind_attrib_pointers(&mut self, binder: AttribBinder) {
+        self.attrib_binder = Some(binder);
+    }
+
+    /// Get the number of vertices that have been buffered for the
+    /// given slot.
+    ///
+    /// # Panics
+    /// This function will panic if `slot` is out of range.
+    pub fn slot_len(&self, slot: usize) -> usize {
+        self.counts[slot]
+    }
+
+    /// Get a reference to the underlying OpenGL buffers, one for each slot.
+    pub fn buffers(&self) -> &[Buffer] {
+        &self.buffers
+    }
+}
+
+/// Represents a [`MultiVertexBuffer`](struct.MultiVertexBuffer.html) that
+/// has been bound to a context.
+pub struct MultiVertexBufferBinding<'a, L: VertexLayout + 'a> {
+    gl_buffer: ArrayBufferBinding<'a>,
+    ids: Vec<GLuint>,
+    counts: &'a mut Vec<usize>,
+    _phantom: PhantomData<*const MultiVertexBuffer<L>>
+}
+
+impl<'a, L: VertexLayout + 'a> MultiVertexBufferBinding<'a, L> {
+    /// Get the number of vertices that can be drawn, which is the smallest
+    /// number of vertices buffered for any slot.
+    pub fn len(&self) -> usize {
+        self.counts.iter().cloned().min().unwrap_or(0)
+    }
+
+    /// Returns `true` if no vertices can be drawn.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Writes vertices directly into a mapped region of a vertex buffer, with
+/// no intermediate copies. A `VertexWriter` is created with
+/// [`gl.vertex_writer`](trait.ContextVertexBufferExt.html#method.vertex_writer),
+/// which maps the unused storage at the end of the buffer. Vertices written
+/// with [`push`](struct.VertexWriter.html#method.push) or [`extend`]
+/// (struct.VertexWriter.html#method.extend) are appended after the buffer's
+/// current vertices. When the `VertexWriter` is dropped, the buffer is
+/// unmapped and its vertex count is updated to include the new vertices.
+///
+/// # Note
+/// If the buffer's contents were corrupted while it was mapped (see
+/// [`gl.unmap_buffer`]
+/// (../context/buffer_context/trait.ContextBufferExt.html#method.unmap_buffer)),
+/// the new vertices are discarded and the vertex count is left unchanged.
+pub struct VertexWriter<'a, 'b: 'a, T: VertexData + 'b> {
+    gl_vbo: &'a mut VertexBufferBinding<'b, T>,
+    ptr: *mut u8,
+    len: usize,
+    capacity: usize
+}
+
+impl<'a, 'b: 'a, T: VertexData + 'b> VertexWriter<'a, 'b, T> {
+    /// Get the number of vertices that have been written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no vertices have been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the number of vertices that can still be written.
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.len
+    }
+
+    /// Write a vertex.
+    ///
+    /// # Panics
+    /// This function will panic if there is no room left for the vertex.
+    pub fn push(&mut self, vertex: T) {
+        assert!(self.len < self.capacity,
+                "VertexWriter is full ({} vertices)", self.capacity);
+
+        let size = mem::size_of::<T>();
+        unsafe {
+            // The mapped pointer may not be aligned for `T`, so copy bytes
+            let src = &vertex as *const T as *const u8;
+            let dest = self.ptr.offset((self.len * size) as isize);
+            ptr::copy_nonoverlapping(src, dest, size);
+        }
+        self.len += 1;
+    }
+
+    /// Write each vertex from an iterator.
+    ///
+    /// # Panics
+    /// This function will panic if the iterator yields more vertices than
+    /// there is room left for.
+    pub fn extend<I>(&mut self, vertices: I)
+        where I: IntoIterator<Item=T>
+    {
+        for vertex in vertices {
+            self.push(vertex);
+        }
+    }
+}
+
+impl<'a, 'b: 'a, T: VertexData + 'b> Drop for VertexWriter<'a, 'b, T> {
+    fn drop(&mut self) {
+        if self.ptr.is_null() {
+            return;
+        }
+
+        let intact = unsafe {
+            buffer_context::_unmap_buffer(&mut self.gl_vbo.gl_buffer)
+        };
+        if intact {
+            *self.gl_vbo.count += self.len;
+        }
+    }
+}
+
+/// An extension trait that adds vertex buffer and index buffer-related methods
+/// to OpenGL contexts.
+pub trait ContextVertexBufferExt: AContext {
+    /// Create a new, empty vertex buffer.
+    fn new_vertex_buffer<V: VertexData>(&self) -> VertexBuffer<V> {
+        VertexBuffer {
+            attrib_binder: None,
+            buffer: self.gen_buffer(),
+            count: 0,
+            capacity: 0,
+            phantom: PhantomData
+        }
+    }
+
+    /// Send data to a vertex buffer. Note that this will replace the buffer's
+    /// current contents, if any.
+    fn buffer_vertices<T>(&self,
+                          gl_vbo: &mut VertexBufferBinding<T>,
+                          vertices: &[T],
+                          usage: BufferDataUsage)
+        where T: VertexData, [T]: VertexBytes
+    {
+
+        *gl_vbo.count = vertices.len();
+        *gl_vbo.capacity = vertices.len();
+        self.buffer_bytes(&mut gl_vbo.gl_buffer,
+                          vertices.vertex_bytes(),
+                          usage);
+    }
+
+    /// Send vertices from an iterator to a vertex buffer, without first
+    /// collecting them into a `Vec`. Storage for all of the vertices is
+    /// allocated up front (using the iterator's length), and then the
+    /// vertices are sent in small chunks, so only a bounded amount of
+    /// scratch memory is used no matter how many vertices there are. Note
+    /// that this will replace the buffer's current contents, if any.
+    ///
+    /// # Panics
+    /// This function will panic if the iterator yields more items than
+    /// its reported length.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// #[derive(Clone, Copy)]
+    /// struct Vertex {
+    ///     position: [f32; 2]
+    /// }
+    ///
+    /// impl_vertex_data!(Vertex, position);
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mut vbo = gl.new_vertex_buffer();
+    /// let (mut gl_vbo, gl) = gl.bind_vertex_buffer(&mut vbo);
+    ///
+    /// // A 256x256 grid of points
+    /// let grid = (0..256 * 256).map(|i| {
+    ///     Vertex { position: [(i % 256) as f32, (i / 256) as f32] }
+    /// });
+    /// gl.buffer_vertices_iter(&mut gl_vbo, grid, glitter::STATIC_DRAW);
+    /// # }
+    /// ```
+    fn buffer_vertices_iter<T, I>(&self,
+                                  gl_vbo: &mut VertexBufferBinding<T>,
+                                  vertices: I,
+                                  usage: BufferDataUsage)
+        where T: VertexData,
+              [T]: VertexBytes,
+              I: IntoIterator<Item=T>,
+              I::IntoIter: ExactSizeIterator
+    {
+        let mut vertices = vertices.into_iter();
+        self.reserve_vertices(gl_vbo, vertices.len(), usage);
+
+        let mut chunk = Vec::with_capacity(_iter_chunk_len::<T>());
+        loop {
+            chunk.clear();
+            chunk.extend(vertices.by_ref().take(_iter_chunk_len::<T>()));
+            if chunk.is_empty() {
+                break;
+            }
+
+            let offset = *gl_vbo.count;
+            self.buffer_vertices_range(gl_vbo, offset, &chunk);
+        }
+    }
+
+    /// Allocate storage for `capacity` vertices in a vertex buffer, without
+    /// sending any data. Note that this will replace the buffer's current
+    /// contents, if any, and that the buffer will be treated as empty
+    /// until vertices are sent with [`gl.buffer_vertices_range`]
+    /// (trait.ContextVertexBufferExt.html#method.buffer_vertices_range).
+    fn reserve_vertices<T>(&self,
+                           gl_vbo: &mut VertexBufferBinding<T>,
+                           capacity: usize,
+                           usage: BufferDataUsage)
+        where T: VertexData
+    {
+        *gl_vbo.count = 0;
+        *gl_vbo.capacity = capacity;
+        self.allocate_buffer_bytes(&mut gl_vbo.gl_buffer,
+                                   capacity * mem::size_of::<T>(),
+                                   usage);
+    }
+
+    /// Replace a range of the vertices in a vertex buffer, starting at the
+    /// vertex `offset`, without reallocating the buffer. If the range extends
+    /// past the vertices that have been buffered so far, the vertex count
+    /// grows to include the range; otherwise, the count is unchanged.
+    ///
+    /// # Panics
+    /// This function will panic if the range extends past the storage
+    /// allocated for the buffer (by [`gl.buffer_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.buffer_vertices) or
+    /// [`gl.reserve_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.reserve_vertices)).
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// #[derive(Clone, Copy)]
+    /// struct Vertex {
+    ///     position: [f32; 2]
+    /// }
+    ///
+    /// impl_vertex_data!(Vertex, position);
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mut vbo = gl.new_vertex_buffer();
+    /// let (mut gl_vbo, gl) = gl.bind_vertex_buffer(&mut vbo);
+    ///
+    /// let quad = [Vertex { position: [0.0, 0.0] }; 4];
+    /// let vertices = vec![Vertex { position: [0.0, 0.0] }; 1024];
+    /// gl.buffer_vertices(&mut gl_vbo, &vertices, glitter::DYNAMIC_DRAW);
+    ///
+    /// // Only update the vertices of the 10th quad
+    /// gl.buffer_vertices_range(&mut gl_vbo, 40, &quad);
+    /// # }
+    /// ```
+    fn buffer_vertices_range<T>(&self,
+                                gl_vbo: &mut VertexBufferBinding<T>,
+                                offset: usize,
+                                vertices: &[T])
+        where T: VertexData, [T]: VertexBytes
+    {
+        let end = offset + vertices.len();
+        assert!(end <= *gl_vbo.capacity,
+                "Vertex range {}..{} is out of bounds of the buffer's capacity ({})",
+                offset, end, *gl_vbo.capacity);
+
+        if vertices.is_empty() {
+            return;
+        }
+
+        self.buffer_sub_data(&mut gl_vbo.gl_buffer,
+                             offset * mem::size_of::<T>(),
+                             vertices.vertex_bytes());
+        if end > *gl_vbo.count {
+            *gl_vbo.count = end;
+        }
+    }
+
+    /// Make sure a vertex buffer has room for at least `additional` more
+    /// vertices, keeping the vertices that have already been buffered. If
+    /// the buffer needs to grow, its capacity is at least doubled, so that
+    /// repeatedly appending vertices only reallocates the buffer
+    /// a logarithmic number of times.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if the buffer needs to grow while it already
+    /// has vertices, and copying between buffers is not supported by the
+    /// current context (see [`gl.grow_buffer_bytes`]
+    /// (../context/buffer_context/trait.ContextBufferExt.html#method.grow_buffer_bytes)).
+    fn reserve_additional_vertices<T>(&self,
+                                      gl_vbo: &mut VertexBufferBinding<T>,
+                                      additional: usize,
+                                      usage: BufferDataUsage)
+        -> Result<(), GLError>
+        where T: VertexData
+    {
+        let required = *gl_vbo.count + additional;
+        if required <= *gl_vbo.capacity {
+            return Ok(());
+        }
+
+        let new_capacity = cmp::max(required, *gl_vbo.capacity * 2);
+        try!(self.grow_buffer_bytes(&mut gl_vbo.gl_buffer,
+                                    *gl_vbo.count * mem::size_of::<T>(),
+                                    new_capacity * mem::size_of::<T>(),
+                                    usage));
+        *gl_vbo.capacity = new_capacity;
+        Ok(())
+    }
+
+    /// Append vertices to the end of a vertex buffer, growing the buffer
+    /// if needed (see [`gl.reserve_additional_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.reserve_additional_vertices)).
+    ///
+    /// # Failures
+    /// An error will be returned if the buffer could not grow (see
+    /// `gl.reserve_additional_vertices`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// #[derive(Clone, Copy)]
+    /// struct Vertex {
+    ///     position: [f32; 2]
+    /// }
+    ///
+    /// impl_vertex_data!(Vertex, position);
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mut vbo = gl.new_vertex_buffer();
+    /// let (mut gl_vbo, gl) = gl.bind_vertex_buffer(&mut vbo);
+    ///
+    /// for i in 0..100 {
+    ///     let x = i as f32;
+    ///     let line = [Vertex { position: [x, 0.0] },
+    ///                 Vertex { position: [x, 1.0] }];
+    ///     gl.push_vertices(&mut gl_vbo, &line, glitter::DYNAMIC_DRAW).unwrap();
+    /// }
+    /// # }
+    /// ```
+    fn push_vertices<T>(&self,
+                        gl_vbo: &mut VertexBufferBinding<T>,
+                        vertices: &[T],
+                        usage: BufferDataUsage)
+        -> Result<(), GLError>
+        where T: VertexData, [T]: VertexBytes
+    {
+        try!(self.reserve_additional_vertices(gl_vbo, vertices.len(), usage));
+        let offset = *gl_vbo.count;
+        self.buffer_vertices_range(gl_vbo, offset, vertices);
+        Ok(())
+    }
+
+    /// Map the unused storage at the end of a vertex buffer (between its
+    /// vertex count and its capacity), returning a [`VertexWriter`]
+    /// (struct.VertexWriter.html) that appends vertices directly into the
+    /// mapped storage. Use [`gl.reserve_additional_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.reserve_additional_vertices)
+    /// or [`gl.reserve_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.reserve_vertices) first to
+    /// make room for the vertices. Mapping a buffer requires OpenGL 3.0 or
+    /// OpenGL ES 3.0.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if buffer mapping is not supported by the
+    /// current context. Otherwise, an error will be returned if the buffer
+    /// could not be mapped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// #[derive(Clone, Copy)]
+    /// struct Vertex {
+    ///     position: [f32; 2]
+    /// }
+    ///
+    /// impl_vertex_data!(Vertex, position);
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mut vbo = gl.new_vertex_buffer();
+    /// let (mut gl_vbo, gl) = gl.bind_vertex_buffer(&mut vbo);
+    ///
+    /// gl.reserve_vertices(&mut gl_vbo, 1024, glitter::DYNAMIC_DRAW);
+    /// {
+    ///     let mut writer = gl.vertex_writer(&mut gl_vbo).unwrap();
+    ///     writer.push(Vertex { position: [0.0, 0.0] });
+    ///     writer.push(Vertex { position: [1.0, 1.0] });
+    /// }
+    /// gl.draw_arrays_vbo(&gl_vbo, glitter::LINES);
+    /// # }
+    /// ```
+    fn vertex_writer<'a, 'b, T>(&self,
+                                gl_vbo: &'a mut VertexBufferBinding<'b, T>)
+        -> Result<VertexWriter<'a, 'b, T>, GLError>
+        where T: VertexData
+    {
+        if !self.get_extensions().has_map_buffer_range() {
+            return Err(GLError::Unsupported("buffer mapping"));
+        }
+
+        let start = *gl_vbo.count;
+        let capacity = *gl_vbo.capacity - start;
+
+        // Mapping an empty range is an error, so leave the writer unmapped
+        let ptr = if capacity == 0 {
+            ptr::null_mut()
+        }
+        else {
+            let size = mem::size_of::<T>();
+            let access = MAP_WRITE_BIT | MAP_INVALIDATE_RANGE_BIT;
+            let ptr = unsafe {
+                self.map_buffer_range(&mut gl_vbo.gl_buffer,
+                                      start * size,
+                                      capacity * size,
+                                      access)
+            };
+            if ptr.is_null() {
+                return Err(GLError::BufferMapFailed);
+            }
+            ptr
+        };
+
+        Ok(VertexWriter {
+            gl_vbo: gl_vbo,
+            ptr: ptr,
+            len: 0,
+            capacity: capacity
+        })
+    }
+
+    /// Create a new streaming vertex buffer, which can hold up to `capacity`
+    /// vertices at once. Storage for the buffer is allocated the first time
+    /// vertices are appended.
+    fn new_streaming_vertex_buffer<V: VertexData>(&self, capacity: usize)
+        -> StreamingVertexBuffer<V>
+    {
+        StreamingVertexBuffer {
+            vbo: self.new_vertex_buffer(),
+            capacity: capacity,
+            cursor: 0
+        }
+    }
+
+    /// Append vertices to a streaming vertex buffer, returning the range of
+    /// vertices that were written. If there is not enough room left in the
+    /// buffer, the buffer is orphaned first and the vertices are written at
+    /// the start of the new storage. See the [`StreamingVertexBuffer`]
+    /// (struct.StreamingVertexBuffer.html) docs for more details.
+    ///
+    /// # Panics
+    /// This function will panic if more vertices are appended at once than
+    /// the buffer's capacity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[macro_use] extern crate glitter;
+    /// use glitter::prelude::*;
+    ///
+    /// # fn main() {
+    /// #[derive(Clone, Copy)]
+    /// struct Vertex {
+    ///     position: [f32; 2]
+    /// }
+    ///
+    /// impl_vertex_data!(Vertex, position);
+    ///
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mut stream = gl.new_streaming_vertex_buffer(4096);
+    /// # let program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+    /// stream.bind_attrib_pointers(attrib_pointers! {
+    ///     position => gl.get_attrib_location(&program, "position").unwrap()
+    /// });
+    ///
+    /// let (mut gl_stream, gl) = gl.bind_streaming_vertex_buffer(&mut stream);
+    /// loop {
+    ///     let lines = [Vertex { position: [0.0, 0.0] },
+    ///                  Vertex { position: [1.0, 1.0] }];
+    ///     let range = gl.stream_vertices(&mut gl_stream, &lines);
+    ///     gl.draw_arrays_range_vbo(gl_stream.vertex_buffer(),
+    ///                              glitter::LINES,
+    ///                              range.start as u32,
+    ///                              range.end - range.start);
+    /// }
+    /// # }
+    /// ```
+    fn stream_vertices<T>(&self,
+                          gl_svbo: &mut StreamingVertexBufferBinding<T>,
+                          vertices: &[T])
+        -> Range<usize>
+        where T: VertexData, [T]: VertexBytes
+    {
+        assert!(vertices.len() <= gl_svbo.capacity,
+                "Cannot stream {} vertices into a buffer with a capacity of {}",
+                vertices.len(), gl_svbo.capacity);
+
+        let start = *gl_svbo.cursor;
+        let needs_orphan = start + vertices.len() > gl_svbo.capacity
+                        || *gl_svbo.gl_vbo.capacity != gl_svbo.capacity;
+        let start = if needs_orphan {
+            self.reserve_vertices(&mut gl_svbo.gl_vbo,
+                                  gl_svbo.capacity,
+                                  BufferDataUsage::StreamDraw);
+            0
+        }
+        else {
+            start
+        };
+
+        let end = start + vertices.len();
+        self.buffer_vertices_range(&mut gl_svbo.gl_vbo, start, vertices);
+        *gl_svbo.cursor = end;
+
+        start..end
+    }
+
+    /// Create a new, empty mesh.
+    fn new_mesh<V, I>(&self) -> Mesh<V, I>
+        where V: VertexData, I: IndexDatum
+    {
+        Mesh {
+            vbo: self.new_vertex_buffer(),
+            ibo: IndexBuffer {
+                buffer: self.gen_buffer(),
+                count: 0,
+                capacity: 0,
+                phantom: PhantomData
+            }
+        }
+    }
+
+    /// Send vertex and index data to a mesh. Note that this will replace
+    /// the mesh's current contents, if any.
+    fn buffer_mesh<V, I>(&self,
+                         gl_mesh: &mut MeshBinding<V, I>,
+                         vertices: &[V],
+                         indices: &[I],
+                         usage: BufferDataUsage)
+        where V: VertexData, [V]: VertexBytes,
+              I: IndexDatum, [I]: IndexData
+    {
+        self.buffer_vertices(&mut gl_mesh.gl_vbo, vertices, usage);
+        self.buffer_indices(&mut gl_mesh.gl_ibo, indices, usage);
+    }
+
+    /// Create a new, empty multi-buffer vertex buffer, with one buffer for
+    /// each slot of the layout.
+    fn new_multi_vertex_buffer<L: VertexLayout>(&self) -> MultiVertexBuffer<L> {
+        let slots = L::slot_count();
+        MultiVertexBuffer {
             attrib_binder: None,
-            buffer: self.gen_buffer(),
-            count: 0,
+            buffers: (0..slots).map(|_| self.gen_buffer()).collect(),
+            counts: vec![0; slots],
             phantom: PhantomData
         }
     }
 
-    /// Send data to a vertex buffer. Note that this will replace the buffer's
-    /// current contents, if any.
-    fn buffer_vertices<T>(&self,
-                          gl_vbo: &mut VertexBufferBinding<T>,
-                          vertices: &[T],
-                          usage: super::BufferDataUsage)
-        where T: VertexData, [T]: VertexBytes
+    /// Send vertex data to a single slot of a multi-buffer vertex buffer.
+    /// Note that this will replace the slot's current contents, if any.
+    /// `T` should be the slot's type in the layout (or a type with the
+    /// same attributes).
+    ///
+    /// # Panics
+    /// This function will panic if `slot` is out of range, if the size
+    /// of `T` doesn't match the stride of the layout's slot, or if the
+    /// attributes of `T` (including their names, types, and offsets) don't
+    /// match the attributes of the layout's slot.
+    fn buffer_slot_vertices<L, T>(&self,
+                                  gl_mvbo: &mut MultiVertexBufferBinding<L>,
+                                  slot: usize,
+                                  vertices: &[T],
+                                  usage: BufferDataUsage)
+        where L: VertexLayout, T: VertexData, [T]: VertexBytes
     {
+        assert!(slot < gl_mvbo.ids.len(),
+                "Vertex layout slot out of range: {}", slot);
+        assert_eq!(mem::size_of::<T>(), L::slot_stride(slot));
+        assert!(_slot_attributes_match::<L, T>(slot),
+                "Vertex data attributes don't match layout slot {}", slot);
 
-        *gl_vbo.count = vertices.len();
-        self.buffer_bytes(&mut gl_vbo.gl_buffer,
+        unsafe {
+            _bind_array_buffer_id(gl_mvbo.ids[slot]);
+        }
+        self.buffer_bytes(&mut gl_mvbo.gl_buffer,
                           vertices.vertex_bytes(),
                           usage);
+        unsafe {
+            _bind_array_buffer_id(gl_mvbo.ids[0]);
+        }
+        gl_mvbo.counts[slot] = vertices.len();
+    }
+
+    /// Use the data from the provided multi-buffer vertex buffer binding
+    /// to render primitives. The number of vertices drawn is the smallest
+    /// number of vertices buffered for any slot.
+    ///
+    /// - `gl_mvbo`: The binding of the vertex buffers to read vertices from.
+    /// - `mode`: The type of primitives to draw.
+    fn draw_arrays_multi_vbo<L>(&self,
+                                gl_mvbo: &MultiVertexBufferBinding<L>,
+                                mode: DrawingMode)
+        where L: VertexLayout
+    {
+        unsafe {
+            self.draw_arrays_range(&gl_mvbo.gl_buffer, mode, 0, gl_mvbo.len());
+        }
+    }
+
+    /// Draw primitives using the provided index buffer as the indices
+    /// into the provided multi-buffer vertex buffer. All buffered indices
+    /// will be used.
+    ///
+    /// - `gl_mvbo`: The binding of the buffers that contain the vertex data.
+    /// - `gl_ibo`: The binding of the buffer that contains the index data.
+    /// - `mode`: The type of primitives to draw.
+    fn draw_elements_buffered_multi_vbo<L, I>(&self,
+                                              gl_mvbo: &MultiVertexBufferBinding<L>,
+                                              gl_ibo: &IndexBufferBinding<I>,
+                                              mode: DrawingMode)
+        where L: VertexLayout, I: IndexDatum
+    {
+        unsafe {
+            self.draw_n_elements_buffered(&gl_mvbo.gl_buffer,
+                                          &gl_ibo.gl_buffer,
+                                          mode,
+                                          *gl_ibo.count,
+                                          I::index_datum_type());
+        }
     }
 
     /// Send data to an index buffer. Note that this will replace the buffer's
@@ -254,13 +1382,185 @@ pub trait ContextVertexBufferExt: AContext {
     fn buffer_indices<T>(&self,
                          gl_ibo: &mut IndexBufferBinding<T>,
                          indices: &[T],
-                         usage: super::BufferDataUsage)
+                         usage: BufferDataUsage)
         where T: IndexDatum, [T]: IndexData
     {
         *gl_ibo.count = indices.len();
+        *gl_ibo.capacity = indices.len();
         self.buffer_bytes(&mut gl_ibo.gl_buffer, indices.index_bytes(), usage);
     }
 
+    /// Create a new, empty index buffer that automatically picks its
+    /// index type.
+    fn new_auto_index_buffer(&self) -> AutoIndexBuffer {
+        AutoIndexBuffer {
+            buffer: self.gen_buffer(),
+            count: 0,
+            index_type: IndexDatumType::UnsignedByte
+        }
+    }
+
+    /// Send data to an automatically-typed index buffer, replacing the
+    /// buffer's current contents, if any. The indices are stored using the
+    /// smallest index type that can represent the largest index.
+    ///
+    /// # Failures
+    /// A [`GLError::Unsupported`](../types/enum.GLError.html#variant.Unsupported)
+    /// error will be returned if an index is larger than 65,535 but
+    /// 32-bit indices are not supported by the current context (see
+    /// [`gl.supports_index_datum_type`]
+    /// (../context/buffer_context/trait.ContextBufferExt.html#method.supports_index_datum_type)).
+    /// In this case, the buffer is left unchanged.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # extern crate glitter;
+    /// use glitter::prelude::*;
+    /// use glitter::IndexDatumType;
+    ///
+    /// # fn main() {
+    /// let gl = unsafe { glitter::Context::current_context() };
+    /// let mut aibo = gl.new_auto_index_buffer();
+    /// let (mut gl_aibo, gl) = gl.bind_auto_index_buffer(&mut aibo);
+    ///
+    /// gl.buffer_auto_indices(&mut gl_aibo,
+    ///                        &[0, 1, 2, 0, 2, 3],
+    ///                        glitter::STATIC_DRAW).unwrap();
+    /// assert_eq!(gl_aibo.index_type(), IndexDatumType::UnsignedByte);
+    /// # }
+    /// ```
+    fn buffer_auto_indices(&self,
+                           gl_aibo: &mut AutoIndexBufferBinding,
+                           indices: &[u32],
+                           usage: BufferDataUsage)
+        -> Result<(), GLError>
+    {
+        let max_index = indices.iter().cloned().max().unwrap_or(0);
+        if max_index > u16::MAX as u32 {
+            if !self.supports_index_datum_type(IndexDatumType::UnsignedInt) {
+                return Err(GLError::Unsupported("32-bit indices"));
+            }
+
+            self.buffer_bytes(&mut gl_aibo.gl_buffer,
+                              indices.index_bytes(),
+                              usage);
+            *gl_aibo.index_type = IndexDatumType::UnsignedInt;
+        }
+        else if max_index > u8::MAX as u32 {
+            let indices: Vec<u16> = indices.iter().map(|&i| i as u16).collect();
+            self.buffer_bytes(&mut gl_aibo.gl_buffer,
+                              indices.index_bytes(),
+                              usage);
+            *gl_aibo.index_type = IndexDatumType::UnsignedShort;
+        }
+        else {
+            let indices: Vec<u8> = indices.iter().map(|&i| i as u8).collect();
+            self.buffer_bytes(&mut gl_aibo.gl_buffer,
+                              indices.index_bytes(),
+                              usage);
+            *gl_aibo.index_type = IndexDatumType::UnsignedByte;
+        }
+
+        *gl_aibo.count = indices.len();
+        Ok(())
+    }
+
+    /// Send indices from an iterator to an index buffer, without first
+    /// collecting them into a `Vec`. See [`gl.buffer_vertices_iter`]
+    /// (trait.ContextVertexBufferExt.html#method.buffer_vertices_iter) for
+    /// more details.
+    ///
+    /// # Panics
+    /// This function will panic if the iterator yields more items than
+    /// its reported length.
+    fn buffer_indices_iter<T, I>(&self,
+                                 gl_ibo: &mut IndexBufferBinding<T>,
+                                 indices: I,
+                                 usage: BufferDataUsage)
+        where T: IndexDatum,
+              [T]: IndexData,
+              I: IntoIterator<Item=T>,
+              I::IntoIter: ExactSizeIterator
+    {
+        let mut indices = indices.into_iter();
+        let capacity = indices.len();
+        self.allocate_buffer_bytes(&mut gl_ibo.gl_buffer,
+                                   capacity * mem::size_of::<T>(),
+                                   usage);
+        *gl_ibo.count = 0;
+        *gl_ibo.capacity = capacity;
+
+        let mut chunk = Vec::with_capacity(_iter_chunk_len::<T>());
+        loop {
+            chunk.clear();
+            chunk.extend(indices.by_ref().take(_iter_chunk_len::<T>()));
+            if chunk.is_empty() {
+                break;
+            }
+
+            let end = *gl_ibo.count + chunk.len();
+            assert!(end <= capacity,
+                    "Index iterator yielded more than its length ({})",
+                    capacity);
+            self.buffer_sub_data(&mut gl_ibo.gl_buffer,
+                                 *gl_ibo.count * mem::size_of::<T>(),
+                                 chunk.index_bytes());
+            *gl_ibo.count = end;
+        }
+    }
+
+    /// Make sure an index buffer has room for at least `additional` more
+    /// indices, keeping the indices that have already been buffered. See
+    /// [`gl.reserve_additional_vertices`]
+    /// (trait.ContextVertexBufferExt.html#method.reserve_additional_vertices)
+    /// for more details.
+    fn reserve_additional_indices<T>(&self,
+                                     gl_ibo: &mut IndexBufferBinding<T>,
+                                     additional: usize,
+                                     usage: BufferDataUsage)
+        -> Result<(), GLError>
+        where T: IndexDatum
+    {
+        let required = *gl_ibo.count + additional;
+        if required <= *gl_ibo.capacity {
+            return Ok(());
+        }
+
+        let new_capacity = cmp::max(required, *gl_ibo.capacity * 2);
+        try!(self.grow_buffer_bytes(&mut gl_ibo.gl_buffer,
+                                    *gl_ibo.count * mem::size_of::<T>(),
+                                    new_capacity * mem::size_of::<T>(),
+                                    usage));
+        *gl_ibo.capacity = new_capacity;
+        Ok(())
+    }
+
+    /// Append indices to the end of an index buffer, growing the buffer
+    /// if needed (see [`gl.reserve_additional_indices`]
+    /// (trait.ContextVertexBufferExt.html#method.reserve_additional_indices)).
+    ///
+    /// # Failures
+    /// An error will be returned if the buffer could not grow (see
+    /// `gl.reserve_additional_indices`).
+    fn push_indices<T>(&self,
+                       gl_ibo: &mut IndexBufferBinding<T>,
+                       indices: &[T],
+                       usage: BufferDataUsage)
+        -> Result<(), GLError>
+        where T: IndexDatum, [T]: IndexData
+    {
+        if indices.is_empty() {
+            return Ok(());
+        }
+
+        try!(self.reserve_additional_indices(gl_ibo, indices.len(), usage));
+        self.buffer_sub_data(&mut gl_ibo.gl_buffer,
+                             *gl_ibo.count * mem::size_of::<T>(),
+                             indices.index_bytes());
+        *gl_ibo.count += indices.len();
+        Ok(())
+    }
+
     /// Use the data from the provided vertex buffer binding to render
     /// primitives.
     ///
@@ -352,6 +1652,29 @@ pub trait ContextVertexBufferExt: AContext {
         }
     }
 
+    /// Draw primitives using the provided automatically-typed index buffer
+    /// as the indices into the provided vertex buffer. All buffered indices
+    /// will be used, with the index type that was chosen when they were
+    /// buffered.
+    ///
+    /// - `gl_vbo`: The binding of the buffer that contains the vertex data.
+    /// - `gl_aibo`: The binding of the buffer that contains the index data.
+    /// - `mode`: The type of primitives to draw.
+    fn draw_elements_buffered_auto_vbo<V>(&self,
+                                          gl_vbo: &VertexBufferBinding<V>,
+                                          gl_aibo: &AutoIndexBufferBinding,
+                                          mode: DrawingMode)
+        where V: VertexData
+    {
+        unsafe {
+            self.draw_n_elements_buffered(&gl_vbo.gl_buffer,
+                                          &gl_aibo.gl_buffer,
+                                          mode,
+                                          *gl_aibo.count,
+                                          *gl_aibo.index_type);
+        }
+    }
+
     /// Draw primitives specified by the provided index array,
     /// treated as indices into the provided vertex buffer.
     ///
@@ -389,6 +1712,13 @@ pub trait ContextVertexBufferExt: AContext {
     }
 }
 
+// The number of items to send at once when buffering from an iterator,
+// keeping each chunk of scratch memory to around 16 KiB
+fn _iter_chunk_len<T>() -> usize {
+    const CHUNK_BYTES: usize = 16 * 1024;
+    cmp::max(1, CHUNK_BYTES / cmp::max(1, mem::size_of::<T>()))
+}
+
 impl<C: AContext> ContextVertexBufferExt for C {
 
 }
@@ -404,33 +1734,192 @@ impl<C: AContext> ContextVertexBufferExt for C {
 pub trait VertexBufferContext: ArrayBufferContext + Sized {
     /// Bind a vertex buffer to this context, returning a binding
     /// and a new context.
+    ///
+    /// # Panics
+    /// This function will panic if the vertex buffer could not be bound.
+    /// See [`try_bind_vertex_buffer`]
+    /// (trait.VertexBufferContext.html#method.try_bind_vertex_buffer) for
+    /// more details.
     fn bind_vertex_buffer<'a, V>(self, vbo: &'a mut VertexBuffer<V>)
         -> (VertexBufferBinding<V>, Self::Rest)
         where V: VertexData
     {
-        // TODO: Cleanup error handling
+        match self.try_bind_vertex_buffer(vbo) {
+            Ok(binding) => binding,
+            Err((_, e)) => { panic!("Error binding vertex buffer: {}", e); }
+        }
+    }
+
+    /// Bind a vertex buffer to this context, returning a binding and a new
+    /// context, or return an error along with the unchanged context.
+    ///
+    /// # Failures
+    /// An error will be returned if no attribute bindings were provided for
+    /// the vertex buffer (see [`VertexBuffer::bind_attrib_pointers`]
+    /// (struct.VertexBuffer.html#method.bind_attrib_pointers)), or if the
+    /// attribute bindings do not exactly match the attributes of the vertex
+    /// data type. An error will also be returned if an attribute uses
+    /// a feature that is not supported by the current context (see
+    /// [`Extensions`](../extensions/struct.Extensions.html)). All of these
+    /// are checked before anything is bound, so the context is returned
+    /// with the error and can still be used.
+    fn try_bind_vertex_buffer<'a, V>(self, vbo: &'a mut VertexBuffer<V>)
+        -> Result<(VertexBufferBinding<V>, Self::Rest),
+                  (Self, VertexBindError)>
+        where V: VertexData
+    {
         let (gl_array_buffer, rest) = match vbo.attrib_binder {
             Some(ref binder) => {
+                // Check the attributes before binding anything, so an error
+                // doesn't leave any attribute arrays half-enabled
+                let checked = binder.for_each::<V, _>(|_, _| { })
+                    .map_err(VertexBindError::from)
+                    .and_then(|_| {
+                        binder.check_support::<(V,)>(&self.get_extensions())
+                    });
+                if let Err(e) = checked {
+                    return Err((self, e));
+                }
+
                 let buf = &mut vbo.buffer;
                 let (gl_buffer, mut rest) = self.bind_array_buffer(buf);
-                binder.enable::<V, _>(&mut rest).unwrap();
-                binder.bind::<V, _>(&rest).unwrap();
+                binder.enable::<V, _>(&mut rest)
+                    .expect("Vertex attributes were already checked");
+                binder.bind::<V, _>(&rest)
+                    .expect("Vertex attributes were already checked");
                 (gl_buffer, rest)
             },
             None => {
-                panic!("No attribute bindings provided for vertex buffer");
+                return Err((self, VertexBindError::NoAttributeBindings));
             }
         };
 
-        (
+        Ok((
             VertexBufferBinding {
                 gl_buffer: gl_array_buffer,
                 count: &mut vbo.count,
+                capacity: &mut vbo.capacity,
+                _phantom: PhantomData
+            },
+            rest
+        ))
+    }
+
+    /// Bind a multi-buffer vertex buffer to this context, returning a binding
+    /// and a new context.
+    ///
+    /// # Panics
+    /// This function will panic if the vertex buffers could not be bound.
+    /// See [`try_bind_multi_vertex_buffer`]
+    /// (trait.VertexBufferContext.html#method.try_bind_multi_vertex_buffer)
+    /// for more details.
+    fn bind_multi_vertex_buffer<'a, L>(self, mvbo: &'a mut MultiVertexBuffer<L>)
+        -> (MultiVertexBufferBinding<L>, Self::Rest)
+        where L: VertexLayout
+    {
+        match self.try_bind_multi_vertex_buffer(mvbo) {
+            Ok(binding) => binding,
+            Err((_, e)) => { panic!("Error binding vertex buffers: {}", e); }
+        }
+    }
+
+    /// Bind a multi-buffer vertex buffer to this context, returning a binding
+    /// and a new context, or return an error along with the unchanged
+    /// context. The attributes for each slot are read from that slot's
+    /// buffer, using that slot's stride.
+    ///
+    /// # Failures
+    /// An error will be returned if no attribute bindings were provided for
+    /// the vertex buffer (see [`MultiVertexBuffer::bind_attrib_pointers`]
+    /// (struct.MultiVertexBuffer.html#method.bind_attrib_pointers)), or if
+    /// the attribute bindings do not exactly match the attributes of all of
+    /// the layout's slots, or if an attribute uses a feature that is not
+    /// supported by the current context. All of these are checked before
+    /// anything is bound, so the context is returned with the error and
+    /// can still be used.
+    fn try_bind_multi_vertex_buffer<'a, L>(self,
+                                           mvbo: &'a mut MultiVertexBuffer<L>)
+        -> Result<(MultiVertexBufferBinding<L>, Self::Rest),
+                  (Self, VertexBindError)>
+        where L: VertexLayout
+    {
+        let ids: Vec<_> = mvbo.buffers.iter().map(|buffer| buffer.id()).collect();
+        let (gl_array_buffer, rest) = match mvbo.attrib_binder {
+            Some(ref binder) => {
+                let checked = binder.for_each_slot::<L, _>(|_, _, _| { })
+                    .map_err(VertexBindError::from)
+                    .and_then(|_| {
+                        binder.check_support::<L>(&self.get_extensions())
+                    });
+                if let Err(e) = checked {
+                    return Err((self, e));
+                }
+
+                let buf = &mut mvbo.buffers[0];
+                let (gl_buffer, mut rest) = self.bind_array_buffer(buf);
+                binder.enable_layout::<L, _>(&mut rest)
+                    .expect("Vertex attributes were already checked");
+                unsafe {
+                    binder.bind_layout::<L, _>(&rest, &ids)
+                        .expect("Vertex attributes were already checked");
+                }
+                (gl_buffer, rest)
+            },
+            None => {
+                return Err((self, VertexBindError::NoAttributeBindings));
+            }
+        };
+
+        Ok((
+            MultiVertexBufferBinding {
+                gl_buffer: gl_array_buffer,
+                ids: ids,
+                counts: &mut mvbo.counts,
                 _phantom: PhantomData
             },
             rest
+        ))
+    }
+
+    /// Bind a streaming vertex buffer to this context, returning a binding
+    /// and a new context.
+    fn bind_streaming_vertex_buffer<'a, V>(self,
+                                           svbo: &'a mut StreamingVertexBuffer<V>)
+        -> (StreamingVertexBufferBinding<V>, Self::Rest)
+        where V: VertexData
+    {
+        let capacity = svbo.capacity;
+        let (gl_vbo, rest) = self.bind_vertex_buffer(&mut svbo.vbo);
+        (
+            StreamingVertexBufferBinding {
+                gl_vbo: gl_vbo,
+                capacity: capacity,
+                cursor: &mut svbo.cursor
+            },
+            rest
         )
     }
+
+    /// Bind a mesh's vertex buffer and index buffer to this context,
+    /// returning a binding and the remaining context. The context must
+    /// have both a free `GL_ARRAY_BUFFER` and a free
+    /// `GL_ELEMENT_ARRAY_BUFFER` binding.
+    ///
+    /// # Panics
+    /// This function will panic if no attribute bindings were provided
+    /// for the mesh (see [`Mesh::bind_attrib_pointers`]
+    /// (struct.Mesh.html#method.bind_attrib_pointers)).
+    fn bind_mesh<'a, V, I>(self, mesh: &'a mut Mesh<V, I>)
+        -> (MeshBinding<'a, V, I>,
+            <<Self as ArrayBufferContext>::Rest as ElementArrayBufferContext>::Rest)
+        where V: VertexData,
+              I: IndexDatum,
+              <Self as ArrayBufferContext>::Rest: ElementArrayBufferContext
+    {
+        let (gl_vbo, rest) = self.bind_vertex_buffer(&mut mesh.vbo);
+        let (gl_ibo, rest) = rest.bind_index_buffer(&mut mesh.ibo);
+        (MeshBinding { gl_vbo: gl_vbo, gl_ibo: gl_ibo }, rest)
+    }
 }
 
 impl<C: ArrayBufferContext> VertexBufferContext for C {
@@ -457,11 +1946,28 @@ pub trait IndexBufferContext: ElementArrayBufferContext + Sized {
             IndexBufferBinding {
                 gl_buffer: gl_be,
                 count: &mut ibo.count,
+                capacity: &mut ibo.capacity,
                 _phantom: PhantomData
             },
             rest
         )
     }
+
+    /// Bind an automatically-typed index buffer to this context, returning
+    /// a binding and the remaining context.
+    fn bind_auto_index_buffer<'a>(self, aibo: &'a mut AutoIndexBuffer)
+        -> (AutoIndexBufferBinding, Self::Rest)
+    {
+        let (gl_be, rest) = self.bind_element_array_buffer(&mut aibo.buffer);
+        (
+            AutoIndexBufferBinding {
+                gl_buffer: gl_be,
+                count: &mut aibo.count,
+                index_type: &mut aibo.index_type
+            },
+            rest
+        )
+    }
 }
 
 impl<C: ElementArrayBufferContext> IndexBufferContext for C {
@@ -476,10 +1982,26 @@ impl<C: ElementArrayBufferContext> IndexBufferContext for C {
 pub struct IndexBuffer<T: IndexDatum> {
     buffer: Buffer,
     count: usize,
+    capacity: usize,
     phantom: PhantomData<*const T>
 }
 
 impl<T: IndexDatum> IndexBuffer<T> {
+    /// Get the number of indices that have been buffered.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no indices have been buffered.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get the number of indices the buffer has storage for.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
     /// Get a reference to the underlying OpenGL buffer.
     pub fn buffer(&self) -> &Buffer {
         &self.buffer
@@ -496,6 +2018,7 @@ impl<T: IndexDatum> IndexBuffer<T> {
 pub struct IndexBufferBinding<'a, T: IndexDatum + 'a> {
     gl_buffer: ElementArrayBufferBinding<'a>,
     count: &'a mut usize,
+    capacity: &'a mut usize,
     _phantom: PhantomData<*const IndexBuffer<T>>
 }
 
@@ -505,6 +2028,7 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
         IndexBuffer {
             buffer: self.gen_buffer(),
             count: 0,
+            capacity: 0,
             phantom: PhantomData
         }
     }
@@ -512,6 +2036,182 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
 
 
 
+/// An index buffer that picks the smallest index type that can hold its
+/// indices. Indices are always sent as `u32`s (using
+/// [`gl.buffer_auto_indices`]
+/// (trait.ContextVertexBufferExt.html#method.buffer_auto_indices)), but are
+/// stored as `u8`s, `u16`s, or `u32`s depending on the largest index, which
+/// saves memory for small meshes without needing a separate code path for
+/// each index type.
+pub struct AutoIndexBuffer {
+    buffer: Buffer,
+    count: usize,
+    index_type: IndexDatumType
+}
+
+impl AutoIndexBuffer {
+    /// Get the number of indices that have been buffered.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no indices have been buffered.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get the index type that was chosen when indices were last buffered.
+    pub fn index_type(&self) -> IndexDatumType {
+        self.index_type
+    }
+
+    /// Get a reference to the underlying OpenGL buffer.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+}
+
+/// Represents an [`AutoIndexBuffer`](struct.AutoIndexBuffer.html) that has
+/// been bound to a context.
+pub struct AutoIndexBufferBinding<'a> {
+    gl_buffer: ElementArrayBufferBinding<'a>,
+    count: &'a mut usize,
+    index_type: &'a mut IndexDatumType
+}
+
+impl<'a> AutoIndexBufferBinding<'a> {
+    /// Get the number of indices that have been buffered.
+    pub fn len(&self) -> usize {
+        *self.count
+    }
+
+    /// Returns `true` if no indices have been buffered.
+    pub fn is_empty(&self) -> bool {
+        *self.count == 0
+    }
+
+    /// Get the index type that was chosen when indices were last buffered.
+    pub fn index_type(&self) -> IndexDatumType {
+        *self.index_type
+    }
+}
+
+
+
+/// A vertex buffer and an index buffer that are used together to draw
+/// indexed geometry. A `Mesh` can be created with [`gl.new_mesh`]
+/// (trait.ContextVertexBufferExt.html#method.new_mesh), and bound with
+/// [`gl.bind_mesh`](trait.VertexBufferContext.html#method.bind_mesh).
+///
+/// # Example
+/// ```no_run
+/// #[macro_use] extern crate glitter;
+/// use glitter::prelude::*;
+///
+/// # fn main() {
+/// #[derive(Clone, Copy)]
+/// struct Vertex {
+///     position: [f32; 2]
+/// }
+///
+/// impl_vertex_data!(Vertex, position);
+///
+/// let gl = unsafe { glitter::Context::current_context() };
+/// # let program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+/// let mut quad = gl.new_mesh();
+/// quad.bind_attrib_pointers(attrib_pointers! {
+///     position => gl.get_attrib_location(&program, "position").unwrap()
+/// });
+///
+/// let vertices = [Vertex { position: [0.0, 0.0] },
+///                 Vertex { position: [1.0, 0.0] },
+///                 Vertex { position: [1.0, 1.0] },
+///                 Vertex { position: [0.0, 1.0] }];
+/// let indices: [u16; 6] = [0, 1, 2, 0, 2, 3];
+///
+/// let (mut gl_quad, gl) = gl.bind_mesh(&mut quad);
+/// gl.buffer_mesh(&mut gl_quad, &vertices, &indices, glitter::STATIC_DRAW);
+/// gl_quad.draw(glitter::TRIANGLES);
+/// # }
+/// ```
+pub struct Mesh<V: VertexData, I: IndexDatum> {
+    vbo: VertexBuffer<V>,
+    ibo: IndexBuffer<I>
+}
+
+impl<V: VertexData, I: IndexDatum> Mesh<V, I> {
+    /// Set the `AttribBinder` that will contain all of the vertex attributes
+    /// used when rendering.
+    pub fn bind_attrib_pointers(&mut self, binder: AttribBinder) {
+        self.vbo.bind_attrib_pointers(binder);
+    }
+
+    /// Get a reference to the mesh's vertex buffer.
+    pub fn vertex_buffer(&self) -> &VertexBuffer<V> {
+        &self.vbo
+    }
+
+    /// Get a mutable reference to the mesh's vertex buffer.
+    pub fn vertex_buffer_mut(&mut self) -> &mut VertexBuffer<V> {
+        &mut self.vbo
+    }
+
+    /// Get a reference to the mesh's index buffer.
+    pub fn index_buffer(&self) -> &IndexBuffer<I> {
+        &self.ibo
+    }
+
+    /// Get a mutable reference to the mesh's index buffer.
+    pub fn index_buffer_mut(&mut self) -> &mut IndexBuffer<I> {
+        &mut self.ibo
+    }
+}
+
+/// Represents a [`Mesh`](struct.Mesh.html) that has been bound to
+/// a context.
+pub struct MeshBinding<'a, V: VertexData + 'a, I: IndexDatum + 'a> {
+    gl_vbo: VertexBufferBinding<'a, V>,
+    gl_ibo: IndexBufferBinding<'a, I>
+}
+
+impl<'a, V: VertexData + 'a, I: IndexDatum + 'a> MeshBinding<'a, V, I> {
+    /// Get the binding for the mesh's vertex buffer.
+    pub fn vertex_buffer(&self) -> &VertexBufferBinding<'a, V> {
+        &self.gl_vbo
+    }
+
+    /// Get a mutable reference to the binding for the mesh's vertex buffer.
+    pub fn vertex_buffer_mut(&mut self) -> &mut VertexBufferBinding<'a, V> {
+        &mut self.gl_vbo
+    }
+
+    /// Get the binding for the mesh's index buffer.
+    pub fn index_buffer(&self) -> &IndexBufferBinding<'a, I> {
+        &self.gl_ibo
+    }
+
+    /// Get a mutable reference to the binding for the mesh's index buffer.
+    pub fn index_buffer_mut(&mut self) -> &mut IndexBufferBinding<'a, I> {
+        &mut self.gl_ibo
+    }
+
+    /// Draw the mesh, using all of the indices that have been buffered.
+    ///
+    /// # See also
+    /// [`glDrawElements`](http://docs.gl/es2/glDrawElements) OpenGL docs
+    pub fn draw(&self, mode: DrawingMode) {
+        unsafe {
+            buffer_context::_draw_elements_buffered(&self.gl_vbo.gl_buffer,
+                                                    &self.gl_ibo.gl_buffer,
+                                                    mode,
+                                                    *self.gl_ibo.count,
+                                                    I::index_datum_type());
+        }
+    }
+}
+
+
+
 /// Create an [`AttribBinder`](vertex_buffer/struct.AttribBinder.html) from
 /// a set of associations from vertex attribute names to [`ProgramAttribs`]
 /// (program/struct.ProgramAttrib.html).
@@ -532,12 +2232,37 @@ impl<B, F, P, R, T> ContextOf<B, F, P, R, T> {
 /// };
 /// # }
 /// ```
+///
+/// A `divisor` can be given for attributes that hold per-instance data
+/// (see [`AttribBinder::add_instanced`]
+/// (vertex_buffer/struct.AttribBinder.html#method.add_instanced)):
+///
+/// ```no_run
+/// # #[macro_use] extern crate glitter;
+/// # use glitter::prelude::*;
+/// # fn main() {
+/// # let gl = unsafe { glitter::Context::current_context() };
+/// # let program: glitter::Program = unsafe { ::std::mem::uninitialized() };
+/// // "offset" advances once per instance, rather than once per vertex
+/// let instance_attribs = attrib_pointers! {
+///    offset => gl.get_attrib_location(&program, "offset").unwrap();
+///        divisor: 1
+/// };
+/// # }
+/// ```
 #[macro_export]
 macro_rules! attrib_pointers {
-    ($($field_name:ident => $field_attrib:expr),*) => {
+    (@divisor) => { 0 };
+    (@divisor $divisor:expr) => { $divisor };
+    ($($field_name:ident => $field_attrib:expr $(; divisor: $divisor:expr)*),*) => {
         {
             let mut binder = $crate::AttribBinder::new();
-            $(binder.add(stringify!($field_name), $field_attrib).unwrap());*;
+            $(
+                binder.add_instanced(stringify!($field_name),
+                                     $field_attrib,
+                                     attrib_pointers!(@divisor $($divisor)*))
+                      .unwrap();
+            )*
             binder
         }
     }
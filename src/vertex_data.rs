@@ -39,7 +39,8 @@ use types::DataType;
 ///         // let vec3 = VertexAttributeType {
 ///         //     data: glitter::FLOAT,
 ///         //     components: 3,
-///         //     normalize: false
+///         //     normalize: false,
+///         //     integer: false
 ///         // };
 ///
 ///         let stride = mem::size_of::<MyVertex>();
@@ -70,6 +71,46 @@ use types::DataType;
 /// # }
 /// ```
 ///
+/// # Deriving
+/// With the `derive` feature enabled, `VertexData` can be derived for
+/// `#[repr(C)]` and `#[repr(C, packed)]` structs using
+/// `#[derive(VertexData)]`. Other representations (such as
+/// `#[repr(C, packed(2))]` or `#[repr(C, align(16))]`) fail to compile. The
+/// `derive` feature requires Rust 1.30 or later. Each field must
+/// implement [`VertexDatum`](trait.VertexDatum.html), and can be customized
+/// with the following attributes:
+///
+/// - `#[glitter(name = "a_pos")]`: Use `"a_pos"` as the attribute's name,
+///   instead of the field's name.
+/// - `#[glitter(normalized)]`: Normalize fixed-point data when it is
+///   accessed (so a `[u8; 4]` maps to a `vec4` with components between
+///   `0.0` and `1.0`).
+/// - `#[glitter(integer)]`: Pass the attribute to the shader as integers
+///   (such as an `ivec4`), rather than converting it to floating-point
+///   values.
+/// - `#[glitter(components = 3)]`: Override the number of components that
+///   make up the attribute. This must be between 1 and 4, and must not be
+///   greater than the number of components in the field's type. Values
+///   outside of 1 to 4 (or larger than the length of an array field) fail
+///   to compile, and `visit_attributes` panics for any other field type
+///   with too few components.
+/// - `#[glitter(nested)]`: Embed a field that is itself a `VertexData`
+///   type, including all of its attributes.
+/// - `#[glitter(skip)]`: Don't use the field as a vertex attribute.
+///
+/// ```ignore
+/// #[macro_use] extern crate glitter;
+///
+/// #[derive(Clone, Copy, VertexData)]
+/// #[repr(C)]
+/// struct MyVertex {
+///     #[glitter(name = "a_pos")]
+///     position: [f32; 3],
+///     #[glitter(normalized)]
+///     color: [u8; 4]
+/// }
+/// ```
+///
 /// # See also
 /// [`impl_vertx_data!`](../macro.impl_vertex_data!.html): A macro that
 /// automatically implements `VertexData` for structs.
@@ -86,6 +127,84 @@ pub unsafe trait VertexData: Copy {
     fn visit_attributes<F>(f: F) where F: FnMut(VertexAttribute);
 }
 
+/// Call the given function with each attribute of the `VertexData` type
+/// `T`, adjusted as if `T` were embedded within another vertex type. The
+/// offset of each attribute is moved forward by `offset` (the offset of the
+/// embedded `T` within the outer type), and the stride of each attribute is
+/// replaced with `stride` (the size of the outer type). This is used to
+/// implement `VertexData` for types that contain other `VertexData` types,
+/// such as with the `nested` option of [`impl_vertex_data!`]
+/// (../macro.impl_vertex_data!.html).
+pub fn visit_nested_attributes<T, F>(offset: usize, stride: usize, mut f: F)
+    where T: VertexData, F: FnMut(VertexAttribute)
+{
+    T::visit_attributes(|attrib| {
+        f(VertexAttribute {
+            offset: offset + attrib.offset,
+            stride: stride,
+            ..attrib
+        });
+    });
+}
+
+/// A vertex layout that is split across multiple buffers, or "slots"
+/// (a structure-of-arrays layout). Each slot holds its own [`VertexData`]
+/// (trait.VertexData.html) type; for example, a skinned mesh could keep
+/// its positions in one buffer and its bone indices and weights in another,
+/// using the layout `(Position, Skinning)`.
+///
+/// `VertexLayout` is implemented for tuples of up to four `VertexData`
+/// types, where the first element is slot 0, the second element is slot 1,
+/// and so on. A multi-buffer layout can be used with a [`MultiVertexBuffer`]
+/// (../vertex_buffer/struct.MultiVertexBuffer.html).
+///
+/// # Safety
+/// The attributes passed to the visitor function must be correct for the
+/// `VertexData` type of the slot they are passed with. See the
+/// [`VertexData`](trait.VertexData.html) docs for more details.
+pub unsafe trait VertexLayout {
+    /// Return the number of slots (buffers) that make up this layout.
+    fn slot_count() -> usize;
+
+    /// Return the size (in bytes) of a single vertex in the given slot.
+    ///
+    /// # Panics
+    /// This function will panic if `slot` is out of range.
+    fn slot_stride(slot: usize) -> usize;
+
+    /// Call the given function with each attribute of this layout, along
+    /// with the slot the attribute belongs to.
+    fn visit_slot_attributes<F>(f: F) where F: FnMut(usize, VertexAttribute);
+}
+
+macro_rules! impl_vertex_layout {
+    ($count:expr; $($slot:expr => $T:ident),+) => {
+        unsafe impl<$($T: VertexData),+> VertexLayout for ($($T,)+) {
+            fn slot_count() -> usize {
+                $count
+            }
+
+            fn slot_stride(slot: usize) -> usize {
+                match slot {
+                    $($slot => { mem::size_of::<$T>() },)+
+                    _ => { panic!("Vertex layout slot out of range: {}", slot); }
+                }
+            }
+
+            fn visit_slot_attributes<F>(mut f: F)
+                where F: FnMut(usize, VertexAttribute)
+            {
+                $($T::visit_attributes(|attrib| f($slot, attrib));)+
+            }
+        }
+    }
+}
+
+impl_vertex_layout!(1; 0 => A);
+impl_vertex_layout!(2; 0 => A, 1 => B);
+impl_vertex_layout!(3; 0 => A, 1 => B, 2 => C);
+impl_vertex_layout!(4; 0 => A, 1 => B, 2 => C, 3 => D);
+
 /// A single value that can be treated as a part of a vertex. Implementors
 /// should map to a GLSL primitive that can be used as a vertex attribute.
 /// For example, `[f32; 2]` corresponds to `vec2` in memory layout
@@ -126,7 +245,7 @@ pub unsafe trait VertexPrimitive: Copy {
 /// Specifies the type, name, and memory layout of a vertex attribute.
 /// Generally, a "vertex attribute" corresponds to a field in a [`VertexData`]
 /// (trait.VertexData.html) struct.
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct VertexAttribute {
     /// The type of the vertex attribute. This also specifies the number
     /// of bytes that make up a vertex attribute.
@@ -149,7 +268,7 @@ pub struct VertexAttribute {
 
 /// Used to specify type of a vertex attribute. The size of the vertex
 /// attribute is `size_of(data) * components`.
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct VertexAttributeType {
     /// The type of data that makes up this vertex attribute.
     pub data: DataType,
@@ -161,7 +280,15 @@ pub struct VertexAttributeType {
     /// should be normalized when being accessed. `true` indicates
     /// that the vertex attribute **should** be normalized when being
     /// accessed.
-    pub normalize: bool
+    pub normalize: bool,
+
+    /// Indicates if the vertex attribute should be passed to the shader
+    /// as integers (such as an `ivec4` or `uvec4`), rather than being
+    /// converted to floating-point values. `true` requires that `data`
+    /// is an integral type, and causes `normalize` to be ignored. Integer
+    /// attributes are bound using [`gl.vertex_attrib_i_pointer`]
+    /// (../context/buffer_context/trait.ContextBufferExt.html#method.vertex_attrib_i_pointer).
+    pub integer: bool
 }
 
 
@@ -182,6 +309,14 @@ unsafe impl VertexPrimitive for u16 {
     fn data_type() -> DataType { DataType::UnsignedShort }
 }
 
+unsafe impl VertexPrimitive for i32 {
+    fn data_type() -> DataType { DataType::Int }
+}
+
+unsafe impl VertexPrimitive for u32 {
+    fn data_type() -> DataType { DataType::UnsignedInt }
+}
+
 unsafe impl VertexPrimitive for f32 {
     fn data_type() -> DataType { DataType::Float }
 }
@@ -191,7 +326,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for T {
         VertexAttributeType {
             data: T::data_type(),
             components: 1,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -201,7 +337,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for [T; 1] {
         VertexAttributeType {
             data: T::data_type(),
             components: 1,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -211,7 +348,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for [T; 2] {
         VertexAttributeType {
             data: T::data_type(),
             components: 2,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -221,7 +359,8 @@ unsafe impl<T: VertexPrimitive> VertexDatum for [T; 3] {
         VertexAttributeType {
             data: T::data_type(),
             components: 3,
-            normalize: false
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -231,7 +370,80 @@ unsafe impl<T: VertexPrimitive> VertexDatum for [T; 4] {
         VertexAttributeType {
             data: T::data_type(),
             components: 4,
-            normalize: false
+            normalize: false,
+            integer: false
+        }
+    }
+}
+
+
+
+/// Four signed components packed into a single 32-bit value, which maps to
+/// a `vec4` in GLSL. The first three components have 10 bits each, and the
+/// fourth component has 2 bits. Packed data is typically normalized (for
+/// example, to store normals in a quarter of the space of a `[f32; 4]`),
+/// which can be set with the `normalized` option of [`impl_vertex_data!`]
+/// (../macro.impl_vertex_data!.html).
+///
+/// # Example
+/// ```
+/// let packed = glitter::Int2_10_10_10Rev::new(-1, 0, 511, 1);
+/// assert_eq!(packed.0, 0x5ff003ff);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Int2_10_10_10Rev(pub u32);
+
+impl Int2_10_10_10Rev {
+    /// Pack four components into a single value. `x`, `y`, and `z` should
+    /// be between -512 and 511, and `w` should be between -2 and 1; any
+    /// additional bits are discarded.
+    pub fn new(x: i16, y: i16, z: i16, w: i8) -> Self {
+        Int2_10_10_10Rev((x as u32 & 0x3ff)
+                         | (y as u32 & 0x3ff) << 10
+                         | (z as u32 & 0x3ff) << 20
+                         | (w as u32 & 0x3) << 30)
+    }
+}
+
+unsafe impl VertexDatum for Int2_10_10_10Rev {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: DataType::Int2_10_10_10Rev,
+            components: 4,
+            normalize: false,
+            integer: false
+        }
+    }
+}
+
+/// Four unsigned components packed into a single 32-bit value, which maps
+/// to a `vec4` in GLSL. The first three components have 10 bits each, and
+/// the fourth component has 2 bits. See [`Int2_10_10_10Rev`]
+/// (struct.Int2_10_10_10Rev.html) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct UnsignedInt2_10_10_10Rev(pub u32);
+
+impl UnsignedInt2_10_10_10Rev {
+    /// Pack four components into a single value. `x`, `y`, and `z` should
+    /// be between 0 and 1023, and `w` should be between 0 and 3; any
+    /// additional bits are discarded.
+    pub fn new(x: u16, y: u16, z: u16, w: u8) -> Self {
+        UnsignedInt2_10_10_10Rev((x as u32 & 0x3ff)
+                                 | (y as u32 & 0x3ff) << 10
+                                 | (z as u32 & 0x3ff) << 20
+                                 | (w as u32 & 0x3) << 30)
+    }
+}
+
+unsafe impl VertexDatum for UnsignedInt2_10_10_10Rev {
+    fn attrib_type() -> VertexAttributeType {
+        VertexAttributeType {
+            data: DataType::UnsignedInt2_10_10_10Rev,
+            components: 4,
+            normalize: false,
+            integer: false
         }
     }
 }
@@ -311,6 +523,29 @@ macro_rules! offset_of {
 // [`VertexDatum::attrib_type`](trait.VertexDatum.html#tymethod.attrib_type)
 /// notes, implementors should be aware of this already.
 ///
+/// # Attribute options
+/// Each field can optionally be followed by a set of options in braces,
+/// which override the values that would otherwise be derived from the
+/// field's type:
+///
+/// - `name: "a_pos"`: Use `"a_pos"` as the attribute's name, instead of
+///   the field's name.
+/// - `normalized: true`: Normalize fixed-point data when it is accessed
+///   (so a `[u8; 4]` maps to a `vec4` with components between `0.0` and
+///   `1.0`).
+/// - `integer: true`: Pass the attribute to the shader as integers (such as
+///   an `ivec4`), rather than converting it to floating-point values.
+/// - `components: 3`: Override the number of components that make up the
+///   attribute. This must be at least 1, and must not be greater than the
+///   number of components in the field's type (otherwise, the attribute
+///   would read past the end of the field), or `visit_attributes` will
+///   panic.
+///
+/// A field that is itself a `VertexData` type can be embedded by writing
+/// `field: nested` instead. The attributes of the embedded type are
+/// included as-is (using their own names), with their offsets adjusted to
+/// point within the outer type.
+///
 /// # Examples
 ///
 /// ```
@@ -329,9 +564,110 @@ macro_rules! offset_of {
 /// impl_vertex_data!(MyVertex, position, color);
 /// # }
 /// ```
+///
+/// ```
+/// #[macro_use] extern crate glitter;
+///
+/// # fn main() {
+/// use glitter::VertexData;
+///
+/// #[derive(Clone, Copy)]
+/// struct MyVertex {
+///     position: [f32; 4],
+///     color: [u8; 4]
+/// }
+///
+/// // Use "a_pos" as the shader attribute name for `position`, only
+/// // use the first 3 components of `position`, and map each `u8`
+/// // of `color` to a normalized `float`.
+/// impl_vertex_data!(MyVertex,
+///                   position { name: "a_pos", components: 3 },
+///                   color { normalized: true });
+///
+/// let mut attribs = vec![];
+/// MyVertex::visit_attributes(|attrib| attribs.push(attrib));
+/// assert_eq!(attribs[0].name, "a_pos");
+/// assert_eq!(attribs[0].ty.components, 3);
+/// assert_eq!(attribs[1].name, "color");
+/// assert!(attribs[1].ty.normalize);
+/// # }
+/// ```
+///
+/// ```
+/// #[macro_use] extern crate glitter;
+///
+/// # fn main() {
+/// use glitter::VertexData;
+///
+/// #[derive(Clone, Copy)]
+/// struct PositionNormal {
+///     position: [f32; 3],
+///     normal: [f32; 3]
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// struct TexturedVertex {
+///     base: PositionNormal,
+///     tex_coord: [f32; 2]
+/// }
+///
+/// impl_vertex_data!(PositionNormal, position, normal);
+///
+/// // Reuse the "position" and "normal" attributes of `PositionNormal`
+/// impl_vertex_data!(TexturedVertex, base: nested, tex_coord);
+///
+/// let mut attribs = vec![];
+/// TexturedVertex::visit_attributes(|attrib| attribs.push(attrib));
+/// let names: Vec<_> = attribs.iter().map(|a| a.name.clone()).collect();
+/// assert_eq!(names, ["position", "normal", "tex_coord"]);
+/// assert_eq!(attribs[1].stride, ::std::mem::size_of::<TexturedVertex>());
+/// # }
+/// ```
 #[macro_export]
 macro_rules! impl_vertex_data {
-    ($name:ty, $($field_name:ident),*) => {
+    (@option $attrib:ident, name, $value:expr) => {
+        $attrib.name = ($value).into();
+    };
+    (@option $attrib:ident, normalized, $value:expr) => {
+        $attrib.ty.normalize = $value;
+    };
+    (@option $attrib:ident, integer, $value:expr) => {
+        $attrib.ty.integer = $value;
+    };
+    (@option $attrib:ident, components, $value:expr) => {
+        let components = $value;
+        assert!(components >= 1 && components <= $attrib.ty.components,
+                "`components: {}` for attribute {:?} must be between 1 and the {} components of the field's type",
+                components,
+                $attrib.name,
+                $attrib.ty.components);
+        $attrib.ty.components = components;
+    };
+    (@fields $name:ty, $f:ident, $data:ident; $(,)*) => { };
+    (@fields $name:ty, $f:ident, $data:ident;
+     $field_name:ident: nested, $($rest:tt)*) => {
+        visit_nested(&$data.$field_name,
+                     offset_of!($name, $field_name),
+                     ::std::mem::size_of::<$name>(),
+                     &mut $f);
+        impl_vertex_data!(@fields $name, $f, $data; $($rest)*);
+    };
+    (@fields $name:ty, $f:ident, $data:ident;
+     $field_name:ident $({ $($option:ident: $value:expr),* })*, $($rest:tt)*) => {
+        #[allow(unused_mut)]
+        let mut attrib = $crate::VertexAttribute {
+            ty: get_attribute_type(&$data.$field_name),
+            name: stringify!($field_name).into(),
+            stride: ::std::mem::size_of::<$name>(),
+            offset: offset_of!($name, $field_name)
+        };
+        $($(
+            impl_vertex_data!(@option attrib, $option, $value);
+        )*)*
+        $f(attrib);
+        impl_vertex_data!(@fields $name, $f, $data; $($rest)*);
+    };
+    ($name:ty, $($fields:tt)*) => {
         unsafe impl $crate::VertexData for $name {
             fn visit_attributes<F>(mut f: F)
                 where F: FnMut($crate::VertexAttribute)
@@ -339,19 +675,23 @@ macro_rules! impl_vertex_data {
                 // TODO: A better way of iterating over field types
                 // TODO: Panic safety (using `catch_panic`/`recover`)
                 let _data: $name = unsafe { ::std::mem::uninitialized() };
+                #[allow(dead_code)]
                 fn get_attribute_type<T: $crate::VertexDatum>(_: &T)
                     -> $crate::VertexAttributeType
                 {
                     T::attrib_type()
                 }
-                $(
-                    f($crate::VertexAttribute {
-                        ty: get_attribute_type(&_data.$field_name),
-                        name: stringify!($field_name).into(),
-                        stride: ::std::mem::size_of::<$name>(),
-                        offset: offset_of!($name, $field_name)
-                    });
-                )*
+                #[allow(dead_code)]
+                fn visit_nested<T, F>(_: &T, offset: usize, stride: usize, f: F)
+                    where T: $crate::VertexData,
+                          F: FnMut($crate::VertexAttribute)
+                {
+                    $crate::vertex_data::visit_nested_attributes::<T, F>(offset,
+                                                                         stride,
+                                                                         f);
+                }
+
+                impl_vertex_data!(@fields $name, f, _data; $($fields)*,);
 
                 ::std::mem::forget(_data);
             }
@@ -0,0 +1,105 @@
+// Tests that check the attributes generated by `#[derive(VertexData)]`. Run
+// with `cargo test --features derive`.
+
+#![cfg(feature = "derive")]
+
+#[macro_use] extern crate glitter;
+
+use std::mem;
+use glitter::VertexAttribute;
+
+fn attributes<V: glitter::VertexData>() -> Vec<VertexAttribute> {
+    let mut attributes = vec![];
+    V::visit_attributes(|attribute| attributes.push(attribute));
+    attributes
+}
+
+// Get the offset of a field from its address within `value`
+fn field_offset<T, F>(value: &T, field: &F) -> usize {
+    field as *const F as usize - value as *const T as usize
+}
+
+#[derive(Clone, Copy, VertexData)]
+#[repr(C)]
+struct Vertex {
+    #[glitter(name = "a_pos")]
+    position: [f32; 3],
+    #[glitter(normalized)]
+    color: [u8; 3],
+    // Padded to a 4-byte boundary after `color`
+    weight: f32,
+    #[glitter(skip)]
+    _id: u16,
+    tex_coord: [u16; 2]
+}
+
+#[derive(Clone, Copy, VertexData)]
+#[repr(C)]
+struct Instance {
+    scale: u8,
+    #[glitter(nested)]
+    vertex: Vertex
+}
+
+#[derive(Clone, Copy, VertexData)]
+#[repr(C, packed)]
+struct PackedVertex {
+    flag: u8,
+    position: [f32; 2]
+}
+
+fn vertex() -> Vertex {
+    Vertex {
+        position: [0.0, 0.0, 0.0],
+        color: [0, 0, 0],
+        weight: 0.0,
+        _id: 0,
+        tex_coord: [0, 0]
+    }
+}
+
+#[test]
+fn offsets_match_the_field_addresses() {
+    let vertex = vertex();
+    let attributes = attributes::<Vertex>();
+
+    let names: Vec<_> = attributes.iter().map(|a| &a.name[..]).collect();
+    assert_eq!(names, ["a_pos", "color", "weight", "tex_coord"]);
+
+    let offsets: Vec<_> = attributes.iter().map(|a| a.offset).collect();
+    assert_eq!(offsets, [field_offset(&vertex, &vertex.position),
+                         field_offset(&vertex, &vertex.color),
+                         field_offset(&vertex, &vertex.weight),
+                         field_offset(&vertex, &vertex.tex_coord)]);
+    for attribute in &attributes {
+        assert_eq!(attribute.stride, mem::size_of::<Vertex>());
+    }
+    assert!(attributes[1].ty.normalize);
+}
+
+#[test]
+fn nested_offsets_match_the_field_addresses() {
+    let instance = Instance { scale: 0, vertex: vertex() };
+    let attributes = attributes::<Instance>();
+
+    let vertex = &instance.vertex;
+    let offsets: Vec<_> = attributes.iter().map(|a| a.offset).collect();
+    assert_eq!(offsets, [field_offset(&instance, &instance.scale),
+                         field_offset(&instance, &vertex.position),
+                         field_offset(&instance, &vertex.color),
+                         field_offset(&instance, &vertex.weight),
+                         field_offset(&instance, &vertex.tex_coord)]);
+    for attribute in &attributes {
+        assert_eq!(attribute.stride, mem::size_of::<Instance>());
+    }
+}
+
+#[test]
+fn packed_offsets_have_no_padding() {
+    let attributes = attributes::<PackedVertex>();
+
+    let offsets: Vec<_> = attributes.iter().map(|a| a.offset).collect();
+    assert_eq!(offsets, [0, 1]);
+    assert_eq!(attributes[0].stride, 9);
+    assert_eq!(mem::size_of::<PackedVertex>(), 9);
+}
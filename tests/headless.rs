@@ -0,0 +1,96 @@
+// Tests that render with a real OpenGL ES implementation, using a headless
+// EGL context. Run with `cargo test --features headless`. Each test is
+// skipped (and passes) when no EGL device is available, such as on CI
+// machines without Mesa.
+
+#![cfg(feature = "headless")]
+
+extern crate glitter;
+
+use glitter::prelude::*;
+use glitter::Image2d;
+use glitter::headless::HeadlessContext;
+
+fn headless_context() -> Option<(HeadlessContext, glitter::Context)> {
+    match unsafe { HeadlessContext::new() } {
+        Ok(context) => Some(context),
+        Err(e) => {
+            println!("Skipping test, no EGL device is available: {}", e);
+            None
+        }
+    }
+}
+
+#[test]
+fn queries_the_context() {
+    let (_headless, gl) = match headless_context() {
+        Some(context) => context,
+        None => { return; }
+    };
+
+    let version = gl.extensions().version().expect("No OpenGL version");
+    assert!(version.es);
+    assert!(version.is_at_least(2, 0));
+    assert!(gl.limits().max_texture_size >= 64);
+}
+
+#[test]
+fn clears_a_framebuffer() {
+    let (_headless, mut gl) = match headless_context() {
+        Some(context) => context,
+        None => { return; }
+    };
+
+    let mut renderbuffer = gl.build_renderbuffer()
+                             .storage(glitter::RGBA4, 4, 4)
+                             .unwrap();
+    let mut fbo = gl.build_framebuffer()
+                    .renderbuffer(glitter::COLOR_ATTACHMENT0,
+                                  &mut renderbuffer)
+                    .unwrap();
+
+    gl.clear_color(glitter::Color::rgba(1.0, 0.0, 0.0, 1.0));
+    let (_gl_fbo, gl) = gl.bind_framebuffer(&mut fbo);
+    gl.viewport(glitter::Viewport::new(0, 0, 4, 4));
+    gl.clear(glitter::COLOR_BUFFER_BIT);
+
+    let pixels = gl.read_pixels(glitter::Viewport::new(0, 0, 4, 4));
+    assert_eq!((pixels.width(), pixels.height()), (4, 4));
+    for texel in pixels.texel_bytes().chunks(4) {
+        assert_eq!(texel, [255, 0, 0, 255]);
+    }
+}
+
+#[test]
+fn clear_rect_stays_inside_the_scissor_box() {
+    let (_headless, mut gl) = match headless_context() {
+        Some(context) => context,
+        None => { return; }
+    };
+
+    let mut renderbuffer = gl.build_renderbuffer()
+                             .storage(glitter::RGBA4, 4, 4)
+                             .unwrap();
+    let mut fbo = gl.build_framebuffer()
+                    .renderbuffer(glitter::COLOR_ATTACHMENT0,
+                                  &mut renderbuffer)
+                    .unwrap();
+
+    gl.clear_color(glitter::Color::rgba(0.0, 0.0, 0.0, 1.0));
+    let (_gl_fbo, mut gl) = gl.bind_framebuffer(&mut fbo);
+    gl.viewport(glitter::Viewport::new(0, 0, 4, 4));
+    gl.clear(glitter::COLOR_BUFFER_BIT);
+
+    gl.enable(glitter::SCISSOR_TEST);
+    gl.scissor(glitter::Rect::new(0, 0, 2, 4));
+    gl.clear_rect(glitter::Rect::new(1, 0, 3, 4),
+                  glitter::COLOR_BUFFER_BIT,
+                  glitter::Color::rgba(1.0, 0.0, 0.0, 1.0));
+
+    let pixels = gl.read_pixels(glitter::Viewport::new(0, 0, 4, 4));
+    for (i, texel) in pixels.texel_bytes().chunks(4).enumerate() {
+        let expected = if i % 4 == 1 { [255, 0, 0, 255] }
+                       else { [0, 0, 0, 255] };
+        assert_eq!(texel, expected, "texel {}", i);
+    }
+}
@@ -0,0 +1,299 @@
+// Tests that drive glitter through the mock OpenGL implementation. Run with
+// `cargo test --features mock`.
+
+#![cfg(feature = "mock")]
+
+#[macro_use] extern crate glitter;
+
+use glitter::prelude::*;
+use glitter::mock;
+
+const GL_VERSION: u32 = 0x1F02;
+
+// Load the mock implementation and get a context for it, discarding the
+// calls made while querying the context. Canned values that the context
+// queries (such as extensions) need to be set before calling this.
+fn mock_context() -> glitter::Context {
+    unsafe { mock::load(); }
+    let gl = unsafe { glitter::Context::current_context() };
+    mock::take_calls();
+    gl
+}
+
+fn call_names() -> Vec<&'static str> {
+    mock::take_calls().iter().map(|call| call.name).collect()
+}
+
+#[test]
+fn buffer_upload() {
+    let gl = mock_context();
+
+    let mut buffer = gl.gen_buffer();
+    let (mut gl_buffer, gl) = gl.bind_array_buffer(&mut buffer);
+    gl.buffer_bytes(&mut gl_buffer, &[1, 2, 3, 4], glitter::STATIC_DRAW);
+
+    let calls = mock::take_calls();
+    let names: Vec<_> = calls.iter().map(|call| call.name).collect();
+    assert_eq!(names, ["glGenBuffers", "glBindBuffer", "glBufferData"]);
+    assert_eq!(calls[2].args[1], "4");
+}
+
+#[test]
+fn adopt_checks_the_object() {
+    let gl = mock_context();
+
+    let id = gl.gen_buffer().id();
+    assert!(unsafe { glitter::Buffer::adopt(id, &gl) }.is_ok());
+    assert!(unsafe { glitter::Buffer::adopt(id + 100, &gl) }.is_err());
+}
+
+#[test]
+fn debug_groups_use_khr_debug() {
+    mock::set_extensions(&["GL_KHR_debug"]);
+    let gl = mock_context();
+
+    {
+        let _group = gl.debug_group("Shadow pass");
+        gl.insert_debug_marker("Draw");
+    }
+
+    assert_eq!(call_names(),
+               ["glPushDebugGroup", "glDebugMessageInsert", "glPopDebugGroup"]);
+}
+
+#[test]
+fn debug_groups_fall_back_to_ext_debug_marker() {
+    mock::set_extensions(&["GL_EXT_debug_marker"]);
+    let gl = mock_context();
+
+    {
+        let _group = gl.debug_group("Shadow pass");
+        gl.insert_debug_marker("Draw");
+    }
+
+    assert_eq!(call_names(),
+               ["glPushGroupMarkerEXT",
+                "glInsertEventMarkerEXT",
+                "glPopGroupMarkerEXT"]);
+}
+
+#[test]
+fn graphics_reset_status() {
+    mock::set_extensions(&["GL_KHR_robustness"]);
+    let gl = mock_context();
+
+    assert_eq!(gl.graphics_reset_status(), None);
+    assert_eq!(call_names(), ["glGetGraphicsResetStatus"]);
+}
+
+#[test]
+fn validate_program_uses_the_bound_program() {
+    const GL_CURRENT_PROGRAM: u32 = 0x8B8D;
+
+    let gl = mock_context();
+    let mut program = unsafe { gl.create_program() }.unwrap();
+    let program_id = program.id();
+    let (gl_program, gl) = gl.use_program(&mut program);
+    mock::set_integer(GL_CURRENT_PROGRAM, &[999]);
+    mock::take_calls();
+
+    assert!(gl.validate_program(&gl_program).is_ok());
+    let calls = mock::take_calls();
+    assert_eq!(calls[0].name, "glValidateProgram");
+    assert_eq!(calls[0].args, [program_id.to_string()]);
+}
+
+#[test]
+fn shader_binary_ignores_earlier_errors() {
+    const GL_INVALID_ENUM: u32 = 0x0500;
+
+    let gl = mock_context();
+    let mut shaders = unsafe {
+        vec![gl.create_shader(glitter::VERTEX_SHADER).unwrap()]
+    };
+    let format = glitter::ShaderBinaryFormat { gl_enum: 0x9130 };
+    mock::push_error(GL_INVALID_ENUM);
+
+    assert!(gl.shader_binary(&mut shaders, format, &[0, 1, 2, 3]).is_ok());
+}
+
+#[test]
+fn push_indices_grows_the_buffer() {
+    let gl = mock_context();
+    let mut ibo = gl.new_index_buffer::<u16>();
+    let (mut gl_ibo, gl) = gl.bind_index_buffer(&mut ibo);
+
+    gl.push_indices(&mut gl_ibo, &[0, 1, 2], glitter::DYNAMIC_DRAW).unwrap();
+    mock::take_calls();
+    gl.push_indices(&mut gl_ibo, &[2, 3, 0], glitter::DYNAMIC_DRAW).unwrap();
+
+    assert_eq!(mock::call_count("glCopyBufferSubData"), 2);
+}
+
+#[test]
+fn growing_a_buffer_requires_copy_buffer() {
+    mock::set_string(GL_VERSION, "OpenGL ES 2.0 Mock");
+    let gl = mock_context();
+    let mut ibo = gl.new_index_buffer::<u16>();
+    let (mut gl_ibo, gl) = gl.bind_index_buffer(&mut ibo);
+
+    gl.push_indices(&mut gl_ibo, &[0, 1, 2], glitter::DYNAMIC_DRAW).unwrap();
+    match gl.push_indices(&mut gl_ibo, &[2, 3, 0], glitter::DYNAMIC_DRAW) {
+        Err(glitter::GLError::Unsupported(_)) => { },
+        result => { panic!("Expected an unsupported error, got {:?}", result); }
+    }
+}
+
+#[test]
+fn each_context_keeps_its_own_extensions() {
+    mock::set_extensions(&["GL_KHR_debug"]);
+    let gl_a = mock_context();
+    mock::set_extensions(&[]);
+    let gl_b = mock_context();
+
+    assert!(gl_a.get_extensions().has("GL_KHR_debug"));
+    assert!(!gl_b.get_extensions().has("GL_KHR_debug"));
+}
+
+#[test]
+fn current_context_clears_pending_errors() {
+    const GL_INVALID_OPERATION: u32 = 0x0502;
+
+    mock::push_error(GL_INVALID_OPERATION);
+    let _gl = mock_context();
+
+    assert!(glitter::Context::get_error().is_none());
+}
+
+#[test]
+fn dyn_texture_bindings_hold_the_active_texture_unit() {
+    use glitter::{DynBindError, DynSlot};
+
+    let gl = glitter::DynContext::new(mock_context());
+    let mut texture: glitter::Texture2d = unsafe { gl.gen_texture() };
+    let mut cube_map: glitter::TextureCubeMap = unsafe { gl.gen_texture() };
+    let mut other_texture: glitter::Texture2d = unsafe { gl.gen_texture() };
+
+    {
+        let _gl_texture = gl.bind_texture_2d(0, &mut texture).unwrap();
+        let _gl_cube_map = gl.bind_texture_cube_map(0, &mut cube_map)
+                             .unwrap();
+        assert!(gl.is_bound(DynSlot::ActiveTexture(0)));
+        assert_eq!(gl.bind_texture_2d(1, &mut other_texture).err(),
+                   Some(DynBindError::AlreadyBound(DynSlot::ActiveTexture(0))));
+    }
+
+    assert!(!gl.is_bound(DynSlot::ActiveTexture(0)));
+    assert!(gl.bind_texture_2d(1, &mut other_texture).is_ok());
+}
+
+#[derive(Clone, Copy)]
+struct Vertex {
+    position: [f32; 2]
+}
+
+impl_vertex_data!(Vertex, position);
+
+#[test]
+fn failed_vertex_buffer_bind_returns_the_context() {
+    let gl = mock_context();
+    let mut vbo = gl.new_vertex_buffer::<Vertex>();
+
+    let gl = match gl.try_bind_vertex_buffer(&mut vbo) {
+        Err((gl, glitter::VertexBindError::NoAttributeBindings)) => gl,
+        _ => { panic!("Expected a missing attribute bindings error"); }
+    };
+    let mut buffer = gl.gen_buffer();
+    let _ = gl.bind_array_buffer(&mut buffer);
+}
+
+#[test]
+fn per_vertex_attributes_reset_their_divisor() {
+    let gl = mock_context();
+    let mut vbo = gl.new_vertex_buffer::<Vertex>();
+    let mut binder = glitter::AttribBinder::new();
+    binder.add("position", glitter::ProgramAttrib { gl_index: 0 }).unwrap();
+    vbo.bind_attrib_pointers(binder);
+    let _ = gl.bind_vertex_buffer(&mut vbo);
+
+    let calls = mock::take_calls();
+    let divisor = calls.iter()
+                       .find(|call| call.name == "glVertexAttribDivisor")
+                       .expect("The divisor wasn't reset");
+    assert_eq!(divisor.args, ["0", "0"]);
+}
+
+#[derive(Clone, Copy)]
+struct TexCoord {
+    tex_coord: [f32; 2]
+}
+
+impl_vertex_data!(TexCoord, tex_coord);
+
+#[test]
+#[should_panic(expected = "don't match layout slot 1")]
+fn buffer_slot_vertices_checks_the_slot_attributes() {
+    let gl = mock_context();
+    let mut mvbo = gl.new_multi_vertex_buffer::<(Vertex, TexCoord)>();
+    let mut binder = glitter::AttribBinder::new();
+    binder.add("position", glitter::ProgramAttrib { gl_index: 0 }).unwrap();
+    binder.add("tex_coord", glitter::ProgramAttrib { gl_index: 1 }).unwrap();
+    mvbo.bind_attrib_pointers(binder);
+    let (mut gl_mvbo, gl) = gl.bind_multi_vertex_buffer(&mut mvbo);
+
+    // `Vertex` has the same size as `TexCoord`, but different attributes
+    let vertices = [Vertex { position: [0.0, 0.0] }];
+    gl.buffer_slot_vertices(&mut gl_mvbo, 1, &vertices, glitter::STATIC_DRAW);
+}
+
+#[test]
+fn drawing_modes_require_shader_stages() {
+    mock::set_string(GL_VERSION, "OpenGL ES 2.0 Mock");
+    let gl = mock_context();
+
+    assert!(glitter::TRIANGLES.check_supported(gl.extensions()).is_ok());
+    match glitter::PATCHES.check_supported(gl.extensions()) {
+        Err(glitter::GLError::Unsupported(_)) => { },
+        result => { panic!("Expected an unsupported error, got {:?}", result); }
+    }
+
+    mock::set_extensions(&["GL_EXT_geometry_shader"]);
+    let gl = mock_context();
+    assert!(glitter::LINES_ADJACENCY.check_supported(gl.extensions()).is_ok());
+}
+
+#[test]
+fn unfinished_error_scopes_report_to_the_debug_callback() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const GL_INVALID_VALUE: u32 = 0x0501;
+
+    mock::set_extensions(&["GL_KHR_debug"]);
+    let mut gl = mock_context();
+    let messages = Rc::new(RefCell::new(vec![]));
+    let callback_messages = messages.clone();
+    gl.debug_message_callback(move |_, _, _, _, message| {
+        callback_messages.borrow_mut().push(message.to_string());
+    }).unwrap();
+
+    {
+        let _scope = gl.error_scope("upload");
+        mock::push_error(GL_INVALID_VALUE);
+    }
+
+    let messages = messages.borrow();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("upload"));
+}
+
+#[test]
+#[should_panic(expected = "upload")]
+fn unfinished_error_scopes_panic_without_a_debug_callback() {
+    const GL_INVALID_VALUE: u32 = 0x0501;
+
+    let gl = mock_context();
+
+    let _scope = gl.error_scope("upload");
+    mock::push_error(GL_INVALID_VALUE);
+}